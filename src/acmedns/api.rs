@@ -0,0 +1,301 @@
+//! Low-level acme-dns API client.
+//!
+//! This module provides direct access to the acme-dns HTTP API.
+//! For most use cases, prefer using [`AcmeDnsProvider`](super::AcmeDnsProvider) instead.
+//!
+//! acme-dns only has two relevant endpoints: `/register` creates a new
+//! subdomain with its own credentials, and `/update` sets the TXT value of a
+//! subdomain, authenticated with the `X-Api-User` and `X-Api-Key` headers.
+//!
+//! # API Reference
+//!
+//! - [acme-dns API](https://github.com/joohoi/acme-dns#api)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::acmedns::api::{Client, Credentials, RegisterRequest};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("https://auth.example.org")?;
+//!
+//! let credentials = client.register(&RegisterRequest::default()).await?;
+//! credentials.save("acme-dns.json")?;
+//!
+//! let credentials = Credentials::load("acme-dns.json")?;
+//! client
+//!     .update(&credentials, "___validation_token_received_from_the_ca___")
+//!     .await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::io::Write;
+use std::path::Path;
+
+use reqwest::header::HeaderValue;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// Errors that may occur when interacting with the acme-dns API.
+#[derive(Debug, Error)]
+pub enum AcmeDnsError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message (e.g., `forbidden` or `bad_txt`).
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+
+    /// Failed to read or write persisted credentials.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The credentials contain characters that cannot be sent in a header.
+    #[error("Invalid credentials: {0}")]
+    InvalidCredentials(#[from] reqwest::header::InvalidHeaderValue),
+}
+
+impl AcmeDnsError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            AcmeDnsError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// acme-dns error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    error: String,
+}
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// Credentials of a registered acme-dns subdomain.
+///
+/// acme-dns only returns the password once, at registration, so the
+/// credentials must be persisted; they serialize to the same JSON object
+/// the `/register` endpoint returns.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Credentials {
+    /// API user, sent as `X-Api-User`.
+    pub username: String,
+    /// API key, sent as `X-Api-Key`.
+    pub password: String,
+    /// Fully qualified name serving the TXT record, to be used as the target
+    /// of the `_acme-challenge` CNAME.
+    pub fulldomain: String,
+    /// Subdomain label, sent with updates.
+    pub subdomain: String,
+    /// Networks allowed to update the subdomain; empty allows all.
+    #[serde(default)]
+    pub allowfrom: Vec<String>,
+}
+
+impl Credentials {
+    /// Loads credentials from a JSON file.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, AcmeDnsError> {
+        let text = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Saves the credentials to a JSON file, replacing its contents.
+    ///
+    /// On Unix, the file is only readable and writable by its owner
+    /// (mode `0600`), since it contains the API key.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), AcmeDnsError> {
+        let json = serde_json::to_string_pretty(self)?;
+
+        let mut options = std::fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::OpenOptionsExt;
+            options.mode(0o600);
+        }
+
+        let mut file = options.open(path)?;
+        // The mode only applies to new files; restrict existing ones too.
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+        }
+        file.write_all(json.as_bytes())?;
+        Ok(())
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("fulldomain", &self.fulldomain)
+            .field("subdomain", &self.subdomain)
+            .field("allowfrom", &self.allowfrom)
+            .finish()
+    }
+}
+
+/// Request body for registering a subdomain.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct RegisterRequest {
+    /// Networks (CIDR) allowed to update the subdomain; empty allows all.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub allowfrom: Vec<String>,
+}
+
+/// Request body for updating the TXT value of a subdomain.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateRequest<'a> {
+    /// Subdomain label.
+    pub subdomain: &'a str,
+    /// New TXT value (a 43 character ACME validation token).
+    pub txt: &'a str,
+}
+
+/// Response of a TXT update.
+#[derive(Debug, Clone, Deserialize)]
+pub struct UpdateResponse {
+    /// The TXT value that was set.
+    pub txt: String,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// acme-dns API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new acme-dns API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the acme-dns server (e.g., `https://auth.example.org`)
+    pub fn new(base_url: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_config(base_url, HttpClientConfig::default())
+    }
+
+    /// Creates a new acme-dns API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the acme-dns server (e.g., `https://auth.example.org`)
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`AcmeDnsError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, AcmeDnsError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => e.error,
+                Err(_) => text,
+            };
+            return Err(AcmeDnsError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Registers a new subdomain and returns its credentials.
+    pub async fn register(&self, request: &RegisterRequest) -> Result<Credentials, AcmeDnsError> {
+        let url = format!("{}/register", self.base_url);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Sets the TXT value of the subdomain the credentials belong to.
+    ///
+    /// acme-dns keeps the two most recent values, so a certificate covering
+    /// both a domain and its wildcard can be validated at the same time.
+    pub async fn update(
+        &self,
+        credentials: &Credentials,
+        txt: &str,
+    ) -> Result<UpdateResponse, AcmeDnsError> {
+        let url = format!("{}/update", self.base_url);
+        let mut key = HeaderValue::from_str(&credentials.password)?;
+        key.set_sensitive(true);
+
+        let request = UpdateRequest {
+            subdomain: &credentials.subdomain,
+            txt,
+        };
+
+        let text = self
+            .send(
+                self.http_client
+                    .post(url)
+                    .header("X-Api-User", HeaderValue::from_str(&credentials.username)?)
+                    .header("X-Api-Key", key)
+                    .json(&request),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+}
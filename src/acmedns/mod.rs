@@ -0,0 +1,269 @@
+//! acme-dns provider implementation.
+//!
+//! This provider sets ACME DNS-01 challenge tokens on an
+//! [acme-dns](https://github.com/joohoi/acme-dns) server. Instead of handing
+//! out registrar credentials, a domain owner points
+//! `_acme-challenge.example.com` at a registered acme-dns subdomain with a
+//! CNAME record, and the challenge TXT value is then set through acme-dns.
+//!
+//! # Authentication
+//!
+//! Each registered subdomain has its own credentials, sent as the
+//! `X-Api-User` and `X-Api-Key` headers. acme-dns only returns them once, at
+//! registration, so they have to be persisted:
+//! [`Credentials`] serialize to the JSON returned by `/register` and can be
+//! stored with [`Credentials::save`] and read back with [`Credentials::load`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::acmedns::{AcmeDnsProvider, Credentials};
+//! use manydns::{CreateRecord, Provider, RecordData};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = AcmeDnsProvider::new("https://auth.example.org")?;
+//!
+//! // Register once and persist the credentials
+//! let credentials = provider.register(&[]).await?;
+//! credentials.save("acme-dns.json")?;
+//! println!("CNAME _acme-challenge.example.com to {}", credentials.fulldomain);
+//!
+//! // Later, set a challenge token
+//! let provider = provider.with_credentials([Credentials::load("acme-dns.json")?]);
+//! let zone = provider.get_zone(&credentials.fulldomain).await?;
+//! zone.create_record(
+//!     "_acme-challenge",
+//!     &RecordData::TXT("___validation_token_received_from_the_ca___".to_string()),
+//!     120,
+//! )
+//! .await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones and Records
+//!
+//! Each set of credentials is exposed as a zone, identified by its
+//! subdomain or full domain. A zone holds a single TXT name, so the host
+//! passed to [`CreateRecord::create_record`] is ignored and the value is
+//! always written to the registered name. The TTL is fixed by the server.
+//!
+//! acme-dns keeps the two most recent values and has no API to read or
+//! delete them: [`Zone::list_records`] always returns an empty list,
+//! [`Zone::get_record`] always fails with
+//! [`RetrieveRecordError::NotFound`], and [`DeleteRecord`](crate::DeleteRecord)
+//! is not implemented. Old values are simply replaced by newer ones.
+//!
+//! # Supported Record Types
+//!
+//! - TXT only; all other types fail with [`CreateRecordError::UnsupportedType`]
+//!
+//! # API Reference
+//!
+//! - [acme-dns API](https://github.com/joohoi/acme-dns#api)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{AcmeDnsError, Client, Credentials};
+
+use crate::{
+    CreateRecord, CreateRecordError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// acme-dns provider.
+#[derive(Clone)]
+pub struct AcmeDnsProvider {
+    api_client: Arc<Client>,
+    credentials: Vec<Credentials>,
+}
+
+/// A registered acme-dns subdomain.
+pub struct AcmeDnsZone {
+    api_client: Arc<Client>,
+    credentials: Credentials,
+}
+
+impl AcmeDnsProvider {
+    /// Creates a new acme-dns provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the acme-dns server (e.g., `https://auth.example.org`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::acmedns::AcmeDnsProvider;
+    ///
+    /// let provider = AcmeDnsProvider::new("https://auth.example.org").unwrap();
+    /// ```
+    pub fn new(base_url: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        Self::with_config(base_url, HttpClientConfig::default())
+    }
+
+    /// Creates a new acme-dns provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the acme-dns server (e.g., `https://auth.example.org`)
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::acmedns::AcmeDnsProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = AcmeDnsProvider::with_config("https://auth.example.org", config).unwrap();
+    /// ```
+    pub fn with_config(
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(base_url, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            credentials: Vec::new(),
+        })
+    }
+
+    /// Sets the credentials of the subdomains exposed as zones.
+    pub fn with_credentials<I>(mut self, credentials: I) -> Self
+    where
+        I: IntoIterator<Item = Credentials>,
+    {
+        self.credentials = credentials.into_iter().collect();
+        self
+    }
+
+    /// Registers a new subdomain on the server.
+    ///
+    /// The returned credentials are neither saved nor added to the
+    /// provider. acme-dns only returns the password once, so the caller must
+    /// persist them with [`Credentials::save`] and pass them to
+    /// [`with_credentials`](Self::with_credentials) or [`zone`](Self::zone).
+    ///
+    /// # Arguments
+    ///
+    /// * `allow_from` - Networks (CIDR) allowed to update the subdomain; empty allows all
+    pub async fn register(&self, allow_from: &[&str]) -> Result<Credentials, AcmeDnsError> {
+        let request = api::RegisterRequest {
+            allowfrom: allow_from.iter().map(|net| net.to_string()).collect(),
+        };
+        self.api_client.register(&request).await
+    }
+
+    /// Returns the zone of the given credentials, without requiring them to be
+    /// configured on the provider.
+    pub fn zone(&self, credentials: Credentials) -> AcmeDnsZone {
+        AcmeDnsZone {
+            api_client: self.api_client.clone(),
+            credentials,
+        }
+    }
+}
+
+impl Provider for AcmeDnsProvider {
+    type Zone = AcmeDnsZone;
+    type CustomRetrieveError = AcmeDnsError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone_id = zone_id.trim_end_matches('.');
+
+        self.credentials
+            .iter()
+            .find(|credentials| {
+                credentials.subdomain.eq_ignore_ascii_case(zone_id)
+                    || credentials
+                        .fulldomain
+                        .trim_end_matches('.')
+                        .eq_ignore_ascii_case(zone_id)
+            })
+            .map(|credentials| self.zone(credentials.clone()))
+            .ok_or(RetrieveZoneError::NotFound)
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        Ok(self
+            .credentials
+            .iter()
+            .map(|credentials| self.zone(credentials.clone()))
+            .collect())
+    }
+}
+
+impl AcmeDnsZone {
+    /// Returns the credentials of the subdomain.
+    pub fn credentials(&self) -> &Credentials {
+        &self.credentials
+    }
+}
+
+impl Zone for AcmeDnsZone {
+    type CustomRetrieveError = AcmeDnsError;
+
+    fn id(&self) -> &str {
+        &self.credentials.subdomain
+    }
+
+    fn domain(&self) -> &str {
+        self.credentials.fulldomain.trim_end_matches('.')
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // acme-dns has no API to read values back.
+        Ok(Vec::new())
+    }
+
+    async fn get_record(
+        &self,
+        _record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        Err(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for AcmeDnsZone {
+    type CustomCreateError = AcmeDnsError;
+
+    async fn create_record(
+        &self,
+        _host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let RecordData::TXT(txt) = data else {
+            return Err(CreateRecordError::UnsupportedType);
+        };
+
+        let response = self
+            .api_client
+            .update(&self.credentials, txt)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(Record {
+            id: format!("@/TXT/{}", response.txt),
+            host: "@".to_string(),
+            data: RecordData::TXT(response.txt),
+            ttl,
+        })
+    }
+}
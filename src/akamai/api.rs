@@ -0,0 +1,869 @@
+//! Low-level Akamai Edge DNS API client.
+//!
+//! This module provides direct access to the Edge DNS Zone Management API
+//! (`/config-dns/v2`) using EdgeGrid (`EG1-HMAC-SHA256`) request signing.
+//! For most use cases, prefer using [`AkamaiProvider`](super::AkamaiProvider) instead.
+//!
+//! # Request Signing
+//!
+//! Each request carries an `Authorization` header computed from the API
+//! client credentials, a timestamp and a nonce:
+//!
+//! ```text
+//! AuthPrefix  = EG1-HMAC-SHA256 client_token=CT;access_token=AT;timestamp=TS;nonce=NONCE;
+//! SigningKey  = base64(hmac_sha256(client_secret, TS))
+//! DataToSign  = METHOD \t https \t HOST \t PATH?QUERY \t HEADERS \t CONTENT_HASH \t AuthPrefix
+//! Signature   = base64(hmac_sha256(SigningKey, DataToSign))
+//! Header      = AuthPrefix signature=Signature
+//! ```
+//!
+//! The content hash is the base64 SHA-256 of the body of `POST` requests and
+//! empty otherwise.
+//!
+//! # Changelists
+//!
+//! Besides editing record sets directly, Edge DNS supports changelists: a
+//! copy of the zone that collects changes which are then submitted as a
+//! single new zone version. They are the preferred way to apply large
+//! modifications.
+//!
+//! # API Reference
+//!
+//! - [Edge DNS Zone Management API](https://techdocs.akamai.com/edge-dns/reference/edge-dns-api)
+//! - [EdgeGrid authentication](https://techdocs.akamai.com/developer/docs/authenticate-with-edgegrid)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::akamai::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new(
+//!     "akab-xxxxxxxxxxxxxxxx-xxxxxxxxxxxxxxxx.luna.akamaiapis.net",
+//!     "akab-client-token",
+//!     "client-secret",
+//!     "akab-access-token",
+//! );
+//! let client = Client::new(credentials)?;
+//!
+//! for zone in client.list_zones().await? {
+//!     println!("{} ({})", zone.zone, zone.zone_type);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::signing::hmac_sha256;
+use crate::HttpClientConfig;
+
+/// Signing algorithm name.
+const ALGORITHM: &str = "EG1-HMAC-SHA256";
+
+/// Maximum number of body bytes included in the content hash.
+const MAX_BODY: usize = 131072;
+
+/// Page size requested from list endpoints.
+const PAGE_SIZE: u64 = 1000;
+
+/// Errors that may occur when interacting with the Edge DNS API.
+#[derive(Debug, Error)]
+pub enum AkamaiError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {title}: {detail}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Problem title (e.g., `Not Found`).
+        title: String,
+        /// Problem detail.
+        detail: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl AkamaiError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            AkamaiError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Problem details (RFC 7807) returned on errors.
+#[derive(Debug, Deserialize)]
+struct ProblemDetails {
+    #[serde(default)]
+    title: String,
+    #[serde(default)]
+    detail: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// EdgeGrid API client credentials, as found in an `.edgerc` section.
+#[derive(Clone)]
+pub struct Credentials {
+    /// API host (e.g., `akab-xxx.luna.akamaiapis.net`), without a scheme.
+    pub host: String,
+    /// Client token.
+    pub client_token: String,
+    /// Client secret.
+    pub client_secret: String,
+    /// Access token.
+    pub access_token: String,
+}
+
+impl Credentials {
+    /// Creates credentials from the values of an API client.
+    pub fn new(
+        host: impl Into<String>,
+        client_token: impl Into<String>,
+        client_secret: impl Into<String>,
+        access_token: impl Into<String>,
+    ) -> Self {
+        Self {
+            host: host.into(),
+            client_token: client_token.into(),
+            client_secret: client_secret.into(),
+            access_token: access_token.into(),
+        }
+    }
+
+    /// Reads credentials from the `AKAMAI_HOST`, `AKAMAI_CLIENT_TOKEN`,
+    /// `AKAMAI_CLIENT_SECRET` and `AKAMAI_ACCESS_TOKEN` environment variables.
+    pub fn from_env() -> Option<Self> {
+        Some(Self::new(
+            std::env::var("AKAMAI_HOST").ok()?,
+            std::env::var("AKAMAI_CLIENT_TOKEN").ok()?,
+            std::env::var("AKAMAI_CLIENT_SECRET").ok()?,
+            std::env::var("AKAMAI_ACCESS_TOKEN").ok()?,
+        ))
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("host", &self.host)
+            .field("client_token", &self.client_token)
+            .field("client_secret", &"<redacted>")
+            .field("access_token", &"<redacted>")
+            .finish()
+    }
+}
+
+/// A request to be signed with EdgeGrid.
+#[derive(Debug, Clone)]
+pub struct SigningRequest<'a> {
+    /// HTTP method (e.g., `GET`).
+    pub method: &'a str,
+    /// Host header value (including a non-default port).
+    pub host: &'a str,
+    /// Path and query string as sent (e.g., `/config-dns/v2/zones?page=1`).
+    pub path_and_query: &'a str,
+    /// Request body.
+    pub body: &'a [u8],
+    /// Request timestamp in `YYYYMMDDTHH:MM:SS+0000` format.
+    pub timestamp: &'a str,
+    /// Unique nonce for this request.
+    pub nonce: &'a str,
+}
+
+/// Computes the EdgeGrid `Authorization` header value for a request.
+///
+/// No headers are included in the signature.
+pub fn sign(credentials: &Credentials, request: &SigningRequest<'_>) -> String {
+    let auth_prefix = format!(
+        "{} client_token={};access_token={};timestamp={};nonce={};",
+        ALGORITHM,
+        credentials.client_token,
+        credentials.access_token,
+        request.timestamp,
+        request.nonce
+    );
+
+    let content_hash = if request.method == "POST" && !request.body.is_empty() {
+        use sha2::{Digest, Sha256};
+        let body = &request.body[..request.body.len().min(MAX_BODY)];
+        base64_encode(&Sha256::digest(body))
+    } else {
+        String::new()
+    };
+
+    let data_to_sign = [
+        request.method,
+        "https",
+        request.host,
+        request.path_and_query,
+        "",
+        &content_hash,
+        &auth_prefix,
+    ]
+    .join("\t");
+
+    let signing_key = base64_encode(&hmac_sha256(
+        credentials.client_secret.as_bytes(),
+        request.timestamp,
+    ));
+    let signature = base64_encode(&hmac_sha256(signing_key.as_bytes(), &data_to_sign));
+
+    format!("{}signature={}", auth_prefix, signature)
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    base64::engine::general_purpose::STANDARD.encode(data)
+}
+
+/// Returns the current time in `YYYYMMDDTHH:MM:SS+0000` format.
+fn timestamp_now() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap()
+        .format("%Y%m%dT%H:%M:%S+0000")
+        .to_string()
+}
+
+/// Returns a unique nonce in UUID format.
+fn nonce() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    let hex = format!(
+        "{:016x}{:08x}{:08x}",
+        nanos as u64,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed) as u32
+    );
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// An Edge DNS zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    /// Zone name (e.g., `example.com`).
+    pub zone: String,
+    /// Zone type (`PRIMARY`, `SECONDARY` or `ALIAS`).
+    #[serde(rename = "type")]
+    pub zone_type: String,
+    /// Comment.
+    #[serde(default)]
+    pub comment: Option<String>,
+    /// Contract the zone belongs to.
+    #[serde(default)]
+    pub contract_id: Option<String>,
+    /// Activation state (e.g., `ACTIVE`, `PENDING`).
+    #[serde(default)]
+    pub activation_state: Option<String>,
+    /// ID of the current zone version.
+    #[serde(default)]
+    pub version_id: Option<String>,
+    /// Whether DNSSEC signing is enabled.
+    #[serde(default)]
+    pub sign_and_serve: bool,
+}
+
+/// Pagination metadata of list responses.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListMetadata {
+    #[serde(default)]
+    total_elements: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZoneList {
+    #[serde(default)]
+    zones: Vec<Zone>,
+    #[serde(default)]
+    metadata: ListMetadata,
+}
+
+// =============================================================================
+// Record Set Types
+// =============================================================================
+
+/// A record set: all records of one name and type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordSet {
+    /// Fully-qualified owner name without a trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Record values in zone file format.
+    pub rdata: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordSetList {
+    #[serde(default)]
+    recordsets: Vec<RecordSet>,
+    #[serde(default)]
+    metadata: ListMetadata,
+}
+
+#[derive(Debug, Serialize)]
+struct RecordSets<'a> {
+    recordsets: &'a [RecordSet],
+}
+
+// =============================================================================
+// Changelist Types
+// =============================================================================
+
+/// Metadata of a changelist.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Changelist {
+    /// Zone the changelist belongs to.
+    pub zone: String,
+    /// Tag identifying the state of the changelist.
+    #[serde(default)]
+    pub change_tag: Option<String>,
+    /// Zone version the changelist was created from.
+    #[serde(default)]
+    pub zone_version_id: Option<String>,
+    /// Whether the zone changed since the changelist was created.
+    #[serde(default)]
+    pub stale: bool,
+}
+
+/// Operation of a changelist change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChangeOp {
+    /// Adds a record set.
+    Add,
+    /// Replaces an existing record set.
+    Edit,
+    /// Deletes a record set.
+    Delete,
+}
+
+/// A change to a record set within a changelist.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordSetChange {
+    /// Fully-qualified owner name without a trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Operation to apply.
+    pub op: ChangeOp,
+    /// TTL in seconds (ignored for deletions).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// Record values (ignored for deletions).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rdata: Vec<String>,
+}
+
+impl RecordSetChange {
+    /// Creates a change adding a record set.
+    pub fn add(record_set: RecordSet) -> Self {
+        Self {
+            name: record_set.name,
+            record_type: record_set.record_type,
+            op: ChangeOp::Add,
+            ttl: Some(record_set.ttl),
+            rdata: record_set.rdata,
+        }
+    }
+
+    /// Creates a change replacing a record set.
+    pub fn edit(record_set: RecordSet) -> Self {
+        Self {
+            op: ChangeOp::Edit,
+            ..Self::add(record_set)
+        }
+    }
+
+    /// Creates a change deleting a record set.
+    pub fn delete(name: impl Into<String>, record_type: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            record_type: record_type.into(),
+            op: ChangeOp::Delete,
+            ttl: None,
+            rdata: Vec::new(),
+        }
+    }
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Akamai Edge DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    credentials: Credentials,
+    base_url: String,
+    host: String,
+}
+
+impl Client {
+    /// Creates a new Edge DNS API client for the host of the credentials.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - EdgeGrid API client credentials
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_config(credentials, HttpClientConfig::default())
+    }
+
+    /// Creates a new Edge DNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - EdgeGrid API client credentials
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let base_url = format!("https://{}", credentials.host.trim_end_matches('/'));
+        Self::with_base_url(credentials, &base_url, config)
+    }
+
+    /// Creates a new Edge DNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers. Requests are
+    /// signed for the host of `base_url`.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - EdgeGrid API client credentials
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(base_url)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("base URL has no host".into()),
+        };
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            credentials,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            host,
+        })
+    }
+
+    /// Sends a signed request and returns the response body.
+    ///
+    /// `path_and_query` must already be percent-encoded.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path_and_query: &str,
+        body: Option<String>,
+    ) -> Result<String, AkamaiError> {
+        let body = body.unwrap_or_default();
+        let timestamp = timestamp_now();
+        let nonce = nonce();
+
+        let authorization = sign(
+            &self.credentials,
+            &SigningRequest {
+                method: method.as_str(),
+                host: &self.host,
+                path_and_query,
+                body: body.as_bytes(),
+                timestamp: &timestamp,
+                nonce: &nonce,
+            },
+        );
+
+        let mut request = self
+            .http_client
+            .request(method, format!("{}{}", self.base_url, path_and_query))
+            .header("Authorization", authorization)
+            .header(reqwest::header::ACCEPT, "application/json");
+        if !body.is_empty() {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (title, detail) = match serde_json::from_str::<ProblemDetails>(&text) {
+                Ok(p) => (p.title, p.detail),
+                Err(_) => (String::new(), text),
+            };
+            return Err(AkamaiError::Api {
+                status: status.as_u16(),
+                title,
+                detail,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a signed GET request and deserializes the JSON response.
+    async fn get<T: DeserializeOwned>(&self, path_and_query: &str) -> Result<T, AkamaiError> {
+        let text = self
+            .request(reqwest::Method::GET, path_and_query, None)
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists all zones the API client can access, following pagination.
+    pub async fn list_zones(&self) -> Result<Vec<Zone>, AkamaiError> {
+        let mut zones = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let list: ZoneList = self
+                .get(&format!(
+                    "/config-dns/v2/zones?page={}&pageSize={}&showAll=true",
+                    page, PAGE_SIZE
+                ))
+                .await?;
+            let count = list.zones.len();
+            zones.extend(list.zones);
+
+            if count == 0 || zones.len() as u64 >= list.metadata.total_elements {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(zones)
+    }
+
+    /// Gets a zone by name.
+    pub async fn get_zone(&self, zone: &str) -> Result<Zone, AkamaiError> {
+        self.get(&format!("/config-dns/v2/zones/{}", encode_component(zone)))
+            .await
+    }
+
+    // =========================================================================
+    // Record Set APIs
+    // =========================================================================
+
+    /// Lists the record sets of a zone, following pagination.
+    ///
+    /// `types` restricts the result to the given record types.
+    pub async fn list_record_sets(
+        &self,
+        zone: &str,
+        types: &[&str],
+    ) -> Result<Vec<RecordSet>, AkamaiError> {
+        let mut record_sets = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let mut path = format!(
+                "/config-dns/v2/zones/{}/recordsets?page={}&pageSize={}&sortBy=name,type",
+                encode_component(zone),
+                page,
+                PAGE_SIZE
+            );
+            if !types.is_empty() {
+                path.push_str(&format!("&types={}", encode_component(&types.join(","))));
+            }
+
+            let list: RecordSetList = self.get(&path).await?;
+            let count = list.recordsets.len();
+            record_sets.extend(list.recordsets);
+
+            if count == 0 || record_sets.len() as u64 >= list.metadata.total_elements {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(record_sets)
+    }
+
+    /// Gets the record set with a name and type, if it exists.
+    pub async fn get_record_set(
+        &self,
+        zone: &str,
+        name: &str,
+        record_type: &str,
+    ) -> Result<Option<RecordSet>, AkamaiError> {
+        match self.get(&record_set_path(zone, name, record_type)).await {
+            Ok(record_set) => Ok(Some(record_set)),
+            Err(err) if err.status() == Some(404) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Creates a record set.
+    pub async fn create_record_set(
+        &self,
+        zone: &str,
+        record_set: &RecordSet,
+    ) -> Result<RecordSet, AkamaiError> {
+        let text = self
+            .request(
+                reqwest::Method::POST,
+                &record_set_path(zone, &record_set.name, &record_set.record_type),
+                Some(serde_json::to_string(record_set)?),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Replaces an existing record set.
+    pub async fn update_record_set(
+        &self,
+        zone: &str,
+        record_set: &RecordSet,
+    ) -> Result<RecordSet, AkamaiError> {
+        let text = self
+            .request(
+                reqwest::Method::PUT,
+                &record_set_path(zone, &record_set.name, &record_set.record_type),
+                Some(serde_json::to_string(record_set)?),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a record set.
+    pub async fn delete_record_set(
+        &self,
+        zone: &str,
+        name: &str,
+        record_type: &str,
+    ) -> Result<(), AkamaiError> {
+        self.request(
+            reqwest::Method::DELETE,
+            &record_set_path(zone, name, record_type),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Creates several record sets at once.
+    pub async fn create_record_sets(
+        &self,
+        zone: &str,
+        record_sets: &[RecordSet],
+    ) -> Result<(), AkamaiError> {
+        let body = serde_json::to_string(&RecordSets {
+            recordsets: record_sets,
+        })?;
+        self.request(
+            reqwest::Method::POST,
+            &format!("/config-dns/v2/zones/{}/recordsets", encode_component(zone)),
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Changelist APIs
+    // =========================================================================
+
+    /// Creates a changelist from the current version of a zone.
+    ///
+    /// An existing stale changelist is replaced.
+    pub async fn create_changelist(&self, zone: &str) -> Result<Changelist, AkamaiError> {
+        let text = self
+            .request(
+                reqwest::Method::POST,
+                &format!(
+                    "/config-dns/v2/changelists?zone={}&overwrite=stale",
+                    encode_component(zone)
+                ),
+                None,
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Gets the changelist of a zone.
+    pub async fn get_changelist(&self, zone: &str) -> Result<Changelist, AkamaiError> {
+        self.get(&format!(
+            "/config-dns/v2/changelists/{}",
+            encode_component(zone)
+        ))
+        .await
+    }
+
+    /// Lists the record sets of a changelist, including pending changes.
+    pub async fn list_changelist_record_sets(
+        &self,
+        zone: &str,
+    ) -> Result<Vec<RecordSet>, AkamaiError> {
+        let list: RecordSetList = self
+            .get(&format!(
+                "/config-dns/v2/changelists/{}/recordsets",
+                encode_component(zone)
+            ))
+            .await?;
+        Ok(list.recordsets)
+    }
+
+    /// Adds a change to the changelist of a zone.
+    pub async fn add_change(
+        &self,
+        zone: &str,
+        change: &RecordSetChange,
+    ) -> Result<(), AkamaiError> {
+        self.request(
+            reqwest::Method::POST,
+            &format!(
+                "/config-dns/v2/changelists/{}/recordsets/add-change",
+                encode_component(zone)
+            ),
+            Some(serde_json::to_string(change)?),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Replaces all record sets of the changelist of a zone.
+    pub async fn replace_changelist_record_sets(
+        &self,
+        zone: &str,
+        record_sets: &[RecordSet],
+    ) -> Result<(), AkamaiError> {
+        let body = serde_json::to_string(&RecordSets {
+            recordsets: record_sets,
+        })?;
+        self.request(
+            reqwest::Method::PUT,
+            &format!(
+                "/config-dns/v2/changelists/{}/recordsets",
+                encode_component(zone)
+            ),
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Submits the changelist of a zone, activating a new zone version.
+    pub async fn submit_changelist(&self, zone: &str) -> Result<(), AkamaiError> {
+        self.request(
+            reqwest::Method::POST,
+            &format!(
+                "/config-dns/v2/changelists/{}/submit",
+                encode_component(zone)
+            ),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Discards the changelist of a zone.
+    pub async fn delete_changelist(&self, zone: &str) -> Result<(), AkamaiError> {
+        self.request(
+            reqwest::Method::DELETE,
+            &format!("/config-dns/v2/changelists/{}", encode_component(zone)),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Returns the path of a record set.
+fn record_set_path(zone: &str, name: &str, record_type: &str) -> String {
+    format!(
+        "/config-dns/v2/zones/{}/names/{}/types/{}",
+        encode_component(zone),
+        encode_component(name),
+        encode_component(record_type)
+    )
+}
+
+/// Percent-encodes a path segment or query value.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
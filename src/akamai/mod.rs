@@ -0,0 +1,531 @@
+//! Akamai Edge DNS provider implementation.
+//!
+//! This provider uses the Edge DNS Zone Management API (`/config-dns/v2`)
+//! with EdgeGrid request signing.
+//!
+//! # Authentication
+//!
+//! Requires EdgeGrid API client credentials:
+//! - Create an API client in Akamai Control Center under "Identity & Access"
+//! - Grant it `READ-WRITE` access to the "DNS—Zone Record Management" API
+//! - Use the `host`, `client_token`, `client_secret` and `access_token`
+//!   values of the generated `.edgerc` section
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::akamai::{AkamaiProvider, Credentials};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new(
+//!     "akab-xxxxxxxxxxxxxxxx-xxxxxxxxxxxxxxxx.luna.akamaiapis.net",
+//!     "akab-client-token",
+//!     "client-secret",
+//!     "akab-access-token",
+//! );
+//! let provider = AkamaiProvider::new(credentials)?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones
+//!
+//! The zone ID is the zone name. Zones are created through Akamai Control
+//! Center or the contract-specific zone API, so this provider does not
+//! implement [`CreateZone`](crate::CreateZone) or
+//! [`DeleteZone`](crate::DeleteZone).
+//!
+//! # RRset-based API
+//!
+//! Edge DNS manages records as record sets. Creating a record adds its value
+//! to the record set of the same name and type (creating the set if needed),
+//! and deleting a record removes the value, deleting the set once it is
+//! empty. All records in a set share one TTL, so creating a record also
+//! updates the TTL of its siblings.
+//!
+//! Record IDs use the format `"name/type/value"`, like the Huawei Cloud
+//! provider.
+//!
+//! # Bulk Updates
+//!
+//! Every record set change activates a new zone version. Large modifications
+//! should instead go through a changelist with
+//! [`AkamaiZone::apply_changes`], which submits all changes as a single
+//! version.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CAA, CNAME, MX, NS, PTR, SRV and TXT
+//!
+//! # API Reference
+//!
+//! - [Edge DNS API](https://techdocs.akamai.com/edge-dns/reference/edge-dns-api)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{AkamaiError, ChangeOp, Client, Credentials, RecordSet, RecordSetChange};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] =
+    &["A", "AAAA", "CAA", "CNAME", "MX", "NS", "PTR", "SRV", "TXT"];
+
+fn is_unauthorized(err: &AkamaiError) -> bool {
+    matches!(err.status(), Some(401 | 403))
+}
+
+/// Akamai Edge DNS provider.
+#[derive(Clone)]
+pub struct AkamaiProvider {
+    api_client: Arc<Client>,
+}
+
+/// A zone hosted by Akamai Edge DNS.
+pub struct AkamaiZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+}
+
+impl AkamaiProvider {
+    /// Creates a new Akamai Edge DNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - EdgeGrid API client credentials
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::akamai::{AkamaiProvider, Credentials};
+    ///
+    /// let credentials = Credentials::from_env().expect("AKAMAI_* variables not set");
+    /// let provider = AkamaiProvider::new(credentials).unwrap();
+    /// ```
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Akamai Edge DNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - EdgeGrid API client credentials
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::akamai::{AkamaiProvider, Credentials};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let credentials = Credentials::new(
+    ///     "akab-xxxx.luna.akamaiapis.net",
+    ///     "client-token",
+    ///     "client-secret",
+    ///     "access-token",
+    /// );
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = AkamaiProvider::with_config(credentials, config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Akamai Edge DNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - EdgeGrid API client credentials
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for AkamaiProvider {
+    type Zone = AkamaiZone;
+    type CustomRetrieveError = AkamaiError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                Some(404) => RetrieveZoneError::NotFound,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(AkamaiZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| AkamaiZone {
+                api_client: self.api_client.clone(),
+                repr: zone,
+            })
+            .collect())
+    }
+}
+
+impl AkamaiZone {
+    /// Returns the zone type (`PRIMARY`, `SECONDARY` or `ALIAS`).
+    pub fn zone_type(&self) -> &str {
+        &self.repr.zone_type
+    }
+
+    /// Returns the activation state (e.g., `ACTIVE`).
+    pub fn activation_state(&self) -> Option<&str> {
+        self.repr.activation_state.as_deref()
+    }
+
+    /// Returns whether DNSSEC signing is enabled.
+    pub fn sign_and_serve(&self) -> bool {
+        self.repr.sign_and_serve
+    }
+
+    /// Applies record set changes as a single new zone version.
+    ///
+    /// Creates a changelist for the zone, adds all changes to it and submits
+    /// it. If a change is rejected, the changelist is discarded and the zone
+    /// is left untouched.
+    ///
+    /// Record set names are fully qualified, without a trailing dot.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::akamai::{AkamaiProvider, RecordSet, RecordSetChange};
+    /// use manydns::Provider;
+    ///
+    /// # async fn example(provider: AkamaiProvider) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let zone = provider.get_zone("example.com").await?;
+    /// zone.apply_changes(&[
+    ///     RecordSetChange::add(RecordSet {
+    ///         name: "www.example.com".to_string(),
+    ///         record_type: "A".to_string(),
+    ///         ttl: 300,
+    ///         rdata: vec!["192.0.2.1".to_string(), "192.0.2.2".to_string()],
+    ///     }),
+    ///     RecordSetChange::delete("old.example.com", "CNAME"),
+    /// ])
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn apply_changes(&self, changes: &[RecordSetChange]) -> Result<(), AkamaiError> {
+        let zone = &self.repr.zone;
+        self.api_client.create_changelist(zone).await?;
+
+        for change in changes {
+            if let Err(err) = self.api_client.add_change(zone, change).await {
+                let _ = self.api_client.delete_changelist(zone).await;
+                return Err(err);
+            }
+        }
+
+        if let Err(err) = self.api_client.submit_changelist(zone).await {
+            let _ = self.api_client.delete_changelist(zone).await;
+            return Err(err);
+        }
+
+        Ok(())
+    }
+
+    /// Converts a host relative to the zone into a fully-qualified owner name.
+    fn owner_name(&self, host: &str) -> String {
+        let domain = &self.repr.zone;
+        let host = host.trim_end_matches('.');
+        if host == "@" || host.is_empty() {
+            domain.clone()
+        } else if host.eq_ignore_ascii_case(domain) || host.ends_with(&format!(".{}", domain)) {
+            host.to_string()
+        } else {
+            format!("{}.{}", host, domain)
+        }
+    }
+
+    /// Converts a fully-qualified owner name into a host relative to the zone.
+    fn host(&self, name: &str) -> String {
+        let name = name.trim_end_matches('.');
+        if name.eq_ignore_ascii_case(&self.repr.zone) {
+            "@".to_string()
+        } else if let Some(sub) = name.strip_suffix(&format!(".{}", self.repr.zone)) {
+            sub.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Converts all records of a record set into generic records.
+    fn records_from_rrset(&self, rrset: &RecordSet) -> Vec<Record> {
+        let host = self.host(&rrset.name);
+        rrset
+            .rdata
+            .iter()
+            .map(|value| Record {
+                id: format!("{}/{}/{}", host, rrset.record_type, value),
+                host: host.clone(),
+                data: record_data_from_value(&rrset.record_type, value),
+                ttl: rrset.ttl,
+            })
+            .collect()
+    }
+}
+
+impl Zone for AkamaiZone {
+    type CustomRetrieveError = AkamaiError;
+
+    fn id(&self) -> &str {
+        &self.repr.zone
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.zone
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .api_client
+            .list_record_sets(&self.repr.zone, &[])
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets
+            .iter()
+            .flat_map(|rrset| self.records_from_rrset(rrset))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let rrset = self
+            .api_client
+            .get_record_set(&self.repr.zone, &self.owner_name(parts[0]), parts[1])
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        self.records_from_rrset(&rrset)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for AkamaiZone {
+    type CustomCreateError = AkamaiError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.owner_name(host);
+        let value = value_from_record_data(data);
+
+        let map_err = |err: AkamaiError| match err.status() {
+            _ if is_unauthorized(&err) => CreateRecordError::Unauthorized,
+            Some(400 | 422) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        let existing = self
+            .api_client
+            .get_record_set(&self.repr.zone, &name, typ)
+            .await
+            .map_err(map_err)?;
+
+        match existing {
+            Some(mut rrset) => {
+                if rrset.rdata.contains(&value) {
+                    return Err(CreateRecordError::InvalidRecord);
+                }
+                rrset.rdata.push(value.clone());
+                rrset.ttl = ttl;
+                self.api_client
+                    .update_record_set(&self.repr.zone, &rrset)
+                    .await
+                    .map_err(map_err)?;
+            }
+            None => {
+                let rrset = RecordSet {
+                    name: name.clone(),
+                    record_type: typ.to_string(),
+                    ttl,
+                    rdata: vec![value.clone()],
+                };
+                self.api_client
+                    .create_record_set(&self.repr.zone, &rrset)
+                    .await
+                    .map_err(map_err)?;
+            }
+        }
+
+        let host = self.host(&name);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, value),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for AkamaiZone {
+    type CustomDeleteError = AkamaiError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, value) = (parts[0], parts[1], parts[2]);
+
+        let map_err = |err: AkamaiError| match err.status() {
+            _ if is_unauthorized(&err) => DeleteRecordError::Unauthorized,
+            Some(404) => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let mut rrset = self
+            .api_client
+            .get_record_set(&self.repr.zone, &self.owner_name(host), typ)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let count = rrset.rdata.len();
+        rrset.rdata.retain(|v| v != value);
+        if rrset.rdata.len() == count {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        if rrset.rdata.is_empty() {
+            self.api_client
+                .delete_record_set(&self.repr.zone, &rrset.name, &rrset.record_type)
+                .await
+                .map_err(map_err)
+        } else {
+            self.api_client
+                .update_record_set(&self.repr.zone, &rrset)
+                .await
+                .map_err(map_err)?;
+            Ok(())
+        }
+    }
+}
+
+/// Converts an Edge DNS rdata value into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT character strings are unquoted
+/// and concatenated.
+fn record_data_from_value(typ: &str, value: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, value.trim_end_matches('.')),
+        "TXT" => RecordData::from_raw(typ, &unquote_txt(value)),
+        _ => RecordData::from_raw(typ, value),
+    }
+}
+
+/// Converts [`RecordData`] into an Edge DNS rdata value.
+///
+/// Host names are made fully qualified with a trailing dot; TXT values are
+/// quoted.
+fn value_from_record_data(data: &RecordData) -> String {
+    match data {
+        RecordData::CNAME(_)
+        | RecordData::NS(_)
+        | RecordData::MX { .. }
+        | RecordData::SRV { .. } => {
+            format!("{}.", data.get_value().trim_end_matches('.'))
+        }
+        RecordData::TXT(txt) => quote_txt(txt),
+        _ => data.get_value(),
+    }
+}
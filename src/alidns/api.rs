@@ -0,0 +1,578 @@
+//! Low-level Alibaba Cloud DNS (Alidns) API client.
+//!
+//! This module provides direct access to the Alidns RPC API (version
+//! `2015-01-09`) using the HMAC-SHA1 signature algorithm.
+//!
+//! # Request Signing
+//!
+//! Every request carries the common parameters (`AccessKeyId`, `Timestamp`,
+//! `SignatureNonce`, ...) and a signature over the sorted query string:
+//!
+//! ```text
+//! StringToSign = GET&%2F&percentEncode(sorted, percent-encoded query)
+//! Signature    = base64(hmac_sha1(AccessKeySecret + "&", StringToSign))
+//! ```
+//!
+//! # API Reference
+//!
+//! - [API overview](https://www.alibabacloud.com/help/en/dns/api-alidns-2015-01-09-overview)
+//! - [Request signatures](https://www.alibabacloud.com/help/en/sdk/product-overview/rpc-mechanism)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::alidns::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-access-key-id", "your-access-key-secret")?;
+//!
+//! for record in client.describe_domain_records("example.com").await? {
+//!     println!("{} {} {} ({})", record.rr, record.record_type, record.value, record.line);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use hmac::{Hmac, Mac};
+use serde::{de::DeserializeOwned, Deserialize};
+use sha1::Sha1;
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Alidns API endpoint.
+const ALIDNS_API_URL: &str = "https://alidns.aliyuncs.com";
+
+/// API version.
+const API_VERSION: &str = "2015-01-09";
+
+/// Page size requested from `DescribeDomains` (the API maximum).
+const DOMAINS_PAGE_SIZE: u32 = 100;
+
+/// Page size requested from `DescribeDomainRecords` (the API maximum).
+const RECORDS_PAGE_SIZE: u32 = 500;
+
+/// Errors that may occur when interacting with the Alidns API.
+#[derive(Debug, Error)]
+pub enum AlidnsError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {code}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error code (e.g., `InvalidDomainName.NoExist`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl AlidnsError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            AlidnsError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            AlidnsError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// Alidns error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(rename = "Code")]
+    code: String,
+    #[serde(rename = "Message", default)]
+    message: String,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A domain from `DescribeDomains`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain ID.
+    #[serde(rename = "DomainId")]
+    pub domain_id: String,
+    /// Domain name.
+    #[serde(rename = "DomainName")]
+    pub domain_name: String,
+    /// Punycode of internationalized domain names.
+    #[serde(rename = "PunyCode", default)]
+    pub puny_code: Option<String>,
+    /// Number of records.
+    #[serde(rename = "RecordCount", default)]
+    pub record_count: Option<u64>,
+    /// Assigned name servers.
+    #[serde(rename = "DnsServers", default)]
+    pub dns_servers: DnsServers,
+    /// Edition of the DNS instance (e.g., `mianfei` for the free edition).
+    #[serde(rename = "VersionCode", default)]
+    pub version_code: Option<String>,
+    /// Domain group name.
+    #[serde(rename = "GroupName", default)]
+    pub group_name: Option<String>,
+}
+
+/// Name servers of a domain.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct DnsServers {
+    /// Name server host names.
+    #[serde(rename = "DnsServer", default)]
+    pub dns_server: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DescribeDomainsResponse {
+    #[serde(rename = "TotalCount", default)]
+    total_count: u64,
+    #[serde(rename = "Domains")]
+    domains: DomainList,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainList {
+    #[serde(rename = "Domain", default)]
+    domain: Vec<Domain>,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainRecord {
+    /// Record ID.
+    #[serde(rename = "RecordId")]
+    pub record_id: String,
+    /// Domain name the record belongs to.
+    #[serde(rename = "DomainName", default)]
+    pub domain_name: String,
+    /// Host record relative to the domain (`@` for the apex).
+    #[serde(rename = "RR")]
+    pub rr: String,
+    /// Record type.
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    /// Record value; for SRV records `priority weight port target`.
+    #[serde(rename = "Value")]
+    pub value: String,
+    /// TTL in seconds.
+    #[serde(rename = "TTL", default)]
+    pub ttl: u64,
+    /// Priority for MX records.
+    #[serde(rename = "Priority", default)]
+    pub priority: Option<u16>,
+    /// Resolution line (e.g., `default`, `telecom`, `unicom`, `oversea`).
+    #[serde(rename = "Line", default)]
+    pub line: String,
+    /// Record status (`ENABLE` or `DISABLE`).
+    #[serde(rename = "Status", default)]
+    pub status: Option<String>,
+    /// Whether the record is locked.
+    #[serde(rename = "Locked", default)]
+    pub locked: bool,
+    /// Load balancing weight.
+    #[serde(rename = "Weight", default)]
+    pub weight: Option<u32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DescribeDomainRecordsResponse {
+    #[serde(rename = "TotalCount", default)]
+    total_count: u64,
+    #[serde(rename = "DomainRecords")]
+    domain_records: RecordList,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordList {
+    #[serde(rename = "Record", default)]
+    record: Vec<DomainRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordIdResponse {
+    #[serde(rename = "RecordId")]
+    record_id: String,
+}
+
+/// Parameters of a new record for `AddDomainRecord`.
+#[derive(Debug, Clone)]
+pub struct AddRecordRequest {
+    /// Domain name.
+    pub domain_name: String,
+    /// Host record relative to the domain (`@` for the apex).
+    pub rr: String,
+    /// Record type.
+    pub record_type: String,
+    /// Record value.
+    pub value: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX records.
+    pub priority: Option<u16>,
+    /// Resolution line (`default` for all resolvers).
+    pub line: String,
+}
+
+// =============================================================================
+// Signature
+// =============================================================================
+
+/// Computes the HMAC-SHA1 RPC signature of a request.
+///
+/// `params` are all query parameters except `Signature`.
+pub fn rpc_signature(access_key_secret: &str, method: &str, params: &[(&str, &str)]) -> String {
+    let string_to_sign = format!(
+        "{}&{}&{}",
+        method,
+        percent_encode("/"),
+        percent_encode(&canonical_query(params))
+    );
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(format!("{}&", access_key_secret).as_bytes())
+        .expect("HMAC can take key of any size");
+    mac.update(string_to_sign.as_bytes());
+    base64::engine::general_purpose::STANDARD.encode(mac.finalize().into_bytes())
+}
+
+/// Builds the sorted, percent-encoded query string.
+fn canonical_query(params: &[(&str, &str)]) -> String {
+    let mut encoded: Vec<(String, String)> = params
+        .iter()
+        .map(|(k, v)| (percent_encode(k), percent_encode(v)))
+        .collect();
+    encoded.sort();
+    encoded
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// Percent-encodes a value as required by the RPC signature (RFC 3986).
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+/// Returns a unique value for `SignatureNonce`.
+fn signature_nonce() -> String {
+    static COUNTER: AtomicU64 = AtomicU64::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_nanos())
+        .unwrap_or_default();
+    format!(
+        "{:x}-{:x}-{:x}",
+        nanos,
+        std::process::id(),
+        COUNTER.fetch_add(1, Ordering::Relaxed)
+    )
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Alidns API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    access_key_id: String,
+    access_key_secret: String,
+}
+
+impl Client {
+    /// Creates a new Alidns API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_key_id` - AccessKey ID of a RAM user with Alidns permissions
+    /// * `access_key_secret` - AccessKey secret
+    pub fn new(
+        access_key_id: &str,
+        access_key_secret: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            access_key_id,
+            access_key_secret,
+            ALIDNS_API_URL,
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Alidns API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_key_id` - AccessKey ID of a RAM user with Alidns permissions
+    /// * `access_key_secret` - AccessKey secret
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        access_key_id: &str,
+        access_key_secret: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(access_key_id, access_key_secret, ALIDNS_API_URL, config)
+    }
+
+    /// Creates a new Alidns API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers, or to use a
+    /// regional endpoint such as `https://alidns.cn-hangzhou.aliyuncs.com`.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_key_id` - AccessKey ID of a RAM user with Alidns permissions
+    /// * `access_key_secret` - AccessKey secret
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        access_key_id: &str,
+        access_key_secret: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            access_key_id: access_key_id.to_string(),
+            access_key_secret: access_key_secret.to_string(),
+        })
+    }
+
+    /// Makes a signed API request.
+    async fn request<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, AlidnsError> {
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as i64)
+            .unwrap_or_default();
+        let timestamp = chrono::DateTime::from_timestamp(now, 0)
+            .unwrap_or_default()
+            .format("%Y-%m-%dT%H:%M:%SZ")
+            .to_string();
+        let nonce = signature_nonce();
+
+        let mut all_params: Vec<(&str, &str)> = vec![
+            ("Action", action),
+            ("Format", "JSON"),
+            ("Version", API_VERSION),
+            ("AccessKeyId", &self.access_key_id),
+            ("SignatureMethod", "HMAC-SHA1"),
+            ("SignatureVersion", "1.0"),
+            ("SignatureNonce", &nonce),
+            ("Timestamp", &timestamp),
+        ];
+        all_params.extend_from_slice(params);
+
+        let signature = rpc_signature(&self.access_key_secret, "GET", &all_params);
+        let url = format!(
+            "{}/?{}&Signature={}",
+            self.base_url,
+            canonical_query(&all_params),
+            percent_encode(&signature)
+        );
+
+        let response = self.http_client.get(url).send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (code, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.code, e.message),
+                Err(_) => (String::new(), text),
+            };
+            return Err(AlidnsError::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            });
+        }
+
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account (`DescribeDomains`), following pagination.
+    pub async fn describe_domains(&self) -> Result<Vec<Domain>, AlidnsError> {
+        self.describe_domains_with(&[]).await
+    }
+
+    /// Finds a domain by exact name (`DescribeDomains` with `SearchMode=EXACT`).
+    pub async fn find_domain(&self, domain_name: &str) -> Result<Option<Domain>, AlidnsError> {
+        let domains = self
+            .describe_domains_with(&[("KeyWord", domain_name), ("SearchMode", "EXACT")])
+            .await?;
+        Ok(domains
+            .into_iter()
+            .find(|domain| domain.domain_name.eq_ignore_ascii_case(domain_name)))
+    }
+
+    async fn describe_domains_with(
+        &self,
+        filters: &[(&str, &str)],
+    ) -> Result<Vec<Domain>, AlidnsError> {
+        let page_size = DOMAINS_PAGE_SIZE.to_string();
+        let mut domains = Vec::new();
+        let mut page_number = 1u32;
+
+        loop {
+            let page = page_number.to_string();
+            let mut params = vec![("PageNumber", page.as_str()), ("PageSize", &page_size)];
+            params.extend_from_slice(filters);
+
+            let response: DescribeDomainsResponse =
+                self.request("DescribeDomains", &params).await?;
+            let count = response.domains.domain.len();
+            domains.extend(response.domains.domain);
+
+            if count == 0 || domains.len() as u64 >= response.total_count {
+                break;
+            }
+            page_number += 1;
+        }
+
+        Ok(domains)
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a domain (`DescribeDomainRecords`), following pagination.
+    pub async fn describe_domain_records(
+        &self,
+        domain_name: &str,
+    ) -> Result<Vec<DomainRecord>, AlidnsError> {
+        let page_size = RECORDS_PAGE_SIZE.to_string();
+        let mut records = Vec::new();
+        let mut page_number = 1u32;
+
+        loop {
+            let page = page_number.to_string();
+            let params = [
+                ("DomainName", domain_name),
+                ("PageNumber", page.as_str()),
+                ("PageSize", &page_size),
+            ];
+
+            let response: DescribeDomainRecordsResponse =
+                self.request("DescribeDomainRecords", &params).await?;
+            let count = response.domain_records.record.len();
+            records.extend(response.domain_records.record);
+
+            if count == 0 || records.len() as u64 >= response.total_count {
+                break;
+            }
+            page_number += 1;
+        }
+
+        Ok(records)
+    }
+
+    /// Gets a record by ID (`DescribeDomainRecordInfo`).
+    pub async fn describe_domain_record_info(
+        &self,
+        record_id: &str,
+    ) -> Result<DomainRecord, AlidnsError> {
+        self.request("DescribeDomainRecordInfo", &[("RecordId", record_id)])
+            .await
+    }
+
+    /// Creates a record (`AddDomainRecord`) and returns its ID.
+    pub async fn add_domain_record(
+        &self,
+        request: &AddRecordRequest,
+    ) -> Result<String, AlidnsError> {
+        let ttl = request.ttl.to_string();
+        let priority = request.priority.map(|p| p.to_string());
+
+        let mut params = vec![
+            ("DomainName", request.domain_name.as_str()),
+            ("RR", request.rr.as_str()),
+            ("Type", request.record_type.as_str()),
+            ("Value", request.value.as_str()),
+            ("TTL", ttl.as_str()),
+            ("Line", request.line.as_str()),
+        ];
+        if let Some(priority) = &priority {
+            params.push(("Priority", priority));
+        }
+
+        let response: RecordIdResponse = self.request("AddDomainRecord", &params).await?;
+        Ok(response.record_id)
+    }
+
+    /// Deletes a record (`DeleteDomainRecord`).
+    pub async fn delete_domain_record(&self, record_id: &str) -> Result<(), AlidnsError> {
+        let _: RecordIdResponse = self
+            .request("DeleteDomainRecord", &[("RecordId", record_id)])
+            .await?;
+        Ok(())
+    }
+}
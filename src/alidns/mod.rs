@@ -0,0 +1,500 @@
+//! Alibaba Cloud DNS (Alidns) provider implementation.
+//!
+//! This provider uses the Alidns RPC API (version `2015-01-09`) with
+//! HMAC-SHA1 signed requests.
+//!
+//! # Authentication
+//!
+//! Requires an AccessKey pair:
+//! - Create a RAM user in the Alibaba Cloud console
+//! - Grant it the `AliyunDNSFullAccess` policy
+//! - Create an AccessKey for the user
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::alidns::AlidnsProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = AlidnsProvider::new("your-access-key-id", "your-access-key-secret")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone IDs
+//!
+//! Alidns addresses domains by name in its record APIs, so the zone ID is the
+//! domain name. The numeric Alidns domain ID is available from
+//! [`AlidnsZone::domain_id`].
+//!
+//! # Resolution Lines
+//!
+//! Records created through [`CreateRecord`] use the `default` line. Records on
+//! other lines (e.g., `telecom`, `unicom`, `oversea`) are listed like any other
+//! record; their line is available from [`api::DomainRecord::line`], and they
+//! can be created with [`Client::add_domain_record`].
+//!
+//! # Zone Management
+//!
+//! This provider does not implement [`CreateZone`](crate::CreateZone) or
+//! [`DeleteZone`](crate::DeleteZone).
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, REDIRECT_URL and FORWARD_URL, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [API overview](https://www.alibabacloud.com/help/en/dns/api-alidns-2015-01-09-overview)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{AlidnsError, Client};
+
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Record types that can be passed through as [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["CAA", "REDIRECT_URL", "FORWARD_URL"];
+
+/// Resolution line used for records created through [`CreateRecord`].
+const DEFAULT_LINE: &str = "default";
+
+/// Alibaba Cloud DNS provider.
+#[derive(Clone)]
+pub struct AlidnsProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone hosted by Alibaba Cloud DNS.
+pub struct AlidnsZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl AlidnsProvider {
+    /// Creates a new Alidns provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_key_id` - AccessKey ID
+    /// * `access_key_secret` - AccessKey secret
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::alidns::AlidnsProvider;
+    ///
+    /// let provider = AlidnsProvider::new("access-key-id", "access-key-secret").unwrap();
+    /// ```
+    pub fn new(
+        access_key_id: &str,
+        access_key_secret: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(access_key_id, access_key_secret)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Alidns provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_key_id` - AccessKey ID
+    /// * `access_key_secret` - AccessKey secret
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::alidns::AlidnsProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     AlidnsProvider::with_config("access-key-id", "access-key-secret", config).unwrap();
+    /// ```
+    pub fn with_config(
+        access_key_id: &str,
+        access_key_secret: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(access_key_id, access_key_secret, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Alidns provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `access_key_id` - AccessKey ID
+    /// * `access_key_secret` - AccessKey secret
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        access_key_id: &str,
+        access_key_secret: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(
+            access_key_id,
+            access_key_secret,
+            base_url,
+            HttpClientConfig::default(),
+        )?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+/// Returns whether an error code denotes missing or invalid credentials.
+fn is_unauthorized(err: &AlidnsError) -> bool {
+    matches!(err.status(), Some(401 | 403))
+        || err.code().is_some_and(|code| {
+            code.starts_with("InvalidAccessKeyId")
+                || code.starts_with("Forbidden")
+                || code == "SignatureDoesNotMatch"
+                || code == "IncompleteSignature"
+        })
+}
+
+/// Returns whether an error code denotes a missing domain or record.
+fn is_not_found(err: &AlidnsError) -> bool {
+    err.code().is_some_and(|code| {
+        matches!(
+            code,
+            "InvalidDomainName.NoExist"
+                | "DomainRecordNotBelongToUser"
+                | "InvalidRecordId.NotFound"
+                | "IncorrectDomainUser"
+        )
+    })
+}
+
+/// Returns whether an error code denotes an invalid record.
+fn is_invalid_record(err: &AlidnsError) -> bool {
+    err.code().is_some_and(|code| {
+        code.starts_with("DomainRecordDuplicate")
+            || code.starts_with("DomainRecordConflict")
+            || code.starts_with("InvalidRR")
+            || code.starts_with("InvalidValue")
+            || code.starts_with("InvalidTTL")
+            || code.starts_with("InvalidLine")
+            || code.starts_with("QuotaExceeded")
+    })
+}
+
+impl Provider for AlidnsProvider {
+    type Zone = AlidnsZone;
+    type CustomRetrieveError = AlidnsError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .find_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| {
+                if is_unauthorized(&err) {
+                    RetrieveZoneError::Unauthorized
+                } else if is_not_found(&err) {
+                    RetrieveZoneError::NotFound
+                } else {
+                    RetrieveZoneError::Custom(err)
+                }
+            })?
+            .ok_or(RetrieveZoneError::NotFound)?;
+
+        Ok(AlidnsZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self.api_client.describe_domains().await.map_err(|err| {
+            if is_unauthorized(&err) {
+                RetrieveZoneError::Unauthorized
+            } else {
+                RetrieveZoneError::Custom(err)
+            }
+        })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| AlidnsZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl AlidnsZone {
+    /// Returns the Alidns domain ID.
+    pub fn domain_id(&self) -> &str {
+        &self.repr.domain_id
+    }
+
+    /// Returns the name servers assigned to the domain.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.dns_servers.dns_server
+    }
+
+    /// Converts a host into a host record relative to the zone ("@" for the apex).
+    fn rr(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.domain_name;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Fetches a record and checks that it belongs to this zone.
+    async fn fetch_record(&self, record_id: &str) -> Result<api::DomainRecord, AlidnsError> {
+        let record = self
+            .api_client
+            .describe_domain_record_info(record_id)
+            .await?;
+
+        if !record.domain_name.is_empty()
+            && !record
+                .domain_name
+                .eq_ignore_ascii_case(&self.repr.domain_name)
+        {
+            return Err(AlidnsError::Api {
+                status: 400,
+                code: "DomainRecordNotBelongToUser".to_string(),
+                message: format!(
+                    "record {} does not belong to {}",
+                    record_id, self.repr.domain_name
+                ),
+            });
+        }
+
+        Ok(record)
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::DomainRecord) -> Record {
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority: record.priority.unwrap_or_default(),
+            mail_server: record.value.trim_end_matches('.').to_string(),
+        },
+        "TXT" => RecordData::TXT(record.value),
+        typ => match RecordData::from_raw(typ, &record.value) {
+            RecordData::CNAME(target) => {
+                RecordData::CNAME(target.trim_end_matches('.').to_string())
+            }
+            RecordData::NS(target) => RecordData::NS(target.trim_end_matches('.').to_string()),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target: target.trim_end_matches('.').to_string(),
+            },
+            data => data,
+        },
+    };
+
+    Record {
+        id: record.record_id,
+        host: record.rr,
+        data,
+        ttl: record.ttl,
+    }
+}
+
+/// Converts generic record data into an Alidns value and MX priority.
+fn value_from_record_data(
+    data: &RecordData,
+) -> Result<(String, Option<u16>), CreateRecordError<AlidnsError>> {
+    Ok(match data {
+        RecordData::A(_) | RecordData::AAAA(_) => (data.get_value(), None),
+        RecordData::CNAME(target) | RecordData::NS(target) => {
+            (target.trim_end_matches('.').to_string(), None)
+        }
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => (
+            mail_server.trim_end_matches('.').to_string(),
+            Some(*priority),
+        ),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => (
+            format!(
+                "{} {} {} {}",
+                priority,
+                weight,
+                port,
+                target.trim_end_matches('.')
+            ),
+            None,
+        ),
+        RecordData::TXT(text) => (text.clone(), None),
+        RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+            (value.clone(), None)
+        }
+        RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+    })
+}
+
+impl Zone for AlidnsZone {
+    type CustomRetrieveError = AlidnsError;
+
+    fn id(&self) -> &str {
+        &self.repr.domain_name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain_name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .describe_domain_records(&self.repr.domain_name)
+            .await
+            .map_err(|err| {
+                if is_unauthorized(&err) {
+                    RetrieveRecordError::Unauthorized
+                } else if is_not_found(&err) {
+                    RetrieveRecordError::NotFound
+                } else {
+                    RetrieveRecordError::Custom(err)
+                }
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record = self.fetch_record(record_id).await.map_err(|err| {
+            if is_unauthorized(&err) {
+                RetrieveRecordError::Unauthorized
+            } else if is_not_found(&err) {
+                RetrieveRecordError::NotFound
+            } else {
+                RetrieveRecordError::Custom(err)
+            }
+        })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for AlidnsZone {
+    type CustomCreateError = AlidnsError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let (value, priority) = value_from_record_data(data)?;
+        let request = api::AddRecordRequest {
+            domain_name: self.repr.domain_name.clone(),
+            rr: self.rr(host),
+            record_type: data.get_type().to_string(),
+            value,
+            ttl,
+            priority,
+            line: DEFAULT_LINE.to_string(),
+        };
+
+        let record_id = self
+            .api_client
+            .add_domain_record(&request)
+            .await
+            .map_err(|err| {
+                if is_unauthorized(&err) {
+                    CreateRecordError::Unauthorized
+                } else if is_invalid_record(&err) {
+                    CreateRecordError::InvalidRecord
+                } else {
+                    CreateRecordError::Custom(err)
+                }
+            })?;
+
+        Ok(Record {
+            id: record_id,
+            host: request.rr,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for AlidnsZone {
+    type CustomDeleteError = AlidnsError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let map_err = |err: AlidnsError| {
+            if is_unauthorized(&err) {
+                DeleteRecordError::Unauthorized
+            } else if is_not_found(&err) {
+                DeleteRecordError::NotFound
+            } else {
+                DeleteRecordError::Custom(err)
+            }
+        };
+
+        self.fetch_record(record_id).await.map_err(map_err)?;
+        self.api_client
+            .delete_domain_record(record_id)
+            .await
+            .map_err(map_err)
+    }
+}
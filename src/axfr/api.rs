@@ -0,0 +1,772 @@
+//! Low-level DNS zone transfer client.
+//!
+//! This module speaks the DNS wire protocol over TCP to request full (AXFR) and
+//! incremental (IXFR) zone transfers from a primary server.
+//! For most use cases, prefer using [`AxfrProvider`](super::AxfrProvider) instead.
+//!
+//! # Protocol Reference
+//!
+//! - [RFC 1035: Domain Names - Implementation and Specification](https://www.rfc-editor.org/rfc/rfc1035)
+//! - [RFC 1995: Incremental Zone Transfer in DNS](https://www.rfc-editor.org/rfc/rfc1995)
+//! - [RFC 5936: DNS Zone Transfer Protocol (AXFR)](https://www.rfc-editor.org/rfc/rfc5936)
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::axfr::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("192.0.2.53:53".parse()?);
+//!
+//! // Transfer the full zone
+//! let records = client.axfr("example.com").await?;
+//! for rr in &records {
+//!     println!("{} {} {}", rr.name, rr.type_name(), rr.rdata.to_presentation());
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddr};
+use std::sync::atomic::{AtomicU16, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use thiserror::Error;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpSocket, TcpStream};
+
+use crate::types::{DomainName, RecordClass, RecordType, SoaData};
+use crate::HttpClientConfig;
+
+/// The default DNS port.
+pub const DEFAULT_PORT: u16 = 53;
+
+/// Default timeout for a complete transfer when none is configured.
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Length of the fixed DNS message header.
+const HEADER_LEN: usize = 12;
+
+/// Maximum number of compression pointers followed while decoding a single name.
+const MAX_POINTER_HOPS: usize = 64;
+
+/// Query type code for IXFR (RFC 1995).
+const TYPE_IXFR: u16 = 251;
+
+/// Query type code for AXFR (RFC 5936).
+const TYPE_AXFR: u16 = 252;
+
+/// Errors that may occur during a zone transfer.
+#[derive(Debug, Error)]
+pub enum AxfrError {
+    /// A network I/O error occurred.
+    #[error("I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// The transfer did not complete within the configured timeout.
+    #[error("zone transfer timed out")]
+    Timeout,
+
+    /// The zone name cannot be encoded as a DNS name.
+    #[error("invalid zone name: {0}")]
+    InvalidName(String),
+
+    /// The server sent a message that could not be decoded.
+    #[error("malformed DNS message: {0}")]
+    Malformed(&'static str),
+
+    /// The server answered with a non-zero response code.
+    #[error("server responded with {0}")]
+    Rcode(Rcode),
+}
+
+/// DNS response code (RFC 1035 §4.1.1).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Rcode {
+    /// No error condition.
+    NoError,
+    /// The server was unable to interpret the query.
+    FormErr,
+    /// The server was unable to process the query.
+    ServFail,
+    /// The queried name does not exist.
+    NxDomain,
+    /// The server does not support the requested kind of query.
+    NotImp,
+    /// The server refuses to perform the operation (e.g. transfers not allowed).
+    Refused,
+    /// The server is not authoritative for the zone.
+    NotAuth,
+    /// Any other response code.
+    Other(u8),
+}
+
+impl Rcode {
+    /// Creates an Rcode from the 4-bit header value.
+    pub const fn from_u8(value: u8) -> Self {
+        match value {
+            0 => Self::NoError,
+            1 => Self::FormErr,
+            2 => Self::ServFail,
+            3 => Self::NxDomain,
+            4 => Self::NotImp,
+            5 => Self::Refused,
+            9 => Self::NotAuth,
+            other => Self::Other(other),
+        }
+    }
+}
+
+impl fmt::Display for Rcode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::NoError => write!(f, "NOERROR"),
+            Self::FormErr => write!(f, "FORMERR"),
+            Self::ServFail => write!(f, "SERVFAIL"),
+            Self::NxDomain => write!(f, "NXDOMAIN"),
+            Self::NotImp => write!(f, "NOTIMP"),
+            Self::Refused => write!(f, "REFUSED"),
+            Self::NotAuth => write!(f, "NOTAUTH"),
+            Self::Other(code) => write!(f, "RCODE{}", code),
+        }
+    }
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// Decoded record data.
+///
+/// Well-known types are decoded into their fields; everything else is kept
+/// as raw bytes and rendered in the RFC 3597 generic format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RData {
+    A(Ipv4Addr),
+    AAAA(Ipv6Addr),
+    NS(String),
+    CNAME(String),
+    PTR(String),
+    DNAME(String),
+    MX {
+        preference: u16,
+        exchange: String,
+    },
+    TXT(Vec<String>),
+    SRV {
+        priority: u16,
+        weight: u16,
+        port: u16,
+        target: String,
+    },
+    SOA(Box<SoaData>),
+    CAA {
+        flags: u8,
+        tag: String,
+        value: String,
+    },
+    /// Record data of a type this client does not decode.
+    Unknown(Vec<u8>),
+}
+
+impl RData {
+    /// Renders the record data in zone-file presentation format.
+    ///
+    /// Domain names are rendered without the trailing dot.
+    pub fn to_presentation(&self) -> String {
+        match self {
+            RData::A(addr) => addr.to_string(),
+            RData::AAAA(addr) => addr.to_string(),
+            RData::NS(name) | RData::CNAME(name) | RData::PTR(name) | RData::DNAME(name) => {
+                name.clone()
+            }
+            RData::MX {
+                preference,
+                exchange,
+            } => format!("{} {}", preference, exchange),
+            RData::TXT(strings) => strings.concat(),
+            RData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => format!("{} {} {} {}", priority, weight, port, target),
+            RData::SOA(soa) => format!(
+                "{} {} {} {} {} {} {}",
+                soa.mname.to_dotted(),
+                soa.rname.to_dotted(),
+                soa.serial,
+                soa.refresh,
+                soa.retry,
+                soa.expire,
+                soa.minimum
+            ),
+            RData::CAA { flags, tag, value } => format!("{} {} \"{}\"", flags, tag, value),
+            RData::Unknown(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                if hex.is_empty() {
+                    "\\# 0".to_string()
+                } else {
+                    format!("\\# {} {}", bytes.len(), hex)
+                }
+            }
+        }
+    }
+}
+
+/// A resource record received in a zone transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceRecord {
+    /// Fully-qualified owner name, without the trailing dot.
+    pub name: String,
+    /// Record type code.
+    pub rtype: u16,
+    /// Record class code.
+    pub class: u16,
+    /// TTL in seconds.
+    pub ttl: u32,
+    /// Decoded record data.
+    pub rdata: RData,
+}
+
+impl ResourceRecord {
+    /// Returns the record type mnemonic, or `TYPEnnn` (RFC 3597) for unknown types.
+    pub fn type_name(&self) -> String {
+        match self.rtype {
+            39 => "DNAME".to_string(),
+            typ => RecordType::from_u16(typ)
+                .map(|t| t.as_str().to_string())
+                .unwrap_or_else(|| format!("TYPE{}", typ)),
+        }
+    }
+
+    /// Returns the SOA data if this is an SOA record.
+    pub fn soa(&self) -> Option<&SoaData> {
+        match &self.rdata {
+            RData::SOA(soa) => Some(soa.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+/// Response to an incremental zone transfer request.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum IxfrResponse {
+    /// The server sent the complete zone instead of differences.
+    ///
+    /// Servers do this when they have no journal covering the requested serial.
+    Full(Vec<ResourceRecord>),
+    /// The server sent a sequence of differences.
+    ///
+    /// The list is empty when the requested serial is already current.
+    Incremental(Vec<Difference>),
+}
+
+/// One step of an incremental zone transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Difference {
+    /// Serial of the zone version the changes apply to.
+    pub from_serial: u32,
+    /// Serial of the zone version after the changes.
+    pub to_serial: u32,
+    /// Records removed in this step.
+    pub deleted: Vec<ResourceRecord>,
+    /// Records added in this step.
+    pub added: Vec<ResourceRecord>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// DNS zone transfer client.
+///
+/// Each transfer opens a new TCP connection to the configured server.
+#[derive(Debug, Clone)]
+pub struct Client {
+    server: SocketAddr,
+    config: HttpClientConfig,
+}
+
+impl Client {
+    /// Creates a new zone transfer client for the given server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Address of the primary server (usually port 53)
+    pub fn new(server: SocketAddr) -> Self {
+        Self::with_config(server, HttpClientConfig::default())
+    }
+
+    /// Creates a new zone transfer client with custom network configuration.
+    ///
+    /// The timeout applies to each complete transfer. Local address and
+    /// interface binding are honored when connecting.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Address of the primary server
+    /// * `config` - Network configuration for the outgoing connection
+    pub fn with_config(server: SocketAddr, config: HttpClientConfig) -> Self {
+        Self { server, config }
+    }
+
+    /// Returns the server this client transfers from.
+    pub fn server(&self) -> SocketAddr {
+        self.server
+    }
+
+    /// Queries the SOA record of a zone.
+    pub async fn query_soa(&self, zone: &str) -> Result<ResourceRecord, AxfrError> {
+        let query = encode_query(zone, RecordType::SOA.as_u16(), None)?;
+        let answers = self.exchange(&query, |_| true).await?;
+
+        answers
+            .into_iter()
+            .find(|rr| rr.soa().is_some())
+            .ok_or(AxfrError::Malformed("SOA query returned no SOA record"))
+    }
+
+    /// Performs a full zone transfer (AXFR).
+    ///
+    /// The returned records start with the zone's SOA record. The closing SOA
+    /// record that terminates the transfer is not included.
+    pub async fn axfr(&self, zone: &str) -> Result<Vec<ResourceRecord>, AxfrError> {
+        let query = encode_query(zone, TYPE_AXFR, None)?;
+        let mut answers = self.exchange(&query, axfr_complete).await?;
+
+        if answers.first().and_then(ResourceRecord::soa).is_none() {
+            return Err(AxfrError::Malformed("transfer does not start with SOA"));
+        }
+        answers.pop();
+        Ok(answers)
+    }
+
+    /// Performs an incremental zone transfer (IXFR) from the given serial.
+    ///
+    /// # Arguments
+    ///
+    /// * `zone` - Zone name
+    /// * `serial` - SOA serial of the zone version the caller already has
+    pub async fn ixfr(&self, zone: &str, serial: u32) -> Result<IxfrResponse, AxfrError> {
+        let query = encode_query(zone, TYPE_IXFR, Some(serial))?;
+        let answers = self
+            .exchange(&query, |rrs| ixfr_complete(rrs, serial))
+            .await?;
+
+        parse_ixfr(answers, serial)
+    }
+
+    /// Sends a query and reads response messages until `complete` returns `true`.
+    async fn exchange<F>(&self, query: &[u8], complete: F) -> Result<Vec<ResourceRecord>, AxfrError>
+    where
+        F: Fn(&[ResourceRecord]) -> bool,
+    {
+        let timeout = self.config.timeout.unwrap_or(DEFAULT_TIMEOUT);
+        tokio::time::timeout(timeout, self.exchange_inner(query, complete))
+            .await
+            .map_err(|_| AxfrError::Timeout)?
+    }
+
+    async fn exchange_inner<F>(
+        &self,
+        query: &[u8],
+        complete: F,
+    ) -> Result<Vec<ResourceRecord>, AxfrError>
+    where
+        F: Fn(&[ResourceRecord]) -> bool,
+    {
+        let mut stream = self.connect().await?;
+        let id = u16::from_be_bytes([query[0], query[1]]);
+
+        let mut framed = Vec::with_capacity(query.len() + 2);
+        framed.extend_from_slice(&(query.len() as u16).to_be_bytes());
+        framed.extend_from_slice(query);
+        stream.write_all(&framed).await?;
+
+        let mut answers = Vec::new();
+        loop {
+            let mut len_buf = [0u8; 2];
+            stream.read_exact(&mut len_buf).await?;
+            let mut message = vec![0u8; u16::from_be_bytes(len_buf) as usize];
+            stream.read_exact(&mut message).await?;
+
+            let (response_id, rcode, records) = decode_response(&message)?;
+            if response_id != id {
+                return Err(AxfrError::Malformed("response ID does not match query"));
+            }
+            if rcode != Rcode::NoError {
+                return Err(AxfrError::Rcode(rcode));
+            }
+
+            answers.extend(records);
+            if complete(&answers) {
+                return Ok(answers);
+            }
+        }
+    }
+
+    async fn connect(&self) -> Result<TcpStream, AxfrError> {
+        let socket = match self.server {
+            SocketAddr::V4(_) => TcpSocket::new_v4()?,
+            SocketAddr::V6(_) => TcpSocket::new_v6()?,
+        };
+
+        if let Some(addr) = self.config.local_address {
+            socket.bind(SocketAddr::new(addr, 0))?;
+        }
+
+        #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+        if let Some(ref iface) = self.config.interface {
+            socket.bind_device(Some(iface.as_bytes()))?;
+        }
+
+        Ok(socket.connect(self.server).await?)
+    }
+}
+
+// =============================================================================
+// Transfer Framing
+// =============================================================================
+
+/// Returns `true` once an AXFR stream has been terminated by its closing SOA.
+fn axfr_complete(rrs: &[ResourceRecord]) -> bool {
+    rrs.len() > 1 && rrs.last().and_then(ResourceRecord::soa).is_some()
+}
+
+/// Returns `true` once an IXFR stream is complete (RFC 1995 §4).
+fn ixfr_complete(rrs: &[ResourceRecord], requested: u32) -> bool {
+    let Some(first) = rrs.first().and_then(ResourceRecord::soa) else {
+        return false;
+    };
+
+    if rrs.len() == 1 {
+        // A lone SOA means the requested version is already current.
+        return !serial_gt(first.serial, requested);
+    }
+
+    if rrs[1].soa().is_none() {
+        return axfr_complete(rrs);
+    }
+
+    split_differences(&rrs[1..], first.serial).is_some()
+}
+
+/// Splits the body of an incremental transfer into differences.
+///
+/// Returns `None` if the sequence is not yet terminated.
+fn split_differences(rrs: &[ResourceRecord], current: u32) -> Option<Vec<Difference>> {
+    let mut differences = Vec::new();
+    let mut pos = 0;
+
+    loop {
+        let old = rrs.get(pos)?.soa()?;
+        if old.serial == current && pos == rrs.len() - 1 {
+            return Some(differences);
+        }
+        pos += 1;
+
+        let deleted_start = pos;
+        while rrs.get(pos)?.soa().is_none() {
+            pos += 1;
+        }
+        let deleted = rrs[deleted_start..pos].to_vec();
+
+        let new = rrs[pos].soa()?;
+        pos += 1;
+
+        let added_start = pos;
+        while rrs.get(pos)?.soa().is_none() {
+            pos += 1;
+        }
+        let added = rrs[added_start..pos].to_vec();
+
+        differences.push(Difference {
+            from_serial: old.serial,
+            to_serial: new.serial,
+            deleted,
+            added,
+        });
+    }
+}
+
+/// Interprets a complete IXFR answer stream.
+fn parse_ixfr(rrs: Vec<ResourceRecord>, requested: u32) -> Result<IxfrResponse, AxfrError> {
+    let current = rrs
+        .first()
+        .and_then(ResourceRecord::soa)
+        .ok_or(AxfrError::Malformed("transfer does not start with SOA"))?
+        .serial;
+
+    if rrs.len() == 1 {
+        debug_assert!(!serial_gt(current, requested));
+        return Ok(IxfrResponse::Incremental(Vec::new()));
+    }
+
+    // `[SOA, SOA]` is the full transfer of a zone holding only its SOA; an
+    // up-to-date reply is a single SOA, so it cannot be empty differences.
+    if rrs[1].soa().is_none() || rrs.len() == 2 {
+        let mut records = rrs;
+        records.pop();
+        return Ok(IxfrResponse::Full(records));
+    }
+
+    split_differences(&rrs[1..], current)
+        .map(IxfrResponse::Incremental)
+        .ok_or(AxfrError::Malformed("incomplete incremental transfer"))
+}
+
+/// Compares two SOA serials using RFC 1982 serial number arithmetic.
+fn serial_gt(a: u32, b: u32) -> bool {
+    a != b && a.wrapping_sub(b) < (1 << 31)
+}
+
+// =============================================================================
+// Wire Format
+// =============================================================================
+
+/// Returns a fresh message ID.
+fn next_id() -> u16 {
+    static COUNTER: AtomicU16 = AtomicU16::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    (nanos as u16) ^ COUNTER.fetch_add(0x9e37, Ordering::Relaxed)
+}
+
+/// Encodes a transfer query.
+///
+/// For IXFR queries, `serial` is placed in an SOA record in the authority section.
+fn encode_query(zone: &str, qtype: u16, serial: Option<u32>) -> Result<Vec<u8>, AxfrError> {
+    let name = DomainName::from_dotted(zone.trim_end_matches('.'))
+        .ok_or_else(|| AxfrError::InvalidName(zone.to_string()))?;
+    let class = RecordClass::IN.as_u16();
+
+    let mut buf = Vec::with_capacity(HEADER_LEN + name.wire_len() * 2 + 32);
+    buf.extend_from_slice(&next_id().to_be_bytes());
+    buf.extend_from_slice(&0u16.to_be_bytes()); // standard query, no recursion
+    buf.extend_from_slice(&1u16.to_be_bytes()); // QDCOUNT
+    buf.extend_from_slice(&0u16.to_be_bytes()); // ANCOUNT
+    buf.extend_from_slice(&(serial.is_some() as u16).to_be_bytes()); // NSCOUNT
+    buf.extend_from_slice(&0u16.to_be_bytes()); // ARCOUNT
+
+    buf.extend_from_slice(name.as_wire_bytes());
+    buf.extend_from_slice(&qtype.to_be_bytes());
+    buf.extend_from_slice(&class.to_be_bytes());
+
+    if let Some(serial) = serial {
+        buf.extend_from_slice(name.as_wire_bytes());
+        buf.extend_from_slice(&RecordType::SOA.as_u16().to_be_bytes());
+        buf.extend_from_slice(&class.to_be_bytes());
+        buf.extend_from_slice(&0u32.to_be_bytes()); // TTL
+        buf.extend_from_slice(&22u16.to_be_bytes()); // RDLENGTH
+        buf.push(0); // MNAME (root)
+        buf.push(0); // RNAME (root)
+        buf.extend_from_slice(&serial.to_be_bytes());
+        buf.extend_from_slice(&[0u8; 16]); // REFRESH, RETRY, EXPIRE, MINIMUM
+    }
+
+    Ok(buf)
+}
+
+/// Decodes a response message, returning its ID, response code and answer records.
+fn decode_response(msg: &[u8]) -> Result<(u16, Rcode, Vec<ResourceRecord>), AxfrError> {
+    if msg.len() < HEADER_LEN {
+        return Err(AxfrError::Malformed("message shorter than header"));
+    }
+
+    let id = read_u16(msg, 0)?;
+    let flags = read_u16(msg, 2)?;
+    if flags & 0x8000 == 0 {
+        return Err(AxfrError::Malformed("message is not a response"));
+    }
+    let rcode = Rcode::from_u8((flags & 0x000f) as u8);
+
+    let qdcount = read_u16(msg, 4)?;
+    let ancount = read_u16(msg, 6)?;
+
+    let mut pos = HEADER_LEN;
+    for _ in 0..qdcount {
+        let (_, next) = decode_name(msg, pos)?;
+        pos = next + 4;
+    }
+
+    let mut records = Vec::with_capacity(ancount as usize);
+    for _ in 0..ancount {
+        let (rr, next) = decode_record(msg, pos)?;
+        records.push(rr);
+        pos = next;
+    }
+
+    Ok((id, rcode, records))
+}
+
+/// Decodes a resource record starting at `pos`.
+fn decode_record(msg: &[u8], pos: usize) -> Result<(ResourceRecord, usize), AxfrError> {
+    let (name, pos) = decode_name(msg, pos)?;
+    let rtype = read_u16(msg, pos)?;
+    let class = read_u16(msg, pos + 2)?;
+    let ttl = read_u32(msg, pos + 4)?;
+    let rdlength = read_u16(msg, pos + 8)? as usize;
+    let start = pos + 10;
+    let end = start + rdlength;
+    if end > msg.len() {
+        return Err(AxfrError::Malformed("record data exceeds message"));
+    }
+
+    let rdata = decode_rdata(msg, rtype, start, end)?;
+    Ok((
+        ResourceRecord {
+            name,
+            rtype,
+            class,
+            ttl,
+            rdata,
+        },
+        end,
+    ))
+}
+
+/// Decodes record data of the given type occupying `msg[start..end]`.
+fn decode_rdata(msg: &[u8], rtype: u16, start: usize, end: usize) -> Result<RData, AxfrError> {
+    let rdata = &msg[start..end];
+
+    let data = match RecordType::from_u16(rtype) {
+        Some(RecordType::A) => {
+            let octets: [u8; 4] = rdata
+                .try_into()
+                .map_err(|_| AxfrError::Malformed("A record is not 4 bytes"))?;
+            RData::A(Ipv4Addr::from(octets))
+        }
+        Some(RecordType::AAAA) => {
+            let octets: [u8; 16] = rdata
+                .try_into()
+                .map_err(|_| AxfrError::Malformed("AAAA record is not 16 bytes"))?;
+            RData::AAAA(Ipv6Addr::from(octets))
+        }
+        Some(RecordType::NS) => RData::NS(decode_name(msg, start)?.0),
+        Some(RecordType::CNAME) => RData::CNAME(decode_name(msg, start)?.0),
+        Some(RecordType::PTR) => RData::PTR(decode_name(msg, start)?.0),
+        Some(RecordType::MX) => RData::MX {
+            preference: read_u16(msg, start)?,
+            exchange: decode_name(msg, start + 2)?.0,
+        },
+        Some(RecordType::TXT) => {
+            let mut strings = Vec::new();
+            let mut pos = 0;
+            while pos < rdata.len() {
+                let len = rdata[pos] as usize;
+                let bytes = rdata
+                    .get(pos + 1..pos + 1 + len)
+                    .ok_or(AxfrError::Malformed("TXT string exceeds record data"))?;
+                strings.push(String::from_utf8_lossy(bytes).into_owned());
+                pos += 1 + len;
+            }
+            RData::TXT(strings)
+        }
+        Some(RecordType::SRV) => RData::SRV {
+            priority: read_u16(msg, start)?,
+            weight: read_u16(msg, start + 2)?,
+            port: read_u16(msg, start + 4)?,
+            target: decode_name(msg, start + 6)?.0,
+        },
+        Some(RecordType::SOA) => {
+            let (mname, pos) = decode_name(msg, start)?;
+            let (rname, pos) = decode_name(msg, pos)?;
+            if pos + 20 > end {
+                return Err(AxfrError::Malformed("SOA record too short"));
+            }
+            RData::SOA(Box::new(SoaData {
+                mname: DomainName::from_dotted(&mname)
+                    .ok_or(AxfrError::Malformed("invalid SOA MNAME"))?,
+                rname: DomainName::from_dotted(&rname)
+                    .ok_or(AxfrError::Malformed("invalid SOA RNAME"))?,
+                serial: read_u32(msg, pos)?,
+                refresh: read_u32(msg, pos + 4)?,
+                retry: read_u32(msg, pos + 8)?,
+                expire: read_u32(msg, pos + 12)?,
+                minimum: read_u32(msg, pos + 16)?,
+            }))
+        }
+        Some(RecordType::CAA) => {
+            let flags = *rdata
+                .first()
+                .ok_or(AxfrError::Malformed("CAA record too short"))?;
+            let tag_len = *rdata
+                .get(1)
+                .ok_or(AxfrError::Malformed("CAA record too short"))?
+                as usize;
+            let tag = rdata
+                .get(2..2 + tag_len)
+                .ok_or(AxfrError::Malformed("CAA tag exceeds record data"))?;
+            RData::CAA {
+                flags,
+                tag: String::from_utf8_lossy(tag).into_owned(),
+                value: String::from_utf8_lossy(&rdata[2 + tag_len..]).into_owned(),
+            }
+        }
+        _ if rtype == 39 => RData::DNAME(decode_name(msg, start)?.0),
+        _ => RData::Unknown(rdata.to_vec()),
+    };
+
+    Ok(data)
+}
+
+/// Decodes a possibly compressed domain name starting at `pos`.
+///
+/// Returns the name without the trailing dot and the position after the name
+/// in the original (uncompressed) stream.
+fn decode_name(msg: &[u8], mut pos: usize) -> Result<(String, usize), AxfrError> {
+    let mut name = String::new();
+    let mut end: Option<usize> = None;
+    let mut hops = 0;
+
+    loop {
+        let len = *msg
+            .get(pos)
+            .ok_or(AxfrError::Malformed("name exceeds message"))? as usize;
+
+        match len & 0xc0 {
+            0x00 if len == 0 => {
+                return Ok((name, end.unwrap_or(pos + 1)));
+            }
+            0x00 => {
+                let label = msg
+                    .get(pos + 1..pos + 1 + len)
+                    .ok_or(AxfrError::Malformed("label exceeds message"))?;
+                if !name.is_empty() {
+                    name.push('.');
+                }
+                name.push_str(&String::from_utf8_lossy(label));
+                if name.len() > 255 {
+                    return Err(AxfrError::Malformed("name exceeds 255 octets"));
+                }
+                pos += 1 + len;
+            }
+            0xc0 => {
+                hops += 1;
+                if hops > MAX_POINTER_HOPS {
+                    return Err(AxfrError::Malformed("compression pointer loop"));
+                }
+                let target = (read_u16(msg, pos)? & 0x3fff) as usize;
+                end.get_or_insert(pos + 2);
+                pos = target;
+            }
+            _ => return Err(AxfrError::Malformed("unsupported label type")),
+        }
+    }
+}
+
+fn read_u16(msg: &[u8], pos: usize) -> Result<u16, AxfrError> {
+    msg.get(pos..pos + 2)
+        .map(|b| u16::from_be_bytes([b[0], b[1]]))
+        .ok_or(AxfrError::Malformed("unexpected end of message"))
+}
+
+fn read_u32(msg: &[u8], pos: usize) -> Result<u32, AxfrError> {
+    msg.get(pos..pos + 4)
+        .map(|b| u32::from_be_bytes([b[0], b[1], b[2], b[3]]))
+        .ok_or(AxfrError::Malformed("unexpected end of message"))
+}
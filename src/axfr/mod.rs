@@ -0,0 +1,306 @@
+//! Zone transfer (AXFR/IXFR) provider implementation.
+//!
+//! This provider reads zones directly from an authoritative DNS server using
+//! standard zone transfers over TCP. It is read-only: records can be listed
+//! and looked up, but not created or deleted.
+//!
+//! # Authentication
+//!
+//! Zone transfers are usually restricted by source address on the primary
+//! server. Use [`HttpClientConfig::local_address`] or
+//! [`HttpClientConfig::interface`] to control which address the connection
+//! originates from. TSIG-signed transfers are not supported.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::axfr::AxfrProvider;
+//! use manydns::{Provider, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = AxfrProvider::new("192.0.2.53:53".parse()?);
+//!
+//! let zone = provider.get_zone("example.com").await?;
+//! println!("Serial: {}", zone.serial());
+//!
+//! for record in zone.list_records().await? {
+//!     println!("{} {} {}", record.host, record.data.get_type(), record.data.get_value());
+//! }
+//!
+//! // Fetch only what changed since a known serial
+//! let changes = zone.changes_since(2024010101).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Record IDs
+//!
+//! DNS has no notion of record IDs, so records are identified by
+//! `"name/type/value"`, the same scheme used by the Hetzner provider.
+//! Record hosts are relative to the zone, with `@` for the apex.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CNAME, MX, NS, SRV and TXT map to their [`RecordData`] variants.
+//! All other types (including SOA) are returned as [`RecordData::Other`] in
+//! presentation format.
+//!
+//! # Protocol Reference
+//!
+//! - [RFC 1995: Incremental Zone Transfer in DNS](https://www.rfc-editor.org/rfc/rfc1995)
+//! - [RFC 5936: DNS Zone Transfer Protocol (AXFR)](https://www.rfc-editor.org/rfc/rfc5936)
+
+pub mod api;
+
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+pub use api::{AxfrError, Client, Rcode};
+
+use crate::types::SoaData;
+use crate::{
+    HttpClientConfig, Provider, Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Read-only DNS provider backed by zone transfers.
+#[derive(Clone)]
+pub struct AxfrProvider {
+    api_client: Arc<Client>,
+    zones: Vec<String>,
+}
+
+/// A DNS zone read via zone transfer.
+pub struct AxfrZone {
+    api_client: Arc<Client>,
+    domain: String,
+    soa: SoaData,
+}
+
+/// Changes to a zone since a given serial.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ZoneChanges {
+    /// The server sent the complete zone instead of differences.
+    Full(Vec<Record>),
+    /// The server sent incremental differences, oldest first.
+    ///
+    /// Empty when the requested serial is already current.
+    Incremental(Vec<RecordDiff>),
+}
+
+/// One step of an incremental zone transfer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RecordDiff {
+    /// Serial the changes apply to.
+    pub from_serial: u32,
+    /// Serial after the changes.
+    pub to_serial: u32,
+    /// Records removed.
+    pub deleted: Vec<Record>,
+    /// Records added.
+    pub added: Vec<Record>,
+}
+
+impl AxfrProvider {
+    /// Creates a new zone transfer provider for the given server.
+    ///
+    /// # Arguments
+    ///
+    /// * `server` - Address of the primary server (usually port 53)
+    pub fn new(server: SocketAddr) -> Self {
+        Self::with_config(server, HttpClientConfig::default())
+    }
+
+    /// Creates a new zone transfer provider with custom network configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::axfr::AxfrProvider;
+    /// use manydns::HttpClientConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.0.2.10".parse().unwrap())
+    ///     .timeout(Duration::from_secs(60));
+    /// let provider = AxfrProvider::with_config("192.0.2.53:53".parse().unwrap(), config);
+    /// ```
+    pub fn with_config(server: SocketAddr, config: HttpClientConfig) -> Self {
+        Self {
+            api_client: Arc::new(Client::with_config(server, config)),
+            zones: Vec::new(),
+        }
+    }
+
+    /// Sets the zones returned by [`Provider::list_zones`].
+    ///
+    /// DNS has no way to enumerate the zones a server is authoritative for,
+    /// so `list_zones` only returns the zones configured here.
+    pub fn with_zones<I, S>(mut self, zones: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.zones = zones.into_iter().map(Into::into).collect();
+        self
+    }
+}
+
+impl Provider for AxfrProvider {
+    type Zone = AxfrZone;
+    type CustomRetrieveError = AxfrError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = zone_id.trim_end_matches('.').to_ascii_lowercase();
+
+        let soa = self
+            .api_client
+            .query_soa(&domain)
+            .await
+            .map_err(|err| match &err {
+                AxfrError::Rcode(Rcode::NxDomain | Rcode::NotAuth) => RetrieveZoneError::NotFound,
+                AxfrError::Rcode(Rcode::Refused) => RetrieveZoneError::Unauthorized,
+                AxfrError::InvalidName(_) => RetrieveZoneError::NotFound,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        // An SOA for a parent zone means the server is not authoritative here.
+        if !soa.name.eq_ignore_ascii_case(&domain) {
+            return Err(RetrieveZoneError::NotFound);
+        }
+
+        let soa = soa.soa().cloned().ok_or(RetrieveZoneError::NotFound)?;
+
+        Ok(AxfrZone {
+            api_client: self.api_client.clone(),
+            domain,
+            soa,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let mut zones = Vec::with_capacity(self.zones.len());
+        for name in &self.zones {
+            zones.push(self.get_zone(name).await?);
+        }
+        Ok(zones)
+    }
+}
+
+impl AxfrZone {
+    /// Returns the SOA serial seen when the zone was retrieved.
+    pub fn serial(&self) -> u32 {
+        self.soa.serial
+    }
+
+    /// Returns the SOA data seen when the zone was retrieved.
+    pub fn soa(&self) -> &SoaData {
+        &self.soa
+    }
+
+    /// Retrieves the changes made since `serial` using IXFR.
+    ///
+    /// Servers without a journal for the requested serial fall back to sending
+    /// the whole zone, which is returned as [`ZoneChanges::Full`].
+    pub async fn changes_since(&self, serial: u32) -> Result<ZoneChanges, AxfrError> {
+        let response = self.api_client.ixfr(&self.domain, serial).await?;
+
+        Ok(match response {
+            api::IxfrResponse::Full(rrs) => ZoneChanges::Full(self.convert_all(&rrs)),
+            api::IxfrResponse::Incremental(diffs) => ZoneChanges::Incremental(
+                diffs
+                    .iter()
+                    .map(|diff| RecordDiff {
+                        from_serial: diff.from_serial,
+                        to_serial: diff.to_serial,
+                        deleted: self.convert_all(&diff.deleted),
+                        added: self.convert_all(&diff.added),
+                    })
+                    .collect(),
+            ),
+        })
+    }
+
+    fn convert_all(&self, rrs: &[api::ResourceRecord]) -> Vec<Record> {
+        rrs.iter().map(|rr| self.convert(rr)).collect()
+    }
+
+    /// Converts a transferred resource record into a [`Record`].
+    fn convert(&self, rr: &api::ResourceRecord) -> Record {
+        let host = relative_host(&rr.name, &self.domain);
+        let typ = rr.type_name();
+        let value = rr.rdata.to_presentation();
+
+        Record {
+            id: format!("{}/{}/{}", host, typ, value),
+            data: RecordData::from_raw(&typ, &value),
+            host,
+            ttl: rr.ttl as u64,
+        }
+    }
+}
+
+impl Zone for AxfrZone {
+    type CustomRetrieveError = AxfrError;
+
+    fn id(&self) -> &str {
+        &self.domain
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrs = self
+            .api_client
+            .axfr(&self.domain)
+            .await
+            .map_err(|err| match &err {
+                AxfrError::Rcode(Rcode::NxDomain | Rcode::NotAuth) => RetrieveRecordError::NotFound,
+                AxfrError::Rcode(Rcode::Refused) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(self.convert_all(&rrs))
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        if record_id.splitn(3, '/').count() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        self.list_records()
+            .await?
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+/// Returns `name` relative to `zone`, using `@` for the apex.
+fn relative_host(name: &str, zone: &str) -> String {
+    if name.eq_ignore_ascii_case(zone) {
+        return "@".to_string();
+    }
+
+    let suffix_len = zone.len() + 1;
+    if name.len() > suffix_len
+        && name.as_bytes()[name.len() - zone.len()..].eq_ignore_ascii_case(zone.as_bytes())
+        && name.as_bytes()[name.len() - suffix_len] == b'.'
+    {
+        return name[..name.len() - suffix_len].to_string();
+    }
+
+    name.to_string()
+}
@@ -0,0 +1,924 @@
+//! Low-level Azure DNS API client.
+//!
+//! This module provides direct access to the Azure Resource Manager (ARM)
+//! REST API for DNS zones and record sets, authenticated with the OAuth 2.0
+//! client-credentials flow of Microsoft Entra ID.
+//! For most use cases, prefer using [`AzureProvider`](super::AzureProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [DNS Zones](https://learn.microsoft.com/en-us/rest/api/dns/zones)
+//! - [Record Sets](https://learn.microsoft.com/en-us/rest/api/dns/record-sets)
+//! - [Client credentials flow](https://learn.microsoft.com/en-us/entra/identity-platform/v2-oauth2-client-creds-grant-flow)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::azure::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("tenant-id", "client-id", "client-secret");
+//! let client = Client::new(credentials, "subscription-id", "resource-group")?;
+//!
+//! for zone in client.list_zones().await? {
+//!     println!("Zone: {}", zone.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use reqwest::header::{CONTENT_TYPE, IF_MATCH, IF_NONE_MATCH};
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Azure Resource Manager base URL (public cloud).
+const AZURE_MANAGEMENT_URL: &str = "https://management.azure.com";
+
+/// The Microsoft Entra ID authority (public cloud).
+const AZURE_AUTHORITY_URL: &str = "https://login.microsoftonline.com";
+
+/// OAuth scope of the Azure Resource Manager (public cloud).
+const AZURE_MANAGEMENT_SCOPE: &str = "https://management.azure.com/.default";
+
+/// DNS resource provider API version.
+const API_VERSION: &str = "2018-05-01";
+
+/// Cached tokens are refreshed when they expire within this margin.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Errors that may occur when interacting with the Azure API.
+#[derive(Debug, Error)]
+pub enum AzureError {
+    /// The API returned an error response.
+    #[error("API error ({status}) {code}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error code (e.g., `ResourceNotFound`, `PreconditionFailed`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// The authority rejected the client credentials.
+    #[error("OAuth error ({status}) {error}: {description}")]
+    Auth {
+        /// HTTP status code.
+        status: u16,
+        /// OAuth error code (e.g., `invalid_client`).
+        error: String,
+        /// Error description.
+        description: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl AzureError {
+    /// Returns the HTTP status code if this is an API or OAuth error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            AzureError::Api { status, .. } | AzureError::Auth { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            AzureError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// ARM error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// Service principal credentials for the client-credentials flow.
+#[derive(Clone)]
+pub struct Credentials {
+    /// Directory (tenant) ID.
+    pub tenant_id: String,
+    /// Application (client) ID.
+    pub client_id: String,
+    /// Client secret.
+    pub client_secret: String,
+    /// Authority URL; the token endpoint is `{authority_url}/{tenant_id}/oauth2/v2.0/token`.
+    pub authority_url: String,
+    /// OAuth scope to request.
+    pub scope: String,
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("tenant_id", &self.tenant_id)
+            .field("client_id", &self.client_id)
+            .field("client_secret", &"<redacted>")
+            .field("authority_url", &self.authority_url)
+            .field("scope", &self.scope)
+            .finish()
+    }
+}
+
+impl Credentials {
+    /// Creates credentials for the Azure public cloud.
+    pub fn new(tenant_id: &str, client_id: &str, client_secret: &str) -> Self {
+        Self {
+            tenant_id: tenant_id.to_string(),
+            client_id: client_id.to_string(),
+            client_secret: client_secret.to_string(),
+            authority_url: AZURE_AUTHORITY_URL.to_string(),
+            scope: AZURE_MANAGEMENT_SCOPE.to_string(),
+        }
+    }
+
+    /// Reads credentials from the `AZURE_TENANT_ID`, `AZURE_CLIENT_ID` and
+    /// `AZURE_CLIENT_SECRET` environment variables, and the authority from
+    /// `AZURE_AUTHORITY_HOST` if set.
+    ///
+    /// Returns `None` if any of the required variables is missing.
+    pub fn from_env() -> Option<Self> {
+        let credentials = Self::new(
+            &std::env::var("AZURE_TENANT_ID").ok()?,
+            &std::env::var("AZURE_CLIENT_ID").ok()?,
+            &std::env::var("AZURE_CLIENT_SECRET").ok()?,
+        );
+        Some(match std::env::var("AZURE_AUTHORITY_HOST") {
+            Ok(authority) => credentials.with_authority_url(&authority),
+            Err(_) => credentials,
+        })
+    }
+
+    /// Overrides the authority URL, e.g. for sovereign clouds
+    /// (`https://login.microsoftonline.us`) or a local stand-in.
+    pub fn with_authority_url(mut self, authority_url: &str) -> Self {
+        self.authority_url = authority_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Overrides the OAuth scope, e.g. `https://management.usgovcloudapi.net/.default`.
+    pub fn with_scope(mut self, scope: &str) -> Self {
+        self.scope = scope.to_string();
+        self
+    }
+
+    /// Returns the token endpoint URL.
+    pub fn token_url(&self) -> String {
+        format!(
+            "{}/{}/oauth2/v2.0/token",
+            self.authority_url,
+            encode_component(&self.tenant_id)
+        )
+    }
+}
+
+/// Successful token endpoint response.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Token endpoint error response.
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+
+/// A cached OAuth 2.0 access token.
+#[derive(Debug, Clone)]
+struct AccessToken {
+    token: String,
+    expires_at: Instant,
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// An Azure DNS zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Zone {
+    /// ARM resource ID.
+    #[serde(default, rename = "id")]
+    pub resource_id: String,
+    /// Zone name (the domain, e.g. `example.com`).
+    pub name: String,
+    /// ETag of the zone.
+    #[serde(default)]
+    pub etag: Option<String>,
+    /// Zone properties.
+    #[serde(default)]
+    pub properties: ZoneProperties,
+}
+
+/// Properties of a DNS zone.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneProperties {
+    /// Maximum number of record sets allowed in the zone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_number_of_record_sets: Option<u64>,
+    /// Current number of record sets in the zone.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub number_of_record_sets: Option<u64>,
+    /// Name servers assigned to the zone.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub name_servers: Vec<String>,
+    /// Zone type (`Public` or `Private`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zone_type: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateZoneRequest<'a> {
+    location: &'a str,
+    properties: ZoneProperties,
+}
+
+/// A page of an ARM list operation.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ListResponse<T> {
+    #[serde(default = "Vec::new")]
+    value: Vec<T>,
+    #[serde(default)]
+    next_link: Option<String>,
+}
+
+// =============================================================================
+// Record Set Types
+// =============================================================================
+
+/// An Azure DNS record set: all records sharing a name and type.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordSet {
+    /// ARM resource ID.
+    #[serde(default, rename = "id")]
+    pub resource_id: String,
+    /// Name relative to the zone (`@` for the apex).
+    pub name: String,
+    /// Resource type (e.g., `Microsoft.Network/dnszones/A`).
+    #[serde(rename = "type")]
+    pub resource_type: String,
+    /// ETag used for optimistic concurrency.
+    #[serde(default)]
+    pub etag: Option<String>,
+    /// Record set properties.
+    #[serde(default)]
+    pub properties: RecordSetProperties,
+}
+
+impl RecordSet {
+    /// Returns the DNS record type (e.g., `A`).
+    pub fn record_type(&self) -> &str {
+        self.resource_type
+            .rsplit('/')
+            .next()
+            .unwrap_or(&self.resource_type)
+    }
+}
+
+/// Properties of a record set with the typed record arrays.
+///
+/// Only the array matching the record set type is populated.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct RecordSetProperties {
+    /// TTL in seconds.
+    #[serde(rename = "TTL", default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// Fully-qualified name of the record set (read-only).
+    #[serde(default, skip_serializing)]
+    pub fqdn: Option<String>,
+    /// Metadata key/value pairs.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<serde_json::Map<String, serde_json::Value>>,
+    /// Azure resource the record set points to (alias record sets).
+    #[serde(
+        rename = "targetResource",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub target_resource: Option<serde_json::Value>,
+    /// A records.
+    #[serde(rename = "ARecords", default, skip_serializing_if = "Option::is_none")]
+    pub a_records: Option<Vec<ARecord>>,
+    /// AAAA records.
+    #[serde(
+        rename = "AAAARecords",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub aaaa_records: Option<Vec<AaaaRecord>>,
+    /// CAA records.
+    #[serde(
+        rename = "caaRecords",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub caa_records: Option<Vec<CaaRecord>>,
+    /// CNAME record (at most one per record set).
+    #[serde(
+        rename = "CNAMERecord",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub cname_record: Option<CnameRecord>,
+    /// MX records.
+    #[serde(rename = "MXRecords", default, skip_serializing_if = "Option::is_none")]
+    pub mx_records: Option<Vec<MxRecord>>,
+    /// NS records.
+    #[serde(rename = "NSRecords", default, skip_serializing_if = "Option::is_none")]
+    pub ns_records: Option<Vec<NsRecord>>,
+    /// PTR records.
+    #[serde(
+        rename = "PTRRecords",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub ptr_records: Option<Vec<PtrRecord>>,
+    /// SOA record (apex only, read-mostly).
+    #[serde(rename = "SOARecord", default, skip_serializing_if = "Option::is_none")]
+    pub soa_record: Option<SoaRecord>,
+    /// SRV records.
+    #[serde(
+        rename = "SRVRecords",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub srv_records: Option<Vec<SrvRecord>>,
+    /// TXT records.
+    #[serde(
+        rename = "TXTRecords",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub txt_records: Option<Vec<TxtRecord>>,
+}
+
+/// An A record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ARecord {
+    /// IPv4 address.
+    pub ipv4_address: String,
+}
+
+/// An AAAA record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AaaaRecord {
+    /// IPv6 address.
+    pub ipv6_address: String,
+}
+
+/// A CAA record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CaaRecord {
+    /// Flags (0 or 128).
+    pub flags: u8,
+    /// Property tag (`issue`, `issuewild` or `iodef`).
+    pub tag: String,
+    /// Property value.
+    pub value: String,
+}
+
+/// A CNAME record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CnameRecord {
+    /// Canonical name.
+    pub cname: String,
+}
+
+/// An MX record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MxRecord {
+    /// Preference value.
+    pub preference: u16,
+    /// Mail exchange host.
+    pub exchange: String,
+}
+
+/// An NS record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NsRecord {
+    /// Name server host.
+    pub nsdname: String,
+}
+
+/// A PTR record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PtrRecord {
+    /// Pointer target host.
+    pub ptrdname: String,
+}
+
+/// An SOA record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SoaRecord {
+    /// Primary name server.
+    #[serde(default)]
+    pub host: String,
+    /// Responsible person mailbox.
+    #[serde(default)]
+    pub email: String,
+    /// Serial number.
+    #[serde(default)]
+    pub serial_number: u64,
+    /// Refresh interval in seconds.
+    #[serde(default)]
+    pub refresh_time: u64,
+    /// Retry interval in seconds.
+    #[serde(default)]
+    pub retry_time: u64,
+    /// Expiry in seconds.
+    #[serde(default)]
+    pub expire_time: u64,
+    /// Negative caching TTL in seconds.
+    #[serde(default, rename = "minimumTTL")]
+    pub minimum_ttl: u64,
+}
+
+/// An SRV record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SrvRecord {
+    /// Priority.
+    pub priority: u16,
+    /// Weight.
+    pub weight: u16,
+    /// Port.
+    pub port: u16,
+    /// Target host.
+    pub target: String,
+}
+
+/// A TXT record consisting of one or more character strings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TxtRecord {
+    /// Character strings of at most 255 bytes each.
+    pub value: Vec<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct RecordSetRequest<'a> {
+    properties: &'a RecordSetProperties,
+}
+
+/// Concurrency precondition for record set writes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Precondition<'a> {
+    /// Write unconditionally.
+    None,
+    /// Only write if the record set does not exist yet (`If-None-Match: *`).
+    NotExists,
+    /// Only write if the record set still has this ETag (`If-Match`).
+    Matches(&'a str),
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Azure DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    credentials: Credentials,
+    subscription_id: String,
+    resource_group: String,
+    token: Arc<Mutex<Option<AccessToken>>>,
+}
+
+impl Client {
+    /// Creates a new Azure DNS API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Service principal credentials
+    /// * `subscription_id` - Subscription containing the resource group
+    /// * `resource_group` - Resource group containing the DNS zones
+    pub fn new(
+        credentials: Credentials,
+        subscription_id: &str,
+        resource_group: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            credentials,
+            subscription_id,
+            resource_group,
+            AZURE_MANAGEMENT_URL,
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Azure DNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Service principal credentials
+    /// * `subscription_id` - Subscription containing the resource group
+    /// * `resource_group` - Resource group containing the DNS zones
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        subscription_id: &str,
+        resource_group: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            credentials,
+            subscription_id,
+            resource_group,
+            AZURE_MANAGEMENT_URL,
+            config,
+        )
+    }
+
+    /// Creates a new Azure DNS API client with a custom Resource Manager URL.
+    ///
+    /// This is primarily useful for sovereign clouds and for testing with mock
+    /// servers; the authority is taken from [`Credentials::authority_url`].
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Service principal credentials
+    /// * `subscription_id` - Subscription containing the resource group
+    /// * `resource_group` - Resource group containing the DNS zones
+    /// * `base_url` - Custom Resource Manager URL
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        subscription_id: &str,
+        resource_group: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            credentials,
+            subscription_id: subscription_id.to_string(),
+            resource_group: resource_group.to_string(),
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Returns a valid access token, requesting a new one if the cached token
+    /// is missing or about to expire.
+    pub async fn access_token(&self) -> Result<String, AzureError> {
+        if let Some(cached) = self.token.lock().unwrap().as_ref() {
+            if cached.expires_at > Instant::now() + TOKEN_REFRESH_MARGIN {
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let body = format!(
+            "grant_type=client_credentials&client_id={}&client_secret={}&scope={}",
+            encode_component(&self.credentials.client_id),
+            encode_component(&self.credentials.client_secret),
+            encode_component(&self.credentials.scope)
+        );
+
+        let requested_at = Instant::now();
+        let response = self
+            .http_client
+            .post(self.credentials.token_url())
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (error, description) = match serde_json::from_str::<TokenErrorResponse>(&text) {
+                Ok(e) => (e.error, e.error_description),
+                Err(_) => (String::new(), text),
+            };
+            return Err(AzureError::Auth {
+                status: status.as_u16(),
+                error,
+                description,
+            });
+        }
+
+        let response: TokenResponse = serde_json::from_str(&text)?;
+        let lifetime = Duration::from_secs(response.expires_in.unwrap_or(3600));
+        *self.token.lock().unwrap() = Some(AccessToken {
+            token: response.access_token.clone(),
+            expires_at: requested_at + lifetime,
+        });
+
+        Ok(response.access_token)
+    }
+
+    /// Discards the cached access token so the next request obtains a new one.
+    pub fn invalidate_token(&self) {
+        *self.token.lock().unwrap() = None;
+    }
+
+    /// Sends an authorized request and turns non-success responses into
+    /// [`AzureError::Api`].
+    ///
+    /// A `401` response discards the cached token and retries once with a
+    /// fresh one.
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+        precondition: Precondition<'_>,
+    ) -> Result<String, AzureError> {
+        let mut retried = false;
+
+        loop {
+            let token = self.access_token().await?;
+            let mut request = self
+                .http_client
+                .request(method.clone(), url)
+                .bearer_auth(token);
+            if let Some(ref body) = body {
+                request = request
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body.clone());
+            }
+            request = match precondition {
+                Precondition::None => request,
+                Precondition::NotExists => request.header(IF_NONE_MATCH, "*"),
+                Precondition::Matches(etag) => request.header(IF_MATCH, etag),
+            };
+
+            let response = request.send().await?;
+            let status = response.status();
+            let text = response.text().await?;
+
+            if status.as_u16() == 401 && !retried {
+                self.invalidate_token();
+                retried = true;
+                continue;
+            }
+
+            if !status.is_success() {
+                let (code, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                    Ok(e) => (e.error.code, e.error.message),
+                    Err(_) => (String::new(), text),
+                };
+                return Err(AzureError::Api {
+                    status: status.as_u16(),
+                    code,
+                    message,
+                });
+            }
+
+            return Ok(text);
+        }
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T, AzureError> {
+        let text = self
+            .send(Method::GET, url, None, Precondition::None)
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Fetches all pages of a list operation using the skip token of `nextLink`.
+    async fn get_all<T: DeserializeOwned>(&self, url: String) -> Result<Vec<T>, AzureError> {
+        let mut items = Vec::new();
+        let mut page_url = url.clone();
+
+        loop {
+            let page: ListResponse<T> = self.get(&page_url).await?;
+            items.extend(page.value);
+
+            // Only the skip token is taken from `nextLink`, so the bearer token
+            // is never sent to a host other than the configured one.
+            match page.next_link.as_deref().and_then(skip_token) {
+                Some(token) => page_url = format!("{}&$skipToken={}", url, token),
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    /// Returns the URL of the DNS zones collection of the resource group.
+    fn zones_url(&self) -> String {
+        format!(
+            "{}/subscriptions/{}/resourceGroups/{}/providers/Microsoft.Network/dnsZones",
+            self.base_url,
+            encode_component(&self.subscription_id),
+            encode_component(&self.resource_group)
+        )
+    }
+
+    /// Returns the URL of a DNS zone, or of a path below it.
+    fn zone_url(&self, zone: &str, suffix: &str) -> String {
+        format!(
+            "{}/{}{}?api-version={}",
+            self.zones_url(),
+            encode_component(zone),
+            suffix,
+            API_VERSION
+        )
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists the DNS zones of the resource group, following pagination.
+    pub async fn list_zones(&self) -> Result<Vec<Zone>, AzureError> {
+        self.get_all(format!("{}?api-version={}", self.zones_url(), API_VERSION))
+            .await
+    }
+
+    /// Gets a DNS zone by name.
+    pub async fn get_zone(&self, zone: &str) -> Result<Zone, AzureError> {
+        self.get(&self.zone_url(zone, "")).await
+    }
+
+    /// Creates a public DNS zone, failing if it already exists.
+    pub async fn create_zone(&self, zone: &str) -> Result<Zone, AzureError> {
+        let body = serde_json::to_string(&CreateZoneRequest {
+            location: "global",
+            properties: ZoneProperties {
+                zone_type: Some("Public".to_string()),
+                ..Default::default()
+            },
+        })?;
+        let text = self
+            .send(
+                Method::PUT,
+                &self.zone_url(zone, ""),
+                Some(body),
+                Precondition::NotExists,
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a DNS zone and all of its record sets.
+    ///
+    /// Deletion is a long-running operation; a `202 Accepted` response means
+    /// it was started.
+    pub async fn delete_zone(&self, zone: &str) -> Result<(), AzureError> {
+        self.send(
+            Method::DELETE,
+            &self.zone_url(zone, ""),
+            None,
+            Precondition::None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record Set APIs
+    // =========================================================================
+
+    /// Lists all record sets of a zone, following pagination.
+    pub async fn list_record_sets(&self, zone: &str) -> Result<Vec<RecordSet>, AzureError> {
+        self.get_all(self.zone_url(zone, "/recordsets")).await
+    }
+
+    /// Gets a record set by type and relative name.
+    ///
+    /// Returns `None` if no such record set exists.
+    pub async fn get_record_set(
+        &self,
+        zone: &str,
+        record_type: &str,
+        name: &str,
+    ) -> Result<Option<RecordSet>, AzureError> {
+        let url = self.zone_url(zone, &record_set_suffix(record_type, name));
+        match self.get(&url).await {
+            Ok(record_set) => Ok(Some(record_set)),
+            Err(err) if err.code() == Some("NotFound") => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Creates or replaces a record set.
+    pub async fn put_record_set(
+        &self,
+        zone: &str,
+        record_type: &str,
+        name: &str,
+        properties: &RecordSetProperties,
+        precondition: Precondition<'_>,
+    ) -> Result<RecordSet, AzureError> {
+        let url = self.zone_url(zone, &record_set_suffix(record_type, name));
+        let body = serde_json::to_string(&RecordSetRequest { properties })?;
+        let text = self
+            .send(Method::PUT, &url, Some(body), precondition)
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a record set.
+    pub async fn delete_record_set(
+        &self,
+        zone: &str,
+        record_type: &str,
+        name: &str,
+        precondition: Precondition<'_>,
+    ) -> Result<(), AzureError> {
+        let url = self.zone_url(zone, &record_set_suffix(record_type, name));
+        self.send(Method::DELETE, &url, None, precondition).await?;
+        Ok(())
+    }
+}
+
+/// Returns the path of a record set below its zone.
+///
+/// The apex record set name `@` is a valid path character and stays unescaped.
+fn record_set_suffix(record_type: &str, name: &str) -> String {
+    format!(
+        "/{}/{}",
+        encode_component(record_type),
+        encode_component(name).replace("%40", "@")
+    )
+}
+
+/// Extracts the still percent-encoded `$skipToken` query parameter of a link.
+fn skip_token(link: &str) -> Option<&str> {
+    let (_, query) = link.split_once('?')?;
+    query.split('&').find_map(|pair| {
+        pair.strip_prefix("$skipToken=")
+            .or_else(|| pair.strip_prefix("%24skipToken="))
+    })
+}
+
+/// Percent-encodes a path segment or form value (RFC 3986 unreserved characters are kept).
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,725 @@
+//! Azure DNS provider implementation.
+//!
+//! This provider manages public DNS zones in one resource group through the
+//! Azure Resource Manager (ARM) REST API, authenticated with the OAuth 2.0
+//! client-credentials flow.
+//!
+//! # Authentication
+//!
+//! Requires a service principal (app registration) with a client secret:
+//! - Register an application at:
+//!   <https://portal.azure.com/#view/Microsoft_AAD_RegisteredApps>
+//! - Create a client secret under "Certificates & secrets"
+//! - Grant it the "DNS Zone Contributor" role on the resource group
+//!
+//! The provider requests an access token from
+//! `{authority}/{tenant}/oauth2/v2.0/token` and caches it until shortly before
+//! it expires. [`Credentials::with_authority_url`] selects another authority,
+//! e.g. `https://login.microsoftonline.us` for Azure Government, and
+//! [`Credentials::from_env`] reads the standard `AZURE_TENANT_ID`,
+//! `AZURE_CLIENT_ID`, `AZURE_CLIENT_SECRET` and `AZURE_AUTHORITY_HOST`
+//! variables.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::azure::{AzureProvider, Credentials};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("tenant-id", "client-id", "client-secret");
+//! let provider = AzureProvider::new(credentials, "subscription-id", "dns-rg")?;
+//!
+//! // List all zones in the resource group
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Record Sets
+//!
+//! Azure DNS manages records as record sets: all records sharing a name and
+//! type, with one TTL and typed payloads such as `ARecords` or `MXRecords`.
+//! Creating or deleting a single record reads the record set and writes it
+//! back with the value added or removed, guarded by the record set's ETag so
+//! that concurrent changes are not lost. Creating a record also updates the
+//! TTL of its siblings; deleting the last value deletes the record set.
+//!
+//! Record IDs use the format `"name/type/value"`, similar to the Hetzner
+//! provider. Alias record sets (pointing to an Azure resource) have no
+//! values and are not listed.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, as [`RecordData::Other`] with the value `flags tag "value"`
+//! - PTR, as [`RecordData::Other`]
+//!
+//! SOA record sets are listed as [`RecordData::Other`] but cannot be created
+//! or deleted.
+//!
+//! # API Reference
+//!
+//! - [Azure DNS REST API](https://learn.microsoft.com/en-us/rest/api/dns/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{AzureError, Client, Credentials};
+
+use crate::txt::split_txt;
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+use api::Precondition;
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] =
+    &["A", "AAAA", "CAA", "CNAME", "MX", "NS", "PTR", "SRV", "TXT"];
+
+fn is_unauthorized(err: &AzureError) -> bool {
+    matches!(err, AzureError::Auth { .. }) || matches!(err.status(), Some(401 | 403))
+}
+
+/// Azure DNS provider.
+#[derive(Clone)]
+pub struct AzureProvider {
+    api_client: Arc<Client>,
+}
+
+/// An Azure DNS zone.
+pub struct AzureZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+}
+
+impl AzureProvider {
+    /// Creates a new Azure DNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Service principal credentials
+    /// * `subscription_id` - Subscription containing the resource group
+    /// * `resource_group` - Resource group containing the DNS zones
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::azure::{AzureProvider, Credentials};
+    ///
+    /// let credentials = Credentials::new("tenant-id", "client-id", "client-secret");
+    /// let provider = AzureProvider::new(credentials, "subscription-id", "dns-rg").unwrap();
+    /// ```
+    pub fn new(
+        credentials: Credentials,
+        subscription_id: &str,
+        resource_group: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials, subscription_id, resource_group)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Azure DNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Service principal credentials
+    /// * `subscription_id` - Subscription containing the resource group
+    /// * `resource_group` - Resource group containing the DNS zones
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::azure::{AzureProvider, Credentials};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let credentials = Credentials::new("tenant-id", "client-id", "client-secret");
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     AzureProvider::with_config(credentials, "subscription-id", "dns-rg", config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        subscription_id: &str,
+        resource_group: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, subscription_id, resource_group, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Azure DNS provider with a custom Resource Manager URL.
+    ///
+    /// This is primarily useful for testing with mock servers; combine it with
+    /// [`Credentials::with_authority_url`] to redirect token requests too.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Service principal credentials
+    /// * `subscription_id` - Subscription containing the resource group
+    /// * `resource_group` - Resource group containing the DNS zones
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        credentials: Credentials,
+        subscription_id: &str,
+        resource_group: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(
+            credentials,
+            subscription_id,
+            resource_group,
+            base_url,
+            HttpClientConfig::default(),
+        )?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for AzureProvider {
+    type Zone = AzureZone;
+    type CustomRetrieveError = AzureError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                Some(404) => RetrieveZoneError::NotFound,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(AzureZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| AzureZone {
+                api_client: self.api_client.clone(),
+                repr: zone,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for AzureProvider {
+    type CustomCreateError = AzureError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let zone = self
+            .api_client
+            .create_zone(domain.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => CreateZoneError::Unauthorized,
+                Some(400) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(AzureZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+}
+
+impl DeleteZone for AzureProvider {
+    type CustomDeleteError = AzureError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        let zone_id = zone_id.trim_end_matches('.');
+        let map_err = |err: AzureError| match err.status() {
+            _ if is_unauthorized(&err) => DeleteZoneError::Unauthorized,
+            Some(404) => DeleteZoneError::NotFound,
+            _ => DeleteZoneError::Custom(err),
+        };
+
+        // Deleting a missing zone succeeds with 204, so check that it exists first.
+        self.api_client.get_zone(zone_id).await.map_err(map_err)?;
+        self.api_client.delete_zone(zone_id).await.map_err(map_err)
+    }
+}
+
+impl AzureZone {
+    /// Returns the ARM resource ID of the zone.
+    pub fn resource_id(&self) -> &str {
+        &self.repr.resource_id
+    }
+
+    /// Returns the name servers assigned to the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.properties.name_servers
+    }
+
+    /// Returns the number of record sets in the zone, if reported.
+    pub fn number_of_record_sets(&self) -> Option<u64> {
+        self.repr.properties.number_of_record_sets
+    }
+
+    /// Converts a host into a record set name relative to the zone.
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host == self.repr.name {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.name)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts all records of a record set into generic records.
+    fn records_from_record_set(record_set: &api::RecordSet) -> Vec<Record> {
+        let typ = record_set.record_type();
+        record_data_from_properties(typ, &record_set.properties)
+            .into_iter()
+            .map(|data| Record {
+                id: format!("{}/{}/{}", record_set.name, typ, data.get_value()),
+                host: record_set.name.clone(),
+                data,
+                ttl: record_set.properties.ttl.unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+impl Zone for AzureZone {
+    type CustomRetrieveError = AzureError;
+
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record_sets = self
+            .api_client
+            .list_record_sets(&self.repr.name)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_sets
+            .iter()
+            .flat_map(Self::records_from_record_set)
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let record_set = self
+            .api_client
+            .get_record_set(&self.repr.name, parts[1], parts[0])
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        Self::records_from_record_set(&record_set)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for AzureZone {
+    type CustomCreateError = AzureError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.relative_name(host);
+
+        let map_err = |err: AzureError| match err.status() {
+            _ if is_unauthorized(&err) => CreateRecordError::Unauthorized,
+            Some(400) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        let existing = self
+            .api_client
+            .get_record_set(&self.repr.name, typ, &name)
+            .await
+            .map_err(map_err)?;
+
+        let (mut properties, precondition) = match existing {
+            Some(ref record_set) => (
+                record_set.properties.clone(),
+                record_set
+                    .etag
+                    .as_deref()
+                    .map_or(Precondition::None, Precondition::Matches),
+            ),
+            None => (api::RecordSetProperties::default(), Precondition::NotExists),
+        };
+
+        let mut records = record_data_from_properties(typ, &properties);
+        if records.iter().any(|r| r.get_value() == data.get_value())
+            || (typ == "CNAME" && !records.is_empty())
+        {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+        records.push(data.clone());
+
+        properties.ttl = Some(ttl);
+        set_record_data(&mut properties, typ, &records).ok_or(CreateRecordError::InvalidRecord)?;
+
+        self.api_client
+            .put_record_set(&self.repr.name, typ, &name, &properties, precondition)
+            .await
+            .map_err(map_err)?;
+
+        Ok(Record {
+            id: format!("{}/{}/{}", name, typ, data.get_value()),
+            host: name,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for AzureZone {
+    type CustomDeleteError = AzureError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (name, typ, value) = (parts[0], parts[1], parts[2]);
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        let map_err = |err: AzureError| match err.status() {
+            _ if is_unauthorized(&err) => DeleteRecordError::Unauthorized,
+            Some(404) => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let record_set = self
+            .api_client
+            .get_record_set(&self.repr.name, typ, name)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+        let precondition = record_set
+            .etag
+            .as_deref()
+            .map_or(Precondition::None, Precondition::Matches);
+
+        let records = record_data_from_properties(typ, &record_set.properties);
+        let remaining: Vec<RecordData> = records
+            .iter()
+            .filter(|r| r.get_value() != value)
+            .cloned()
+            .collect();
+        if remaining.len() == records.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        if remaining.is_empty() {
+            self.api_client
+                .delete_record_set(&self.repr.name, typ, name, precondition)
+                .await
+                .map_err(map_err)?;
+        } else {
+            let mut properties = record_set.properties.clone();
+            set_record_data(&mut properties, typ, &remaining).ok_or(DeleteRecordError::NotFound)?;
+            self.api_client
+                .put_record_set(&self.repr.name, typ, name, &properties, precondition)
+                .await
+                .map_err(map_err)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Converts the typed record payloads of a record set into [`RecordData`].
+///
+/// Host names lose their trailing dot and the character strings of TXT
+/// records are concatenated.
+fn record_data_from_properties(
+    typ: &str,
+    properties: &api::RecordSetProperties,
+) -> Vec<RecordData> {
+    let host = |name: &str| name.trim_end_matches('.').to_string();
+
+    match typ {
+        "A" => properties
+            .a_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::from_raw("A", &r.ipv4_address))
+            .collect(),
+        "AAAA" => properties
+            .aaaa_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::from_raw("AAAA", &r.ipv6_address))
+            .collect(),
+        "CAA" => properties
+            .caa_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::Other {
+                typ: "CAA".to_string(),
+                value: format!("{} {} \"{}\"", r.flags, r.tag, r.value),
+            })
+            .collect(),
+        "CNAME" => properties
+            .cname_record
+            .iter()
+            .map(|r| RecordData::CNAME(host(&r.cname)))
+            .collect(),
+        "MX" => properties
+            .mx_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::MX {
+                priority: r.preference,
+                mail_server: host(&r.exchange),
+            })
+            .collect(),
+        "NS" => properties
+            .ns_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::NS(host(&r.nsdname)))
+            .collect(),
+        "PTR" => properties
+            .ptr_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::Other {
+                typ: "PTR".to_string(),
+                value: host(&r.ptrdname),
+            })
+            .collect(),
+        "SOA" => properties
+            .soa_record
+            .iter()
+            .map(|r| RecordData::Other {
+                typ: "SOA".to_string(),
+                value: format!(
+                    "{} {} {} {} {} {} {}",
+                    r.host,
+                    r.email,
+                    r.serial_number,
+                    r.refresh_time,
+                    r.retry_time,
+                    r.expire_time,
+                    r.minimum_ttl
+                ),
+            })
+            .collect(),
+        "SRV" => properties
+            .srv_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::SRV {
+                priority: r.priority,
+                weight: r.weight,
+                port: r.port,
+                target: host(&r.target),
+            })
+            .collect(),
+        "TXT" => properties
+            .txt_records
+            .iter()
+            .flatten()
+            .map(|r| RecordData::TXT(r.value.concat()))
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+/// Replaces the typed record payload of `typ` in `properties` with `records`.
+///
+/// Returns `None` if a record does not match the type or a CAA value is
+/// malformed.
+fn set_record_data(
+    properties: &mut api::RecordSetProperties,
+    typ: &str,
+    records: &[RecordData],
+) -> Option<()> {
+    match typ {
+        "A" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::A(addr) => Some(api::ARecord {
+                    ipv4_address: addr.to_string(),
+                }),
+                _ => None,
+            });
+            properties.a_records = Some(records.collect::<Option<_>>()?);
+        }
+        "AAAA" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::AAAA(addr) => Some(api::AaaaRecord {
+                    ipv6_address: addr.to_string(),
+                }),
+                _ => None,
+            });
+            properties.aaaa_records = Some(records.collect::<Option<_>>()?);
+        }
+        "CAA" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::Other { typ, value } if typ == "CAA" => {
+                    let mut parts = value.splitn(3, ' ');
+                    let flags = parts.next()?.parse().ok()?;
+                    let tag = parts.next()?.to_string();
+                    let value = parts.next()?.trim_matches('"').to_string();
+                    Some(api::CaaRecord { flags, tag, value })
+                }
+                _ => None,
+            });
+            properties.caa_records = Some(records.collect::<Option<_>>()?);
+        }
+        "CNAME" => match records {
+            [RecordData::CNAME(cname)] => {
+                properties.cname_record = Some(api::CnameRecord {
+                    cname: cname.clone(),
+                })
+            }
+            _ => return None,
+        },
+        "MX" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::MX {
+                    priority,
+                    mail_server,
+                } => Some(api::MxRecord {
+                    preference: *priority,
+                    exchange: mail_server.clone(),
+                }),
+                _ => None,
+            });
+            properties.mx_records = Some(records.collect::<Option<_>>()?);
+        }
+        "NS" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::NS(nsdname) => Some(api::NsRecord {
+                    nsdname: nsdname.clone(),
+                }),
+                _ => None,
+            });
+            properties.ns_records = Some(records.collect::<Option<_>>()?);
+        }
+        "PTR" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::Other { typ, value } if typ == "PTR" => Some(api::PtrRecord {
+                    ptrdname: value.clone(),
+                }),
+                _ => None,
+            });
+            properties.ptr_records = Some(records.collect::<Option<_>>()?);
+        }
+        "SRV" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::SRV {
+                    priority,
+                    weight,
+                    port,
+                    target,
+                } => Some(api::SrvRecord {
+                    priority: *priority,
+                    weight: *weight,
+                    port: *port,
+                    target: target.clone(),
+                }),
+                _ => None,
+            });
+            properties.srv_records = Some(records.collect::<Option<_>>()?);
+        }
+        "TXT" => {
+            let records = records.iter().map(|data| match data {
+                RecordData::TXT(txt) => Some(api::TxtRecord {
+                    value: split_txt(txt),
+                }),
+                _ => None,
+            });
+            properties.txt_records = Some(records.collect::<Option<_>>()?);
+        }
+        _ => return None,
+    }
+
+    Some(())
+}
@@ -0,0 +1,632 @@
+//! Low-level Baidu AI Cloud (BCE) DNS API client.
+//!
+//! This module provides direct access to the BCE DNS v1 API using
+//! `bce-auth-v1` request signing.
+//! For most use cases, prefer using [`BaiduCloudProvider`](super::BaiduCloudProvider) instead.
+//!
+//! # Request Signing
+//!
+//! ```text
+//! AuthStringPrefix = bce-auth-v1/AK/TIMESTAMP/EXPIRATION
+//! SigningKey       = hex(hmac_sha256(SK, AuthStringPrefix))
+//! CanonicalRequest = METHOD \n URI \n QUERY \n HEADERS
+//! Signature        = hex(hmac_sha256(SigningKey, CanonicalRequest))
+//! Authorization    = AuthStringPrefix/SIGNED_HEADERS/Signature
+//! ```
+//!
+//! Canonical headers are `name:value` lines with URI-encoded values, sorted
+//! and joined by newlines; there is no trailing newline or payload hash.
+//!
+//! # API Reference
+//!
+//! - [DNS API](https://cloud.baidu.com/doc/DNS/s/El4s7lssr)
+//! - [Request signing](https://cloud.baidu.com/doc/Reference/s/njwvz1yfu)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::baiducloud::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("your-access-key-id", "your-secret-access-key");
+//! let client = Client::new(credentials)?;
+//!
+//! for zone in client.list_zones(None).await? {
+//!     println!("{} ({})", zone.name, zone.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::signing::{canonical_query, hmac_sha256_hex, uri_encode};
+use crate::HttpClientConfig;
+
+/// Default API endpoint.
+const BAIDUCLOUD_API_URL: &str = "https://dns.baidubce.com";
+
+/// Authentication protocol version.
+const AUTH_VERSION: &str = "bce-auth-v1";
+
+/// Validity of a request signature, in seconds.
+const EXPIRATION_SECONDS: u64 = 1800;
+
+/// Page size requested from list endpoints.
+const MAX_KEYS: u32 = 1000;
+
+/// Errors that may occur when interacting with the BCE DNS API.
+#[derive(Debug, Error)]
+pub enum BaiduCloudError {
+    /// The API returned an error response.
+    #[error("API error {code} ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error code (e.g., `AccessDenied`, `NoSuchZone`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl BaiduCloudError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            BaiduCloudError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            BaiduCloudError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// BCE error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// Access key credentials.
+#[derive(Clone)]
+pub struct Credentials {
+    /// Access key ID.
+    pub access_key: String,
+    /// Secret access key.
+    pub secret_key: String,
+}
+
+impl Credentials {
+    /// Creates credentials from an access key pair.
+    pub fn new(access_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// Reads credentials from the `BAIDUCLOUD_ACCESS_KEY_ID` and
+    /// `BAIDUCLOUD_SECRET_ACCESS_KEY` environment variables.
+    pub fn from_env() -> Option<Self> {
+        let access_key = std::env::var("BAIDUCLOUD_ACCESS_KEY_ID").ok()?;
+        let secret_key = std::env::var("BAIDUCLOUD_SECRET_ACCESS_KEY").ok()?;
+        Some(Self::new(access_key, secret_key))
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// A request to be signed with `bce-auth-v1`.
+#[derive(Debug, Clone)]
+pub struct SigningRequest<'a> {
+    /// HTTP method (e.g., `GET`).
+    pub method: &'a str,
+    /// URI path, not yet percent-encoded.
+    pub path: &'a str,
+    /// Query parameters.
+    pub query: &'a [(&'a str, &'a str)],
+    /// Headers to sign as `(name, value)` pairs; `host` must be included.
+    pub headers: &'a [(&'a str, &'a str)],
+    /// Request timestamp in `YYYY-MM-DDTHH:MM:SSZ` format.
+    pub timestamp: &'a str,
+    /// Validity of the signature, in seconds.
+    pub expiration: u64,
+}
+
+/// Computes the `bce-auth-v1` `Authorization` header value for a request.
+pub fn sign(credentials: &Credentials, request: &SigningRequest<'_>) -> String {
+    let auth_string_prefix = format!(
+        "{}/{}/{}/{}",
+        AUTH_VERSION, credentials.access_key, request.timestamp, request.expiration
+    );
+    let signing_key = hmac_sha256_hex(credentials.secret_key.as_bytes(), &auth_string_prefix);
+
+    let mut headers: Vec<(String, String)> = request
+        .headers
+        .iter()
+        .map(|(name, value)| {
+            (
+                uri_encode(&name.to_ascii_lowercase(), true),
+                uri_encode(value.trim(), true),
+            )
+        })
+        .collect();
+    headers.sort();
+
+    let canonical_headers = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}", name, value))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| name.as_str())
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}",
+        request.method,
+        uri_encode(request.path, false),
+        canonical_query(request.query),
+        canonical_headers
+    );
+    let signature = hmac_sha256_hex(signing_key.as_bytes(), &canonical_request);
+
+    format!("{}/{}/{}", auth_string_prefix, signed_headers, signature)
+}
+
+/// Returns the current time in `YYYY-MM-DDTHH:MM:SSZ` format.
+fn timestamp_now() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap()
+        .format("%Y-%m-%dT%H:%M:%SZ")
+        .to_string()
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A public zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    /// Zone ID.
+    pub id: String,
+    /// Zone name (e.g., `example.com`).
+    pub name: String,
+    /// Zone status (e.g., `running`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Product version (e.g., `free`, `discount`, `flagship`).
+    #[serde(default)]
+    pub product_version: Option<String>,
+    /// Creation time.
+    #[serde(default)]
+    pub create_time: Option<String>,
+    /// Expiration time of paid versions.
+    #[serde(default)]
+    pub expire_time: Option<String>,
+}
+
+/// Marker-based pagination fields of list responses.
+#[derive(Debug, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Page {
+    #[serde(default)]
+    is_truncated: bool,
+    #[serde(default)]
+    next_marker: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZoneList {
+    #[serde(default)]
+    zones: Vec<Zone>,
+    #[serde(flatten)]
+    page: Page,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Record {
+    /// Record ID.
+    pub id: String,
+    /// Host relative to the zone (`@` for the apex).
+    pub rr: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record value; for SRV records the RDATA without the priority.
+    pub value: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Resolution line (`default` for all resolvers).
+    #[serde(default)]
+    pub line: String,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: Option<u16>,
+    /// Record status (e.g., `running`, `pause`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Description.
+    #[serde(default)]
+    pub description: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordList {
+    #[serde(default)]
+    records: Vec<Record>,
+    #[serde(flatten)]
+    page: Page,
+}
+
+/// Request for creating or updating a record.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordRequest {
+    /// Host relative to the zone (`@` for the apex).
+    pub rr: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record value; for SRV records the RDATA without the priority.
+    pub value: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Baidu AI Cloud DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    credentials: Credentials,
+    base_url: String,
+    host: String,
+}
+
+impl Client {
+    /// Creates a new BCE DNS API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair used to sign requests
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, BAIDUCLOUD_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new BCE DNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair used to sign requests
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, BAIDUCLOUD_API_URL, config)
+    }
+
+    /// Creates a new BCE DNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair used to sign requests
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(base_url)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("base URL has no host".into()),
+        };
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            credentials,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            host,
+        })
+    }
+
+    /// Sends a signed request and returns the response body.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        params: &[(&str, &str)],
+        body: Option<String>,
+    ) -> Result<String, BaiduCloudError> {
+        let timestamp = timestamp_now();
+        let authorization = sign(
+            &self.credentials,
+            &SigningRequest {
+                method: method.as_str(),
+                path,
+                query: params,
+                headers: &[("host", &self.host), ("x-bce-date", &timestamp)],
+                timestamp: &timestamp,
+                expiration: EXPIRATION_SECONDS,
+            },
+        );
+
+        let query = canonical_query(params);
+        let path = uri_encode(path, false);
+        let url = if query.is_empty() {
+            format!("{}{}", self.base_url, path)
+        } else {
+            format!("{}{}?{}", self.base_url, path, query)
+        };
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("Authorization", authorization)
+            .header("x-bce-date", &timestamp);
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (code, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.code, e.message),
+                Err(_) => (String::new(), text),
+            };
+            return Err(BaiduCloudError::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a signed GET request and deserializes the JSON response.
+    async fn get<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        params: &[(&str, &str)],
+    ) -> Result<T, BaiduCloudError> {
+        let text = self
+            .request(reqwest::Method::GET, path, params, None)
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists zones, following pagination.
+    ///
+    /// `name` restricts the result to the zone with the given name.
+    pub async fn list_zones(&self, name: Option<&str>) -> Result<Vec<Zone>, BaiduCloudError> {
+        let max_keys = MAX_KEYS.to_string();
+        let mut zones = Vec::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let mut params = vec![("maxKeys", max_keys.as_str())];
+            if let Some(name) = name {
+                params.push(("name", name));
+            }
+            if let Some(marker) = &marker {
+                params.push(("marker", marker));
+            }
+
+            let list: ZoneList = self.get("/v1/dns/zone", &params).await?;
+            zones.extend(list.zones);
+
+            match list.page.next_marker {
+                Some(next) if list.page.is_truncated => marker = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// Creates a zone (free version).
+    pub async fn create_zone(&self, name: &str) -> Result<(), BaiduCloudError> {
+        let body = serde_json::json!({ "name": name }).to_string();
+        self.request(reqwest::Method::POST, "/v1/dns/zone", &[], Some(body))
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a zone.
+    pub async fn delete_zone(&self, name: &str) -> Result<(), BaiduCloudError> {
+        let path = format!("/v1/dns/zone/{}", name);
+        self.request(reqwest::Method::DELETE, &path, &[], None)
+            .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists the records of a zone, following pagination.
+    ///
+    /// `rr` restricts the result to records of the given host.
+    pub async fn list_records(
+        &self,
+        zone_name: &str,
+        rr: Option<&str>,
+    ) -> Result<Vec<Record>, BaiduCloudError> {
+        let path = format!("/v1/dns/zone/{}/record", zone_name);
+        let max_keys = MAX_KEYS.to_string();
+        let mut records = Vec::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let mut params = vec![("maxKeys", max_keys.as_str())];
+            if let Some(rr) = rr {
+                params.push(("rr", rr));
+            }
+            if let Some(marker) = &marker {
+                params.push(("marker", marker));
+            }
+
+            let list: RecordList = self.get(&path, &params).await?;
+            records.extend(list.records);
+
+            match list.page.next_marker {
+                Some(next) if list.page.is_truncated => marker = Some(next),
+                _ => break,
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        zone_name: &str,
+        record_id: &str,
+    ) -> Result<Record, BaiduCloudError> {
+        let path = format!("/v1/dns/zone/{}/record", zone_name);
+        let list: RecordList = self.get(&path, &[("id", record_id)]).await?;
+        list.records
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or_else(|| BaiduCloudError::Api {
+                status: 404,
+                code: "NoSuchRecord".to_string(),
+                message: format!("record {} not found", record_id),
+            })
+    }
+
+    /// Creates a record.
+    ///
+    /// The API does not return the new record; look it up with
+    /// [`list_records`](Self::list_records) if its ID is needed.
+    pub async fn create_record(
+        &self,
+        zone_name: &str,
+        request: &RecordRequest,
+    ) -> Result<(), BaiduCloudError> {
+        let path = format!("/v1/dns/zone/{}/record", zone_name);
+        let body = serde_json::to_string(request)?;
+        self.request(reqwest::Method::POST, &path, &[], Some(body))
+            .await?;
+        Ok(())
+    }
+
+    /// Updates the host, value, TTL and priority of a record.
+    pub async fn update_record(
+        &self,
+        zone_name: &str,
+        record_id: &str,
+        request: &RecordRequest,
+    ) -> Result<(), BaiduCloudError> {
+        let path = format!("/v1/dns/zone/{}/record/{}", zone_name, record_id);
+        let body = serde_json::to_string(request)?;
+        self.request(reqwest::Method::PUT, &path, &[], Some(body))
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(
+        &self,
+        zone_name: &str,
+        record_id: &str,
+    ) -> Result<(), BaiduCloudError> {
+        let path = format!("/v1/dns/zone/{}/record/{}", zone_name, record_id);
+        self.request(reqwest::Method::DELETE, &path, &[], None)
+            .await?;
+        Ok(())
+    }
+}
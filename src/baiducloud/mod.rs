@@ -0,0 +1,474 @@
+//! Baidu AI Cloud DNS provider implementation.
+//!
+//! This provider uses the Baidu AI Cloud (BCE) DNS v1 API for public zones,
+//! with `bce-auth-v1` request signing.
+//!
+//! # Authentication
+//!
+//! Requires an access key ID and secret access key:
+//! - Create them under "Security Authentication" > "Access Key" in the console
+//! - IAM users need the `DNSFullControlAccessPolicy` policy
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::baiducloud::{BaiduCloudProvider, Credentials};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("your-access-key-id", "your-secret-access-key");
+//! let provider = BaiduCloudProvider::new(credentials)?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones
+//!
+//! The BCE DNS API addresses zones by name, so the zone ID is the domain
+//! name. New zones are created as the free version.
+//!
+//! # Record Creation
+//!
+//! The API does not return created records, so
+//! [`create_record`](crate::CreateRecord::create_record) looks the record up
+//! by host, type and value afterwards to learn its ID.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CAA, CNAME, MX, NS, SRV and TXT
+//!
+//! # API Reference
+//!
+//! - [DNS API](https://cloud.baidu.com/doc/DNS/s/El4s7lssr)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{BaiduCloudError, Client, Credentials};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &["A", "AAAA", "CAA", "CNAME", "MX", "NS", "SRV", "TXT"];
+
+fn is_unauthorized(err: &BaiduCloudError) -> bool {
+    matches!(err.status(), Some(401 | 403))
+}
+
+/// Baidu AI Cloud DNS provider.
+#[derive(Clone)]
+pub struct BaiduCloudProvider {
+    api_client: Arc<Client>,
+}
+
+/// A public zone hosted by Baidu AI Cloud DNS.
+pub struct BaiduCloudZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+}
+
+impl BaiduCloudProvider {
+    /// Creates a new Baidu AI Cloud DNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::baiducloud::{BaiduCloudProvider, Credentials};
+    ///
+    /// let credentials = Credentials::new("access-key-id", "secret-access-key");
+    /// let provider = BaiduCloudProvider::new(credentials).unwrap();
+    /// ```
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Baidu AI Cloud DNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::baiducloud::{BaiduCloudProvider, Credentials};
+    /// use manydns::HttpClientConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = HttpClientConfig {
+    ///     timeout: Some(Duration::from_secs(60)),
+    ///     ..Default::default()
+    /// };
+    /// let credentials = Credentials::new("access-key-id", "secret-access-key");
+    /// let provider = BaiduCloudProvider::with_config(credentials, config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Baidu AI Cloud DNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Looks up a zone by name.
+    async fn find_zone(&self, name: &str) -> Result<Option<api::Zone>, BaiduCloudError> {
+        Ok(self
+            .api_client
+            .list_zones(Some(name))
+            .await?
+            .into_iter()
+            .find(|zone| zone.name.trim_end_matches('.').eq_ignore_ascii_case(name)))
+    }
+
+    fn zone(&self, repr: api::Zone) -> BaiduCloudZone {
+        BaiduCloudZone {
+            api_client: self.api_client.clone(),
+            repr,
+        }
+    }
+}
+
+impl Provider for BaiduCloudProvider {
+    type Zone = BaiduCloudZone;
+    type CustomRetrieveError = BaiduCloudError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .find_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                Some(404) => RetrieveZoneError::NotFound,
+                _ => RetrieveZoneError::Custom(err),
+            })?
+            .ok_or(RetrieveZoneError::NotFound)?;
+
+        Ok(self.zone(zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones(None)
+            .await
+            .map_err(|err| match err {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones.into_iter().map(|zone| self.zone(zone)).collect())
+    }
+}
+
+impl CreateZone for BaiduCloudProvider {
+    type CustomCreateError = BaiduCloudError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let map_err = |err: BaiduCloudError| match err.status() {
+            _ if is_unauthorized(&err) => CreateZoneError::Unauthorized,
+            Some(400) => CreateZoneError::InvalidDomainName,
+            _ => CreateZoneError::Custom(err),
+        };
+
+        let domain = domain.trim_end_matches('.');
+        self.api_client.create_zone(domain).await.map_err(map_err)?;
+
+        // The API does not return the new zone.
+        let zone = self
+            .find_zone(domain)
+            .await
+            .map_err(map_err)?
+            .ok_or(CreateZoneError::InvalidDomainName)?;
+
+        Ok(self.zone(zone))
+    }
+}
+
+impl DeleteZone for BaiduCloudProvider {
+    type CustomDeleteError = BaiduCloudError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => DeleteZoneError::Unauthorized,
+                Some(404) => DeleteZoneError::NotFound,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl BaiduCloudZone {
+    /// Returns the zone status (e.g., `running`).
+    pub fn status(&self) -> Option<&str> {
+        self.repr.status.as_deref()
+    }
+
+    /// Returns the product version (e.g., `free`).
+    pub fn product_version(&self) -> Option<&str> {
+        self.repr.product_version.as_deref()
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<BaiduCloudError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        self.api_client
+            .update_record(self.domain(), record_id, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(Record {
+            id: record_id.to_string(),
+            host: request.rr,
+            data: data.clone(),
+            ttl,
+        })
+    }
+
+    /// Converts a host into a name relative to the zone (`@` for the apex).
+    fn relative_host(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = self.domain();
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<BaiduCloudError>> {
+        if !SUPPORTED_RECORD_TYPES.contains(&data.get_type()) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let (value, priority) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (mail_server.clone(), Some(*priority)),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (format!("{} {} {}", weight, port, target), Some(*priority)),
+            _ => (data.get_value(), None),
+        };
+
+        Ok(api::RecordRequest {
+            rr: self.relative_host(host),
+            record_type: data.get_type().to_string(),
+            value,
+            ttl,
+            priority,
+        })
+    }
+}
+
+/// Maps an API error of a record modification.
+fn map_create_error(err: BaiduCloudError) -> CreateRecordError<BaiduCloudError> {
+    match err.status() {
+        _ if is_unauthorized(&err) => CreateRecordError::Unauthorized,
+        Some(400) => CreateRecordError::InvalidRecord,
+        _ => CreateRecordError::Custom(err),
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::Record) -> Record {
+    let priority = record.priority.unwrap_or_default();
+    let value = record.value.trim_end_matches('.');
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority,
+            mail_server: value.to_string(),
+        },
+        // The value holds `weight port target`, or the full RDATA including
+        // the priority.
+        "SRV" if value.split_whitespace().count() == 3 => {
+            RecordData::from_raw("SRV", &format!("{} {}", priority, value))
+        }
+        "SRV" | "CNAME" | "NS" => RecordData::from_raw(&record.record_type, value),
+        typ => RecordData::from_raw(typ, &record.value),
+    };
+
+    Record {
+        id: record.id,
+        host: record.rr,
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for BaiduCloudZone {
+    type CustomRetrieveError = BaiduCloudError;
+
+    fn id(&self) -> &str {
+        self.domain()
+    }
+
+    fn domain(&self) -> &str {
+        self.repr.name.trim_end_matches('.')
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(self.domain(), None)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record = self
+            .api_client
+            .get_record(self.domain(), record_id)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for BaiduCloudZone {
+    type CustomCreateError = BaiduCloudError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        self.api_client
+            .create_record(self.domain(), &request)
+            .await
+            .map_err(map_create_error)?;
+
+        // The API does not return the new record, so look up its ID.
+        let normalize = |value: &str| value.trim_end_matches('.').to_string();
+        let record = self
+            .api_client
+            .list_records(self.domain(), Some(&request.rr))
+            .await
+            .map_err(map_create_error)?
+            .into_iter()
+            .find(|record| {
+                record.record_type == request.record_type
+                    && normalize(&record.value) == normalize(&request.value)
+            })
+            .ok_or(CreateRecordError::InvalidRecord)?;
+
+        Ok(Record {
+            id: record.id,
+            host: request.rr,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for BaiduCloudZone {
+    type CustomDeleteError = BaiduCloudError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_record(self.domain(), record_id)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => DeleteRecordError::Unauthorized,
+                Some(404) => DeleteRecordError::NotFound,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,607 @@
+//! Low-level bunny.net DNS API client.
+//!
+//! This module provides direct access to the bunny.net DNS zone endpoints
+//! (`/dnszone`).
+//! For most use cases, prefer using [`BunnyProvider`](super::BunnyProvider) instead.
+//!
+//! # Record Types
+//!
+//! Bunny identifies record types by number rather than by name. The numbers
+//! are listed in [`BunnyRecordType`], which also converts them from and into
+//! [`RecordType`] where a standard DNS type exists.
+//!
+//! # API Reference
+//!
+//! - [DNS Zone API](https://docs.bunny.net/reference/dnszonepublic_index)
+//! - [Add DNS Record](https://docs.bunny.net/reference/dnszonepublic_addrecord)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::bunny::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-api-key")?;
+//!
+//! for zone in client.list_zones().await? {
+//!     println!("Zone: {} (ID: {})", zone.domain, zone.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::types::RecordType;
+use crate::HttpClientConfig;
+
+/// The bunny.net API base URL.
+const BUNNY_API_URL: &str = "https://api.bunny.net";
+
+/// Page size requested from the zone list endpoint (the API maximum).
+const PAGE_SIZE: u32 = 1000;
+
+/// Errors that may occur when interacting with the bunny.net API.
+#[derive(Debug, Error)]
+pub enum BunnyError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {code}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error key (e.g., `dnszone.record.invalid`), empty if none was returned.
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl BunnyError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            BunnyError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error key if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            BunnyError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// bunny.net error response body.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ErrorResponse {
+    #[serde(default)]
+    error_key: Option<String>,
+    #[serde(default)]
+    field: Option<String>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// A page of the zone list response.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Page<T> {
+    items: Vec<T>,
+    #[serde(default)]
+    has_more_items: bool,
+}
+
+// =============================================================================
+// Record Type Enum
+// =============================================================================
+
+/// Record types as numbered by the bunny.net API.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum BunnyRecordType {
+    /// IPv4 address.
+    A = 0,
+    /// IPv6 address.
+    AAAA = 1,
+    /// Canonical name.
+    CNAME = 2,
+    /// Text.
+    TXT = 3,
+    /// Mail exchange.
+    MX = 4,
+    /// HTTP redirect to the URL in the record value.
+    Redirect = 5,
+    /// CNAME flattened into A/AAAA answers at query time.
+    Flatten = 6,
+    /// Linked to a bunny.net pull zone.
+    PullZone = 7,
+    /// Service locator.
+    SRV = 8,
+    /// Certification Authority Authorization.
+    CAA = 9,
+    /// Domain name pointer.
+    PTR = 10,
+    /// Answered by a bunny.net edge script.
+    Script = 11,
+    /// Name server.
+    NS = 12,
+}
+
+impl BunnyRecordType {
+    /// Creates a record type from its API number.
+    pub const fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(Self::A),
+            1 => Some(Self::AAAA),
+            2 => Some(Self::CNAME),
+            3 => Some(Self::TXT),
+            4 => Some(Self::MX),
+            5 => Some(Self::Redirect),
+            6 => Some(Self::Flatten),
+            7 => Some(Self::PullZone),
+            8 => Some(Self::SRV),
+            9 => Some(Self::CAA),
+            10 => Some(Self::PTR),
+            11 => Some(Self::Script),
+            12 => Some(Self::NS),
+            _ => None,
+        }
+    }
+
+    /// Returns the API number of the record type.
+    #[inline]
+    pub const fn as_u8(&self) -> u8 {
+        *self as u8
+    }
+
+    /// Returns the name of the record type.
+    ///
+    /// Bunny-specific types are named `REDIRECT`, `FLATTEN`, `PULLZONE` and
+    /// `SCRIPT`.
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::A => "A",
+            Self::AAAA => "AAAA",
+            Self::CNAME => "CNAME",
+            Self::TXT => "TXT",
+            Self::MX => "MX",
+            Self::Redirect => "REDIRECT",
+            Self::Flatten => "FLATTEN",
+            Self::PullZone => "PULLZONE",
+            Self::SRV => "SRV",
+            Self::CAA => "CAA",
+            Self::PTR => "PTR",
+            Self::Script => "SCRIPT",
+            Self::NS => "NS",
+        }
+    }
+
+    /// Creates a record type from its name, as returned by [`as_str`](Self::as_str).
+    pub fn parse(s: &str) -> Option<Self> {
+        match s.to_uppercase().as_str() {
+            "REDIRECT" => Some(Self::Redirect),
+            "FLATTEN" => Some(Self::Flatten),
+            "PULLZONE" => Some(Self::PullZone),
+            "SCRIPT" => Some(Self::Script),
+            typ => RecordType::parse(typ).and_then(Self::from_record_type),
+        }
+    }
+
+    /// Returns the standard DNS record type, or `None` for Bunny-specific types.
+    pub const fn to_record_type(&self) -> Option<RecordType> {
+        match self {
+            Self::A => Some(RecordType::A),
+            Self::AAAA => Some(RecordType::AAAA),
+            Self::CNAME => Some(RecordType::CNAME),
+            Self::TXT => Some(RecordType::TXT),
+            Self::MX => Some(RecordType::MX),
+            Self::SRV => Some(RecordType::SRV),
+            Self::CAA => Some(RecordType::CAA),
+            Self::PTR => Some(RecordType::PTR),
+            Self::NS => Some(RecordType::NS),
+            Self::Redirect | Self::Flatten | Self::PullZone | Self::Script => None,
+        }
+    }
+
+    /// Creates a record type from a standard DNS record type, if Bunny supports it.
+    pub const fn from_record_type(typ: RecordType) -> Option<Self> {
+        match typ {
+            RecordType::A => Some(Self::A),
+            RecordType::AAAA => Some(Self::AAAA),
+            RecordType::CNAME => Some(Self::CNAME),
+            RecordType::TXT => Some(Self::TXT),
+            RecordType::MX => Some(Self::MX),
+            RecordType::SRV => Some(Self::SRV),
+            RecordType::CAA => Some(Self::CAA),
+            RecordType::PTR => Some(Self::PTR),
+            RecordType::NS => Some(Self::NS),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for BunnyRecordType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A bunny.net DNS zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DnsZone {
+    /// Zone ID.
+    pub id: u64,
+    /// Domain name (e.g., `example.com`).
+    pub domain: String,
+    /// Records of the zone.
+    #[serde(default)]
+    pub records: Vec<DnsRecord>,
+    /// Whether the bunny.net name servers were detected at the registrar.
+    #[serde(default)]
+    pub nameservers_detected: bool,
+    /// Whether custom name servers are used instead of the bunny.net ones.
+    #[serde(default)]
+    pub custom_nameservers_enabled: bool,
+    /// Primary name server.
+    #[serde(default)]
+    pub nameserver1: Option<String>,
+    /// Secondary name server.
+    #[serde(default)]
+    pub nameserver2: Option<String>,
+    /// SOA responsible person mailbox.
+    #[serde(default)]
+    pub soa_email: Option<String>,
+    /// Last modification time (ISO 8601).
+    #[serde(default)]
+    pub date_modified: Option<String>,
+    /// Creation time (ISO 8601).
+    #[serde(default)]
+    pub date_created: Option<String>,
+}
+
+/// Request body for creating a zone.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct CreateZoneRequest {
+    /// Domain name.
+    pub domain: String,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a bunny.net zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct DnsRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Record type number, see [`BunnyRecordType`].
+    #[serde(rename = "Type")]
+    pub record_type: u8,
+    /// Host name relative to the zone (empty for the apex).
+    #[serde(default)]
+    pub name: String,
+    /// Record value; for MX, CNAME, NS and SRV the target host name.
+    #[serde(default)]
+    pub value: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: u16,
+    /// Weight for SRV records.
+    #[serde(default)]
+    pub weight: u16,
+    /// Port for SRV records.
+    #[serde(default)]
+    pub port: u16,
+    /// Flags for CAA records.
+    #[serde(default)]
+    pub flags: u8,
+    /// Tag for CAA records (`issue`, `issuewild` or `iodef`).
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// Name of the linked pull zone or edge script, for `PullZone` and `Script` records.
+    #[serde(default)]
+    pub link_name: Option<String>,
+    /// Whether the record is disabled.
+    #[serde(default)]
+    pub disabled: bool,
+    /// Comment.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+impl DnsRecord {
+    /// Returns the record type, or `None` for types unknown to this client.
+    pub fn kind(&self) -> Option<BunnyRecordType> {
+        BunnyRecordType::from_u8(self.record_type)
+    }
+}
+
+/// Request body for adding a record.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct AddRecordRequest {
+    /// Record type number, see [`BunnyRecordType`].
+    #[serde(rename = "Type")]
+    pub record_type: u8,
+    /// Host name relative to the zone (empty for the apex).
+    pub name: String,
+    /// Record value.
+    pub value: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+    /// Weight for SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    /// Port for SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Flags for CAA records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub flags: Option<u8>,
+    /// Tag for CAA records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+}
+
+impl AddRecordRequest {
+    /// Creates a request for a record without type-specific fields.
+    pub fn new(record_type: BunnyRecordType, name: &str, value: &str, ttl: u64) -> Self {
+        Self {
+            record_type: record_type.as_u8(),
+            name: name.to_string(),
+            value: value.to_string(),
+            ttl,
+            priority: None,
+            weight: None,
+            port: None,
+            flags: None,
+            tag: None,
+        }
+    }
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// bunny.net API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new bunny.net API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Account API key
+    pub fn new(api_key: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, BUNNY_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new bunny.net API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Account API key
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, BUNNY_API_URL, config)
+    }
+
+    /// Creates a new bunny.net API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Account API key
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        api_key: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut key_value = HeaderValue::from_str(api_key)?;
+        key_value.set_sensitive(true);
+        headers.insert("AccessKey", key_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`BunnyError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, BunnyError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (code, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => {
+                    let message = e.message.unwrap_or_default();
+                    let message = match e.field {
+                        Some(field) if !field.is_empty() => format!("{}: {}", field, message),
+                        _ => message,
+                    };
+                    (e.error_key.unwrap_or_default(), message)
+                }
+                Err(_) => (String::new(), text),
+            };
+            return Err(BunnyError::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, BunnyError> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Fetches all pages of the zone list, optionally filtered by a search term.
+    async fn get_zones(&self, search: Option<&str>) -> Result<Vec<DnsZone>, BunnyError> {
+        let mut zones = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let mut path = format!("/dnszone?page={}&perPage={}", page, PAGE_SIZE);
+            if let Some(search) = search {
+                path.push_str(&format!("&search={}", encode_component(search)));
+            }
+
+            let response: Page<DnsZone> = self.get(&path).await?;
+            zones.extend(response.items);
+
+            if !response.has_more_items {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(zones)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists all zones of the account, following pagination.
+    pub async fn list_zones(&self) -> Result<Vec<DnsZone>, BunnyError> {
+        self.get_zones(None).await
+    }
+
+    /// Finds a zone by domain name.
+    pub async fn find_zone(&self, domain: &str) -> Result<Option<DnsZone>, BunnyError> {
+        let zones = self.get_zones(Some(domain)).await?;
+        Ok(zones
+            .into_iter()
+            .find(|zone| zone.domain.eq_ignore_ascii_case(domain)))
+    }
+
+    /// Gets a zone, including its records, by ID.
+    pub async fn get_zone(&self, zone_id: u64) -> Result<DnsZone, BunnyError> {
+        self.get(&format!("/dnszone/{}", zone_id)).await
+    }
+
+    /// Creates a zone.
+    pub async fn create_zone(&self, request: &CreateZoneRequest) -> Result<DnsZone, BunnyError> {
+        let url = format!("{}/dnszone", self.base_url);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a zone and all of its records.
+    pub async fn delete_zone(&self, zone_id: u64) -> Result<(), BunnyError> {
+        let url = format!("{}/dnszone/{}", self.base_url, zone_id);
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Adds a record to a zone.
+    pub async fn add_record(
+        &self,
+        zone_id: u64,
+        request: &AddRecordRequest,
+    ) -> Result<DnsRecord, BunnyError> {
+        let url = format!("{}/dnszone/{}/records", self.base_url, zone_id);
+        let text = self.send(self.http_client.put(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a record from a zone.
+    pub async fn delete_record(&self, zone_id: u64, record_id: u64) -> Result<(), BunnyError> {
+        let url = format!(
+            "{}/dnszone/{}/records/{}",
+            self.base_url, zone_id, record_id
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+/// Percent-encodes a query string component.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,536 @@
+//! bunny.net DNS provider implementation.
+//!
+//! This provider uses the bunny.net DNS zone API with `AccessKey` header
+//! authentication.
+//!
+//! # Authentication
+//!
+//! Requires the bunny.net account API key:
+//! - Find the key at: <https://dash.bunny.net/account/api-key>
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::bunny::BunnyProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = BunnyProvider::new("your-api-key")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone IDs
+//!
+//! Zone IDs are bunny.net's numeric zone IDs.
+//! [`get_zone`](crate::Provider::get_zone) and
+//! [`delete_zone`](crate::DeleteZone::delete_zone) also accept the domain name.
+//!
+//! # Bunny-Specific Records
+//!
+//! Besides the standard DNS types, bunny.net has record types that are
+//! resolved by its own infrastructure. These are returned as
+//! [`RecordData::Other`] with the type names of
+//! [`BunnyRecordType`]:
+//!
+//! - `REDIRECT`: HTTP redirect, the value is the target URL
+//! - `FLATTEN`: flattened CNAME, the value is the target host name
+//! - `PULLZONE`: linked to a pull zone, the value is the pull zone name
+//! - `SCRIPT`: answered by an edge script, the value is the script name
+//!
+//! `REDIRECT` and `FLATTEN` records can also be created; pull zone and script
+//! records have to be linked in the bunny.net dashboard.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, as [`RecordData::Other`] with the value `flags tag "value"`
+//! - PTR, as [`RecordData::Other`]
+//! - REDIRECT, FLATTEN, PULLZONE and SCRIPT, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [DNS Zone API](https://docs.bunny.net/reference/dnszonepublic_index)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{BunnyError, BunnyRecordType, Client};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// bunny.net DNS provider.
+#[derive(Clone)]
+pub struct BunnyProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone managed by bunny.net.
+pub struct BunnyZone {
+    api_client: Arc<Client>,
+    repr: api::DnsZone,
+    /// Zone ID as a string.
+    id: String,
+}
+
+impl BunnyProvider {
+    /// Creates a new bunny.net provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Account API key
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::bunny::BunnyProvider;
+    ///
+    /// let provider = BunnyProvider::new("your-api-key").unwrap();
+    /// ```
+    pub fn new(api_key: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(api_key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new bunny.net provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Account API key
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::bunny::BunnyProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = BunnyProvider::with_config("your-api-key", config).unwrap();
+    /// ```
+    pub fn with_config(
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(api_key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new bunny.net provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Account API key
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        api_key: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(api_key, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Resolves a zone ID or domain name into a zone.
+    async fn find_zone(&self, zone_id: &str) -> Result<Option<api::DnsZone>, BunnyError> {
+        match zone_id.parse::<u64>() {
+            Ok(id) => match self.api_client.get_zone(id).await {
+                Ok(zone) => Ok(Some(zone)),
+                Err(err) if err.status() == Some(404) => Ok(None),
+                Err(err) => Err(err),
+            },
+            Err(_) => {
+                self.api_client
+                    .find_zone(zone_id.trim_end_matches('.'))
+                    .await
+            }
+        }
+    }
+}
+
+impl Provider for BunnyProvider {
+    type Zone = BunnyZone;
+    type CustomRetrieveError = BunnyError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .find_zone(zone_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?
+            .ok_or(RetrieveZoneError::NotFound)?;
+
+        Ok(BunnyZone::from_api(self.api_client.clone(), zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| BunnyZone::from_api(self.api_client.clone(), zone))
+            .collect())
+    }
+}
+
+impl CreateZone for BunnyProvider {
+    type CustomCreateError = BunnyError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let request = api::CreateZoneRequest {
+            domain: domain.trim_end_matches('.').to_string(),
+        };
+
+        let zone =
+            self.api_client
+                .create_zone(&request)
+                .await
+                .map_err(|err| match err.status() {
+                    Some(401 | 403) => CreateZoneError::Unauthorized,
+                    Some(400) => CreateZoneError::InvalidDomainName,
+                    _ => CreateZoneError::Custom(err),
+                })?;
+
+        Ok(BunnyZone::from_api(self.api_client.clone(), zone))
+    }
+}
+
+impl DeleteZone for BunnyProvider {
+    type CustomDeleteError = BunnyError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        let map_err = |err: BunnyError| match err.status() {
+            Some(404) => DeleteZoneError::NotFound,
+            Some(401 | 403) => DeleteZoneError::Unauthorized,
+            _ => DeleteZoneError::Custom(err),
+        };
+
+        let zone = self
+            .find_zone(zone_id)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteZoneError::NotFound)?;
+
+        self.api_client.delete_zone(zone.id).await.map_err(map_err)
+    }
+}
+
+impl BunnyZone {
+    /// Creates a new BunnyZone from API response data.
+    fn from_api(api_client: Arc<Client>, zone: api::DnsZone) -> Self {
+        let id = zone.id.to_string();
+        Self {
+            api_client,
+            repr: zone,
+            id,
+        }
+    }
+
+    /// Returns the name servers of the zone.
+    pub fn name_servers(&self) -> Vec<&str> {
+        [&self.repr.nameserver1, &self.repr.nameserver2]
+            .into_iter()
+            .filter_map(|ns| ns.as_deref())
+            .collect()
+    }
+
+    /// Returns whether the bunny.net name servers were detected at the registrar.
+    pub fn nameservers_detected(&self) -> bool {
+        self.repr.nameservers_detected
+    }
+
+    /// Fetches the records of the zone.
+    async fn fetch_records(&self) -> Result<Vec<api::DnsRecord>, BunnyError> {
+        Ok(self.api_client.get_zone(self.repr.id).await?.records)
+    }
+
+    /// Converts a host into a name relative to the zone (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(&self.repr.domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts an API record into a generic record.
+    fn record_from_api(&self, record: api::DnsRecord) -> Record {
+        let value = record.value.trim_end_matches('.').to_string();
+        let data = match record.kind() {
+            Some(BunnyRecordType::A) => RecordData::from_raw("A", &value),
+            Some(BunnyRecordType::AAAA) => RecordData::from_raw("AAAA", &value),
+            Some(BunnyRecordType::CNAME) => RecordData::CNAME(value),
+            Some(BunnyRecordType::NS) => RecordData::NS(value),
+            Some(BunnyRecordType::TXT) => RecordData::TXT(record.value.clone()),
+            Some(BunnyRecordType::MX) => RecordData::MX {
+                priority: record.priority,
+                mail_server: value,
+            },
+            Some(BunnyRecordType::SRV) => RecordData::SRV {
+                priority: record.priority,
+                weight: record.weight,
+                port: record.port,
+                target: value,
+            },
+            Some(BunnyRecordType::CAA) => RecordData::Other {
+                typ: "CAA".to_string(),
+                value: format!(
+                    "{} {} \"{}\"",
+                    record.flags,
+                    record.tag.as_deref().unwrap_or_default(),
+                    record.value
+                ),
+            },
+            Some(typ @ (BunnyRecordType::PullZone | BunnyRecordType::Script)) => {
+                RecordData::Other {
+                    typ: typ.as_str().to_string(),
+                    value: record
+                        .link_name
+                        .clone()
+                        .filter(|name| !name.is_empty())
+                        .unwrap_or_else(|| record.value.clone()),
+                }
+            }
+            Some(typ) => RecordData::Other {
+                typ: typ.as_str().to_string(),
+                value: record.value.clone(),
+            },
+            None => RecordData::Other {
+                typ: format!("TYPE{}", record.record_type),
+                value: record.value.clone(),
+            },
+        };
+
+        Record {
+            id: record.id.to_string(),
+            host: if record.name.is_empty() {
+                "@".to_string()
+            } else {
+                record.name
+            },
+            data,
+            ttl: record.ttl,
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::AddRecordRequest, CreateRecordError<BunnyError>> {
+        let name = self.relative_name(host);
+        let request = match data {
+            RecordData::A(addr) => {
+                api::AddRecordRequest::new(BunnyRecordType::A, &name, &addr.to_string(), ttl)
+            }
+            RecordData::AAAA(addr) => {
+                api::AddRecordRequest::new(BunnyRecordType::AAAA, &name, &addr.to_string(), ttl)
+            }
+            RecordData::CNAME(target) => {
+                api::AddRecordRequest::new(BunnyRecordType::CNAME, &name, target, ttl)
+            }
+            RecordData::NS(target) => {
+                api::AddRecordRequest::new(BunnyRecordType::NS, &name, target, ttl)
+            }
+            RecordData::TXT(text) => {
+                api::AddRecordRequest::new(BunnyRecordType::TXT, &name, text, ttl)
+            }
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => api::AddRecordRequest {
+                priority: Some(*priority),
+                ..api::AddRecordRequest::new(BunnyRecordType::MX, &name, mail_server, ttl)
+            },
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => api::AddRecordRequest {
+                priority: Some(*priority),
+                weight: Some(*weight),
+                port: Some(*port),
+                ..api::AddRecordRequest::new(BunnyRecordType::SRV, &name, target, ttl)
+            },
+            RecordData::Other { typ, value } => match BunnyRecordType::parse(typ) {
+                Some(BunnyRecordType::CAA) => {
+                    let mut parts = value.splitn(3, ' ');
+                    let (flags, tag, value) = match (parts.next(), parts.next(), parts.next()) {
+                        (Some(flags), Some(tag), Some(value)) => (
+                            flags
+                                .parse::<u8>()
+                                .map_err(|_| CreateRecordError::InvalidRecord)?,
+                            tag,
+                            value.trim_matches('"'),
+                        ),
+                        _ => return Err(CreateRecordError::InvalidRecord),
+                    };
+                    api::AddRecordRequest {
+                        flags: Some(flags),
+                        tag: Some(tag.to_string()),
+                        ..api::AddRecordRequest::new(BunnyRecordType::CAA, &name, value, ttl)
+                    }
+                }
+                Some(
+                    typ @ (BunnyRecordType::PTR
+                    | BunnyRecordType::Redirect
+                    | BunnyRecordType::Flatten),
+                ) => api::AddRecordRequest::new(typ, &name, value, ttl),
+                _ => return Err(CreateRecordError::UnsupportedType),
+            },
+        };
+
+        Ok(request)
+    }
+}
+
+impl Zone for BunnyZone {
+    type CustomRetrieveError = BunnyError;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .fetch_records()
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| self.record_from_api(record))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| RetrieveRecordError::NotFound)?;
+
+        // The API has no endpoint for single records.
+        let record = self
+            .fetch_records()
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl CreateRecord for BunnyZone {
+    type CustomCreateError = BunnyError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .add_record(self.repr.id, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl DeleteRecord for BunnyZone {
+    type CustomDeleteError = BunnyError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| DeleteRecordError::NotFound)?;
+
+        self.api_client
+            .delete_record(self.repr.id, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,673 @@
+//! Low-level ClouDNS API client.
+//!
+//! This module provides direct access to the ClouDNS HTTP API (`/dns/*.json`)
+//! for zone, record and dynamic URL management.
+//! For most use cases, prefer using [`CloudnsProvider`](super::CloudnsProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [API introduction](https://www.cloudns.net/wiki/article/41/)
+//! - [DNS zones](https://www.cloudns.net/wiki/article/47/)
+//! - [DNS records](https://www.cloudns.net/wiki/article/57/)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::cloudns::api::{AuthId, Client};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new(AuthId::User("1234".to_string()), "your-auth-password")?;
+//!
+//! for record in client.list_records("example.com").await? {
+//!     println!("{} {} {}", record.host, record.record_type, record.record);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The ClouDNS API base URL.
+const CLOUDNS_API_URL: &str = "https://api.cloudns.net";
+
+/// Page size requested from the zone list endpoint (the API maximum).
+const PAGE_SIZE: usize = 100;
+
+/// Errors that may occur when interacting with the ClouDNS API.
+#[derive(Debug, Error)]
+pub enum CloudnsError {
+    /// The API reported a failed request.
+    ///
+    /// ClouDNS answers failures with HTTP 200 and a `Failed` status, so only
+    /// the status description is available.
+    #[error("API error: {message}")]
+    Api {
+        /// Status description returned by the API.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl CloudnsError {
+    /// Returns the status description if this is an API error.
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            CloudnsError::Api { message } => Some(message),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the API rejected the credentials or the source IP.
+    pub fn is_unauthorized(&self) -> bool {
+        self.message().is_some_and(|message| {
+            let message = message.to_ascii_lowercase();
+            message.contains("auth") || message.contains("not allowed")
+        })
+    }
+
+    /// Returns `true` if the API reported a missing zone or record.
+    pub fn is_not_found(&self) -> bool {
+        self.message().is_some_and(|message| {
+            let message = message.to_ascii_lowercase();
+            message.contains("not found")
+                || message.contains("not exist")
+                || message.contains("n't exist")
+                || message.contains("invalid record-id")
+        })
+    }
+}
+
+/// Status response returned by modifying endpoints and by failed requests.
+#[derive(Debug, Deserialize)]
+struct StatusResponse {
+    status: String,
+    #[serde(default, rename = "statusDescription")]
+    status_description: String,
+    #[serde(default)]
+    data: Option<serde_json::Value>,
+}
+
+/// Helpers for fields the API returns either as strings or as numbers.
+mod lenient {
+    use serde::{Deserialize, Deserializer};
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt {
+        String(String),
+        Int(u64),
+    }
+
+    fn parse<E: serde::de::Error>(value: StringOrInt) -> Result<Option<u64>, E> {
+        match value {
+            StringOrInt::Int(i) => Ok(Some(i)),
+            StringOrInt::String(s) if s.is_empty() => Ok(None),
+            StringOrInt::String(s) => s.parse().map(Some).map_err(E::custom),
+        }
+    }
+
+    pub fn u64<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+        Ok(parse(StringOrInt::deserialize(deserializer)?)?.unwrap_or_default())
+    }
+
+    pub fn option_u16<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u16>, D::Error> {
+        match Option::<StringOrInt>::deserialize(deserializer)? {
+            Some(value) => parse(value)?
+                .map(|v| u16::try_from(v).map_err(serde::de::Error::custom))
+                .transpose(),
+            None => Ok(None),
+        }
+    }
+
+    pub fn flag<'de, D: Deserializer<'de>>(deserializer: D) -> Result<bool, D::Error> {
+        Ok(parse(StringOrInt::deserialize(deserializer)?)?.unwrap_or_default() != 0)
+    }
+
+    pub fn string<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+        Ok(match StringOrInt::deserialize(deserializer)? {
+            StringOrInt::String(s) => s,
+            StringOrInt::Int(i) => i.to_string(),
+        })
+    }
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A DNS zone of the account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsZone {
+    /// Zone name.
+    pub name: String,
+    /// Zone type (`master`, `slave`, `parked` or `geodns`).
+    #[serde(rename = "type", default)]
+    pub zone_type: String,
+    /// Zone kind (`domain`, `reverse` or `ipv6`).
+    #[serde(rename = "zone", default)]
+    pub zone_kind: String,
+    /// Whether the zone is active.
+    #[serde(default, deserialize_with = "lenient::flag")]
+    pub status: bool,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsRecord {
+    /// Record ID.
+    #[serde(deserialize_with = "lenient::string")]
+    pub id: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the zone (empty for the apex).
+    #[serde(default)]
+    pub host: String,
+    /// Record value; the target for MX and SRV records.
+    #[serde(default)]
+    pub record: String,
+    /// TTL in seconds.
+    #[serde(default, deserialize_with = "lenient::u64")]
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default, deserialize_with = "lenient::option_u16")]
+    pub priority: Option<u16>,
+    /// Weight for SRV records.
+    #[serde(default, deserialize_with = "lenient::option_u16")]
+    pub weight: Option<u16>,
+    /// Port for SRV records.
+    #[serde(default, deserialize_with = "lenient::option_u16")]
+    pub port: Option<u16>,
+    /// Flag for CAA records.
+    #[serde(default, deserialize_with = "lenient::option_u16")]
+    pub caa_flag: Option<u16>,
+    /// Tag for CAA records.
+    #[serde(default)]
+    pub caa_type: Option<String>,
+    /// Value for CAA records.
+    #[serde(default)]
+    pub caa_value: Option<String>,
+    /// Whether the record is active.
+    #[serde(default, deserialize_with = "lenient::flag")]
+    pub status: bool,
+    /// Whether a dynamic URL is enabled for the record.
+    #[serde(
+        rename = "dynamicurl_status",
+        default,
+        deserialize_with = "lenient::flag"
+    )]
+    pub dynamic_url: bool,
+}
+
+/// Response of the record list endpoint: an object keyed by record ID, or an
+/// empty array when the zone has no records.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RecordsResponse {
+    Map(std::collections::BTreeMap<String, DnsRecord>),
+    List(Vec<DnsRecord>),
+}
+
+/// Parameters for creating or modifying a record.
+#[derive(Debug, Clone, Default)]
+pub struct RecordRequest {
+    /// Record type; ignored when modifying a record.
+    pub record_type: String,
+    /// Host name relative to the zone (empty for the apex).
+    pub host: String,
+    /// Record value; the target for MX and SRV records.
+    pub record: String,
+    /// TTL in seconds; must be one of the values accepted by ClouDNS.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    pub priority: Option<u16>,
+    /// Weight for SRV records.
+    pub weight: Option<u16>,
+    /// Port for SRV records.
+    pub port: Option<u16>,
+    /// Flag for CAA records.
+    pub caa_flag: Option<u16>,
+    /// Tag for CAA records.
+    pub caa_type: Option<String>,
+    /// Value for CAA records.
+    pub caa_value: Option<String>,
+}
+
+impl RecordRequest {
+    /// Returns the form parameters of the request.
+    fn params(&self) -> Vec<(&'static str, String)> {
+        let mut params = vec![
+            ("host", self.host.clone()),
+            ("record", self.record.clone()),
+            ("ttl", self.ttl.to_string()),
+        ];
+        let optional = [
+            ("priority", self.priority),
+            ("weight", self.weight),
+            ("port", self.port),
+            ("caa_flag", self.caa_flag),
+        ];
+        for (key, value) in optional {
+            if let Some(value) = value {
+                params.push((key, value.to_string()));
+            }
+        }
+        if let Some(caa_type) = &self.caa_type {
+            params.push(("caa_type", caa_type.clone()));
+        }
+        if let Some(caa_value) = &self.caa_value {
+            params.push(("caa_value", caa_value.clone()));
+        }
+        params
+    }
+}
+
+/// A dynamic URL of a record.
+///
+/// Requesting the URL (e.g., from a router) updates the record to the
+/// address of the requesting client.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DynamicUrl {
+    /// Host name of the record.
+    #[serde(default)]
+    pub host: String,
+    /// Update URL.
+    pub url: String,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// The identity used to authenticate against the API.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthId {
+    /// The main user's `auth-id`.
+    User(String),
+    /// An API sub-user's `sub-auth-id`.
+    SubUser(String),
+    /// An API sub-user's `sub-auth-user` name.
+    SubUserName(String),
+}
+
+impl AuthId {
+    /// Returns the form parameter name and value of the identity.
+    fn param(&self) -> (&'static str, &str) {
+        match self {
+            AuthId::User(id) => ("auth-id", id),
+            AuthId::SubUser(id) => ("sub-auth-id", id),
+            AuthId::SubUserName(name) => ("sub-auth-user", name),
+        }
+    }
+}
+
+/// ClouDNS API client.
+#[derive(Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    auth_id: AuthId,
+    auth_password: String,
+    base_url: String,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("auth_id", &self.auth_id)
+            .field("auth_password", &"<redacted>")
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl Client {
+    /// Creates a new ClouDNS API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_id` - API user or sub-user identity
+    /// * `auth_password` - Password of the API user
+    pub fn new(
+        auth_id: AuthId,
+        auth_password: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            auth_id,
+            auth_password,
+            CLOUDNS_API_URL,
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new ClouDNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_id` - API user or sub-user identity
+    /// * `auth_password` - Password of the API user
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        auth_id: AuthId,
+        auth_password: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(auth_id, auth_password, CLOUDNS_API_URL, config)
+    }
+
+    /// Creates a new ClouDNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_id` - API user or sub-user identity
+    /// * `auth_password` - Password of the API user
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        auth_id: AuthId,
+        auth_password: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            auth_id,
+            auth_password: auth_password.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends an authenticated request and deserializes the JSON response.
+    ///
+    /// The credentials and parameters are sent as a form body rather than in
+    /// the query string, so the password does not end up in access logs.
+    async fn call<T: DeserializeOwned>(
+        &self,
+        endpoint: &str,
+        params: &[(&str, String)],
+    ) -> Result<T, CloudnsError> {
+        let (auth_key, auth_value) = self.auth_id.param();
+        let mut body = format!(
+            "{}={}&auth-password={}",
+            auth_key,
+            encode_component(auth_value),
+            encode_component(&self.auth_password)
+        );
+        for (key, value) in params {
+            body.push_str(&format!("&{}={}", key, encode_component(value)));
+        }
+
+        let response = self
+            .http_client
+            .post(format!("{}/dns/{}.json", self.base_url, endpoint))
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(body)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            return Err(CloudnsError::Api { message: text });
+        }
+
+        if let Ok(StatusResponse {
+            status,
+            status_description,
+            ..
+        }) = serde_json::from_str::<StatusResponse>(&text)
+        {
+            if status.eq_ignore_ascii_case("failed") {
+                return Err(CloudnsError::Api {
+                    message: status_description,
+                });
+            }
+        }
+
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists all zones of the account, following pagination.
+    pub async fn list_zones(&self) -> Result<Vec<DnsZone>, CloudnsError> {
+        let mut zones = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response: Vec<DnsZone> = self
+                .call(
+                    "list-zones",
+                    &[
+                        ("page", page.to_string()),
+                        ("rows-per-page", PAGE_SIZE.to_string()),
+                    ],
+                )
+                .await?;
+            let count = response.len();
+            zones.extend(response);
+
+            if count < PAGE_SIZE {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(zones)
+    }
+
+    /// Gets a zone.
+    pub async fn get_zone(&self, domain: &str) -> Result<DnsZone, CloudnsError> {
+        self.call("get-zone-info", &[("domain-name", domain.to_string())])
+            .await
+    }
+
+    /// Registers a new master zone.
+    pub async fn create_zone(&self, domain: &str) -> Result<(), CloudnsError> {
+        self.call::<StatusResponse>(
+            "register",
+            &[
+                ("domain-name", domain.to_string()),
+                ("zone-type", "master".to_string()),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Deletes a zone.
+    pub async fn delete_zone(&self, domain: &str) -> Result<(), CloudnsError> {
+        self.call::<StatusResponse>("delete", &[("domain-name", domain.to_string())])
+            .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a zone.
+    pub async fn list_records(&self, domain: &str) -> Result<Vec<DnsRecord>, CloudnsError> {
+        let response: RecordsResponse = self
+            .call("records", &[("domain-name", domain.to_string())])
+            .await?;
+
+        Ok(match response {
+            RecordsResponse::Map(records) => records.into_values().collect(),
+            RecordsResponse::List(records) => records,
+        })
+    }
+
+    /// Creates a record and returns its ID.
+    pub async fn create_record(
+        &self,
+        domain: &str,
+        request: &RecordRequest,
+    ) -> Result<String, CloudnsError> {
+        let mut params = vec![
+            ("domain-name", domain.to_string()),
+            ("record-type", request.record_type.clone()),
+        ];
+        params.extend(request.params());
+
+        let response: StatusResponse = self.call("add-record", &params).await?;
+        response
+            .data
+            .as_ref()
+            .and_then(|data| data.get("id"))
+            .and_then(|id| match id {
+                serde_json::Value::String(id) => Some(id.clone()),
+                serde_json::Value::Number(id) => Some(id.to_string()),
+                _ => None,
+            })
+            .ok_or_else(|| CloudnsError::Api {
+                message: format!(
+                    "record ID missing from response: {}",
+                    response.status_description
+                ),
+            })
+    }
+
+    /// Modifies the host, value and TTL of a record, keeping its ID.
+    pub async fn update_record(
+        &self,
+        domain: &str,
+        record_id: &str,
+        request: &RecordRequest,
+    ) -> Result<(), CloudnsError> {
+        let mut params = vec![
+            ("domain-name", domain.to_string()),
+            ("record-id", record_id.to_string()),
+        ];
+        params.extend(request.params());
+
+        self.call::<StatusResponse>("mod-record", &params).await?;
+        Ok(())
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(&self, domain: &str, record_id: &str) -> Result<(), CloudnsError> {
+        self.call::<StatusResponse>(
+            "delete-record",
+            &[
+                ("domain-name", domain.to_string()),
+                ("record-id", record_id.to_string()),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Dynamic URL APIs
+    // =========================================================================
+
+    /// Gets the dynamic URL of an A or AAAA record, enabling it if needed.
+    pub async fn get_dynamic_url(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<DynamicUrl, CloudnsError> {
+        self.call(
+            "get-dynamic-url",
+            &[
+                ("domain-name", domain.to_string()),
+                ("record-id", record_id.to_string()),
+            ],
+        )
+        .await
+    }
+
+    /// Replaces the dynamic URL of a record, invalidating the previous one.
+    pub async fn change_dynamic_url(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<DynamicUrl, CloudnsError> {
+        self.call(
+            "change-dynamic-url",
+            &[
+                ("domain-name", domain.to_string()),
+                ("record-id", record_id.to_string()),
+            ],
+        )
+        .await
+    }
+
+    /// Disables the dynamic URL of a record.
+    pub async fn disable_dynamic_url(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<(), CloudnsError> {
+        self.call::<StatusResponse>(
+            "disable-dynamic-url",
+            &[
+                ("domain-name", domain.to_string()),
+                ("record-id", record_id.to_string()),
+            ],
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Percent-encodes a form value.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,572 @@
+//! ClouDNS DNS provider implementation.
+//!
+//! This provider uses the ClouDNS HTTP API for zone and record management.
+//!
+//! # Authentication
+//!
+//! Requires an API user and its password:
+//! - Create an API user at: <https://www.cloudns.net/api-settings/>
+//!
+//! Either the main API user (`auth-id`) or an API sub-user (`sub-auth-id` or
+//! `sub-auth-user`) can be used, see [`AuthId`]. API users can be restricted
+//! to a list of source IP addresses.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::cloudns::{AuthId, CloudnsProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = CloudnsProvider::new(AuthId::User("1234".to_string()), "your-auth-password")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record and get a dynamic URL for it
+//! let zone = provider.get_zone("example.com").await?;
+//! let record = zone.create_record("home", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! println!("Update URL: {}", zone.dynamic_url(&record.id).await?);
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone Management
+//!
+//! [`CreateZone`] registers master zones. The zone ID is
+//! the domain name.
+//!
+//! # Dynamic URLs
+//!
+//! ClouDNS can issue a secret URL per A or AAAA record which updates the
+//! record to the address of whoever requests it. Use
+//! [`CloudnsZone::dynamic_url`] to get (and enable) it,
+//! [`CloudnsZone::change_dynamic_url`] to rotate it and
+//! [`CloudnsZone::disable_dynamic_url`] to revoke it.
+//!
+//! # TTLs
+//!
+//! ClouDNS only accepts a fixed set of TTLs (60, 300, 900, 1800, 3600, 21600,
+//! 43200 seconds, 1 to 3 days, 1 and 2 weeks and 1 month). Other TTLs are
+//! rounded up to the next accepted value.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ALIAS, CAA and PTR, as [`RecordData::Other`] (CAA in zone file format)
+//!
+//! # API Reference
+//!
+//! - [API documentation](https://www.cloudns.net/wiki/article/41/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{AuthId, Client, CloudnsError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ALIAS", "CAA", "PTR"];
+
+/// TTLs accepted by ClouDNS, in ascending order.
+const ALLOWED_TTLS: &[u64] = &[
+    60, 300, 900, 1800, 3600, 21600, 43200, 86400, 172800, 259200, 604800, 1209600, 2592000,
+];
+
+/// ClouDNS DNS provider.
+#[derive(Clone)]
+pub struct CloudnsProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone hosted by ClouDNS.
+pub struct CloudnsZone {
+    api_client: Arc<Client>,
+    repr: api::DnsZone,
+}
+
+impl CloudnsProvider {
+    /// Creates a new ClouDNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_id` - API user or sub-user identity
+    /// * `auth_password` - Password of the API user
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::cloudns::{AuthId, CloudnsProvider};
+    ///
+    /// let provider =
+    ///     CloudnsProvider::new(AuthId::SubUser("5678".to_string()), "your-auth-password").unwrap();
+    /// ```
+    pub fn new(
+        auth_id: AuthId,
+        auth_password: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(auth_id, auth_password)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new ClouDNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_id` - API user or sub-user identity
+    /// * `auth_password` - Password of the API user
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::cloudns::{AuthId, CloudnsProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = CloudnsProvider::with_config(
+    ///     AuthId::User("1234".to_string()),
+    ///     "your-auth-password",
+    ///     config,
+    /// )
+    /// .unwrap();
+    /// ```
+    pub fn with_config(
+        auth_id: AuthId,
+        auth_password: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(auth_id, auth_password, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new ClouDNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `auth_id` - API user or sub-user identity
+    /// * `auth_password` - Password of the API user
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        auth_id: AuthId,
+        auth_password: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(
+            auth_id,
+            auth_password,
+            base_url,
+            HttpClientConfig::default(),
+        )?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for CloudnsProvider {
+    type Zone = CloudnsZone;
+    type CustomRetrieveError = CloudnsError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| {
+                if err.is_unauthorized() {
+                    RetrieveZoneError::Unauthorized
+                } else if err.is_not_found() {
+                    RetrieveZoneError::NotFound
+                } else {
+                    RetrieveZoneError::Custom(err)
+                }
+            })?;
+
+        Ok(CloudnsZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self.api_client.list_zones().await.map_err(|err| {
+            if err.is_unauthorized() {
+                RetrieveZoneError::Unauthorized
+            } else {
+                RetrieveZoneError::Custom(err)
+            }
+        })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| CloudnsZone {
+                api_client: self.api_client.clone(),
+                repr: zone,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for CloudnsProvider {
+    type CustomCreateError = CloudnsError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let map_err = |err: CloudnsError| {
+            if err.is_unauthorized() {
+                CreateZoneError::Unauthorized
+            } else if err
+                .message()
+                .is_some_and(|m| m.to_ascii_lowercase().contains("invalid domain"))
+            {
+                CreateZoneError::InvalidDomainName
+            } else {
+                CreateZoneError::Custom(err)
+            }
+        };
+
+        let domain = domain.trim_end_matches('.');
+        self.api_client.create_zone(domain).await.map_err(map_err)?;
+        let zone = self.api_client.get_zone(domain).await.map_err(map_err)?;
+
+        Ok(CloudnsZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+}
+
+impl DeleteZone for CloudnsProvider {
+    type CustomDeleteError = CloudnsError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| {
+                if err.is_unauthorized() {
+                    DeleteZoneError::Unauthorized
+                } else if err.is_not_found() {
+                    DeleteZoneError::NotFound
+                } else {
+                    DeleteZoneError::Custom(err)
+                }
+            })
+    }
+}
+
+impl CloudnsZone {
+    /// Returns the zone type (`master`, `slave`, `parked` or `geodns`).
+    pub fn zone_type(&self) -> &str {
+        &self.repr.zone_type
+    }
+
+    /// Returns the dynamic URL of an A or AAAA record, enabling it if needed.
+    pub async fn dynamic_url(&self, record_id: &str) -> Result<String, CloudnsError> {
+        let url = self
+            .api_client
+            .get_dynamic_url(&self.repr.name, record_id)
+            .await?;
+        Ok(url.url)
+    }
+
+    /// Replaces the dynamic URL of a record and returns the new URL.
+    ///
+    /// The previous URL stops working.
+    pub async fn change_dynamic_url(&self, record_id: &str) -> Result<String, CloudnsError> {
+        let url = self
+            .api_client
+            .change_dynamic_url(&self.repr.name, record_id)
+            .await?;
+        Ok(url.url)
+    }
+
+    /// Disables the dynamic URL of a record.
+    pub async fn disable_dynamic_url(&self, record_id: &str) -> Result<(), CloudnsError> {
+        self.api_client
+            .disable_dynamic_url(&self.repr.name, record_id)
+            .await
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    ///
+    /// The record type cannot be changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<CloudnsError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        self.api_client
+            .update_record(&self.repr.name, record_id, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(record_from_request(record_id.to_string(), data, &request))
+    }
+
+    /// Converts a host into a name relative to the zone (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.name;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<CloudnsError>> {
+        let mut request = api::RecordRequest {
+            record_type: data.get_type().to_string(),
+            host: self.relative_name(host),
+            ttl: round_ttl(ttl),
+            ..Default::default()
+        };
+
+        match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => {
+                request.record = mail_server.trim_end_matches('.').to_string();
+                request.priority = Some(*priority);
+            }
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => {
+                request.record = target.trim_end_matches('.').to_string();
+                request.priority = Some(*priority);
+                request.weight = Some(*weight);
+                request.port = Some(*port);
+            }
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                request.record = target.trim_end_matches('.').to_string();
+            }
+            RecordData::TXT(text) => request.record = text.clone(),
+            RecordData::Other { typ, value } if typ == "CAA" => {
+                let mut parts = value.splitn(3, ' ');
+                let (Some(flag), Some(tag), Some(caa_value)) =
+                    (parts.next(), parts.next(), parts.next())
+                else {
+                    return Err(CreateRecordError::InvalidRecord);
+                };
+                request.caa_flag =
+                    Some(flag.parse().map_err(|_| CreateRecordError::InvalidRecord)?);
+                request.caa_type = Some(tag.to_string());
+                request.caa_value = Some(caa_value.trim_matches('"').to_string());
+            }
+            RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+                request.record = value.trim_end_matches('.').to_string();
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+            _ => request.record = data.get_value(),
+        }
+
+        Ok(request)
+    }
+}
+
+/// Rounds a TTL up to the next value accepted by ClouDNS.
+fn round_ttl(ttl: u64) -> u64 {
+    ALLOWED_TTLS
+        .iter()
+        .copied()
+        .find(|allowed| *allowed >= ttl)
+        .unwrap_or(ALLOWED_TTLS[ALLOWED_TTLS.len() - 1])
+}
+
+/// Maps an API error of a record modification.
+fn map_create_error(err: CloudnsError) -> CreateRecordError<CloudnsError> {
+    if err.is_unauthorized() {
+        CreateRecordError::Unauthorized
+    } else if err.message().is_some() {
+        CreateRecordError::InvalidRecord
+    } else {
+        CreateRecordError::Custom(err)
+    }
+}
+
+/// Builds the record created or updated by a request.
+fn record_from_request(id: String, data: &RecordData, request: &api::RecordRequest) -> Record {
+    Record {
+        id,
+        host: if request.host.is_empty() {
+            "@".to_string()
+        } else {
+            request.host.clone()
+        },
+        data: data.clone(),
+        ttl: request.ttl,
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::DnsRecord) -> Record {
+    let priority = record.priority.unwrap_or_default();
+    let target = record.record.trim_end_matches('.');
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority,
+            mail_server: target.to_string(),
+        },
+        "SRV" => RecordData::SRV {
+            priority,
+            weight: record.weight.unwrap_or_default(),
+            port: record.port.unwrap_or_default(),
+            target: target.to_string(),
+        },
+        "CAA" => RecordData::Other {
+            typ: "CAA".to_string(),
+            value: format!(
+                "{} {} \"{}\"",
+                record.caa_flag.unwrap_or_default(),
+                record.caa_type.as_deref().unwrap_or_default(),
+                record.caa_value.as_deref().unwrap_or_default()
+            ),
+        },
+        "TXT" => RecordData::TXT(record.record.clone()),
+        "CNAME" | "NS" | "ALIAS" | "PTR" => RecordData::from_raw(&record.record_type, target),
+        typ => RecordData::from_raw(typ, &record.record),
+    };
+
+    Record {
+        id: record.id,
+        host: if record.host.is_empty() {
+            "@".to_string()
+        } else {
+            record.host
+        },
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for CloudnsZone {
+    type CustomRetrieveError = CloudnsError;
+
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.name)
+            .await
+            .map_err(|err| {
+                if err.is_unauthorized() {
+                    RetrieveRecordError::Unauthorized
+                } else if err.is_not_found() {
+                    RetrieveRecordError::NotFound
+                } else {
+                    RetrieveRecordError::Custom(err)
+                }
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // ClouDNS has no endpoint for a single record.
+        self.list_records()
+            .await?
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for CloudnsZone {
+    type CustomCreateError = CloudnsError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let id = self
+            .api_client
+            .create_record(&self.repr.name, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(record_from_request(id, data, &request))
+    }
+}
+
+impl DeleteRecord for CloudnsZone {
+    type CustomDeleteError = CloudnsError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_record(&self.repr.name, record_id)
+            .await
+            .map_err(|err| {
+                if err.is_unauthorized() {
+                    DeleteRecordError::Unauthorized
+                } else if err.is_not_found() {
+                    DeleteRecordError::NotFound
+                } else {
+                    DeleteRecordError::Custom(err)
+                }
+            })
+    }
+}
@@ -0,0 +1,503 @@
+//! Low-level deSEC API client.
+//!
+//! This module provides direct access to the deSEC REST API for domains and
+//! RRsets. For most use cases, prefer using [`DesecProvider`](super::DesecProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [Domain Management](https://desec.readthedocs.io/en/latest/dns/domains.html)
+//! - [RRset Management](https://desec.readthedocs.io/en/latest/dns/rrsets.html)
+//! - [Rate Limits](https://desec.readthedocs.io/en/latest/rate-limits.html)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::desec::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-api-token")?;
+//!
+//! for domain in client.list_domains().await? {
+//!     println!("Domain: {} (minimum TTL: {})", domain.name, domain.minimum_ttl);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE, LINK, RETRY_AFTER};
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The deSEC API base URL.
+const DESEC_API_URL: &str = "https://desec.io/api/v1";
+
+/// Number of times a throttled request is retried.
+const MAX_THROTTLE_RETRIES: u32 = 3;
+
+/// Longest `Retry-After` delay that is waited out by default.
+const DEFAULT_MAX_RETRY_WAIT: Duration = Duration::from_secs(60);
+
+/// Errors that may occur when interacting with the deSEC API.
+#[derive(Debug, Error)]
+pub enum DesecError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {detail}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error detail, or the raw response body for validation errors.
+        detail: String,
+    },
+
+    /// The request was throttled and not retried, either because the
+    /// requested delay is too long or because the retries were exhausted.
+    #[error("Request throttled (retry after {retry_after:?} seconds)")]
+    Throttled {
+        /// Seconds to wait before retrying, from the `Retry-After` header.
+        retry_after: Option<u64>,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl DesecError {
+    /// Returns the HTTP status code if this is an API or throttling error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            DesecError::Api { status, .. } => Some(*status),
+            DesecError::Throttled { .. } => Some(429),
+            _ => None,
+        }
+    }
+}
+
+/// deSEC error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    detail: String,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A deSEC domain (zone).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain name without trailing dot.
+    pub name: String,
+    /// Smallest TTL accepted for RRsets of this domain.
+    pub minimum_ttl: u64,
+    /// Creation timestamp.
+    #[serde(default)]
+    pub created: Option<String>,
+    /// Timestamp of the last publication to the name servers.
+    #[serde(default)]
+    pub published: Option<String>,
+    /// Timestamp of the last change.
+    #[serde(default)]
+    pub touched: Option<String>,
+    /// DNSSEC keys; only present when retrieving or creating a single domain.
+    #[serde(default)]
+    pub keys: Vec<DnssecKey>,
+}
+
+/// A DNSSEC key of a domain, with the DS records to publish at the parent.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnssecKey {
+    /// DNSKEY record content.
+    pub dnskey: String,
+    /// DS record contents for the key.
+    #[serde(default)]
+    pub ds: Vec<String>,
+    /// DNSKEY flags (257 for key-signing keys).
+    #[serde(default)]
+    pub flags: u16,
+    /// Key type (e.g., `csk`).
+    #[serde(default)]
+    pub keytype: String,
+    /// Whether the key is managed by deSEC.
+    #[serde(default)]
+    pub managed: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateDomainRequest<'a> {
+    name: &'a str,
+}
+
+// =============================================================================
+// RRset Types
+// =============================================================================
+
+/// A resource record set: all records sharing a name and type.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RRset {
+    /// Subdomain relative to the domain; empty for the apex.
+    pub subname: String,
+    /// Fully-qualified name with trailing dot.
+    #[serde(default)]
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL shared by all records in the set.
+    pub ttl: u64,
+    /// Record contents in zone-file presentation format.
+    #[serde(default)]
+    pub records: Vec<String>,
+}
+
+/// A single RRset change in a bulk PATCH request.
+///
+/// An empty record list deletes the RRset.
+#[derive(Debug, Clone, Serialize)]
+pub struct RRsetUpdate {
+    /// Subdomain relative to the domain; empty for the apex.
+    pub subname: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL of the RRset (ignored for deletions).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// New records of the RRset.
+    pub records: Vec<String>,
+}
+
+impl RRsetUpdate {
+    /// Creates an update that replaces the RRset with the given records.
+    pub fn replace(subname: &str, record_type: &str, ttl: u64, records: Vec<String>) -> Self {
+        Self {
+            subname: subname.to_string(),
+            record_type: record_type.to_string(),
+            ttl: Some(ttl),
+            records,
+        }
+    }
+
+    /// Creates an update that deletes the RRset.
+    pub fn delete(subname: &str, record_type: &str) -> Self {
+        Self {
+            subname: subname.to_string(),
+            record_type: record_type.to_string(),
+            ttl: None,
+            records: Vec::new(),
+        }
+    }
+}
+
+/// A successful response with the cursor of the next page, if any.
+struct ApiResponse {
+    text: String,
+    next_cursor: Option<String>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// deSEC API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    max_retry_wait: Duration,
+}
+
+impl Client {
+    /// Creates a new deSEC API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - deSEC API token
+    pub fn new(token: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(token, DESEC_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new deSEC API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - deSEC API token
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(token, DESEC_API_URL, config)
+    }
+
+    /// Creates a new deSEC API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - deSEC API token
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        token: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Token {}", token))?;
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            max_retry_wait: DEFAULT_MAX_RETRY_WAIT,
+        })
+    }
+
+    /// Sets the longest `Retry-After` delay that is waited out before
+    /// retrying a throttled request.
+    ///
+    /// Throttled requests asking for a longer delay fail immediately with
+    /// [`DesecError::Throttled`]. Defaults to 60 seconds; `Duration::ZERO`
+    /// disables retries.
+    pub fn with_max_retry_wait(mut self, max_retry_wait: Duration) -> Self {
+        self.max_retry_wait = max_retry_wait;
+        self
+    }
+
+    /// Sends a request and turns non-success responses into [`DesecError`].
+    ///
+    /// Throttled (`429`) responses are retried after the `Retry-After` delay
+    /// if it does not exceed the configured maximum.
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+    ) -> Result<ApiResponse, DesecError> {
+        let mut retries = 0;
+
+        loop {
+            let mut request = self.http_client.request(method.clone(), url);
+            if let Some(ref body) = body {
+                request = request
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body.clone());
+            }
+
+            let response = request.send().await?;
+            let status = response.status();
+
+            if status.as_u16() == 429 {
+                let retry_after = response
+                    .headers()
+                    .get(RETRY_AFTER)
+                    .and_then(|value| value.to_str().ok())
+                    .and_then(|value| value.trim().parse::<u64>().ok());
+                let wait = Duration::from_secs(retry_after.unwrap_or(1));
+
+                if retries >= MAX_THROTTLE_RETRIES || wait > self.max_retry_wait {
+                    return Err(DesecError::Throttled { retry_after });
+                }
+                retries += 1;
+                tokio::time::sleep(wait).await;
+                continue;
+            }
+
+            let next_cursor = response
+                .headers()
+                .get(LINK)
+                .and_then(|value| value.to_str().ok())
+                .and_then(next_link)
+                .and_then(|link| cursor_param(&link));
+            let text = response.text().await?;
+
+            if !status.is_success() {
+                let detail = serde_json::from_str::<ErrorResponse>(&text)
+                    .map(|e| e.detail)
+                    .unwrap_or(text);
+                return Err(DesecError::Api {
+                    status: status.as_u16(),
+                    detail,
+                });
+            }
+
+            return Ok(ApiResponse { text, next_cursor });
+        }
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, DesecError> {
+        let url = format!("{}{}", self.base_url, path);
+        let response = self.send(Method::GET, &url, None).await?;
+        Ok(serde_json::from_str(&response.text)?)
+    }
+
+    /// Fetches all pages of a collection using the cursor of the `next` link.
+    ///
+    /// Requesting the empty cursor selects paginated mode, which large
+    /// collections require.
+    async fn get_all<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>, DesecError> {
+        let mut items = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            // Only the cursor is taken from the `next` link, so the token is
+            // never sent to a host other than the configured one.
+            let url = format!("{}{}?cursor={}", self.base_url, path, cursor);
+            let response = self.send(Method::GET, &url, None).await?;
+            let page: Vec<T> = serde_json::from_str(&response.text)?;
+            items.extend(page);
+
+            match response.next_cursor {
+                Some(next) => cursor = next,
+                None => break,
+            }
+        }
+
+        Ok(items)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, DesecError> {
+        self.get_all("/domains/").await
+    }
+
+    /// Gets a domain including its DNSSEC keys.
+    pub async fn get_domain(&self, name: &str) -> Result<Domain, DesecError> {
+        self.get(&format!("/domains/{}/", name)).await
+    }
+
+    /// Creates a domain. deSEC signs it with DNSSEC right away; the returned
+    /// domain includes the keys and DS records for the parent zone.
+    pub async fn create_domain(&self, name: &str) -> Result<Domain, DesecError> {
+        let url = format!("{}/domains/", self.base_url);
+        let body = serde_json::to_string(&CreateDomainRequest { name })?;
+        let response = self.send(Method::POST, &url, Some(body)).await?;
+        Ok(serde_json::from_str(&response.text)?)
+    }
+
+    /// Deletes a domain and all of its RRsets.
+    pub async fn delete_domain(&self, name: &str) -> Result<(), DesecError> {
+        let url = format!("{}/domains/{}/", self.base_url, name);
+        self.send(Method::DELETE, &url, None).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // RRset APIs
+    // =========================================================================
+
+    /// Lists all RRsets of a domain, following pagination.
+    pub async fn list_rrsets(&self, domain: &str) -> Result<Vec<RRset>, DesecError> {
+        self.get_all(&format!("/domains/{}/rrsets/", domain)).await
+    }
+
+    /// Gets an RRset by subname and type.
+    ///
+    /// Returns `None` if no such RRset exists.
+    pub async fn get_rrset(
+        &self,
+        domain: &str,
+        subname: &str,
+        record_type: &str,
+    ) -> Result<Option<RRset>, DesecError> {
+        // The apex is addressed as `@` in URLs.
+        let subname = if subname.is_empty() { "@" } else { subname };
+        match self
+            .get(&format!(
+                "/domains/{}/rrsets/{}/{}/",
+                domain, subname, record_type
+            ))
+            .await
+        {
+            Ok(rrset) => Ok(Some(rrset)),
+            Err(DesecError::Api { status: 404, .. }) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Applies RRset changes to a domain in a single bulk PATCH request.
+    ///
+    /// All changes are applied atomically. Returns the RRsets that still
+    /// exist after the change.
+    pub async fn patch_rrsets(
+        &self,
+        domain: &str,
+        updates: &[RRsetUpdate],
+    ) -> Result<Vec<RRset>, DesecError> {
+        let url = format!("{}/domains/{}/rrsets/", self.base_url, domain);
+        let body = serde_json::to_string(updates)?;
+        let response = self.send(Method::PATCH, &url, Some(body)).await?;
+        if response.text.trim().is_empty() {
+            return Ok(Vec::new());
+        }
+        Ok(serde_json::from_str(&response.text)?)
+    }
+}
+
+/// Extracts the `rel="next"` target from a `Link` header.
+fn next_link(header: &str) -> Option<String> {
+    header.split(',').find_map(|link| {
+        let (target, params) = link.split_once(';')?;
+        params
+            .split(';')
+            .any(|param| param.trim() == "rel=\"next\"")
+            .then(|| {
+                target
+                    .trim()
+                    .trim_start_matches('<')
+                    .trim_end_matches('>')
+                    .to_string()
+            })
+    })
+}
+
+/// Extracts the still percent-encoded `cursor` query parameter of a link.
+fn cursor_param(link: &str) -> Option<String> {
+    let (_, query) = link.split_once('?')?;
+    query
+        .split('&')
+        .find_map(|pair| pair.strip_prefix("cursor="))
+        .map(str::to_string)
+}
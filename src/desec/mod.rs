@@ -0,0 +1,594 @@
+//! deSEC provider implementation.
+//!
+//! This provider uses the deSEC REST API with token authentication.
+//!
+//! # Authentication
+//!
+//! Requires an API token:
+//! - Create a token at: <https://desec.io/tokens>
+//!
+//! The token is sent in the `Authorization: Token {token}` header.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::desec::DesecProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = DesecProvider::new("your-api-token")?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (minimum TTL: {})", zone.domain(), zone.minimum_ttl());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 3600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # DNSSEC
+//!
+//! deSEC signs every domain with DNSSEC from the moment it is created.
+//! [`DesecZone::keys`] returns the keys with the DS records to publish at the
+//! parent zone; they are included when a zone is retrieved with
+//! [`get_zone`](crate::Provider::get_zone) or created, but not when listing.
+//!
+//! # RRset-based API
+//!
+//! deSEC manages records as RRsets (all records sharing a name and type).
+//! Creating or deleting a single record reads the current RRset and writes it
+//! back through the bulk PATCH endpoint, which deletes the RRset once its last
+//! record is removed. All records in an RRset share one TTL, so creating a
+//! record also updates the TTL of its siblings. [`DesecZone::patch_rrsets`]
+//! applies several RRset changes atomically.
+//!
+//! Record IDs use the format `"name/type/content"` where `content` is the
+//! deSEC presentation value, similar to the Hetzner provider.
+//!
+//! # Minimum TTL
+//!
+//! Each domain has a minimum TTL (3600 seconds by default). TTLs below it are
+//! raised to the minimum when creating records; the returned record carries
+//! the effective TTL.
+//!
+//! # Rate Limits
+//!
+//! Throttled requests (`429 Too Many Requests`) are retried after the delay
+//! from the `Retry-After` header, up to three times. Delays longer than
+//! 60 seconds, e.g. from daily limits, are not waited out and surface as
+//! [`DesecError::Throttled`]; see [`DesecProvider::with_max_retry_wait`].
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, AFSDB, APL, CAA, CDNSKEY, CDS, CERT, CNAME, DHCID, DNAME, DNSKEY,
+//! DS, HINFO, HTTPS, KX, L32, L64, LOC, LP, MX, NAPTR, NID, NS, OPENPGPKEY,
+//! PTR, RP, SMIMEA, SPF, SRV, SSHFP, SVCB, TLSA, TXT and URI
+//!
+//! # API Reference
+//!
+//! - [deSEC API](https://desec.readthedocs.io/en/latest/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub use api::{Client, DesecError};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A",
+    "AAAA",
+    "AFSDB",
+    "APL",
+    "CAA",
+    "CDNSKEY",
+    "CDS",
+    "CERT",
+    "CNAME",
+    "DHCID",
+    "DNAME",
+    "DNSKEY",
+    "DS",
+    "HINFO",
+    "HTTPS",
+    "KX",
+    "L32",
+    "L64",
+    "LOC",
+    "LP",
+    "MX",
+    "NAPTR",
+    "NID",
+    "NS",
+    "OPENPGPKEY",
+    "PTR",
+    "RP",
+    "SMIMEA",
+    "SPF",
+    "SRV",
+    "SSHFP",
+    "SVCB",
+    "TLSA",
+    "TXT",
+    "URI",
+];
+
+/// deSEC provider.
+#[derive(Clone)]
+pub struct DesecProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain hosted on deSEC.
+pub struct DesecZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl DesecProvider {
+    /// Creates a new deSEC provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - deSEC API token
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::desec::DesecProvider;
+    ///
+    /// let provider = DesecProvider::new("your-api-token").unwrap();
+    /// ```
+    pub fn new(token: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(token)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new deSEC provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - deSEC API token
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::desec::DesecProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = DesecProvider::with_config("your-api-token", config).unwrap();
+    /// ```
+    pub fn with_config(
+        token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(token, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new deSEC provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - deSEC API token
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        token: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(token, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Sets the longest `Retry-After` delay that is waited out before
+    /// retrying a throttled request.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use manydns::desec::DesecProvider;
+    ///
+    /// // Fail fast instead of waiting for rate limits to reset.
+    /// let provider = DesecProvider::new("your-api-token")
+    ///     .unwrap()
+    ///     .with_max_retry_wait(Duration::ZERO);
+    /// ```
+    pub fn with_max_retry_wait(self, max_retry_wait: Duration) -> Self {
+        let api_client = (*self.api_client)
+            .clone()
+            .with_max_retry_wait(max_retry_wait);
+        Self {
+            api_client: Arc::new(api_client),
+        }
+    }
+}
+
+impl Provider for DesecProvider {
+    type Zone = DesecZone;
+    type CustomRetrieveError = DesecError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .get_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                Some(404) => RetrieveZoneError::NotFound,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(DesecZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| DesecZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for DesecProvider {
+    type CustomCreateError = DesecError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let domain = self
+            .api_client
+            .create_domain(domain.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateZoneError::Unauthorized,
+                Some(400) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(DesecZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+}
+
+impl DeleteZone for DesecProvider {
+    type CustomDeleteError = DesecError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        let zone_id = zone_id.trim_end_matches('.');
+        let map_err = |err: DesecError| match err.status() {
+            Some(401 | 403) => DeleteZoneError::Unauthorized,
+            Some(404) => DeleteZoneError::NotFound,
+            _ => DeleteZoneError::Custom(err),
+        };
+
+        // Deleting a missing domain succeeds with 204, so check that it exists first.
+        self.api_client.get_domain(zone_id).await.map_err(map_err)?;
+        self.api_client
+            .delete_domain(zone_id)
+            .await
+            .map_err(map_err)
+    }
+}
+
+impl DesecZone {
+    /// Returns the smallest TTL accepted for records of this domain.
+    pub fn minimum_ttl(&self) -> u64 {
+        self.repr.minimum_ttl
+    }
+
+    /// Returns the DNSSEC keys of the domain.
+    ///
+    /// Empty for zones obtained through [`list_zones`](crate::Provider::list_zones).
+    pub fn keys(&self) -> &[api::DnssecKey] {
+        &self.repr.keys
+    }
+
+    /// Returns when the domain was last published to the name servers.
+    pub fn published(&self) -> Option<&str> {
+        self.repr.published.as_deref()
+    }
+
+    /// Applies several RRset changes atomically in one bulk PATCH request.
+    ///
+    /// Returns the RRsets that still exist after the change.
+    pub async fn patch_rrsets(
+        &self,
+        updates: &[api::RRsetUpdate],
+    ) -> Result<Vec<api::RRset>, DesecError> {
+        self.api_client.patch_rrsets(&self.repr.name, updates).await
+    }
+
+    /// Converts a host into a subname relative to the domain.
+    fn subname(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host == self.repr.name {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.name)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts all records of an RRset into generic records.
+    fn records_from_rrset(rrset: &api::RRset) -> Vec<Record> {
+        let host = host_from_subname(&rrset.subname);
+        rrset
+            .records
+            .iter()
+            .map(|content| Record {
+                id: format!("{}/{}/{}", host, rrset.record_type, content),
+                host: host.clone(),
+                data: record_data_from_content(&rrset.record_type, content),
+                ttl: rrset.ttl,
+            })
+            .collect()
+    }
+}
+
+/// Converts a subname into a host, using `@` for the apex.
+fn host_from_subname(subname: &str) -> String {
+    if subname.is_empty() {
+        "@".to_string()
+    } else {
+        subname.to_string()
+    }
+}
+
+impl Zone for DesecZone {
+    type CustomRetrieveError = DesecError;
+
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .api_client
+            .list_rrsets(&self.repr.name)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets.iter().flat_map(Self::records_from_rrset).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/content"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let rrset = self
+            .api_client
+            .get_rrset(&self.repr.name, &self.subname(parts[0]), parts[1])
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        Self::records_from_rrset(&rrset)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for DesecZone {
+    type CustomCreateError = DesecError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let subname = self.subname(host);
+        let content = content_from_record_data(data);
+        let ttl = ttl.max(self.repr.minimum_ttl);
+
+        let map_err = |err: DesecError| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(400) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        // The PATCH replaces the whole RRset, so merge with the existing records.
+        let mut records = self
+            .api_client
+            .get_rrset(&self.repr.name, &subname, typ)
+            .await
+            .map_err(map_err)?
+            .map(|rrset| rrset.records)
+            .unwrap_or_default();
+
+        if records.contains(&content) {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+        records.push(content.clone());
+
+        self.api_client
+            .patch_rrsets(
+                &self.repr.name,
+                &[api::RRsetUpdate::replace(&subname, typ, ttl, records)],
+            )
+            .await
+            .map_err(map_err)?;
+
+        let host = host_from_subname(&subname);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, content),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for DesecZone {
+    type CustomDeleteError = DesecError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/content"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, content) = (parts[0], parts[1], parts[2]);
+        let subname = self.subname(host);
+
+        let map_err = |err: DesecError| match err.status() {
+            Some(401 | 403) => DeleteRecordError::Unauthorized,
+            Some(404) => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let rrset = self
+            .api_client
+            .get_rrset(&self.repr.name, &subname, typ)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let remaining: Vec<String> = rrset
+            .records
+            .iter()
+            .filter(|c| *c != content)
+            .cloned()
+            .collect();
+        if remaining.len() == rrset.records.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        let update = if remaining.is_empty() {
+            api::RRsetUpdate::delete(&subname, typ)
+        } else {
+            api::RRsetUpdate::replace(&subname, typ, rrset.ttl, remaining)
+        };
+
+        self.api_client
+            .patch_rrsets(&self.repr.name, &[update])
+            .await
+            .map_err(map_err)?;
+        Ok(())
+    }
+}
+
+/// Converts deSEC record content into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT character strings are unquoted
+/// and concatenated.
+fn record_data_from_content(typ: &str, content: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, content.trim_end_matches('.')),
+        "TXT" => RecordData::TXT(unquote_txt(content)),
+        _ => RecordData::from_raw(typ, content),
+    }
+}
+
+/// Converts [`RecordData`] into deSEC record content.
+///
+/// Host names are made absolute and TXT values are quoted.
+fn content_from_record_data(data: &RecordData) -> String {
+    match data {
+        RecordData::CNAME(target) => canonical(target),
+        RecordData::NS(ns) => canonical(ns),
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => format!("{} {}", priority, canonical(mail_server)),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => format!("{} {} {} {}", priority, weight, port, canonical(target)),
+        RecordData::TXT(txt) => quote_txt(txt),
+        _ => data.get_value(),
+    }
+}
+
+/// Returns the canonical form of a name (with trailing dot).
+fn canonical(name: &str) -> String {
+    if name.ends_with('.') {
+        name.to_string()
+    } else {
+        format!("{}.", name)
+    }
+}
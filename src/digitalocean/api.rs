@@ -0,0 +1,452 @@
+//! Low-level DigitalOcean Domains API client.
+//!
+//! This module provides direct access to the DigitalOcean Domains API
+//! (`/v2/domains`).
+//! For most use cases, prefer using [`DigitalOceanProvider`](super::DigitalOceanProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [Domains](https://docs.digitalocean.com/reference/api/digitalocean/#tag/Domains)
+//! - [Domain Records](https://docs.digitalocean.com/reference/api/digitalocean/#tag/Domain-Records)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::digitalocean::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-api-token")?;
+//!
+//! for domain in client.list_domains().await? {
+//!     println!("Domain: {}", domain.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The DigitalOcean API base URL.
+const DIGITALOCEAN_API_URL: &str = "https://api.digitalocean.com";
+
+/// Page size requested from list endpoints (the API maximum).
+const PER_PAGE: u32 = 200;
+
+/// Errors that may occur when interacting with the DigitalOcean API.
+#[derive(Debug, Error)]
+pub enum DigitalOceanError {
+    /// The API returned an error response.
+    #[error("API error ({status}) {id}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error identifier (e.g., `not_found`, `unprocessable_entity`).
+        id: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl DigitalOceanError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            DigitalOceanError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// DigitalOcean error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    id: String,
+    #[serde(default)]
+    message: String,
+}
+
+/// Pagination links of list responses.
+#[derive(Debug, Default, Deserialize)]
+struct Links {
+    #[serde(default)]
+    pages: Option<Pages>,
+}
+
+/// Page URLs; only present when there is more than one page.
+#[derive(Debug, Default, Deserialize)]
+struct Pages {
+    #[serde(default)]
+    next: Option<String>,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A DigitalOcean domain (DNS zone).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain name (e.g., `example.com`).
+    pub name: String,
+    /// Default TTL of the domain's records.
+    #[serde(default)]
+    pub ttl: Option<u64>,
+    /// Complete zone file of the domain.
+    #[serde(default)]
+    pub zone_file: Option<String>,
+}
+
+/// Request body for creating a domain.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateDomainRequest {
+    /// Domain name.
+    pub name: String,
+    /// Creates an A record for the apex pointing to this address.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip_address: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainResponse {
+    domain: Domain,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainsResponse {
+    domains: Vec<Domain>,
+    #[serde(default)]
+    links: Links,
+}
+
+// =============================================================================
+// Domain Record Types
+// =============================================================================
+
+/// A DNS record of a DigitalOcean domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the domain (`@` for the apex).
+    pub name: String,
+    /// Record value; for MX, CNAME, NS and SRV the target host name.
+    pub data: String,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: Option<u16>,
+    /// Port for SRV records.
+    #[serde(default)]
+    pub port: Option<u16>,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Weight for SRV records.
+    #[serde(default)]
+    pub weight: Option<u16>,
+    /// Flags for CAA records.
+    #[serde(default)]
+    pub flags: Option<u8>,
+    /// Tag for CAA records (`issue`, `issuewild` or `iodef`).
+    #[serde(default)]
+    pub tag: Option<String>,
+}
+
+/// Request body for creating a domain record.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRecordRequest {
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the domain (`@` for the apex).
+    pub name: String,
+    /// Record value.
+    pub data: String,
+    /// Priority for MX and SRV records.
+    pub priority: Option<u16>,
+    /// Port for SRV records.
+    pub port: Option<u16>,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Weight for SRV records.
+    pub weight: Option<u16>,
+    /// Flags for CAA records.
+    pub flags: Option<u8>,
+    /// Tag for CAA records.
+    pub tag: Option<String>,
+}
+
+impl CreateRecordRequest {
+    /// Creates a request for a record without type-specific fields.
+    pub fn new(record_type: &str, name: &str, data: &str, ttl: u64) -> Self {
+        Self {
+            record_type: record_type.to_string(),
+            name: name.to_string(),
+            data: data.to_string(),
+            priority: None,
+            port: None,
+            ttl,
+            weight: None,
+            flags: None,
+            tag: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordResponse {
+    domain_record: DomainRecord,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordsResponse {
+    domain_records: Vec<DomainRecord>,
+    #[serde(default)]
+    links: Links,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// DigitalOcean API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new DigitalOcean API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with read/write scope
+    pub fn new(api_token: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_token, DIGITALOCEAN_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new DigitalOcean API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with read/write scope
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        api_token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_token, DIGITALOCEAN_API_URL, config)
+    }
+
+    /// Creates a new DigitalOcean API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with read/write scope
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        api_token: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", api_token))?;
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`DigitalOceanError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, DigitalOceanError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (id, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.id, e.message),
+                Err(_) => (String::new(), text),
+            };
+            return Err(DigitalOceanError::Api {
+                status: status.as_u16(),
+                id,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, DigitalOceanError> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Fetches all pages of a list endpoint while `links.pages.next` is set.
+    async fn get_pages<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        mut collect: impl FnMut(T) -> Links,
+    ) -> Result<(), DigitalOceanError> {
+        // The `next` link is only used as a "more pages" marker: following
+        // it as is would send the token to whatever host it names.
+        for page in 1.. {
+            let url = format!(
+                "{}{}?page={}&per_page={}",
+                self.base_url, path, page, PER_PAGE
+            );
+            let text = self.send(self.http_client.get(&url)).await?;
+            let links = collect(serde_json::from_str(&text)?);
+
+            if links.pages.and_then(|pages| pages.next).is_none() {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, DigitalOceanError> {
+        let mut domains = Vec::new();
+        self.get_pages("/v2/domains", |page: DomainsResponse| {
+            domains.extend(page.domains);
+            page.links
+        })
+        .await?;
+        Ok(domains)
+    }
+
+    /// Gets a domain by name.
+    pub async fn get_domain(&self, name: &str) -> Result<Domain, DigitalOceanError> {
+        let response: DomainResponse = self.get(&format!("/v2/domains/{}", name)).await?;
+        Ok(response.domain)
+    }
+
+    /// Creates a domain.
+    pub async fn create_domain(
+        &self,
+        request: &CreateDomainRequest,
+    ) -> Result<Domain, DigitalOceanError> {
+        let url = format!("{}/v2/domains", self.base_url);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        let response: DomainResponse = serde_json::from_str(&text)?;
+        Ok(response.domain)
+    }
+
+    /// Deletes a domain and all of its records.
+    pub async fn delete_domain(&self, name: &str) -> Result<(), DigitalOceanError> {
+        let url = format!("{}/v2/domains/{}", self.base_url, name);
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Domain Record APIs
+    // =========================================================================
+
+    /// Lists all records of a domain, following pagination.
+    pub async fn list_records(&self, domain: &str) -> Result<Vec<DomainRecord>, DigitalOceanError> {
+        let mut records = Vec::new();
+        self.get_pages(
+            &format!("/v2/domains/{}/records", domain),
+            |page: RecordsResponse| {
+                records.extend(page.domain_records);
+                page.links
+            },
+        )
+        .await?;
+        Ok(records)
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<DomainRecord, DigitalOceanError> {
+        let response: RecordResponse = self
+            .get(&format!("/v2/domains/{}/records/{}", domain, record_id))
+            .await?;
+        Ok(response.domain_record)
+    }
+
+    /// Creates a record.
+    pub async fn create_record(
+        &self,
+        domain: &str,
+        request: &CreateRecordRequest,
+    ) -> Result<DomainRecord, DigitalOceanError> {
+        let url = format!("{}/v2/domains/{}/records", self.base_url, domain);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        let response: RecordResponse = serde_json::from_str(&text)?;
+        Ok(response.domain_record)
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<(), DigitalOceanError> {
+        let url = format!(
+            "{}/v2/domains/{}/records/{}",
+            self.base_url, domain, record_id
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,485 @@
+//! DigitalOcean DNS provider implementation.
+//!
+//! This provider uses the DigitalOcean Domains API with Bearer token
+//! authentication.
+//!
+//! # Authentication
+//!
+//! Requires a DigitalOcean personal access token:
+//! - Create a token at: <https://cloud.digitalocean.com/account/api/tokens>
+//! - The token must have write scope (or the `domain` custom scopes)
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::digitalocean::DigitalOceanProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = DigitalOceanProvider::new("your_api_token")?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone IDs
+//!
+//! DigitalOcean identifies domains by name, so the zone ID is the domain name.
+//!
+//! # Supported Record Types
+//!
+//! - A (IPv4 address)
+//! - AAAA (IPv6 address)
+//! - CNAME (Canonical name)
+//! - MX (Mail exchange)
+//! - NS (Name server)
+//! - TXT (Text record)
+//! - SRV (Service record)
+//! - CAA (Certification Authority Authorization), as
+//!   [`RecordData::Other`] with the value `flags tag "value"`
+//!
+//! # API Reference
+//!
+//! - [Domains](https://docs.digitalocean.com/reference/api/digitalocean/#tag/Domains)
+//! - [Domain Records](https://docs.digitalocean.com/reference/api/digitalocean/#tag/Domain-Records)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, DigitalOceanError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// DigitalOcean DNS provider.
+#[derive(Clone)]
+pub struct DigitalOceanProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain managed by DigitalOcean.
+pub struct DigitalOceanZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl DigitalOceanProvider {
+    /// Creates a new DigitalOcean provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with write scope
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::digitalocean::DigitalOceanProvider;
+    ///
+    /// let provider = DigitalOceanProvider::new("your_api_token").unwrap();
+    /// ```
+    pub fn new(api_token: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(api_token)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new DigitalOcean provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with write scope
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::digitalocean::DigitalOceanProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = DigitalOceanProvider::with_config("your_api_token", config).unwrap();
+    /// ```
+    pub fn with_config(
+        api_token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(api_token, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new DigitalOcean provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with write scope
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        api_token: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(api_token, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for DigitalOceanProvider {
+    type Zone = DigitalOceanZone;
+    type CustomRetrieveError = DigitalOceanError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .get_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(DigitalOceanZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| DigitalOceanZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for DigitalOceanProvider {
+    type CustomCreateError = DigitalOceanError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let request = api::CreateDomainRequest {
+            name: domain.trim_end_matches('.').to_string(),
+            ip_address: None,
+        };
+
+        let domain = self
+            .api_client
+            .create_domain(&request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateZoneError::Unauthorized,
+                Some(422) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(DigitalOceanZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+}
+
+impl DeleteZone for DigitalOceanProvider {
+    type CustomDeleteError = DigitalOceanError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteZoneError::NotFound,
+                Some(401 | 403) => DeleteZoneError::Unauthorized,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl DigitalOceanZone {
+    /// Returns the default TTL of the domain, if reported.
+    pub fn ttl(&self) -> Option<u64> {
+        self.repr.ttl
+    }
+
+    /// Returns the zone file of the domain, if reported.
+    pub fn zone_file(&self) -> Option<&str> {
+        self.repr.zone_file.as_deref()
+    }
+
+    /// Converts a host into a name relative to the domain (`@` for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host == self.repr.name {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.name)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts a record target into the absolute form expected by the API.
+    fn absolute_target(&self, target: &str) -> String {
+        if target == "@" {
+            format!("{}.", self.repr.name)
+        } else if target.ends_with('.') {
+            target.to_string()
+        } else {
+            format!("{}.", target)
+        }
+    }
+
+    /// Converts a record target returned by the API into a host name.
+    fn target_host(&self, target: &str) -> String {
+        if target == "@" {
+            self.repr.name.clone()
+        } else {
+            target.trim_end_matches('.').to_string()
+        }
+    }
+
+    /// Converts an API record into a generic record.
+    fn record_from_api(&self, record: api::DomainRecord) -> Record {
+        let data = match record.record_type.as_str() {
+            "CNAME" => RecordData::CNAME(self.target_host(&record.data)),
+            "NS" => RecordData::NS(self.target_host(&record.data)),
+            "MX" => RecordData::MX {
+                priority: record.priority.unwrap_or(0),
+                mail_server: self.target_host(&record.data),
+            },
+            "SRV" => RecordData::SRV {
+                priority: record.priority.unwrap_or(0),
+                weight: record.weight.unwrap_or(0),
+                port: record.port.unwrap_or(0),
+                target: self.target_host(&record.data),
+            },
+            "TXT" => RecordData::TXT(record.data.clone()),
+            "CAA" => RecordData::Other {
+                typ: "CAA".to_string(),
+                value: format!(
+                    "{} {} \"{}\"",
+                    record.flags.unwrap_or(0),
+                    record.tag.as_deref().unwrap_or_default(),
+                    record.data
+                ),
+            },
+            typ => RecordData::from_raw(typ, &record.data),
+        };
+
+        Record {
+            id: record.id.to_string(),
+            host: record.name,
+            data,
+            ttl: record.ttl,
+        }
+    }
+
+    /// Builds a create request from generic record data.
+    ///
+    /// Returns `None` for unsupported record types or malformed CAA values.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Option<api::CreateRecordRequest> {
+        let name = self.relative_name(host);
+
+        let request = match data {
+            RecordData::A(_) | RecordData::AAAA(_) | RecordData::TXT(_) => {
+                api::CreateRecordRequest::new(data.get_type(), &name, &data.get_value(), ttl)
+            }
+            RecordData::CNAME(target) | RecordData::NS(target) => api::CreateRecordRequest::new(
+                data.get_type(),
+                &name,
+                &self.absolute_target(target),
+                ttl,
+            ),
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => api::CreateRecordRequest {
+                priority: Some(*priority),
+                ..api::CreateRecordRequest::new(
+                    "MX",
+                    &name,
+                    &self.absolute_target(mail_server),
+                    ttl,
+                )
+            },
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => api::CreateRecordRequest {
+                priority: Some(*priority),
+                weight: Some(*weight),
+                port: Some(*port),
+                ..api::CreateRecordRequest::new("SRV", &name, &self.absolute_target(target), ttl)
+            },
+            RecordData::Other { typ, value } if typ == "CAA" => {
+                let mut parts = value.splitn(3, ' ');
+                let flags = parts.next()?.parse().ok()?;
+                let tag = parts.next()?;
+                let value = parts.next()?.trim_matches('"');
+                api::CreateRecordRequest {
+                    flags: Some(flags),
+                    tag: Some(tag.to_string()),
+                    ..api::CreateRecordRequest::new("CAA", &name, value, ttl)
+                }
+            }
+            RecordData::Other { .. } => return None,
+        };
+
+        Some(request)
+    }
+}
+
+impl Zone for DigitalOceanZone {
+    type CustomRetrieveError = DigitalOceanError;
+
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.name)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| self.record_from_api(record))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        if record_id.parse::<u64>().is_err() {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let record = self
+            .api_client
+            .get_record(&self.repr.name, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl CreateRecord for DigitalOceanZone {
+    type CustomCreateError = DigitalOceanError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = match data {
+            RecordData::Other { typ, .. } if typ != "CAA" => {
+                return Err(CreateRecordError::UnsupportedType)
+            }
+            _ => self
+                .request_from_record_data(host, data, ttl)
+                .ok_or(CreateRecordError::InvalidRecord)?,
+        };
+
+        let record = self
+            .api_client
+            .create_record(&self.repr.name, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(422) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl DeleteRecord for DigitalOceanZone {
+    type CustomDeleteError = DigitalOceanError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        if record_id.parse::<u64>().is_err() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        self.api_client
+            .delete_record(&self.repr.name, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
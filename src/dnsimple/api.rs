@@ -0,0 +1,587 @@
+//! Low-level DNSimple API client.
+//!
+//! This module provides direct access to the DNSimple API v2 zone, record and
+//! domain endpoints, which are scoped to an account
+//! (`/v2/{account}/zones/{zone}/records`).
+//! For most use cases, prefer using [`DnsimpleProvider`](super::DnsimpleProvider) instead.
+//!
+//! # Environments
+//!
+//! - Production: `https://api.dnsimple.com`
+//! - Sandbox: `https://api.sandbox.dnsimple.com`
+//!
+//! Accounts and tokens are separate for each environment.
+//!
+//! # API Reference
+//!
+//! - [Zones](https://developer.dnsimple.com/v2/zones/)
+//! - [Zone records](https://developer.dnsimple.com/v2/zones/records/)
+//! - [Domains](https://developer.dnsimple.com/v2/domains/)
+//! - [Pagination](https://developer.dnsimple.com/v2/#pagination)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::dnsimple::api::{Client, ClientConfig};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new(ClientConfig::sandbox("your-api-token", "1385"))?;
+//!
+//! for record in client.list_records("example.com").await? {
+//!     println!("{} {} {}", record.name, record.record_type, record.content);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::types::Environment;
+use crate::HttpClientConfig;
+
+/// DNSimple API endpoints.
+const PRODUCTION_API_URL: &str = "https://api.dnsimple.com";
+const SANDBOX_API_URL: &str = "https://api.sandbox.dnsimple.com";
+
+/// Page size requested from list endpoints (the API maximum).
+const PAGE_SIZE: u32 = 100;
+
+/// Errors that may occur when interacting with the DNSimple API.
+#[derive(Debug, Error)]
+pub enum DnsimpleError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message, followed by the validation errors if any.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl DnsimpleError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            DnsimpleError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// DNSimple error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    errors: Option<std::collections::BTreeMap<String, Vec<String>>>,
+}
+
+/// A response wrapping its payload in `data`.
+#[derive(Debug, Deserialize)]
+struct DataResponse<T> {
+    data: T,
+}
+
+/// A page of a paginated list response.
+#[derive(Debug, Deserialize)]
+struct Page<T> {
+    data: Vec<T>,
+    pagination: Pagination,
+}
+
+#[derive(Debug, Deserialize)]
+struct Pagination {
+    current_page: u32,
+    total_pages: u32,
+}
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// Configuration for the DNSimple API client.
+#[derive(Clone)]
+pub struct ClientConfig {
+    /// API access token (account or user token).
+    pub api_token: String,
+    /// Account ID the requests are scoped to.
+    pub account_id: String,
+    /// API environment (sandbox or production).
+    pub environment: Environment,
+}
+
+impl ClientConfig {
+    /// Creates a new client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - API access token
+    /// * `account_id` - Account ID, as shown in the DNSimple dashboard URL
+    /// * `environment` - [`Environment::Sandbox`] for the sandbox, or production
+    pub fn new(
+        api_token: impl Into<String>,
+        account_id: impl Into<String>,
+        environment: Environment,
+    ) -> Self {
+        Self {
+            api_token: api_token.into(),
+            account_id: account_id.into(),
+            environment,
+        }
+    }
+
+    /// Creates a configuration for the sandbox environment.
+    pub fn sandbox(api_token: impl Into<String>, account_id: impl Into<String>) -> Self {
+        Self::new(api_token, account_id, Environment::Sandbox)
+    }
+
+    /// Creates a configuration for the production environment.
+    pub fn production(api_token: impl Into<String>, account_id: impl Into<String>) -> Self {
+        Self::new(api_token, account_id, Environment::Production)
+    }
+
+    /// Returns the API base URL for the configured environment.
+    pub fn api_url(&self) -> &'static str {
+        match self.environment {
+            Environment::Production => PRODUCTION_API_URL,
+            Environment::Sandbox => SANDBOX_API_URL,
+        }
+    }
+}
+
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("api_token", &"<redacted>")
+            .field("account_id", &self.account_id)
+            .field("environment", &self.environment)
+            .finish()
+    }
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A DNS zone of the account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsZone {
+    /// Zone ID.
+    pub id: u64,
+    /// Account ID.
+    pub account_id: u64,
+    /// Zone name (e.g., `example.com`).
+    pub name: String,
+    /// Whether this is a reverse zone.
+    #[serde(default)]
+    pub reverse: bool,
+    /// Whether this is a secondary zone.
+    #[serde(default)]
+    pub secondary: bool,
+    /// Whether the zone is served by the DNSimple name servers.
+    #[serde(default)]
+    pub active: bool,
+    /// Creation time (RFC 3339).
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Last update time (RFC 3339).
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+/// A domain of the account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain ID.
+    pub id: u64,
+    /// Domain name.
+    pub name: String,
+    /// Domain state (`hosted`, `registered`, ...).
+    #[serde(default)]
+    pub state: String,
+}
+
+/// Request body for adding a domain, which also creates its zone.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateDomainRequest {
+    /// Domain name.
+    pub name: String,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ZoneRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Name of the zone the record belongs to.
+    #[serde(default)]
+    pub zone_id: String,
+    /// ID of the parent record, for records managed by a service.
+    #[serde(default)]
+    pub parent_id: Option<u64>,
+    /// Host name relative to the zone (empty for the apex).
+    #[serde(default)]
+    pub name: String,
+    /// Record content; for SRV records `weight port target`.
+    #[serde(default)]
+    pub content: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: Option<u16>,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Regions the record is served from (`global` for all).
+    #[serde(default)]
+    pub regions: Vec<String>,
+    /// Whether the record is managed by DNSimple (SOA and apex NS records).
+    #[serde(default)]
+    pub system_record: bool,
+}
+
+/// Request body for creating a record.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRecordRequest {
+    /// Host name relative to the zone (empty for the apex).
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record content; for SRV records `weight port target`.
+    pub content: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+/// Request body for updating a record; the type cannot be changed.
+#[derive(Debug, Clone, Serialize)]
+pub struct UpdateRecordRequest {
+    /// Host name relative to the zone (empty for the apex).
+    pub name: String,
+    /// Record content.
+    pub content: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// DNSimple API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    config: ClientConfig,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new DNSimple API client.
+    pub fn new(config: ClientConfig) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_config(config, HttpClientConfig::default())
+    }
+
+    /// Creates a new DNSimple API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - DNSimple client configuration
+    /// * `http_config` - HTTP client configuration for network binding
+    pub fn with_config(
+        config: ClientConfig,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let base_url = config.api_url();
+        Self::with_base_url(config, base_url, http_config)
+    }
+
+    /// Creates a new DNSimple API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - DNSimple client configuration
+    /// * `base_url` - Custom base URL for the API
+    /// * `http_config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        config: ClientConfig,
+        base_url: &str,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", config.api_token))?;
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+
+        let mut builder = reqwest::Client::builder().default_headers(headers).timeout(
+            http_config
+                .timeout
+                .unwrap_or(std::time::Duration::from_secs(30)),
+        );
+
+        if let Some(addr) = http_config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = http_config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            config,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Returns the configured environment.
+    pub fn environment(&self) -> Environment {
+        self.config.environment
+    }
+
+    /// Returns the `/v2/{account}` URL prefix.
+    fn account_url(&self) -> String {
+        format!(
+            "{}/v2/{}",
+            self.base_url,
+            encode_component(&self.config.account_id)
+        )
+    }
+
+    /// Sends a request and turns non-success responses into [`DnsimpleError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, DnsimpleError> {
+        let response = request.header("Accept", "application/json").send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => match e.errors.filter(|errors| !errors.is_empty()) {
+                    Some(errors) => {
+                        let details = errors
+                            .into_iter()
+                            .map(|(field, messages)| format!("{}: {}", field, messages.join(", ")))
+                            .collect::<Vec<_>>()
+                            .join("; ");
+                        format!("{} ({})", e.message, details)
+                    }
+                    None => e.message,
+                },
+                Err(_) => text,
+            };
+            return Err(DnsimpleError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request and unwrap the `data` payload.
+    async fn get<T: DeserializeOwned>(&self, url: String) -> Result<T, DnsimpleError> {
+        let text = self.send(self.http_client.get(url)).await?;
+        let response: DataResponse<T> = serde_json::from_str(&text)?;
+        Ok(response.data)
+    }
+
+    /// Fetches all pages of a list endpoint.
+    async fn get_all<T: DeserializeOwned>(&self, url: &str) -> Result<Vec<T>, DnsimpleError> {
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let text = self
+                .send(
+                    self.http_client
+                        .get(format!("{}?page={}&per_page={}", url, page, PAGE_SIZE)),
+                )
+                .await?;
+            let response: Page<T> = serde_json::from_str(&text)?;
+            items.extend(response.data);
+
+            if response.pagination.current_page >= response.pagination.total_pages {
+                break;
+            }
+            page = response.pagination.current_page + 1;
+        }
+
+        Ok(items)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists all zones of the account, following pagination.
+    pub async fn list_zones(&self) -> Result<Vec<DnsZone>, DnsimpleError> {
+        self.get_all(&format!("{}/zones", self.account_url())).await
+    }
+
+    /// Gets a zone by name.
+    pub async fn get_zone(&self, zone: &str) -> Result<DnsZone, DnsimpleError> {
+        self.get(format!(
+            "{}/zones/{}",
+            self.account_url(),
+            encode_component(zone)
+        ))
+        .await
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Adds a domain to the account, which creates its zone.
+    pub async fn create_domain(
+        &self,
+        request: &CreateDomainRequest,
+    ) -> Result<Domain, DnsimpleError> {
+        let url = format!("{}/domains", self.account_url());
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        let response: DataResponse<Domain> = serde_json::from_str(&text)?;
+        Ok(response.data)
+    }
+
+    /// Deletes a domain and its zone from the account.
+    pub async fn delete_domain(&self, domain: &str) -> Result<(), DnsimpleError> {
+        let url = format!(
+            "{}/domains/{}",
+            self.account_url(),
+            encode_component(domain)
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a zone, following pagination.
+    pub async fn list_records(&self, zone: &str) -> Result<Vec<ZoneRecord>, DnsimpleError> {
+        self.get_all(&format!(
+            "{}/zones/{}/records",
+            self.account_url(),
+            encode_component(zone)
+        ))
+        .await
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        zone: &str,
+        record_id: u64,
+    ) -> Result<ZoneRecord, DnsimpleError> {
+        self.get(format!(
+            "{}/zones/{}/records/{}",
+            self.account_url(),
+            encode_component(zone),
+            record_id
+        ))
+        .await
+    }
+
+    /// Creates a record.
+    pub async fn create_record(
+        &self,
+        zone: &str,
+        request: &CreateRecordRequest,
+    ) -> Result<ZoneRecord, DnsimpleError> {
+        let url = format!(
+            "{}/zones/{}/records",
+            self.account_url(),
+            encode_component(zone)
+        );
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        let response: DataResponse<ZoneRecord> = serde_json::from_str(&text)?;
+        Ok(response.data)
+    }
+
+    /// Updates a record.
+    pub async fn update_record(
+        &self,
+        zone: &str,
+        record_id: u64,
+        request: &UpdateRecordRequest,
+    ) -> Result<ZoneRecord, DnsimpleError> {
+        let url = format!(
+            "{}/zones/{}/records/{}",
+            self.account_url(),
+            encode_component(zone),
+            record_id
+        );
+        let text = self.send(self.http_client.patch(url).json(request)).await?;
+        let response: DataResponse<ZoneRecord> = serde_json::from_str(&text)?;
+        Ok(response.data)
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(&self, zone: &str, record_id: u64) -> Result<(), DnsimpleError> {
+        let url = format!(
+            "{}/zones/{}/records/{}",
+            self.account_url(),
+            encode_component(zone),
+            record_id
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+/// Percent-encodes a path segment.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
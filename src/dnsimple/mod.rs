@@ -0,0 +1,484 @@
+//! DNSimple DNS provider implementation.
+//!
+//! This provider uses the DNSimple API v2 with Bearer token authentication.
+//!
+//! # Authentication
+//!
+//! Requires an API access token and the ID of the account to manage:
+//! - Create an account token in the account settings under "Access tokens"
+//! - The account ID is the number in the dashboard URL
+//!
+//! # Environments
+//!
+//! DNSimple provides a sandbox and a production API, selected with
+//! [`Environment`](crate::types::Environment):
+//! - Sandbox ([`Environment::Sandbox`](crate::types::Environment::Sandbox)):
+//!   `https://api.sandbox.dnsimple.com`
+//! - Production: `https://api.dnsimple.com`
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::dnsimple::{ClientConfig, DnsimpleProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let config = ClientConfig::production("your-api-token", "1385");
+//! let provider = DnsimpleProvider::new(config)?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 3600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone Management
+//!
+//! Zones are created and deleted through the domains of the account: creating
+//! a zone adds the domain as a hosted domain, and deleting a zone removes the
+//! domain. The zone ID is the zone name.
+//!
+//! # SRV Records
+//!
+//! DNSimple stores the priority of SRV records separately and the rest of the
+//! record as the content `weight port target`. SRV hosts include the service
+//! and protocol labels (e.g., `_sip._tcp`).
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ALIAS, CAA, HINFO, NAPTR, PTR, SPF and SSHFP, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [Zones](https://developer.dnsimple.com/v2/zones/)
+//! - [Zone records](https://developer.dnsimple.com/v2/zones/records/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, ClientConfig, DnsimpleError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ALIAS", "CAA", "HINFO", "NAPTR", "PTR", "SPF", "SSHFP"];
+
+/// DNSimple DNS provider.
+#[derive(Clone)]
+pub struct DnsimpleProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone managed by DNSimple.
+pub struct DnsimpleZone {
+    api_client: Arc<Client>,
+    repr: api::DnsZone,
+}
+
+impl DnsimpleProvider {
+    /// Creates a new DNSimple provider with the given configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::dnsimple::{ClientConfig, DnsimpleProvider};
+    /// use manydns::types::Environment;
+    ///
+    /// // For sandbox testing
+    /// let config = ClientConfig::sandbox("your-api-token", "1385");
+    /// let provider = DnsimpleProvider::new(config).unwrap();
+    ///
+    /// // For production
+    /// let config = ClientConfig::new("your-api-token", "1385", Environment::Production);
+    /// let provider = DnsimpleProvider::new(config).unwrap();
+    /// ```
+    pub fn new(config: ClientConfig) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new DNSimple provider with custom HTTP client configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::dnsimple::{ClientConfig, DnsimpleProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = ClientConfig::production("your-api-token", "1385");
+    /// let http_config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = DnsimpleProvider::with_config(config, http_config).unwrap();
+    /// ```
+    pub fn with_config(
+        config: ClientConfig,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(config, http_config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new DNSimple provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - DNSimple client configuration
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        config: ClientConfig,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(config, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for DnsimpleProvider {
+    type Zone = DnsimpleZone;
+    type CustomRetrieveError = DnsimpleError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(DnsimpleZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| DnsimpleZone {
+                api_client: self.api_client.clone(),
+                repr: zone,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for DnsimpleProvider {
+    type CustomCreateError = DnsimpleError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let map_err = |err: DnsimpleError| match err.status() {
+            Some(401 | 403) => CreateZoneError::Unauthorized,
+            Some(400 | 422) => CreateZoneError::InvalidDomainName,
+            _ => CreateZoneError::Custom(err),
+        };
+
+        let request = api::CreateDomainRequest {
+            name: domain.trim_end_matches('.').to_string(),
+        };
+        let domain = self
+            .api_client
+            .create_domain(&request)
+            .await
+            .map_err(map_err)?;
+        let zone = self
+            .api_client
+            .get_zone(&domain.name)
+            .await
+            .map_err(map_err)?;
+
+        Ok(DnsimpleZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+}
+
+impl DeleteZone for DnsimpleProvider {
+    type CustomDeleteError = DnsimpleError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteZoneError::NotFound,
+                Some(401 | 403) => DeleteZoneError::Unauthorized,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl DnsimpleZone {
+    /// Returns whether the zone is served by the DNSimple name servers.
+    pub fn is_active(&self) -> bool {
+        self.repr.active
+    }
+
+    /// Returns whether this is a secondary zone.
+    pub fn is_secondary(&self) -> bool {
+        self.repr.secondary
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    ///
+    /// The record type cannot be changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<DnsimpleError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| CreateRecordError::InvalidRecord)?;
+        let request = self.request_from_record_data(host, data, ttl)?;
+        let request = api::UpdateRecordRequest {
+            name: request.name,
+            content: request.content,
+            ttl: request.ttl,
+            priority: request.priority,
+        };
+
+        let record = self
+            .api_client
+            .update_record(&self.repr.name, record_id, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400 | 422) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+
+    /// Converts a host into a name relative to the zone (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let zone = &self.repr.name;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(zone) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", zone)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::CreateRecordRequest, CreateRecordError<DnsimpleError>> {
+        let (content, priority) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (
+                mail_server.trim_end_matches('.').to_string(),
+                Some(*priority),
+            ),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (
+                format!("{} {} {}", weight, port, target.trim_end_matches('.')),
+                Some(*priority),
+            ),
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                (target.trim_end_matches('.').to_string(), None)
+            }
+            RecordData::TXT(text) => (text.clone(), None),
+            RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+                (value.clone(), None)
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+            _ => (data.get_value(), None),
+        };
+
+        Ok(api::CreateRecordRequest {
+            name: self.relative_name(host),
+            record_type: data.get_type().to_string(),
+            content,
+            ttl,
+            priority,
+        })
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::ZoneRecord) -> Record {
+    let priority = record.priority.unwrap_or_default();
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority,
+            mail_server: record.content.trim_end_matches('.').to_string(),
+        },
+        "SRV" => RecordData::from_raw(
+            "SRV",
+            &format!("{} {}", priority, record.content.trim_end_matches('.')),
+        ),
+        "TXT" => RecordData::TXT(record.content.clone()),
+        "CNAME" | "NS" => {
+            RecordData::from_raw(&record.record_type, record.content.trim_end_matches('.'))
+        }
+        typ => RecordData::from_raw(typ, &record.content),
+    };
+
+    Record {
+        id: record.id.to_string(),
+        host: if record.name.is_empty() {
+            "@".to_string()
+        } else {
+            record.name
+        },
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for DnsimpleZone {
+    type CustomRetrieveError = DnsimpleError;
+
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.name)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| RetrieveRecordError::NotFound)?;
+
+        let record = self
+            .api_client
+            .get_record(&self.repr.name, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for DnsimpleZone {
+    type CustomCreateError = DnsimpleError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .create_record(&self.repr.name, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400 | 422) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl DeleteRecord for DnsimpleZone {
+    type CustomDeleteError = DnsimpleError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| DeleteRecordError::NotFound)?;
+
+        self.api_client
+            .delete_record(&self.repr.name, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,644 @@
+//! Low-level Exoscale DNS API client.
+//!
+//! This module provides direct access to the DNS endpoints of the Exoscale
+//! API v2 (`/dns-domain`) using `EXO2-HMAC-SHA256` request signing.
+//! For most use cases, prefer using [`ExoscaleProvider`](super::ExoscaleProvider) instead.
+//!
+//! # Request Signing
+//!
+//! Each request carries an `Authorization` header signed with the API
+//! secret:
+//!
+//! ```text
+//! Message   = METHOD PATH \n BODY \n QUERY_VALUES \n HEADER_VALUES \n EXPIRES
+//! Signature = base64(hmac_sha256(secret, Message))
+//! Header    = EXO2-HMAC-SHA256 credential=KEY[,signed-query-args=a;b],expires=EXPIRES,signature=Signature
+//! ```
+//!
+//! Modifying requests return asynchronous operations whose reference points
+//! to the affected resource.
+//!
+//! # API Reference
+//!
+//! - [DNS API](https://openapi-v2.exoscale.com/topic/topic-dns)
+//! - [Request signature](https://openapi-v2.exoscale.com/#topic-api-request-signature)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::exoscale::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("EXOxxxxxxxxxxxxxxxxxxxxxxxx", "your-api-secret");
+//! let client = Client::new(credentials)?;
+//!
+//! for domain in client.list_domains().await? {
+//!     println!("{} ({})", domain.unicode_name, domain.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use base64::Engine;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::signing::hmac_sha256;
+use crate::HttpClientConfig;
+
+/// Signing algorithm name.
+const ALGORITHM: &str = "EXO2-HMAC-SHA256";
+
+/// Zone of the default API endpoint. DNS is global, so any zone works.
+const DEFAULT_ZONE: &str = "ch-gva-2";
+
+/// Validity of a request signature, in seconds.
+const SIGNATURE_VALIDITY: u64 = 600;
+
+/// Returns the API endpoint of an Exoscale zone (e.g., `de-fra-1`).
+pub fn endpoint_for_zone(zone: &str) -> String {
+    format!("https://api-{}.exoscale.com/v2", zone)
+}
+
+/// Errors that may occur when interacting with the Exoscale API.
+#[derive(Debug, Error)]
+pub enum ExoscaleError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message.
+        message: String,
+    },
+
+    /// An asynchronous operation failed.
+    #[error("Operation {state}: {message}")]
+    Operation {
+        /// Final operation state (`failure` or `timeout`).
+        state: String,
+        /// Failure message or reason.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl ExoscaleError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ExoscaleError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Exoscale error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// API key credentials.
+#[derive(Clone)]
+pub struct Credentials {
+    /// API key (starts with `EXO`).
+    pub api_key: String,
+    /// API secret.
+    pub api_secret: String,
+}
+
+impl Credentials {
+    /// Creates credentials from an API key and secret.
+    pub fn new(api_key: impl Into<String>, api_secret: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            api_secret: api_secret.into(),
+        }
+    }
+
+    /// Reads credentials from the `EXOSCALE_API_KEY` and `EXOSCALE_API_SECRET`
+    /// environment variables.
+    pub fn from_env() -> Option<Self> {
+        let api_key = std::env::var("EXOSCALE_API_KEY").ok()?;
+        let api_secret = std::env::var("EXOSCALE_API_SECRET").ok()?;
+        Some(Self::new(api_key, api_secret))
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &"<redacted>")
+            .finish()
+    }
+}
+
+/// A request to be signed with `EXO2-HMAC-SHA256`.
+#[derive(Debug, Clone)]
+pub struct SigningRequest<'a> {
+    /// HTTP method (e.g., `GET`).
+    pub method: &'a str,
+    /// Full URL path, including the `/v2` prefix.
+    pub path: &'a str,
+    /// Request body.
+    pub body: &'a str,
+    /// Query parameters to sign, in the order they are listed.
+    pub query: &'a [(&'a str, &'a str)],
+    /// Expiration of the signature as a Unix timestamp.
+    pub expires: u64,
+}
+
+/// Computes the `EXO2-HMAC-SHA256` `Authorization` header value for a request.
+pub fn sign(credentials: &Credentials, request: &SigningRequest<'_>) -> String {
+    let query_values: String = request.query.iter().map(|(_, value)| *value).collect();
+    let message = format!(
+        "{} {}\n{}\n{}\n\n{}",
+        request.method, request.path, request.body, query_values, request.expires
+    );
+
+    let signature = base64::engine::general_purpose::STANDARD
+        .encode(hmac_sha256(credentials.api_secret.as_bytes(), &message));
+
+    let mut authorization = format!("{} credential={}", ALGORITHM, credentials.api_key);
+    if !request.query.is_empty() {
+        let names: Vec<&str> = request.query.iter().map(|(name, _)| *name).collect();
+        authorization.push_str(&format!(",signed-query-args={}", names.join(";")));
+    }
+    authorization.push_str(&format!(
+        ",expires={},signature={}",
+        request.expires, signature
+    ));
+    authorization
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A DNS domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsDomain {
+    /// Domain ID.
+    pub id: String,
+    /// Domain name.
+    #[serde(rename = "unicode-name")]
+    pub unicode_name: String,
+    /// Creation date (RFC 3339).
+    #[serde(rename = "created-at", default)]
+    pub created_at: Option<String>,
+}
+
+/// Response of the domain list endpoint.
+#[derive(Debug, Deserialize)]
+struct DomainsResponse {
+    #[serde(rename = "dns-domains", default)]
+    dns_domains: Vec<DnsDomain>,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsRecord {
+    /// Record ID.
+    pub id: String,
+    /// Name relative to the domain (empty for the apex).
+    #[serde(default)]
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record content; for SRV records `weight port target`.
+    #[serde(default)]
+    pub content: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: Option<u16>,
+    /// Whether the record is managed by Exoscale (SOA and NS).
+    #[serde(rename = "system-record", default)]
+    pub system_record: bool,
+}
+
+/// Response of the record list endpoint.
+#[derive(Debug, Deserialize)]
+struct RecordsResponse {
+    #[serde(rename = "dns-domain-records", default)]
+    dns_domain_records: Vec<DnsRecord>,
+}
+
+/// Request body for creating or updating a record.
+///
+/// The type is ignored when updating a record.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordRequest {
+    /// Name relative to the domain (empty for the apex).
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record content; for SRV records `weight port target`.
+    pub content: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+// =============================================================================
+// Operation Types
+// =============================================================================
+
+/// An asynchronous operation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Operation {
+    /// Operation ID.
+    pub id: String,
+    /// Operation state (`pending`, `success`, `failure` or `timeout`).
+    pub state: String,
+    /// Resource affected by the operation.
+    #[serde(default)]
+    pub reference: Option<Reference>,
+    /// Failure message.
+    #[serde(default)]
+    pub message: Option<String>,
+    /// Failure reason.
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Reference to the resource of an operation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Reference {
+    /// Resource ID.
+    pub id: String,
+}
+
+/// Response of the domain creation endpoint, which returns either the domain
+/// or an operation.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum CreateDomainResponse {
+    Domain(DnsDomain),
+    Operation(Operation),
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Exoscale DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    credentials: Credentials,
+    base_url: String,
+    /// Path of the base URL, prepended to signed paths.
+    base_path: String,
+}
+
+impl Client {
+    /// Creates a new Exoscale API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - API key and secret used to sign requests
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            credentials,
+            &endpoint_for_zone(DEFAULT_ZONE),
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Exoscale API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - API key and secret used to sign requests
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, &endpoint_for_zone(DEFAULT_ZONE), config)
+    }
+
+    /// Creates a new Exoscale API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers, or to use the
+    /// endpoint of another zone (see [`endpoint_for_zone`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - API key and secret used to sign requests
+    /// * `base_url` - Custom base URL for the API, including the `/v2` prefix
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(base_url)?;
+        let base_path = url.path().trim_end_matches('/').to_string();
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            credentials,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            base_path,
+        })
+    }
+
+    /// Sends a signed request and returns the response body.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<String, ExoscaleError> {
+        let body = body.unwrap_or_default();
+        let expires = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + SIGNATURE_VALIDITY;
+
+        let authorization = sign(
+            &self.credentials,
+            &SigningRequest {
+                method: method.as_str(),
+                path: &format!("{}{}", self.base_path, path),
+                body: &body,
+                query: &[],
+                expires,
+            },
+        );
+
+        let mut request = self
+            .http_client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("Authorization", authorization);
+        if !body.is_empty() {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) if !e.message.is_empty() => e.message,
+                _ => text,
+            };
+            return Err(ExoscaleError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a GET request and deserializes the JSON response.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ExoscaleError> {
+        let text = self.request(reqwest::Method::GET, path, None).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Sends a modifying request and checks the returned operation.
+    async fn operation(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<Operation, ExoscaleError> {
+        let text = self.request(method, path, body).await?;
+        check_operation(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all DNS domains.
+    pub async fn list_domains(&self) -> Result<Vec<DnsDomain>, ExoscaleError> {
+        let response: DomainsResponse = self.get("/dns-domain").await?;
+        Ok(response.dns_domains)
+    }
+
+    /// Gets a DNS domain by ID.
+    pub async fn get_domain(&self, domain_id: &str) -> Result<DnsDomain, ExoscaleError> {
+        self.get(&format!("/dns-domain/{}", encode_component(domain_id)))
+            .await
+    }
+
+    /// Creates a DNS domain.
+    pub async fn create_domain(&self, name: &str) -> Result<DnsDomain, ExoscaleError> {
+        let body = serde_json::json!({ "unicode-name": name }).to_string();
+        let text = self
+            .request(reqwest::Method::POST, "/dns-domain", Some(body))
+            .await?;
+
+        match serde_json::from_str(&text)? {
+            CreateDomainResponse::Domain(domain) => Ok(domain),
+            CreateDomainResponse::Operation(operation) => {
+                let operation = check_operation(operation)?;
+                let id = reference_id(&operation)?;
+                self.get_domain(&id).await
+            }
+        }
+    }
+
+    /// Deletes a DNS domain.
+    pub async fn delete_domain(&self, domain_id: &str) -> Result<(), ExoscaleError> {
+        self.operation(
+            reqwest::Method::DELETE,
+            &format!("/dns-domain/{}", encode_component(domain_id)),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a DNS domain.
+    pub async fn list_records(&self, domain_id: &str) -> Result<Vec<DnsRecord>, ExoscaleError> {
+        let response: RecordsResponse = self
+            .get(&format!(
+                "/dns-domain/{}/record",
+                encode_component(domain_id)
+            ))
+            .await?;
+        Ok(response.dns_domain_records)
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+    ) -> Result<DnsRecord, ExoscaleError> {
+        self.get(&format!(
+            "/dns-domain/{}/record/{}",
+            encode_component(domain_id),
+            encode_component(record_id)
+        ))
+        .await
+    }
+
+    /// Creates a record and returns its ID.
+    pub async fn create_record(
+        &self,
+        domain_id: &str,
+        request: &RecordRequest,
+    ) -> Result<String, ExoscaleError> {
+        let operation = self
+            .operation(
+                reqwest::Method::POST,
+                &format!("/dns-domain/{}/record", encode_component(domain_id)),
+                Some(serde_json::to_string(request)?),
+            )
+            .await?;
+        reference_id(&operation)
+    }
+
+    /// Updates the name, content, TTL and priority of a record.
+    pub async fn update_record(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+        request: &RecordRequest,
+    ) -> Result<(), ExoscaleError> {
+        let mut body = serde_json::json!({
+            "name": request.name,
+            "content": request.content,
+            "ttl": request.ttl,
+        });
+        if let Some(priority) = request.priority {
+            body["priority"] = priority.into();
+        }
+
+        self.operation(
+            reqwest::Method::PUT,
+            &format!(
+                "/dns-domain/{}/record/{}",
+                encode_component(domain_id),
+                encode_component(record_id)
+            ),
+            Some(body.to_string()),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(
+        &self,
+        domain_id: &str,
+        record_id: &str,
+    ) -> Result<(), ExoscaleError> {
+        self.operation(
+            reqwest::Method::DELETE,
+            &format!(
+                "/dns-domain/{}/record/{}",
+                encode_component(domain_id),
+                encode_component(record_id)
+            ),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Fails if an operation ended unsuccessfully.
+///
+/// Pending operations are accepted: their reference is already known.
+fn check_operation(operation: Operation) -> Result<Operation, ExoscaleError> {
+    match operation.state.as_str() {
+        "failure" | "timeout" => Err(ExoscaleError::Operation {
+            message: operation.message.or(operation.reason).unwrap_or_default(),
+            state: operation.state,
+        }),
+        _ => Ok(operation),
+    }
+}
+
+/// Returns the ID of the resource referenced by an operation.
+fn reference_id(operation: &Operation) -> Result<String, ExoscaleError> {
+    operation
+        .reference
+        .as_ref()
+        .map(|reference| reference.id.clone())
+        .ok_or_else(|| ExoscaleError::Operation {
+            state: operation.state.clone(),
+            message: "operation has no reference".to_string(),
+        })
+}
+
+/// Percent-encodes a path segment.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,470 @@
+//! Exoscale DNS provider implementation.
+//!
+//! This provider uses the DNS endpoints of the Exoscale API v2, with
+//! `EXO2-HMAC-SHA256` request signing.
+//!
+//! # Authentication
+//!
+//! Requires an API key and secret:
+//! - Create them under "IAM" > "API Keys" in the portal
+//! - The key's role needs access to the `dns` service
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::exoscale::{Credentials, ExoscaleProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("EXOxxxxxxxxxxxxxxxxxxxxxxxx", "your-api-secret");
+//! let provider = ExoscaleProvider::new(credentials)?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones
+//!
+//! The zone ID is the domain ID assigned by Exoscale;
+//! [`get_zone`](crate::Provider::get_zone) also accepts a domain name.
+//! DNS is global, so the default `ch-gva-2` endpoint serves all domains.
+//!
+//! # Records
+//!
+//! Record IDs are the IDs assigned by Exoscale. Record changes run as
+//! asynchronous operations; the record ID is taken from the operation
+//! reference, and failed operations are reported as
+//! [`ExoscaleError::Operation`].
+//!
+//! The SOA and NS records managed by Exoscale are listed like any other
+//! record, but cannot be modified.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ALIAS, CAA, HINFO, NAPTR, SPF, SSHFP and URL, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [DNS API](https://openapi-v2.exoscale.com/topic/topic-dns)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, Credentials, ExoscaleError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ALIAS", "CAA", "HINFO", "NAPTR", "SPF", "SSHFP", "URL"];
+
+/// Exoscale DNS provider.
+#[derive(Clone)]
+pub struct ExoscaleProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS domain hosted by Exoscale.
+pub struct ExoscaleZone {
+    api_client: Arc<Client>,
+    repr: api::DnsDomain,
+}
+
+impl ExoscaleProvider {
+    /// Creates a new Exoscale provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - API key and secret
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::exoscale::{Credentials, ExoscaleProvider};
+    ///
+    /// let credentials = Credentials::new("EXOxxxxxxxxxxxxxxxxxxxxxxxx", "your-api-secret");
+    /// let provider = ExoscaleProvider::new(credentials).unwrap();
+    /// ```
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Exoscale provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - API key and secret
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::exoscale::{Credentials, ExoscaleProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let credentials = Credentials::new("EXOxxxxxxxxxxxxxxxxxxxxxxxx", "your-api-secret");
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = ExoscaleProvider::with_config(credentials, config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Exoscale provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - API key and secret
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for ExoscaleProvider {
+    type Zone = ExoscaleZone;
+    type CustomRetrieveError = ExoscaleError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let map_err = |err: ExoscaleError| match err.status() {
+            Some(401 | 403) => RetrieveZoneError::Unauthorized,
+            Some(404) => RetrieveZoneError::NotFound,
+            _ => RetrieveZoneError::Custom(err),
+        };
+
+        // Domain IDs are UUIDs, so anything with a dot is a domain name.
+        let domain = if zone_id.contains('.') {
+            let name = zone_id.trim_end_matches('.');
+            self.api_client
+                .list_domains()
+                .await
+                .map_err(map_err)?
+                .into_iter()
+                .find(|domain| domain.unicode_name.eq_ignore_ascii_case(name))
+                .ok_or(RetrieveZoneError::NotFound)?
+        } else {
+            self.api_client.get_domain(zone_id).await.map_err(map_err)?
+        };
+
+        Ok(ExoscaleZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| ExoscaleZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for ExoscaleProvider {
+    type CustomCreateError = ExoscaleError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let domain = self
+            .api_client
+            .create_domain(domain.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateZoneError::Unauthorized,
+                Some(400) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(ExoscaleZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+}
+
+impl DeleteZone for ExoscaleProvider {
+    type CustomDeleteError = ExoscaleError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_domain(zone_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => DeleteZoneError::Unauthorized,
+                Some(404) => DeleteZoneError::NotFound,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl ExoscaleZone {
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    ///
+    /// The record type cannot be changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<ExoscaleError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        self.api_client
+            .update_record(&self.repr.id, record_id, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(record_from_request(record_id.to_string(), data, &request))
+    }
+
+    /// Converts a host into a name relative to the domain (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.unicode_name;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<ExoscaleError>> {
+        let (content, priority) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (
+                mail_server.trim_end_matches('.').to_string(),
+                Some(*priority),
+            ),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (
+                format!("{} {} {}", weight, port, target.trim_end_matches('.')),
+                Some(*priority),
+            ),
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                (target.trim_end_matches('.').to_string(), None)
+            }
+            RecordData::TXT(text) => (text.clone(), None),
+            RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+                (value.clone(), None)
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+            _ => (data.get_value(), None),
+        };
+
+        Ok(api::RecordRequest {
+            name: self.relative_name(host),
+            record_type: data.get_type().to_string(),
+            content,
+            ttl,
+            priority,
+        })
+    }
+}
+
+/// Maps an API error of a record modification.
+fn map_create_error(err: ExoscaleError) -> CreateRecordError<ExoscaleError> {
+    match err.status() {
+        Some(401 | 403) => CreateRecordError::Unauthorized,
+        Some(400 | 409) => CreateRecordError::InvalidRecord,
+        _ => CreateRecordError::Custom(err),
+    }
+}
+
+/// Builds the record created or updated by a request.
+fn record_from_request(id: String, data: &RecordData, request: &api::RecordRequest) -> Record {
+    Record {
+        id,
+        host: if request.name.is_empty() {
+            "@".to_string()
+        } else {
+            request.name.clone()
+        },
+        data: data.clone(),
+        ttl: request.ttl,
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::DnsRecord) -> Record {
+    let priority = record.priority.unwrap_or_default();
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority,
+            mail_server: record.content.trim_end_matches('.').to_string(),
+        },
+        "SRV" => RecordData::from_raw(
+            "SRV",
+            &format!("{} {}", priority, record.content.trim_end_matches('.')),
+        ),
+        "TXT" => RecordData::TXT(record.content.clone()),
+        "CNAME" | "NS" => {
+            RecordData::from_raw(&record.record_type, record.content.trim_end_matches('.'))
+        }
+        typ => RecordData::from_raw(typ, &record.content),
+    };
+
+    Record {
+        id: record.id,
+        host: if record.name.is_empty() {
+            "@".to_string()
+        } else {
+            record.name
+        },
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for ExoscaleZone {
+    type CustomRetrieveError = ExoscaleError;
+
+    fn id(&self) -> &str {
+        &self.repr.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.unicode_name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record = self
+            .api_client
+            .get_record(&self.repr.id, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for ExoscaleZone {
+    type CustomCreateError = ExoscaleError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let id = self
+            .api_client
+            .create_record(&self.repr.id, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(record_from_request(id, data, &request))
+    }
+}
+
+impl DeleteRecord for ExoscaleZone {
+    type CustomDeleteError = ExoscaleError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_record(&self.repr.id, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                Some(404) => DeleteRecordError::NotFound,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
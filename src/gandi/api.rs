@@ -0,0 +1,437 @@
+//! Low-level Gandi LiveDNS API client.
+//!
+//! This module provides direct access to the Gandi LiveDNS v5 API.
+//! For most use cases, prefer using [`GandiProvider`](super::GandiProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [LiveDNS API](https://api.gandi.net/docs/livedns/)
+//! - [Authentication](https://api.gandi.net/docs/authentication/)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::gandi::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-personal-access-token")?;
+//!
+//! for rrset in client.list_rrsets("example.com").await? {
+//!     println!("{} {} {:?}", rrset.rrset_name, rrset.rrset_type, rrset.rrset_values);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Gandi LiveDNS API base URL.
+const GANDI_API_URL: &str = "https://api.gandi.net/v5/livedns";
+
+/// Page size requested when listing domains.
+const PER_PAGE: usize = 100;
+
+/// Errors that may occur when interacting with the Gandi API.
+#[derive(Debug, Error)]
+pub enum GandiError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl GandiError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            GandiError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Gandi error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    cause: Option<String>,
+}
+
+/// Gandi response to write operations.
+#[derive(Debug, Deserialize)]
+struct MessageResponse {
+    #[serde(default)]
+    #[allow(dead_code)]
+    message: String,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A domain managed by LiveDNS.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Fully-qualified domain name (e.g., `example.com`).
+    pub fqdn: String,
+    /// Whether snapshots are taken automatically before each change.
+    #[serde(default)]
+    pub automatic_snapshots: Option<bool>,
+}
+
+// =============================================================================
+// RRset Types
+// =============================================================================
+
+/// A resource record set: all records sharing a name and type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RRset {
+    /// Name relative to the domain (`@` for the apex).
+    pub rrset_name: String,
+    /// Record type.
+    pub rrset_type: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub rrset_ttl: Option<u64>,
+    /// Record values in presentation format.
+    #[serde(default)]
+    pub rrset_values: Vec<String>,
+}
+
+/// Request body for creating or replacing an RRset.
+#[derive(Debug, Clone, Serialize)]
+pub struct RRsetRequest {
+    /// Record values in presentation format.
+    pub rrset_values: Vec<String>,
+    /// TTL in seconds; the domain default is used when absent.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rrset_ttl: Option<u64>,
+}
+
+/// Request body for replacing all RRsets of a domain.
+#[derive(Debug, Serialize)]
+struct ReplaceAllRequest<'a> {
+    items: &'a [RRset],
+}
+
+// =============================================================================
+// Snapshot Types
+// =============================================================================
+
+/// A snapshot of a domain's records.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Snapshot {
+    /// Snapshot ID (UUID).
+    pub id: String,
+    /// Snapshot name.
+    #[serde(default)]
+    pub name: Option<String>,
+    /// Creation time (RFC 3339).
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// Whether the snapshot was taken automatically.
+    #[serde(default)]
+    pub automatic: bool,
+    /// RRsets captured by the snapshot; only present when retrieving a single snapshot.
+    #[serde(default)]
+    pub zone_data: Vec<RRset>,
+}
+
+#[derive(Debug, Serialize)]
+struct CreateSnapshotRequest<'a> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<&'a str>,
+}
+
+/// Response to snapshot creation.
+#[derive(Debug, Clone, Deserialize)]
+pub struct SnapshotCreated {
+    /// ID of the new snapshot.
+    pub id: String,
+    /// Confirmation message.
+    #[serde(default)]
+    pub message: String,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Gandi LiveDNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new Gandi LiveDNS API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Personal access token with LiveDNS permissions
+    pub fn new(token: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(token, GANDI_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new Gandi LiveDNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Personal access token with LiveDNS permissions
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(token, GANDI_API_URL, config)
+    }
+
+    /// Creates a new Gandi LiveDNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Personal access token with LiveDNS permissions
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        token: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", token))?;
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`GandiError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, GandiError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(ErrorResponse {
+                    message,
+                    cause: Some(cause),
+                }) if message.is_empty() => cause,
+                Ok(e) => e.message,
+                Err(_) => text,
+            };
+            return Err(GandiError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, GandiError> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains managed by LiveDNS, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, GandiError> {
+        let mut domains = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let batch: Vec<Domain> = self
+                .get(&format!("/domains?page={}&per_page={}", page, PER_PAGE))
+                .await?;
+            let count = batch.len();
+            domains.extend(batch);
+
+            if count < PER_PAGE {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(domains)
+    }
+
+    /// Gets a domain by name.
+    pub async fn get_domain(&self, fqdn: &str) -> Result<Domain, GandiError> {
+        self.get(&format!("/domains/{}", fqdn)).await
+    }
+
+    // =========================================================================
+    // RRset APIs
+    // =========================================================================
+
+    /// Lists all RRsets of a domain.
+    pub async fn list_rrsets(&self, fqdn: &str) -> Result<Vec<RRset>, GandiError> {
+        self.get(&format!("/domains/{}/records", fqdn)).await
+    }
+
+    /// Gets the RRset with the given name and type.
+    pub async fn get_rrset(&self, fqdn: &str, name: &str, typ: &str) -> Result<RRset, GandiError> {
+        self.get(&format!("/domains/{}/records/{}/{}", fqdn, name, typ))
+            .await
+    }
+
+    /// Creates an RRset; fails if it already exists.
+    pub async fn create_rrset(
+        &self,
+        fqdn: &str,
+        name: &str,
+        typ: &str,
+        request: &RRsetRequest,
+    ) -> Result<(), GandiError> {
+        let url = format!(
+            "{}/domains/{}/records/{}/{}",
+            self.base_url, fqdn, name, typ
+        );
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        serde_json::from_str::<MessageResponse>(&text)?;
+        Ok(())
+    }
+
+    /// Creates or replaces an RRset.
+    pub async fn replace_rrset(
+        &self,
+        fqdn: &str,
+        name: &str,
+        typ: &str,
+        request: &RRsetRequest,
+    ) -> Result<(), GandiError> {
+        let url = format!(
+            "{}/domains/{}/records/{}/{}",
+            self.base_url, fqdn, name, typ
+        );
+        let text = self.send(self.http_client.put(url).json(request)).await?;
+        serde_json::from_str::<MessageResponse>(&text)?;
+        Ok(())
+    }
+
+    /// Replaces all RRsets of a domain.
+    pub async fn replace_all_rrsets(&self, fqdn: &str, rrsets: &[RRset]) -> Result<(), GandiError> {
+        let url = format!("{}/domains/{}/records", self.base_url, fqdn);
+        let text = self
+            .send(
+                self.http_client
+                    .put(url)
+                    .json(&ReplaceAllRequest { items: rrsets }),
+            )
+            .await?;
+        serde_json::from_str::<MessageResponse>(&text)?;
+        Ok(())
+    }
+
+    /// Deletes an RRset.
+    pub async fn delete_rrset(&self, fqdn: &str, name: &str, typ: &str) -> Result<(), GandiError> {
+        let url = format!(
+            "{}/domains/{}/records/{}/{}",
+            self.base_url, fqdn, name, typ
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Snapshot APIs
+    // =========================================================================
+
+    /// Lists the snapshots of a domain (without zone data).
+    pub async fn list_snapshots(&self, fqdn: &str) -> Result<Vec<Snapshot>, GandiError> {
+        self.get(&format!("/domains/{}/snapshots", fqdn)).await
+    }
+
+    /// Gets a snapshot including its zone data.
+    pub async fn get_snapshot(
+        &self,
+        fqdn: &str,
+        snapshot_id: &str,
+    ) -> Result<Snapshot, GandiError> {
+        self.get(&format!("/domains/{}/snapshots/{}", fqdn, snapshot_id))
+            .await
+    }
+
+    /// Takes a snapshot of the current records of a domain.
+    pub async fn create_snapshot(
+        &self,
+        fqdn: &str,
+        name: Option<&str>,
+    ) -> Result<SnapshotCreated, GandiError> {
+        let url = format!("{}/domains/{}/snapshots", self.base_url, fqdn);
+        let text = self
+            .send(
+                self.http_client
+                    .post(url)
+                    .json(&CreateSnapshotRequest { name }),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a snapshot.
+    pub async fn delete_snapshot(&self, fqdn: &str, snapshot_id: &str) -> Result<(), GandiError> {
+        let url = format!(
+            "{}/domains/{}/snapshots/{}",
+            self.base_url, fqdn, snapshot_id
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
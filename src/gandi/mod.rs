@@ -0,0 +1,506 @@
+//! Gandi LiveDNS provider implementation.
+//!
+//! This provider uses the Gandi LiveDNS v5 API with personal access token
+//! authentication.
+//!
+//! # Authentication
+//!
+//! Requires a Gandi personal access token (PAT):
+//! - Create a token at: <https://admin.gandi.net/organizations/account/pat>
+//! - The token needs the "Manage domain name technical configurations" permission
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::gandi::GandiProvider;
+//! use manydns::{CreateRecord, DeleteRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = GandiProvider::new("your_personal_access_token")?;
+//!
+//! let zone = provider.get_zone("example.com").await?;
+//!
+//! // Take a snapshot before changing anything
+//! let snapshot = zone.create_snapshot(Some("before-www")).await?;
+//!
+//! let record = zone
+//!     .create_record("www", &RecordData::A("192.0.2.1".parse()?), 300)
+//!     .await?;
+//! zone.delete_record(&record.id).await?;
+//!
+//! // Roll back to the snapshot
+//! zone.restore_snapshot(&snapshot.id).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # RRset-based API
+//!
+//! LiveDNS manages records as RRsets (`rrset_name`/`rrset_type`/`rrset_values`).
+//! Creating or deleting a single record reads the current RRset and writes it
+//! back with the value added or removed; the RRset is deleted once its last
+//! value is removed. All values in an RRset share one TTL, so creating a
+//! record also updates the TTL of its siblings.
+//!
+//! Record IDs use the format `"name/type/value"` where `value` is the LiveDNS
+//! presentation value, similar to the Hetzner provider.
+//!
+//! # Snapshots
+//!
+//! [`GandiZone`] exposes the LiveDNS snapshot endpoints: list, create, get,
+//! delete, and restore (replacing all records with a snapshot's zone data).
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, ALIAS, CAA, CDS, CNAME, DNAME, DS, HTTPS, KEY, LOC, MX, NAPTR,
+//! NS, OPENPGPKEY, PTR, RP, SPF, SRV, SSHFP, SVCB, TLSA, TXT and WKS
+//!
+//! # API Reference
+//!
+//! - [LiveDNS API](https://api.gandi.net/docs/livedns/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, GandiError, Snapshot, SnapshotCreated};
+
+use crate::txt::unquote_txt;
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A",
+    "AAAA",
+    "ALIAS",
+    "CAA",
+    "CDS",
+    "CNAME",
+    "DNAME",
+    "DS",
+    "HTTPS",
+    "KEY",
+    "LOC",
+    "MX",
+    "NAPTR",
+    "NS",
+    "OPENPGPKEY",
+    "PTR",
+    "RP",
+    "SPF",
+    "SRV",
+    "SSHFP",
+    "SVCB",
+    "TLSA",
+    "TXT",
+    "WKS",
+];
+
+/// Gandi LiveDNS provider.
+#[derive(Clone)]
+pub struct GandiProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain managed by Gandi LiveDNS.
+pub struct GandiZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl GandiProvider {
+    /// Creates a new Gandi LiveDNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Personal access token with LiveDNS permissions
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::gandi::GandiProvider;
+    ///
+    /// let provider = GandiProvider::new("your_personal_access_token").unwrap();
+    /// ```
+    pub fn new(token: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(token)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Gandi LiveDNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Personal access token with LiveDNS permissions
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::gandi::GandiProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = GandiProvider::with_config("your_personal_access_token", config).unwrap();
+    /// ```
+    pub fn with_config(
+        token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(token, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Gandi LiveDNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `token` - Personal access token with LiveDNS permissions
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        token: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(token, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for GandiProvider {
+    type Zone = GandiZone;
+    type CustomRetrieveError = GandiError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .get_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(GandiZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| GandiZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl GandiZone {
+    /// Returns whether LiveDNS takes a snapshot automatically before each change.
+    pub fn automatic_snapshots(&self) -> Option<bool> {
+        self.repr.automatic_snapshots
+    }
+
+    /// Lists the snapshots of the domain (without zone data).
+    pub async fn list_snapshots(&self) -> Result<Vec<Snapshot>, GandiError> {
+        self.api_client.list_snapshots(&self.repr.fqdn).await
+    }
+
+    /// Gets a snapshot including its zone data.
+    pub async fn get_snapshot(&self, snapshot_id: &str) -> Result<Snapshot, GandiError> {
+        self.api_client
+            .get_snapshot(&self.repr.fqdn, snapshot_id)
+            .await
+    }
+
+    /// Takes a snapshot of the current records, optionally named.
+    pub async fn create_snapshot(&self, name: Option<&str>) -> Result<SnapshotCreated, GandiError> {
+        self.api_client.create_snapshot(&self.repr.fqdn, name).await
+    }
+
+    /// Deletes a snapshot.
+    pub async fn delete_snapshot(&self, snapshot_id: &str) -> Result<(), GandiError> {
+        self.api_client
+            .delete_snapshot(&self.repr.fqdn, snapshot_id)
+            .await
+    }
+
+    /// Replaces all records of the domain with the zone data of a snapshot.
+    pub async fn restore_snapshot(&self, snapshot_id: &str) -> Result<(), GandiError> {
+        let snapshot = self.get_snapshot(snapshot_id).await?;
+        self.api_client
+            .replace_all_rrsets(&self.repr.fqdn, &snapshot.zone_data)
+            .await
+    }
+
+    /// Converts a host into an RRset name relative to the domain.
+    fn rrset_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host == self.repr.fqdn {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.fqdn)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Fetches an RRset, treating a missing RRset as `None`.
+    async fn fetch_rrset(&self, name: &str, typ: &str) -> Result<Option<api::RRset>, GandiError> {
+        match self.api_client.get_rrset(&self.repr.fqdn, name, typ).await {
+            Ok(rrset) => Ok(Some(rrset)),
+            Err(err) if err.status() == Some(404) => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Converts all values of an RRset into generic records.
+    fn records_from_rrset(rrset: &api::RRset) -> Vec<Record> {
+        rrset
+            .rrset_values
+            .iter()
+            .map(|value| Record {
+                id: format!("{}/{}/{}", rrset.rrset_name, rrset.rrset_type, value),
+                host: rrset.rrset_name.clone(),
+                data: record_data_from_value(&rrset.rrset_type, value),
+                ttl: rrset.rrset_ttl.unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+impl Zone for GandiZone {
+    type CustomRetrieveError = GandiError;
+
+    fn id(&self) -> &str {
+        &self.repr.fqdn
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.fqdn
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .api_client
+            .list_rrsets(&self.repr.fqdn)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets.iter().flat_map(Self::records_from_rrset).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let rrset = self
+            .fetch_rrset(parts[0], parts[1])
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        Self::records_from_rrset(&rrset)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for GandiZone {
+    type CustomCreateError = GandiError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.rrset_name(host);
+        let value = value_from_record_data(data);
+
+        let map_err = |err: GandiError| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(400 | 409) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        // PUT replaces the whole RRset, so merge with the existing values.
+        let mut values = self
+            .fetch_rrset(&name, typ)
+            .await
+            .map_err(map_err)?
+            .map(|rrset| rrset.rrset_values)
+            .unwrap_or_default();
+
+        if values.contains(&value) {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+        values.push(value.clone());
+
+        let request = api::RRsetRequest {
+            rrset_values: values,
+            rrset_ttl: Some(ttl),
+        };
+        self.api_client
+            .replace_rrset(&self.repr.fqdn, &name, typ, &request)
+            .await
+            .map_err(map_err)?;
+
+        Ok(Record {
+            id: format!("{}/{}/{}", name, typ, value),
+            host: name,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for GandiZone {
+    type CustomDeleteError = GandiError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (name, typ, value) = (parts[0], parts[1], parts[2]);
+
+        let map_err = |err: GandiError| match err.status() {
+            Some(404) => DeleteRecordError::NotFound,
+            Some(401 | 403) => DeleteRecordError::Unauthorized,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let rrset = self
+            .fetch_rrset(name, typ)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let remaining: Vec<String> = rrset
+            .rrset_values
+            .iter()
+            .filter(|v| *v != value)
+            .cloned()
+            .collect();
+        if remaining.len() == rrset.rrset_values.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        if remaining.is_empty() {
+            self.api_client
+                .delete_rrset(&self.repr.fqdn, name, typ)
+                .await
+                .map_err(map_err)
+        } else {
+            let request = api::RRsetRequest {
+                rrset_values: remaining,
+                rrset_ttl: rrset.rrset_ttl,
+            };
+            self.api_client
+                .replace_rrset(&self.repr.fqdn, name, typ, &request)
+                .await
+                .map_err(map_err)
+        }
+    }
+}
+
+/// Converts a LiveDNS value into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT values are unquoted.
+fn record_data_from_value(typ: &str, value: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, value.trim_end_matches('.')),
+        "TXT" => RecordData::TXT(unquote_txt(value)),
+        _ => RecordData::from_raw(typ, value),
+    }
+}
+
+/// Converts [`RecordData`] into a LiveDNS value.
+///
+/// Host names are made absolute and TXT values are quoted.
+fn value_from_record_data(data: &RecordData) -> String {
+    let absolute = |name: &str| {
+        if name.ends_with('.') {
+            name.to_string()
+        } else {
+            format!("{}.", name)
+        }
+    };
+
+    match data {
+        RecordData::CNAME(target) => absolute(target),
+        RecordData::NS(ns) => absolute(ns),
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => format!("{} {}", priority, absolute(mail_server)),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => format!("{} {} {} {}", priority, weight, port, absolute(target)),
+        RecordData::TXT(txt) if txt.starts_with('"') && txt.ends_with('"') && txt.len() > 1 => {
+            txt.clone()
+        }
+        RecordData::TXT(txt) => format!("\"{}\"", txt.replace('\\', "\\\\").replace('"', "\\\"")),
+        _ => data.get_value(),
+    }
+}
@@ -0,0 +1,814 @@
+//! Low-level Google Cloud DNS API client.
+//!
+//! This module provides direct access to the Cloud DNS v1 REST API and the
+//! service-account OAuth 2.0 flow used to authenticate it.
+//! For most use cases, prefer using [`GoogleCloudProvider`](super::GoogleCloudProvider) instead.
+//!
+//! # Authentication
+//!
+//! Requests are authorized with an OAuth 2.0 access token obtained by signing
+//! a JWT with the service account's RSA key (RS256) and exchanging it at the
+//! key's `token_uri`. The token is cached and refreshed shortly before it
+//! expires, or when the API rejects it.
+//!
+//! # API Reference
+//!
+//! - [Cloud DNS API](https://cloud.google.com/dns/docs/reference/rest/v1)
+//! - [Service account authorization](https://developers.google.com/identity/protocols/oauth2/service-account#authorizingrequests)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::gcloud::api::{Client, ServiceAccountKey};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let key = ServiceAccountKey::from_file("service-account.json")?;
+//! let client = Client::new(key)?;
+//!
+//! for zone in client.list_managed_zones(None).await? {
+//!     println!("{} ({})", zone.dns_name, zone.name);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use base64::engine::general_purpose::URL_SAFE_NO_PAD;
+use base64::Engine;
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::{SignatureEncoding, Signer};
+use rsa::RsaPrivateKey;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha2::Sha256;
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Cloud DNS API base URL.
+const GCLOUD_DNS_API_URL: &str = "https://dns.googleapis.com/dns/v1";
+
+/// The Google OAuth 2.0 token endpoint.
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+
+/// OAuth scope granting read/write access to Cloud DNS.
+pub const CLOUD_DNS_SCOPE: &str = "https://www.googleapis.com/auth/ndev.clouddns.readwrite";
+
+/// Lifetime requested for JWT assertions (the maximum allowed by Google).
+const ASSERTION_LIFETIME: u64 = 3600;
+
+/// Cached tokens are refreshed when they expire within this margin.
+const TOKEN_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+
+/// Errors that may occur when interacting with the Cloud DNS API.
+#[derive(Debug, Error)]
+pub enum GoogleCloudError {
+    /// The API returned an error response.
+    #[error("API error ({status}) {reason}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error reason (e.g., `notFound`, `alreadyExists`, `forbidden`).
+        reason: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// The token endpoint rejected the service-account assertion.
+    #[error("OAuth error ({status}) {error}: {description}")]
+    Auth {
+        /// HTTP status code.
+        status: u16,
+        /// OAuth error code (e.g., `invalid_grant`).
+        error: String,
+        /// Error description.
+        description: String,
+    },
+
+    /// The service-account private key could not be used.
+    #[error("Invalid service account key: {0}")]
+    InvalidKey(String),
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl GoogleCloudError {
+    /// Returns the HTTP status code if this is an API or OAuth error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            GoogleCloudError::Api { status, .. } | GoogleCloudError::Auth { status, .. } => {
+                Some(*status)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the error reason if this is an API error.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            GoogleCloudError::Api { reason, .. } => Some(reason),
+            _ => None,
+        }
+    }
+}
+
+/// Cloud DNS error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: ErrorBody,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    status: String,
+    #[serde(default)]
+    errors: Vec<ErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    #[serde(default)]
+    reason: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// A service-account key as downloaded from the Google Cloud console.
+///
+/// Only the fields needed for the JWT bearer flow are kept; unknown fields are
+/// ignored.
+#[derive(Clone, Deserialize)]
+pub struct ServiceAccountKey {
+    /// Project the service account belongs to; used as the Cloud DNS project.
+    pub project_id: String,
+    /// ID of the private key, sent as the JWT `kid`.
+    #[serde(default)]
+    pub private_key_id: Option<String>,
+    /// PKCS#8 PEM-encoded RSA private key.
+    pub private_key: String,
+    /// Service account e-mail address, sent as the JWT issuer.
+    pub client_email: String,
+    /// OAuth 2.0 token endpoint.
+    #[serde(default = "default_token_uri")]
+    pub token_uri: String,
+}
+
+fn default_token_uri() -> String {
+    GOOGLE_TOKEN_URL.to_string()
+}
+
+impl std::fmt::Debug for ServiceAccountKey {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServiceAccountKey")
+            .field("project_id", &self.project_id)
+            .field("private_key_id", &self.private_key_id)
+            .field("private_key", &"<redacted>")
+            .field("client_email", &self.client_email)
+            .field("token_uri", &self.token_uri)
+            .finish()
+    }
+}
+
+#[derive(Serialize)]
+struct JwtHeader<'a> {
+    alg: &'a str,
+    typ: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    kid: Option<&'a str>,
+}
+
+#[derive(Serialize)]
+struct JwtClaims<'a> {
+    iss: &'a str,
+    scope: &'a str,
+    aud: &'a str,
+    iat: u64,
+    exp: u64,
+}
+
+impl ServiceAccountKey {
+    /// Parses a service-account key from its JSON representation.
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Reads and parses a service-account key file.
+    pub fn from_file(
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Ok(Self::from_json(&std::fs::read_to_string(path)?)?)
+    }
+
+    /// Overrides the OAuth 2.0 token endpoint.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    pub fn with_token_uri(mut self, token_uri: &str) -> Self {
+        self.token_uri = token_uri.to_string();
+        self
+    }
+
+    /// Parses the RSA private key.
+    fn signing_key(&self) -> Result<SigningKey<Sha256>, GoogleCloudError> {
+        let private_key = RsaPrivateKey::from_pkcs8_pem(&self.private_key)
+            .map_err(|err| GoogleCloudError::InvalidKey(err.to_string()))?;
+        Ok(SigningKey::new(private_key))
+    }
+
+    /// Builds a signed JWT assertion for the OAuth 2.0 JWT bearer flow.
+    ///
+    /// # Arguments
+    ///
+    /// * `scope` - Space-separated OAuth scopes to request
+    /// * `issued_at` - Issue time in seconds since the Unix epoch
+    pub fn assertion(&self, scope: &str, issued_at: u64) -> Result<String, GoogleCloudError> {
+        let header = JwtHeader {
+            alg: "RS256",
+            typ: "JWT",
+            kid: self.private_key_id.as_deref(),
+        };
+        let claims = JwtClaims {
+            iss: &self.client_email,
+            scope,
+            aud: &self.token_uri,
+            iat: issued_at,
+            exp: issued_at + ASSERTION_LIFETIME,
+        };
+
+        let signing_input = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&header)?),
+            URL_SAFE_NO_PAD.encode(serde_json::to_vec(&claims)?)
+        );
+        let signature = self
+            .signing_key()?
+            .try_sign(signing_input.as_bytes())
+            .map_err(|err| GoogleCloudError::InvalidKey(err.to_string()))?;
+
+        Ok(format!(
+            "{}.{}",
+            signing_input,
+            URL_SAFE_NO_PAD.encode(signature.to_bytes())
+        ))
+    }
+}
+
+/// Successful token endpoint response.
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: String,
+    #[serde(default)]
+    expires_in: Option<u64>,
+}
+
+/// Token endpoint error response.
+#[derive(Debug, Deserialize)]
+struct TokenErrorResponse {
+    #[serde(default)]
+    error: String,
+    #[serde(default)]
+    error_description: String,
+}
+
+/// A cached OAuth 2.0 access token.
+#[derive(Debug, Clone)]
+struct AccessToken {
+    token: String,
+    expires_at: Instant,
+}
+
+// =============================================================================
+// Managed Zone Types
+// =============================================================================
+
+/// A Cloud DNS managed zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManagedZone {
+    /// Numeric zone ID assigned by the server.
+    #[serde(default)]
+    pub id: String,
+    /// User-assigned zone name, unique within the project.
+    pub name: String,
+    /// DNS name of the zone with a trailing dot (e.g., `example.com.`).
+    pub dns_name: String,
+    /// Zone description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Zone visibility (`public` or `private`).
+    #[serde(default)]
+    pub visibility: Option<String>,
+    /// Name servers delegated to the zone.
+    #[serde(default)]
+    pub name_servers: Vec<String>,
+    /// Creation time (RFC 3339).
+    #[serde(default)]
+    pub creation_time: Option<String>,
+}
+
+/// Request body for creating a managed zone.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateManagedZoneRequest {
+    /// Zone name: lowercase letters, digits and dashes, starting with a letter.
+    pub name: String,
+    /// DNS name of the zone with a trailing dot.
+    pub dns_name: String,
+    /// Zone description (required by the API, may be empty).
+    pub description: String,
+    /// Zone visibility (`public` or `private`); defaults to `public`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ManagedZonesResponse {
+    #[serde(default)]
+    managed_zones: Vec<ManagedZone>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+// =============================================================================
+// Resource Record Set Types
+// =============================================================================
+
+/// A resource record set: all records sharing a name and type.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ResourceRecordSet {
+    /// Fully-qualified owner name with a trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL in seconds.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// Record values in presentation format.
+    #[serde(default)]
+    pub rrdatas: Vec<String>,
+    /// Routing policy (geo, weighted round robin, ...); such sets carry no `rrdatas`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub routing_policy: Option<serde_json::Value>,
+}
+
+impl ResourceRecordSet {
+    /// Creates a simple record set.
+    pub fn new(name: &str, record_type: &str, ttl: u64, rrdatas: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            record_type: record_type.to_string(),
+            ttl: Some(ttl),
+            rrdatas,
+            routing_policy: None,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ResourceRecordSetsResponse {
+    #[serde(default)]
+    rrsets: Vec<ResourceRecordSet>,
+    #[serde(default)]
+    next_page_token: Option<String>,
+}
+
+/// An atomic change to the record sets of a managed zone.
+///
+/// Deletions must match the existing record sets exactly.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    /// Change ID assigned by the server.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// Record sets to add.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub additions: Vec<ResourceRecordSet>,
+    /// Record sets to delete.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub deletions: Vec<ResourceRecordSet>,
+    /// Change status (`pending` or `done`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<String>,
+    /// Time the change was submitted (RFC 3339).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start_time: Option<String>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Google Cloud DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    key: ServiceAccountKey,
+    token: Arc<Mutex<Option<AccessToken>>>,
+}
+
+impl Client {
+    /// Creates a new Cloud DNS API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Service-account key with the DNS Administrator role
+    pub fn new(key: ServiceAccountKey) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(key, GCLOUD_DNS_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new Cloud DNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Service-account key with the DNS Administrator role
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        key: ServiceAccountKey,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(key, GCLOUD_DNS_API_URL, config)
+    }
+
+    /// Creates a new Cloud DNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers; the token
+    /// endpoint is taken from [`ServiceAccountKey::token_uri`].
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Service-account key with the DNS Administrator role
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        key: ServiceAccountKey,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        // Fail early on unusable keys instead of on the first request.
+        key.signing_key()?;
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            key,
+            token: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Returns the project the client operates on.
+    pub fn project(&self) -> &str {
+        &self.key.project_id
+    }
+
+    /// Returns a valid access token, requesting a new one if the cached token
+    /// is missing or about to expire.
+    pub async fn access_token(&self) -> Result<String, GoogleCloudError> {
+        if let Some(cached) = self.token.lock().unwrap().as_ref() {
+            if cached.expires_at > Instant::now() + TOKEN_REFRESH_MARGIN {
+                return Ok(cached.token.clone());
+            }
+        }
+
+        let issued_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_secs();
+        let assertion = self.key.assertion(CLOUD_DNS_SCOPE, issued_at)?;
+        let body = format!(
+            "grant_type={}&assertion={}",
+            encode_component("urn:ietf:params:oauth:grant-type:jwt-bearer"),
+            encode_component(&assertion)
+        );
+
+        let requested_at = Instant::now();
+        let response = self
+            .http_client
+            .post(&self.key.token_uri)
+            .header(CONTENT_TYPE, "application/x-www-form-urlencoded")
+            .body(body)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (error, description) = match serde_json::from_str::<TokenErrorResponse>(&text) {
+                Ok(e) => (e.error, e.error_description),
+                Err(_) => (String::new(), text),
+            };
+            return Err(GoogleCloudError::Auth {
+                status: status.as_u16(),
+                error,
+                description,
+            });
+        }
+
+        let response: TokenResponse = serde_json::from_str(&text)?;
+        let lifetime = Duration::from_secs(response.expires_in.unwrap_or(ASSERTION_LIFETIME));
+        *self.token.lock().unwrap() = Some(AccessToken {
+            token: response.access_token.clone(),
+            expires_at: requested_at + lifetime,
+        });
+
+        Ok(response.access_token)
+    }
+
+    /// Discards the cached access token so the next request obtains a new one.
+    pub fn invalidate_token(&self) {
+        *self.token.lock().unwrap() = None;
+    }
+
+    /// Sends an authorized request and turns non-success responses into
+    /// [`GoogleCloudError::Api`].
+    ///
+    /// A `401` response discards the cached token and retries once with a
+    /// fresh one.
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        body: Option<String>,
+    ) -> Result<String, GoogleCloudError> {
+        let mut retried = false;
+
+        loop {
+            let token = self.access_token().await?;
+            let mut request = self
+                .http_client
+                .request(method.clone(), url)
+                .bearer_auth(token);
+            if let Some(ref body) = body {
+                request = request
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body.clone());
+            }
+
+            let response = request.send().await?;
+            let status = response.status();
+            let text = response.text().await?;
+
+            if status.as_u16() == 401 && !retried {
+                self.invalidate_token();
+                retried = true;
+                continue;
+            }
+
+            if !status.is_success() {
+                let (reason, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                    Ok(e) => (
+                        e.error
+                            .errors
+                            .into_iter()
+                            .next()
+                            .map(|detail| detail.reason)
+                            .unwrap_or(e.error.status),
+                        e.error.message,
+                    ),
+                    Err(_) => (String::new(), text),
+                };
+                return Err(GoogleCloudError::Api {
+                    status: status.as_u16(),
+                    reason,
+                    message,
+                });
+            }
+
+            return Ok(text);
+        }
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, GoogleCloudError> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(Method::GET, &url, None).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Make a POST request with a JSON body.
+    async fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: &B,
+    ) -> Result<T, GoogleCloudError> {
+        let url = format!("{}{}", self.base_url, path);
+        let body = serde_json::to_string(body)?;
+        let text = self.send(Method::POST, &url, Some(body)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Returns the path of a managed zone.
+    fn zone_path(&self, zone: &str) -> String {
+        format!(
+            "/projects/{}/managedZones/{}",
+            encode_component(self.project()),
+            encode_component(zone)
+        )
+    }
+
+    // =========================================================================
+    // Managed Zone APIs
+    // =========================================================================
+
+    /// Lists the managed zones of the project, following pagination.
+    ///
+    /// # Arguments
+    ///
+    /// * `dns_name` - Restricts the result to zones with this DNS name (with trailing dot)
+    pub async fn list_managed_zones(
+        &self,
+        dns_name: Option<&str>,
+    ) -> Result<Vec<ManagedZone>, GoogleCloudError> {
+        let mut zones = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut path = format!(
+                "/projects/{}/managedZones?maxResults=1000",
+                encode_component(self.project())
+            );
+            if let Some(dns_name) = dns_name {
+                path.push_str(&format!("&dnsName={}", encode_component(dns_name)));
+            }
+            if let Some(ref token) = page_token {
+                path.push_str(&format!("&pageToken={}", encode_component(token)));
+            }
+
+            let page: ManagedZonesResponse = self.get(&path).await?;
+            zones.extend(page.managed_zones);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// Gets a managed zone by name or numeric ID.
+    pub async fn get_managed_zone(&self, zone: &str) -> Result<ManagedZone, GoogleCloudError> {
+        self.get(&self.zone_path(zone)).await
+    }
+
+    /// Creates a managed zone.
+    pub async fn create_managed_zone(
+        &self,
+        request: &CreateManagedZoneRequest,
+    ) -> Result<ManagedZone, GoogleCloudError> {
+        let path = format!(
+            "/projects/{}/managedZones",
+            encode_component(self.project())
+        );
+        self.post(&path, request).await
+    }
+
+    /// Deletes a managed zone; it must not contain records besides SOA and NS.
+    pub async fn delete_managed_zone(&self, zone: &str) -> Result<(), GoogleCloudError> {
+        let url = format!("{}{}", self.base_url, self.zone_path(zone));
+        self.send(Method::DELETE, &url, None).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Resource Record Set APIs
+    // =========================================================================
+
+    /// Lists all record sets of a managed zone, following pagination.
+    pub async fn list_resource_record_sets(
+        &self,
+        zone: &str,
+    ) -> Result<Vec<ResourceRecordSet>, GoogleCloudError> {
+        let mut rrsets = Vec::new();
+        let mut page_token: Option<String> = None;
+
+        loop {
+            let mut path = format!("{}/rrsets?maxResults=1000", self.zone_path(zone));
+            if let Some(ref token) = page_token {
+                path.push_str(&format!("&pageToken={}", encode_component(token)));
+            }
+
+            let page: ResourceRecordSetsResponse = self.get(&path).await?;
+            rrsets.extend(page.rrsets);
+
+            match page.next_page_token {
+                Some(token) if !token.is_empty() => page_token = Some(token),
+                _ => break,
+            }
+        }
+
+        Ok(rrsets)
+    }
+
+    /// Gets the record set with the given owner name and type.
+    ///
+    /// Returns `None` if no such record set exists.
+    pub async fn get_resource_record_set(
+        &self,
+        zone: &str,
+        name: &str,
+        record_type: &str,
+    ) -> Result<Option<ResourceRecordSet>, GoogleCloudError> {
+        let path = format!(
+            "{}/rrsets/{}/{}",
+            self.zone_path(zone),
+            encode_component(name),
+            encode_component(record_type)
+        );
+        // A missing zone is also reported as 404; only its message names the zone parameter.
+        match self.get(&path).await {
+            Ok(rrset) => Ok(Some(rrset)),
+            Err(GoogleCloudError::Api {
+                status: 404,
+                ref message,
+                ..
+            }) if !message.contains("managedZone") => Ok(None),
+            Err(err) => Err(err),
+        }
+    }
+
+    // =========================================================================
+    // Change APIs
+    // =========================================================================
+
+    /// Atomically applies record set additions and deletions.
+    pub async fn create_change(
+        &self,
+        zone: &str,
+        change: &Change,
+    ) -> Result<Change, GoogleCloudError> {
+        self.post(&format!("{}/changes", self.zone_path(zone)), change)
+            .await
+    }
+
+    /// Gets a change by ID, e.g. to check whether it is `done`.
+    pub async fn get_change(
+        &self,
+        zone: &str,
+        change_id: &str,
+    ) -> Result<Change, GoogleCloudError> {
+        self.get(&format!(
+            "{}/changes/{}",
+            self.zone_path(zone),
+            encode_component(change_id)
+        ))
+        .await
+    }
+}
+
+/// Percent-encodes a path segment or query value (RFC 3986 unreserved characters are kept).
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
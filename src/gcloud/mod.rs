@@ -0,0 +1,583 @@
+//! Google Cloud DNS provider implementation.
+//!
+//! This provider uses the Cloud DNS v1 REST API, authenticated with a
+//! service-account key through the OAuth 2.0 JWT bearer flow.
+//!
+//! # Authentication
+//!
+//! Requires a service-account JSON key:
+//! - Create a service account with the "DNS Administrator" role at:
+//!   <https://console.cloud.google.com/iam-admin/serviceaccounts>
+//! - Add a JSON key and download it
+//!
+//! The provider signs a JWT with the key, exchanges it at the key's
+//! `token_uri` for an access token, and caches the token until shortly before
+//! it expires. [`ServiceAccountKey::with_token_uri`] overrides the token
+//! endpoint, e.g. to test against a local stand-in.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::gcloud::{GoogleCloudProvider, ServiceAccountKey};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let key = ServiceAccountKey::from_file("service-account.json")?;
+//! let provider = GoogleCloudProvider::new(key)?;
+//!
+//! // List all managed zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Managed Zones
+//!
+//! Zones are managed in the project of the service account. The zone ID is
+//! the managed zone name (e.g., `example-com`);
+//! [`get_zone`](crate::Provider::get_zone) also accepts a DNS name, preferring
+//! the public zone when a private zone shares the name. [`CreateZone`] derives
+//! the managed zone name from the domain by replacing dots with dashes.
+//!
+//! # RRset-based API
+//!
+//! Cloud DNS manages records as resource record sets. Creating or deleting a
+//! single record submits a change that atomically deletes the current record
+//! set and adds it back with the value added or removed. All records in a set
+//! share one TTL, so creating a record also updates the TTL of its siblings.
+//!
+//! Record IDs use the format `"name/type/value"`, similar to the Hetzner
+//! provider. Record sets with a routing policy are not listed.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, ALIAS, CAA, CNAME, DNSKEY, DS, HTTPS, IPSECKEY, MX, NAPTR, NS,
+//! PTR, SPF, SRV, SSHFP, SVCB, TLSA and TXT
+//!
+//! # API Reference
+//!
+//! - [Cloud DNS API](https://cloud.google.com/dns/docs/reference/rest/v1)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, GoogleCloudError, ServiceAccountKey};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "ALIAS", "CAA", "CNAME", "DNSKEY", "DS", "HTTPS", "IPSECKEY", "MX", "NAPTR", "NS",
+    "PTR", "SPF", "SRV", "SSHFP", "SVCB", "TLSA", "TXT",
+];
+
+fn is_unauthorized(err: &GoogleCloudError) -> bool {
+    matches!(err, GoogleCloudError::Auth { .. }) || matches!(err.status(), Some(401 | 403))
+}
+
+/// Google Cloud DNS provider.
+#[derive(Clone)]
+pub struct GoogleCloudProvider {
+    api_client: Arc<Client>,
+}
+
+/// A Cloud DNS managed zone.
+pub struct GoogleCloudZone {
+    api_client: Arc<Client>,
+    repr: api::ManagedZone,
+    /// DNS name without the trailing dot.
+    domain: String,
+}
+
+impl GoogleCloudProvider {
+    /// Creates a new Google Cloud DNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Service-account key with the DNS Administrator role
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::gcloud::{GoogleCloudProvider, ServiceAccountKey};
+    ///
+    /// let key = ServiceAccountKey::from_file("service-account.json").unwrap();
+    /// let provider = GoogleCloudProvider::new(key).unwrap();
+    /// ```
+    pub fn new(key: ServiceAccountKey) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Google Cloud DNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Service-account key with the DNS Administrator role
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::gcloud::{GoogleCloudProvider, ServiceAccountKey};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let key = ServiceAccountKey::from_file("service-account.json").unwrap();
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = GoogleCloudProvider::with_config(key, config).unwrap();
+    /// ```
+    pub fn with_config(
+        key: ServiceAccountKey,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Google Cloud DNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers; combine it with
+    /// [`ServiceAccountKey::with_token_uri`] to redirect token requests too.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - Service-account key with the DNS Administrator role
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        key: ServiceAccountKey,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(key, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for GoogleCloudProvider {
+    type Zone = GoogleCloudZone;
+    type CustomRetrieveError = GoogleCloudError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let map_err = |err: GoogleCloudError| match err.status() {
+            _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+            Some(404) => RetrieveZoneError::NotFound,
+            _ => RetrieveZoneError::Custom(err),
+        };
+
+        // Managed zone names cannot contain dots, so anything with a dot is a DNS name.
+        let zone = if zone_id.contains('.') {
+            let dns_name = format!("{}.", zone_id.trim_end_matches('.'));
+            let mut zones = self
+                .api_client
+                .list_managed_zones(Some(&dns_name))
+                .await
+                .map_err(map_err)?;
+            zones.sort_by_key(|zone| zone.visibility.as_deref() == Some("private"));
+            zones
+                .into_iter()
+                .next()
+                .ok_or(RetrieveZoneError::NotFound)?
+        } else {
+            self.api_client
+                .get_managed_zone(zone_id)
+                .await
+                .map_err(map_err)?
+        };
+
+        Ok(GoogleCloudZone::from_api(self.api_client.clone(), zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_managed_zones(None)
+            .await
+            .map_err(|err| match err {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| GoogleCloudZone::from_api(self.api_client.clone(), zone))
+            .collect())
+    }
+}
+
+impl CreateZone for GoogleCloudProvider {
+    type CustomCreateError = GoogleCloudError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let domain = domain.trim_end_matches('.');
+        let request = api::CreateManagedZoneRequest {
+            name: managed_zone_name(domain),
+            dns_name: format!("{}.", domain),
+            description: String::new(),
+            visibility: None,
+        };
+
+        let zone = self
+            .api_client
+            .create_managed_zone(&request)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => CreateZoneError::Unauthorized,
+                Some(400) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(GoogleCloudZone::from_api(self.api_client.clone(), zone))
+    }
+}
+
+impl DeleteZone for GoogleCloudProvider {
+    type CustomDeleteError = GoogleCloudError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_managed_zone(zone_id)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => DeleteZoneError::Unauthorized,
+                Some(404) => DeleteZoneError::NotFound,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+/// Derives a managed zone name from a domain (`example.com` -> `example-com`).
+///
+/// Managed zone names consist of lowercase letters, digits and dashes, start
+/// with a letter and are at most 63 characters long.
+fn managed_zone_name(domain: &str) -> String {
+    let mut name: String = domain
+        .to_ascii_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+        .collect();
+    if !name.starts_with(|c: char| c.is_ascii_lowercase()) {
+        name.insert_str(0, "zone-");
+    }
+    name.truncate(63);
+    name.trim_end_matches('-').to_string()
+}
+
+impl GoogleCloudZone {
+    /// Creates a new GoogleCloudZone from API response data.
+    fn from_api(api_client: Arc<Client>, zone: api::ManagedZone) -> Self {
+        let domain = zone.dns_name.trim_end_matches('.').to_string();
+        Self {
+            api_client,
+            repr: zone,
+            domain,
+        }
+    }
+
+    /// Returns the numeric zone ID assigned by Cloud DNS.
+    pub fn numeric_id(&self) -> &str {
+        &self.repr.id
+    }
+
+    /// Returns whether this is a private zone.
+    pub fn is_private(&self) -> bool {
+        self.repr.visibility.as_deref() == Some("private")
+    }
+
+    /// Returns the zone description, if any.
+    pub fn description(&self) -> Option<&str> {
+        self.repr.description.as_deref()
+    }
+
+    /// Returns the name servers delegated to the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.name_servers
+    }
+
+    /// Converts a host relative to the zone into a fully-qualified owner name.
+    fn owner_name(&self, host: &str) -> String {
+        if host == "@" || host.is_empty() {
+            format!("{}.", self.domain)
+        } else if host.trim_end_matches('.') == self.domain
+            || host
+                .trim_end_matches('.')
+                .ends_with(&format!(".{}", self.domain))
+        {
+            format!("{}.", host.trim_end_matches('.'))
+        } else {
+            format!("{}.{}.", host, self.domain)
+        }
+    }
+
+    /// Converts a fully-qualified owner name into a host relative to the zone.
+    fn host(&self, name: &str) -> String {
+        let name = name.trim_end_matches('.');
+        if name == self.domain {
+            "@".to_string()
+        } else if let Some(sub) = name.strip_suffix(&format!(".{}", self.domain)) {
+            sub.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Converts all records of a simple record set into generic records.
+    fn records_from_rrset(&self, rrset: &api::ResourceRecordSet) -> Vec<Record> {
+        if rrset.routing_policy.is_some() {
+            return Vec::new();
+        }
+
+        let host = self.host(&rrset.name);
+        rrset
+            .rrdatas
+            .iter()
+            .map(|value| Record {
+                id: format!("{}/{}/{}", host, rrset.record_type, value),
+                host: host.clone(),
+                data: record_data_from_value(&rrset.record_type, value),
+                ttl: rrset.ttl.unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+impl Zone for GoogleCloudZone {
+    type CustomRetrieveError = GoogleCloudError;
+
+    // The managed zone name is the identifier used in API paths; the numeric
+    // ID is available through `numeric_id`.
+    #[allow(clippy::misnamed_getters)]
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .api_client
+            .list_resource_record_sets(&self.repr.name)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets
+            .iter()
+            .flat_map(|rrset| self.records_from_rrset(rrset))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let rrset = self
+            .api_client
+            .get_resource_record_set(&self.repr.name, &self.owner_name(parts[0]), parts[1])
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        self.records_from_rrset(&rrset)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for GoogleCloudZone {
+    type CustomCreateError = GoogleCloudError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.owner_name(host);
+        let value = value_from_record_data(data);
+
+        let map_err = |err: GoogleCloudError| match err.status() {
+            _ if is_unauthorized(&err) => CreateRecordError::Unauthorized,
+            Some(400) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        let existing = self
+            .api_client
+            .get_resource_record_set(&self.repr.name, &name, typ)
+            .await
+            .map_err(map_err)?;
+
+        let mut rrdatas = existing
+            .as_ref()
+            .map(|rrset| rrset.rrdatas.clone())
+            .unwrap_or_default();
+        if rrdatas.contains(&value) {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+        rrdatas.push(value.clone());
+
+        // Replace the record set atomically: delete the current one and add the merged one.
+        let change = api::Change {
+            additions: vec![api::ResourceRecordSet::new(&name, typ, ttl, rrdatas)],
+            deletions: existing.into_iter().collect(),
+            ..Default::default()
+        };
+        self.api_client
+            .create_change(&self.repr.name, &change)
+            .await
+            .map_err(map_err)?;
+
+        let host = self.host(&name);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, value),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for GoogleCloudZone {
+    type CustomDeleteError = GoogleCloudError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, value) = (parts[0], parts[1], parts[2]);
+        let name = self.owner_name(host);
+
+        let map_err = |err: GoogleCloudError| match err.status() {
+            _ if is_unauthorized(&err) => DeleteRecordError::Unauthorized,
+            Some(404) => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let rrset = self
+            .api_client
+            .get_resource_record_set(&self.repr.name, &name, typ)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let remaining: Vec<String> = rrset
+            .rrdatas
+            .iter()
+            .filter(|v| *v != value)
+            .cloned()
+            .collect();
+        if remaining.len() == rrset.rrdatas.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        let additions = if remaining.is_empty() {
+            Vec::new()
+        } else {
+            vec![api::ResourceRecordSet {
+                rrdatas: remaining,
+                ..rrset.clone()
+            }]
+        };
+        let change = api::Change {
+            additions,
+            deletions: vec![rrset],
+            ..Default::default()
+        };
+
+        self.api_client
+            .create_change(&self.repr.name, &change)
+            .await
+            .map_err(map_err)?;
+        Ok(())
+    }
+}
+
+/// Converts a Cloud DNS record value into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT character strings are unquoted
+/// and concatenated.
+fn record_data_from_value(typ: &str, value: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, value.trim_end_matches('.')),
+        "TXT" | "SPF" => RecordData::from_raw(typ, &unquote_txt(value)),
+        _ => RecordData::from_raw(typ, value),
+    }
+}
+
+/// Converts [`RecordData`] into a Cloud DNS record value.
+///
+/// Cloud DNS requires fully-qualified host names with a trailing dot; TXT
+/// values are quoted.
+fn value_from_record_data(data: &RecordData) -> String {
+    match data {
+        RecordData::CNAME(_)
+        | RecordData::NS(_)
+        | RecordData::MX { .. }
+        | RecordData::SRV { .. } => {
+            format!("{}.", data.get_value().trim_end_matches('.'))
+        }
+        RecordData::TXT(txt) => quote_txt(txt),
+        RecordData::Other { typ, value } if typ == "SPF" => quote_txt(value),
+        _ => data.get_value(),
+    }
+}
@@ -0,0 +1,469 @@
+//! Low-level GoDaddy Domains API client.
+//!
+//! This module provides direct access to the GoDaddy Domains API v1 using
+//! `sso-key` authentication.
+//!
+//! # Environments
+//!
+//! - Production: `https://api.godaddy.com`
+//! - OTE (Operational Test Environment): `https://api.ote-godaddy.com`
+//!
+//! API keys are issued per environment.
+//!
+//! # API Reference
+//!
+//! - [Domains API](https://developer.godaddy.com/doc/endpoint/domains)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::godaddy::api::{Client, ClientConfig};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new(ClientConfig::ote("your-key", "your-secret"))?;
+//!
+//! for record in client.list_records("example.com").await? {
+//!     println!("{} {} {}", record.name, record.record_type, record.value.data);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::types::Environment;
+use crate::HttpClientConfig;
+
+/// GoDaddy API endpoints.
+const PRODUCTION_API_URL: &str = "https://api.godaddy.com";
+const OTE_API_URL: &str = "https://api.ote-godaddy.com";
+
+/// Page size requested from the domain list.
+const DOMAINS_PAGE_LIMIT: usize = 1000;
+
+/// Errors that may occur when interacting with the GoDaddy API.
+#[derive(Debug, Error)]
+pub enum GoDaddyError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {code}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error code (e.g., `UNABLE_TO_AUTHENTICATE`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl GoDaddyError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            GoDaddyError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            GoDaddyError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// GoDaddy error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    code: String,
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// Configuration for the GoDaddy API client.
+#[derive(Clone)]
+pub struct ClientConfig {
+    /// API key.
+    pub api_key: String,
+    /// API secret.
+    pub api_secret: String,
+    /// API environment (OTE or production).
+    pub environment: Environment,
+}
+
+impl ClientConfig {
+    /// Creates a new client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key from the GoDaddy developer portal
+    /// * `api_secret` - API secret
+    /// * `environment` - [`Environment::Sandbox`] for OTE, or production
+    pub fn new(
+        api_key: impl Into<String>,
+        api_secret: impl Into<String>,
+        environment: Environment,
+    ) -> Self {
+        Self {
+            api_key: api_key.into(),
+            api_secret: api_secret.into(),
+            environment,
+        }
+    }
+
+    /// Creates a configuration for the OTE test environment.
+    pub fn ote(api_key: impl Into<String>, api_secret: impl Into<String>) -> Self {
+        Self::new(api_key, api_secret, Environment::Sandbox)
+    }
+
+    /// Creates a configuration for the production environment.
+    pub fn production(api_key: impl Into<String>, api_secret: impl Into<String>) -> Self {
+        Self::new(api_key, api_secret, Environment::Production)
+    }
+
+    /// Returns the API base URL for the configured environment.
+    pub fn api_url(&self) -> &'static str {
+        match self.environment {
+            Environment::Production => PRODUCTION_API_URL,
+            Environment::Sandbox => OTE_API_URL,
+        }
+    }
+
+    /// Returns the `Authorization` header value.
+    fn authorization(&self) -> String {
+        format!("sso-key {}:{}", self.api_key, self.api_secret)
+    }
+}
+
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("api_key", &self.api_key)
+            .field("api_secret", &"<redacted>")
+            .field("environment", &self.environment)
+            .finish()
+    }
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A domain of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Domain {
+    /// Domain ID.
+    #[serde(default)]
+    pub domain_id: Option<u64>,
+    /// Domain name.
+    pub domain: String,
+    /// Domain status (e.g., `ACTIVE`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Expiration date.
+    #[serde(default)]
+    pub expires: Option<String>,
+    /// Name servers of the domain.
+    #[serde(default)]
+    pub name_servers: Option<Vec<String>>,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DnsRecord {
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record name relative to the domain (`@` for the apex). For SRV
+    /// records, the name without the service and protocol labels.
+    pub name: String,
+    /// Record value.
+    #[serde(flatten)]
+    pub value: RecordValue,
+}
+
+/// The value of a DNS record, as sent when replacing records of a type and name.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordValue {
+    /// Record data; the target host name for MX and SRV records.
+    pub data: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+    /// Weight for SRV records.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    /// Port for SRV records.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Service label for SRV records (e.g., `_sip`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Protocol label for SRV records (e.g., `_tcp`).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// GoDaddy API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    config: ClientConfig,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new GoDaddy API client.
+    pub fn new(config: ClientConfig) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_config(config, HttpClientConfig::default())
+    }
+
+    /// Creates a new GoDaddy API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - GoDaddy client configuration
+    /// * `http_config` - HTTP client configuration for network binding
+    pub fn with_config(
+        config: ClientConfig,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let base_url = config.api_url();
+        Self::with_base_url(config, base_url, http_config)
+    }
+
+    /// Creates a new GoDaddy API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - GoDaddy client configuration
+    /// * `base_url` - Custom base URL for the API
+    /// * `http_config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        config: ClientConfig,
+        base_url: &str,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder().timeout(
+            http_config
+                .timeout
+                .unwrap_or(std::time::Duration::from_secs(30)),
+        );
+
+        if let Some(addr) = http_config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = http_config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            config,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Returns the configured environment.
+    pub fn environment(&self) -> Environment {
+        self.config.environment
+    }
+
+    /// Sends an authenticated request and returns the response body.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<String, GoDaddyError> {
+        let mut request = self
+            .http_client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("Authorization", self.config.authorization())
+            .header("Accept", "application/json");
+        if let Some(body) = body {
+            request = request
+                .header("Content-Type", "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (code, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.code, e.message),
+                Err(_) => (String::new(), text),
+            };
+            return Err(GoDaddyError::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a GET request and deserializes the JSON response.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, GoDaddyError> {
+        let text = self.request(reqwest::Method::GET, path, None).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists the active domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, GoDaddyError> {
+        let mut domains: Vec<Domain> = Vec::new();
+
+        loop {
+            let mut path = format!("/v1/domains?statuses=ACTIVE&limit={}", DOMAINS_PAGE_LIMIT);
+            if let Some(last) = domains.last() {
+                path.push_str(&format!("&marker={}", encode_component(&last.domain)));
+            }
+
+            let page: Vec<Domain> = self.get(&path).await?;
+            let count = page.len();
+            domains.extend(page);
+
+            if count < DOMAINS_PAGE_LIMIT {
+                break;
+            }
+        }
+
+        Ok(domains)
+    }
+
+    /// Gets a domain.
+    pub async fn get_domain(&self, domain: &str) -> Result<Domain, GoDaddyError> {
+        self.get(&format!("/v1/domains/{}", encode_component(domain)))
+            .await
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a domain.
+    pub async fn list_records(&self, domain: &str) -> Result<Vec<DnsRecord>, GoDaddyError> {
+        self.get(&format!("/v1/domains/{}/records", encode_component(domain)))
+            .await
+    }
+
+    /// Lists the records of a domain with the given type and name.
+    pub async fn get_records(
+        &self,
+        domain: &str,
+        record_type: &str,
+        name: &str,
+    ) -> Result<Vec<DnsRecord>, GoDaddyError> {
+        self.get(&records_path(domain, record_type, name)).await
+    }
+
+    /// Replaces all records of a domain with the given type and name.
+    pub async fn replace_records(
+        &self,
+        domain: &str,
+        record_type: &str,
+        name: &str,
+        values: &[RecordValue],
+    ) -> Result<(), GoDaddyError> {
+        let body = serde_json::to_string(values)?;
+        self.request(
+            reqwest::Method::PUT,
+            &records_path(domain, record_type, name),
+            Some(body),
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Deletes all records of a domain with the given type and name.
+    pub async fn delete_records(
+        &self,
+        domain: &str,
+        record_type: &str,
+        name: &str,
+    ) -> Result<(), GoDaddyError> {
+        self.request(
+            reqwest::Method::DELETE,
+            &records_path(domain, record_type, name),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Builds the `/v1/domains/{domain}/records/{type}/{name}` path.
+fn records_path(domain: &str, record_type: &str, name: &str) -> String {
+    format!(
+        "/v1/domains/{}/records/{}/{}",
+        encode_component(domain),
+        encode_component(record_type),
+        encode_component(name)
+    )
+}
+
+/// Percent-encodes a path segment or query value, keeping `@` for the apex.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'@' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
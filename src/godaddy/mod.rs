@@ -0,0 +1,509 @@
+//! GoDaddy DNS provider implementation.
+//!
+//! This provider uses the GoDaddy Domains API v1 for DNS record management.
+//!
+//! # Authentication
+//!
+//! Requires an API key and secret:
+//! - Create them at <https://developer.godaddy.com/keys>
+//! - Keys are issued for either the OTE test environment or production
+//!
+//! Requests use the `Authorization: sso-key KEY:SECRET` header.
+//!
+//! # Environments
+//!
+//! GoDaddy provides an OTE (Operational Test Environment) and production API,
+//! selected with [`Environment`](crate::types::Environment):
+//! - OTE ([`Environment::Sandbox`](crate::types::Environment::Sandbox)):
+//!   `https://api.ote-godaddy.com`
+//! - Production: `https://api.godaddy.com`
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::godaddy::{ClientConfig, GoDaddyProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let config = ClientConfig::production("your-key", "your-secret");
+//! let provider = GoDaddyProvider::new(config)?;
+//!
+//! // List all active domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Record Management
+//!
+//! GoDaddy records have no IDs, and records are written by replacing all
+//! records of a type and name (`PUT /v1/domains/{domain}/records/{type}/{name}`).
+//! Creating a record fetches the records of its type and name and writes them
+//! back with the new value added; deleting a record writes them back without
+//! the value, or deletes them once none are left.
+//!
+//! Record IDs use the format `"name/type/value"`, similar to the Hetzner
+//! provider, where the value is [`RecordData::get_value`]. SRV names include
+//! the service and protocol labels (e.g., `_sip._tcp`).
+//!
+//! # Zone Management
+//!
+//! Domains are registered or transferred as GoDaddy products, so this provider
+//! does not implement [`CreateZone`](crate::CreateZone) or
+//! [`DeleteZone`](crate::DeleteZone). The zone ID is the domain name.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, as [`RecordData::Other`] with the value in zone file format
+//!
+//! # API Reference
+//!
+//! - [Domains API](https://developer.godaddy.com/doc/endpoint/domains)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, ClientConfig, GoDaddyError};
+
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &["A", "AAAA", "CAA", "CNAME", "MX", "NS", "SRV", "TXT"];
+
+/// GoDaddy DNS provider.
+#[derive(Clone)]
+pub struct GoDaddyProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain whose DNS is hosted by GoDaddy.
+pub struct GoDaddyZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl GoDaddyProvider {
+    /// Creates a new GoDaddy provider with the given configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::godaddy::{ClientConfig, GoDaddyProvider};
+    /// use manydns::types::Environment;
+    ///
+    /// // For OTE testing
+    /// let config = ClientConfig::ote("key", "secret");
+    /// let provider = GoDaddyProvider::new(config).unwrap();
+    ///
+    /// // For production
+    /// let config = ClientConfig::new("key", "secret", Environment::Production);
+    /// let provider = GoDaddyProvider::new(config).unwrap();
+    /// ```
+    pub fn new(config: ClientConfig) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new GoDaddy provider with custom HTTP client configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::godaddy::{ClientConfig, GoDaddyProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = ClientConfig::production("key", "secret");
+    /// let http_config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = GoDaddyProvider::with_config(config, http_config).unwrap();
+    /// ```
+    pub fn with_config(
+        config: ClientConfig,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(config, http_config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new GoDaddy provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - GoDaddy client configuration
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        config: ClientConfig,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(config, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for GoDaddyProvider {
+    type Zone = GoDaddyZone;
+    type CustomRetrieveError = GoDaddyError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .get_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(GoDaddyZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| GoDaddyZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl GoDaddyZone {
+    /// Returns the domain status (e.g., `ACTIVE`).
+    pub fn status(&self) -> Option<&str> {
+        self.repr.status.as_deref()
+    }
+
+    /// Returns the name servers of the domain, if known.
+    pub fn name_servers(&self) -> &[String] {
+        self.repr.name_servers.as_deref().unwrap_or_default()
+    }
+
+    /// Converts a host into a name relative to the domain ("@" for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.domain;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Fetches the records sharing a record's type and API name.
+    async fn fetch_set(&self, typ: &str, name: &str) -> Result<Vec<api::DnsRecord>, GoDaddyError> {
+        match self
+            .api_client
+            .get_records(&self.repr.domain, typ, name)
+            .await
+        {
+            Err(err) if err.status() == Some(404) => Ok(Vec::new()),
+            result => result,
+        }
+    }
+}
+
+/// Splits a host into the API name and, for SRV records, the service and
+/// protocol labels.
+fn api_name(typ: &str, name: &str) -> (String, Option<(String, String)>) {
+    if typ == "SRV" {
+        let mut labels = name.splitn(3, '.');
+        if let (Some(service), Some(protocol)) = (labels.next(), labels.next()) {
+            if service.starts_with('_') && protocol.starts_with('_') {
+                let rest = labels.next().unwrap_or("@");
+                return (
+                    rest.to_string(),
+                    Some((service.to_string(), protocol.to_string())),
+                );
+            }
+        }
+    }
+    (name.to_string(), None)
+}
+
+/// Returns the host of an API record, including SRV service and protocol labels.
+fn host(record: &api::DnsRecord) -> String {
+    match (&record.value.service, &record.value.protocol) {
+        (Some(service), Some(protocol))
+            if record.record_type == "SRV" && !record.name.starts_with('_') =>
+        {
+            let prefix = format!(
+                "_{}._{}",
+                service.trim_start_matches('_'),
+                protocol.trim_start_matches('_')
+            );
+            if record.name.is_empty() || record.name == "@" {
+                prefix
+            } else {
+                format!("{}.{}", prefix, record.name)
+            }
+        }
+        _ if record.name.is_empty() => "@".to_string(),
+        _ => record.name.clone(),
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: &api::DnsRecord) -> Record {
+    let value = &record.value;
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority: value.priority.unwrap_or_default(),
+            mail_server: value.data.trim_end_matches('.').to_string(),
+        },
+        "SRV" => RecordData::SRV {
+            priority: value.priority.unwrap_or_default(),
+            weight: value.weight.unwrap_or_default(),
+            port: value.port.unwrap_or_default(),
+            target: value.data.trim_end_matches('.').to_string(),
+        },
+        "TXT" => RecordData::TXT(value.data.clone()),
+        "CNAME" | "NS" => {
+            RecordData::from_raw(&record.record_type, value.data.trim_end_matches('.'))
+        }
+        typ => RecordData::from_raw(typ, &value.data),
+    };
+
+    let host = host(record);
+    Record {
+        id: format!("{}/{}/{}", host, record.record_type, data.get_value()),
+        host,
+        data,
+        ttl: value.ttl,
+    }
+}
+
+/// Converts generic record data into a GoDaddy record value.
+fn value_from_record_data(
+    data: &RecordData,
+    ttl: u64,
+    srv_labels: Option<(String, String)>,
+) -> api::RecordValue {
+    let mut value = api::RecordValue {
+        ttl,
+        ..Default::default()
+    };
+    match data {
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => {
+            value.data = mail_server.trim_end_matches('.').to_string();
+            value.priority = Some(*priority);
+        }
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => {
+            value.data = target.trim_end_matches('.').to_string();
+            value.priority = Some(*priority);
+            value.weight = Some(*weight);
+            value.port = Some(*port);
+            if let Some((service, protocol)) = srv_labels {
+                value.service = Some(service);
+                value.protocol = Some(protocol);
+            }
+        }
+        RecordData::CNAME(target) | RecordData::NS(target) => {
+            value.data = target.trim_end_matches('.').to_string();
+        }
+        RecordData::TXT(text) => value.data = text.clone(),
+        _ => value.data = data.get_value(),
+    }
+    value
+}
+
+impl Zone for GoDaddyZone {
+    type CustomRetrieveError = GoDaddyError;
+
+    fn id(&self) -> &str {
+        &self.repr.domain
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.domain)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+        let (name, _) = api_name(parts[1], parts[0]);
+
+        let records = self
+            .fetch_set(parts[1], &name)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        records
+            .iter()
+            .map(record_from_api)
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for GoDaddyZone {
+    type CustomCreateError = GoDaddyError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let (name, srv_labels) = api_name(typ, &self.relative_name(host));
+        if typ == "SRV" && srv_labels.is_none() {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+
+        let map_err = |err: GoDaddyError| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(400 | 409 | 422) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        let new_record = api::DnsRecord {
+            record_type: typ.to_string(),
+            name: name.clone(),
+            value: value_from_record_data(data, ttl, srv_labels),
+        };
+        let record = record_from_api(&new_record);
+
+        let existing = self.fetch_set(typ, &name).await.map_err(map_err)?;
+        if existing
+            .iter()
+            .any(|existing| record_from_api(existing).id == record.id)
+        {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+
+        let mut values: Vec<api::RecordValue> = existing.into_iter().map(|r| r.value).collect();
+        values.push(new_record.value);
+
+        self.api_client
+            .replace_records(&self.repr.domain, typ, &name, &values)
+            .await
+            .map_err(map_err)?;
+
+        Ok(record)
+    }
+}
+
+impl DeleteRecord for GoDaddyZone {
+    type CustomDeleteError = GoDaddyError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let typ = parts[1];
+        let (name, _) = api_name(typ, parts[0]);
+
+        let map_err = |err: GoDaddyError| match err.status() {
+            Some(404) => DeleteRecordError::NotFound,
+            Some(401 | 403) => DeleteRecordError::Unauthorized,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let existing = self.fetch_set(typ, &name).await.map_err(map_err)?;
+        let count = existing.len();
+        let remaining: Vec<api::RecordValue> = existing
+            .into_iter()
+            .filter(|record| record_from_api(record).id != record_id)
+            .map(|record| record.value)
+            .collect();
+        if remaining.len() == count {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        if remaining.is_empty() {
+            self.api_client
+                .delete_records(&self.repr.domain, typ, &name)
+                .await
+                .map_err(map_err)
+        } else {
+            self.api_client
+                .replace_records(&self.repr.domain, typ, &name, &remaining)
+                .await
+                .map_err(map_err)
+        }
+    }
+}
@@ -0,0 +1,255 @@
+//! Low-level Hurricane Electric dynamic DNS client.
+//!
+//! This module provides direct access to the dyndns-style update endpoint of
+//! Hurricane Electric's free DNS service (`/nic/update`). Each record that has
+//! dynamic DNS enabled gets its own key, which is sent as the password.
+//! For most use cases, prefer using [`HeDynDnsProvider`](super::HeDynDnsProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [Dynamic DNS](https://dns.he.net/docs.html)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::hedyndns::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new()?;
+//!
+//! let status = client
+//!     .update_address("home.example.com", "record-key", "192.0.2.1".parse()?)
+//!     .await?;
+//! println!("{:?}", status);
+//! # Ok(())
+//! # }
+//! ```
+
+use std::net::IpAddr;
+
+use thiserror::Error;
+
+use crate::types::RecordType;
+use crate::HttpClientConfig;
+
+/// The Hurricane Electric dynamic DNS base URL.
+const HE_DYNDNS_URL: &str = "https://dyn.dns.he.net";
+
+/// Errors that may occur when updating Hurricane Electric records.
+#[derive(Debug, Error)]
+pub enum HeDynDnsError {
+    /// The key does not match the record, or the record does not have
+    /// dynamic DNS enabled (`badauth`).
+    #[error("Invalid hostname or key")]
+    BadAuth,
+
+    /// The hostname is not a fully qualified domain name (`notfqdn`).
+    #[error("Hostname is not a fully qualified domain name")]
+    NotFqdn,
+
+    /// The hostname does not exist (`nohost`).
+    #[error("Hostname does not exist")]
+    NoHost,
+
+    /// The record was updated too often and is blocked (`abuse`).
+    #[error("Updates blocked for abuse")]
+    Abuse,
+
+    /// The service reported a server-side problem (`911`).
+    #[error("Server error, retry later")]
+    ServerError,
+
+    /// The service returned a response this client does not understand.
+    #[error("Unexpected response: {0}")]
+    Unexpected(String),
+
+    /// No key is configured for the record.
+    #[error("No dynamic DNS key configured for {record_type} record {hostname}")]
+    MissingKey {
+        /// Fully qualified hostname of the record.
+        hostname: String,
+        /// Type of the record.
+        record_type: RecordType,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+}
+
+/// Successful outcome of an update.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum UpdateStatus {
+    /// The record was updated to the contained value (`good`).
+    Good(String),
+    /// The record already had the contained value (`nochg`).
+    NoChange(String),
+}
+
+impl UpdateStatus {
+    /// Returns the value reported by the service, if any.
+    pub fn value(&self) -> &str {
+        match self {
+            UpdateStatus::Good(value) | UpdateStatus::NoChange(value) => value,
+        }
+    }
+}
+
+/// Parses the plain-text response of the update endpoint.
+pub fn parse_response(text: &str) -> Result<UpdateStatus, HeDynDnsError> {
+    let text = text.trim();
+    let (code, value) = text.split_once(' ').unwrap_or((text, ""));
+    let value = value.trim().to_string();
+
+    match code {
+        "good" => Ok(UpdateStatus::Good(value)),
+        "nochg" => Ok(UpdateStatus::NoChange(value)),
+        "badauth" => Err(HeDynDnsError::BadAuth),
+        "notfqdn" => Err(HeDynDnsError::NotFqdn),
+        "nohost" => Err(HeDynDnsError::NoHost),
+        "abuse" => Err(HeDynDnsError::Abuse),
+        "911" => Err(HeDynDnsError::ServerError),
+        _ => Err(HeDynDnsError::Unexpected(text.to_string())),
+    }
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Hurricane Electric dynamic DNS client.
+///
+/// The client holds no credentials; the key of each record is passed to the
+/// update methods.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new dynamic DNS client.
+    pub fn new() -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(HE_DYNDNS_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new dynamic DNS client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(HE_DYNDNS_URL, config)
+    }
+
+    /// Creates a new dynamic DNS client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Updates an A or AAAA record to the given address.
+    ///
+    /// The record type follows from the address family.
+    pub async fn update_address(
+        &self,
+        hostname: &str,
+        key: &str,
+        address: IpAddr,
+    ) -> Result<UpdateStatus, HeDynDnsError> {
+        self.update(hostname, key, ("myip", &address.to_string()))
+            .await
+    }
+
+    /// Updates a TXT record to the given text.
+    pub async fn update_txt(
+        &self,
+        hostname: &str,
+        key: &str,
+        txt: &str,
+    ) -> Result<UpdateStatus, HeDynDnsError> {
+        self.update(hostname, key, ("txt", txt)).await
+    }
+
+    /// Sends an update request.
+    async fn update(
+        &self,
+        hostname: &str,
+        key: &str,
+        (param, value): (&str, &str),
+    ) -> Result<UpdateStatus, HeDynDnsError> {
+        let body = format!(
+            "hostname={}&password={}&{}={}",
+            encode_component(hostname),
+            encode_component(key),
+            param,
+            encode_component(value)
+        );
+
+        let response = self
+            .http_client
+            .post(format!("{}/nic/update", self.base_url))
+            .header(
+                reqwest::header::CONTENT_TYPE,
+                "application/x-www-form-urlencoded",
+            )
+            .body(body)
+            .send()
+            .await?;
+        let text = response.text().await?;
+
+        parse_response(&text)
+    }
+}
+
+/// Percent-encodes a form value.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,313 @@
+//! Hurricane Electric dynamic DNS provider implementation.
+//!
+//! Hurricane Electric's free DNS service (<https://dns.he.net>) has no API
+//! for zone or record management. It only lets individual A, AAAA and TXT
+//! records be marked as dynamic, each with its own update key. This provider
+//! wraps that update endpoint: it can change the value of records it has a
+//! key for, and nothing else.
+//!
+//! # Authentication
+//!
+//! Enable dynamic DNS on a record in the dns.he.net web interface and
+//! generate its key, then register the key with
+//! [`HeDynDnsProvider::with_key`]. A and AAAA records of the same name have
+//! separate keys.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::hedyndns::HeDynDnsProvider;
+//! use manydns::types::RecordType;
+//! use manydns::{CreateRecord, Provider, RecordData};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = HeDynDnsProvider::new()?
+//!     .with_key("home.example.com", RecordType::A, "a-record-key")
+//!     .with_key("home.example.com", RecordType::AAAA, "aaaa-record-key");
+//!
+//! let zone = provider.get_zone("home.example.com").await?;
+//! zone.create_record("@", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones and Records
+//!
+//! Each configured hostname is exposed as a zone, identified by the
+//! hostname, whose only records live at the apex (`@`). Creating a record
+//! overwrites the value of the existing dynamic record; the TTL is the one
+//! set in the web interface and the `ttl` argument is ignored.
+//!
+//! Values cannot be read back or deleted: [`Zone::list_records`] always
+//! returns an empty list, [`Zone::get_record`] always fails with
+//! [`RetrieveRecordError::NotFound`], and [`DeleteRecord`](crate::DeleteRecord)
+//! is not implemented. Updating a name or type without a configured key
+//! fails with [`HeDynDnsError::MissingKey`].
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA and TXT; all other types fail with [`CreateRecordError::UnsupportedType`]
+//!
+//! # API Reference
+//!
+//! - [Dynamic DNS](https://dns.he.net/docs.html)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::net::IpAddr;
+use std::sync::Arc;
+
+pub use api::{Client, HeDynDnsError, UpdateStatus};
+
+use crate::types::RecordType;
+use crate::{
+    CreateRecord, CreateRecordError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Hurricane Electric dynamic DNS provider.
+#[derive(Clone)]
+pub struct HeDynDnsProvider {
+    api_client: Arc<Client>,
+    keys: Vec<(String, RecordType, String)>,
+}
+
+/// A hostname with dynamic records.
+pub struct HeDynDnsZone {
+    api_client: Arc<Client>,
+    hostname: String,
+    keys: Vec<(RecordType, String)>,
+}
+
+impl HeDynDnsProvider {
+    /// Creates a new Hurricane Electric dynamic DNS provider without keys.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::hedyndns::HeDynDnsProvider;
+    /// use manydns::types::RecordType;
+    ///
+    /// let provider = HeDynDnsProvider::new()
+    ///     .unwrap()
+    ///     .with_key("home.example.com", RecordType::A, "a-record-key");
+    /// ```
+    pub fn new() -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new()?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            keys: Vec::new(),
+        })
+    }
+
+    /// Creates a new Hurricane Electric dynamic DNS provider with custom HTTP
+    /// client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::hedyndns::HeDynDnsProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = HeDynDnsProvider::with_config(config).unwrap();
+    /// ```
+    pub fn with_config(config: HttpClientConfig) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            keys: Vec::new(),
+        })
+    }
+
+    /// Creates a new Hurricane Electric dynamic DNS provider with a custom
+    /// base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - Custom base URL for the update endpoint
+    pub fn with_base_url(base_url: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            keys: Vec::new(),
+        })
+    }
+
+    /// Adds the dynamic DNS key of a record.
+    ///
+    /// A key replaces any previously added key of the same hostname and type.
+    /// Keys for types other than A, AAAA and TXT are never used.
+    ///
+    /// # Arguments
+    ///
+    /// * `hostname` - Fully qualified name of the record
+    /// * `record_type` - Type of the record
+    /// * `key` - Dynamic DNS key of the record
+    pub fn with_key(mut self, hostname: &str, record_type: RecordType, key: &str) -> Self {
+        let hostname = hostname.trim_end_matches('.').to_ascii_lowercase();
+        self.keys
+            .retain(|(name, typ, _)| !(*name == hostname && *typ == record_type));
+        self.keys.push((hostname, record_type, key.to_string()));
+        self
+    }
+
+    /// Builds the zone of a configured hostname.
+    fn zone(&self, hostname: &str) -> Option<HeDynDnsZone> {
+        let keys: Vec<_> = self
+            .keys
+            .iter()
+            .filter(|(name, _, _)| name == hostname)
+            .map(|(_, typ, key)| (*typ, key.clone()))
+            .collect();
+
+        (!keys.is_empty()).then(|| HeDynDnsZone {
+            api_client: self.api_client.clone(),
+            hostname: hostname.to_string(),
+            keys,
+        })
+    }
+}
+
+impl Provider for HeDynDnsProvider {
+    type Zone = HeDynDnsZone;
+    type CustomRetrieveError = HeDynDnsError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        self.zone(&zone_id.trim_end_matches('.').to_ascii_lowercase())
+            .ok_or(RetrieveZoneError::NotFound)
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let mut hostnames: Vec<&str> = Vec::new();
+        for (hostname, _, _) in &self.keys {
+            if !hostnames.contains(&hostname.as_str()) {
+                hostnames.push(hostname);
+            }
+        }
+
+        Ok(hostnames
+            .into_iter()
+            .filter_map(|hostname| self.zone(hostname))
+            .collect())
+    }
+}
+
+impl HeDynDnsZone {
+    /// Returns the record types with a configured key.
+    pub fn record_types(&self) -> Vec<RecordType> {
+        self.keys.iter().map(|(typ, _)| *typ).collect()
+    }
+
+    /// Returns the key of a record type.
+    fn key(&self, hostname: &str, record_type: RecordType) -> Result<&str, HeDynDnsError> {
+        self.keys
+            .iter()
+            .find(|(typ, _)| hostname == self.hostname && *typ == record_type)
+            .map(|(_, key)| key.as_str())
+            .ok_or_else(|| HeDynDnsError::MissingKey {
+                hostname: hostname.to_string(),
+                record_type,
+            })
+    }
+
+    /// Converts a host into the fully qualified name of the record.
+    fn fqdn(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.').to_ascii_lowercase();
+        if host.is_empty() || host == "@" || host == self.hostname {
+            self.hostname.clone()
+        } else if host.ends_with(&format!(".{}", self.hostname)) {
+            host
+        } else {
+            format!("{}.{}", host, self.hostname)
+        }
+    }
+}
+
+impl Zone for HeDynDnsZone {
+    type CustomRetrieveError = HeDynDnsError;
+
+    fn id(&self) -> &str {
+        &self.hostname
+    }
+
+    fn domain(&self) -> &str {
+        &self.hostname
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // The update endpoint cannot read values back.
+        Ok(Vec::new())
+    }
+
+    async fn get_record(
+        &self,
+        _record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        Err(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for HeDynDnsZone {
+    type CustomCreateError = HeDynDnsError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let hostname = self.fqdn(host);
+        let map_err = |err: HeDynDnsError| match err {
+            HeDynDnsError::BadAuth => CreateRecordError::Unauthorized,
+            err => CreateRecordError::Custom(err),
+        };
+
+        match data {
+            RecordData::A(address) => {
+                let key = self.key(&hostname, RecordType::A).map_err(map_err)?;
+                self.api_client
+                    .update_address(&hostname, key, IpAddr::V4(*address))
+                    .await
+                    .map_err(map_err)?;
+            }
+            RecordData::AAAA(address) => {
+                let key = self.key(&hostname, RecordType::AAAA).map_err(map_err)?;
+                self.api_client
+                    .update_address(&hostname, key, IpAddr::V6(*address))
+                    .await
+                    .map_err(map_err)?;
+            }
+            RecordData::TXT(txt) => {
+                let key = self.key(&hostname, RecordType::TXT).map_err(map_err)?;
+                self.api_client
+                    .update_txt(&hostname, key, txt)
+                    .await
+                    .map_err(map_err)?;
+            }
+            _ => return Err(CreateRecordError::UnsupportedType),
+        }
+
+        Ok(Record {
+            id: format!("@/{}/{}", data.get_type(), data.get_value()),
+            host: "@".to_string(),
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
@@ -0,0 +1,716 @@
+//! Low-level Huawei Cloud DNS API client.
+//!
+//! This module provides direct access to the Huawei Cloud DNS v2 API using
+//! AK/SK (`SDK-HMAC-SHA256`) request signing.
+//!
+//! # Request Signing
+//!
+//! Requests are signed like AWS SigV4, without a credential scope or key
+//! derivation:
+//!
+//! ```text
+//! CanonicalRequest = METHOD \n URI/ \n QUERY \n HEADERS \n SIGNED_HEADERS \n hex(sha256(body))
+//! StringToSign     = SDK-HMAC-SHA256 \n X-Sdk-Date \n hex(sha256(CanonicalRequest))
+//! Signature        = hex(hmac_sha256(SK, StringToSign))
+//! ```
+//!
+//! # API Reference
+//!
+//! - [DNS API](https://support.huaweicloud.com/intl/en-us/api-dns/dns_api_60001.html)
+//! - [AK/SK signing](https://support.huaweicloud.com/intl/en-us/devg-apisign/api-sign-algorithm.html)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::huaweicloud::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("your-access-key", "your-secret-key");
+//! let client = Client::new(credentials, "ap-southeast-1")?;
+//!
+//! for zone in client.list_zones(None).await? {
+//!     println!("{} ({})", zone.name, zone.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::signing::{canonical_query, hmac_sha256_hex, sha256_hex, uri_encode};
+use crate::HttpClientConfig;
+
+/// Signing algorithm name.
+const ALGORITHM: &str = "SDK-HMAC-SHA256";
+
+/// Page size requested from list endpoints (the API maximum).
+const PAGE_LIMIT: u32 = 500;
+
+/// Returns the DNS endpoint of a region (e.g., `ap-southeast-1`).
+pub fn endpoint_for_region(region: &str) -> String {
+    format!("https://dns.{}.myhuaweicloud.com", region)
+}
+
+/// Errors that may occur when interacting with the Huawei Cloud DNS API.
+#[derive(Debug, Error)]
+pub enum HuaweiCloudError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {code}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error code (e.g., `DNS.0101`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl HuaweiCloudError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            HuaweiCloudError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            HuaweiCloudError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+}
+
+/// Huawei Cloud error response body.
+///
+/// The DNS service uses `code`/`message`, while the API gateway (e.g., for
+/// signature failures) uses `error_code`/`error_msg`.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(alias = "error_code", default)]
+    code: String,
+    #[serde(alias = "error_msg", default)]
+    message: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// AK/SK credentials.
+#[derive(Clone)]
+pub struct Credentials {
+    /// Access key ID (AK).
+    pub access_key: String,
+    /// Secret access key (SK).
+    pub secret_key: String,
+    /// Project ID sent as `X-Project-Id`, required by IAM users with
+    /// project-level permissions.
+    pub project_id: Option<String>,
+}
+
+impl Credentials {
+    /// Creates credentials from an access key pair.
+    pub fn new(access_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+            project_id: None,
+        }
+    }
+
+    /// Sets the project ID sent with every request.
+    pub fn with_project_id(mut self, project_id: impl Into<String>) -> Self {
+        self.project_id = Some(project_id.into());
+        self
+    }
+
+    /// Reads credentials from `HUAWEICLOUD_SDK_AK`, `HUAWEICLOUD_SDK_SK` and
+    /// the optional `HUAWEICLOUD_SDK_PROJECT_ID` environment variables.
+    pub fn from_env() -> Option<Self> {
+        let access_key = std::env::var("HUAWEICLOUD_SDK_AK").ok()?;
+        let secret_key = std::env::var("HUAWEICLOUD_SDK_SK").ok()?;
+        let project_id = std::env::var("HUAWEICLOUD_SDK_PROJECT_ID").ok();
+
+        Some(Self {
+            access_key,
+            secret_key,
+            project_id,
+        })
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<redacted>")
+            .field("project_id", &self.project_id)
+            .finish()
+    }
+}
+
+/// A request to be signed with `SDK-HMAC-SHA256`.
+#[derive(Debug, Clone)]
+pub struct SigningRequest<'a> {
+    /// HTTP method (e.g., `GET`).
+    pub method: &'a str,
+    /// Host header value (including a non-default port).
+    pub host: &'a str,
+    /// URI path, already percent-encoded.
+    pub path: &'a str,
+    /// Canonical query string (sorted and percent-encoded), or empty.
+    pub query: &'a str,
+    /// Additional headers to sign as lowercase `(name, value)` pairs.
+    ///
+    /// `x-sdk-date` must be included.
+    pub headers: &'a [(&'a str, &'a str)],
+    /// Request body.
+    pub payload: &'a [u8],
+    /// Request timestamp in `YYYYMMDDTHHMMSSZ` format.
+    pub sdk_date: &'a str,
+}
+
+/// Computes the `SDK-HMAC-SHA256` `Authorization` header value for a request.
+pub fn sign(credentials: &Credentials, request: &SigningRequest<'_>) -> String {
+    // The canonical URI always ends with a slash.
+    let path = if request.path.ends_with('/') {
+        request.path.to_string()
+    } else {
+        format!("{}/", request.path)
+    };
+
+    let mut headers: Vec<(&str, &str)> = vec![("host", request.host)];
+    headers.extend_from_slice(request.headers);
+    headers.sort_by(|a, b| a.0.cmp(b.0));
+
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        path,
+        request.query,
+        canonical_headers,
+        signed_headers,
+        sha256_hex(request.payload)
+    );
+
+    let string_to_sign = format!(
+        "{}\n{}\n{}",
+        ALGORITHM,
+        request.sdk_date,
+        sha256_hex(canonical_request.as_bytes())
+    );
+    let signature = hmac_sha256_hex(credentials.secret_key.as_bytes(), &string_to_sign);
+
+    format!(
+        "{} Access={}, SignedHeaders={}, Signature={}",
+        ALGORITHM, credentials.access_key, signed_headers, signature
+    )
+}
+
+/// Returns the current time in `YYYYMMDDTHHMMSSZ` format.
+fn sdk_date_now() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A public zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Zone {
+    /// Zone ID.
+    pub id: String,
+    /// Zone name with a trailing dot (e.g., `example.com.`).
+    pub name: String,
+    /// Zone description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Administrator email of the SOA record.
+    #[serde(default)]
+    pub email: Option<String>,
+    /// Default TTL of the SOA record.
+    #[serde(default)]
+    pub ttl: Option<u64>,
+    /// Zone status (e.g., `ACTIVE`, `PENDING_CREATE`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Zone type (`public` or `private`).
+    #[serde(default)]
+    pub zone_type: Option<String>,
+    /// Number of record sets.
+    #[serde(default)]
+    pub record_num: Option<u64>,
+    /// Name servers of the zone.
+    #[serde(default)]
+    pub masters: Vec<String>,
+}
+
+/// Request for creating a public zone.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateZoneRequest {
+    /// Zone name (e.g., `example.com.`).
+    pub name: String,
+    /// Zone type; always `public`.
+    pub zone_type: String,
+    /// Administrator email of the SOA record.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    /// Zone description.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl CreateZoneRequest {
+    /// Creates a request for a public zone.
+    pub fn public(name: &str) -> Self {
+        Self {
+            name: format!("{}.", name.trim_end_matches('.')),
+            zone_type: "public".to_string(),
+            email: None,
+            description: None,
+        }
+    }
+}
+
+/// Pagination metadata of list responses.
+#[derive(Debug, Default, Deserialize)]
+struct Metadata {
+    #[serde(default)]
+    total_count: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct ZoneList {
+    #[serde(default)]
+    zones: Vec<Zone>,
+    #[serde(default)]
+    metadata: Metadata,
+}
+
+// =============================================================================
+// Record Set Types
+// =============================================================================
+
+/// A record set.
+#[derive(Debug, Clone, Deserialize)]
+pub struct RecordSet {
+    /// Record set ID.
+    pub id: String,
+    /// Owner name with a trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Record values in zone file format.
+    #[serde(default)]
+    pub records: Vec<String>,
+    /// Record set status (e.g., `ACTIVE`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Whether this is a system-managed record set (SOA and NS at the apex).
+    #[serde(default)]
+    pub default: bool,
+}
+
+/// Request for creating or replacing a record set.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordSetRequest {
+    /// Owner name with a trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Record values in zone file format.
+    pub records: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordSetList {
+    #[serde(default)]
+    recordsets: Vec<RecordSet>,
+    #[serde(default)]
+    metadata: Metadata,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Huawei Cloud DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    credentials: Credentials,
+    base_url: String,
+    host: String,
+}
+
+impl Client {
+    /// Creates a new Huawei Cloud DNS API client for a region.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AK/SK credentials used to sign requests
+    /// * `region` - Region ID (e.g., `ap-southeast-1`, `cn-north-4`)
+    pub fn new(
+        credentials: Credentials,
+        region: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            credentials,
+            &endpoint_for_region(region),
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Huawei Cloud DNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AK/SK credentials used to sign requests
+    /// * `region` - Region ID (e.g., `ap-southeast-1`, `cn-north-4`)
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        region: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, &endpoint_for_region(region), config)
+    }
+
+    /// Creates a new Huawei Cloud DNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AK/SK credentials used to sign requests
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(base_url)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("base URL has no host".into()),
+        };
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            credentials,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            host,
+        })
+    }
+
+    /// Sends a signed request and returns the response body.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        params: &[(&str, &str)],
+        body: Option<String>,
+    ) -> Result<String, HuaweiCloudError> {
+        let query = canonical_query(params);
+        let payload = body.unwrap_or_default();
+        let sdk_date = sdk_date_now();
+
+        let mut signed: Vec<(&str, &str)> = vec![("x-sdk-date", &sdk_date)];
+        if !payload.is_empty() {
+            signed.push(("content-type", "application/json"));
+        }
+        if let Some(project_id) = &self.credentials.project_id {
+            signed.push(("x-project-id", project_id));
+        }
+
+        let authorization = sign(
+            &self.credentials,
+            &SigningRequest {
+                method: method.as_str(),
+                host: &self.host,
+                path,
+                query: &query,
+                headers: &signed,
+                payload: payload.as_bytes(),
+                sdk_date: &sdk_date,
+            },
+        );
+
+        let url = if query.is_empty() {
+            format!("{}{}", self.base_url, path)
+        } else {
+            format!("{}{}?{}", self.base_url, path, query)
+        };
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("Authorization", authorization);
+        for (name, value) in &signed {
+            request = request.header(*name, *value);
+        }
+        if !payload.is_empty() {
+            request = request.body(payload);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (code, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.code, e.message),
+                Err(_) => (String::new(), text),
+            };
+            return Err(HuaweiCloudError::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a signed request and deserializes the JSON response.
+    async fn request_json<T: DeserializeOwned>(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        params: &[(&str, &str)],
+        body: Option<String>,
+    ) -> Result<T, HuaweiCloudError> {
+        let text = self.request(method, path, params, body).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists public zones, following pagination.
+    ///
+    /// `name` filters zones whose name contains the given value.
+    pub async fn list_zones(&self, name: Option<&str>) -> Result<Vec<Zone>, HuaweiCloudError> {
+        let limit = PAGE_LIMIT.to_string();
+        let mut zones = Vec::new();
+
+        loop {
+            let offset = zones.len().to_string();
+            let mut params = vec![
+                ("type", "public"),
+                ("limit", limit.as_str()),
+                ("offset", offset.as_str()),
+            ];
+            if let Some(name) = name {
+                params.push(("name", name));
+            }
+
+            let page: ZoneList = self
+                .request_json(reqwest::Method::GET, "/v2/zones", &params, None)
+                .await?;
+            let count = page.zones.len();
+            zones.extend(page.zones);
+
+            if count == 0 || zones.len() as u64 >= page.metadata.total_count {
+                break;
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// Gets a zone by ID.
+    pub async fn get_zone(&self, zone_id: &str) -> Result<Zone, HuaweiCloudError> {
+        let path = format!("/v2/zones/{}", uri_encode(zone_id, true));
+        self.request_json(reqwest::Method::GET, &path, &[], None)
+            .await
+    }
+
+    /// Creates a zone.
+    pub async fn create_zone(&self, request: &CreateZoneRequest) -> Result<Zone, HuaweiCloudError> {
+        let body = serde_json::to_string(request)?;
+        self.request_json(reqwest::Method::POST, "/v2/zones", &[], Some(body))
+            .await
+    }
+
+    /// Deletes a zone.
+    pub async fn delete_zone(&self, zone_id: &str) -> Result<(), HuaweiCloudError> {
+        let path = format!("/v2/zones/{}", uri_encode(zone_id, true));
+        self.request(reqwest::Method::DELETE, &path, &[], None)
+            .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record Set APIs
+    // =========================================================================
+
+    /// Lists the record sets of a zone, following pagination.
+    ///
+    /// `name` and `record_type` filter for an exact owner name and type.
+    pub async fn list_record_sets(
+        &self,
+        zone_id: &str,
+        name: Option<&str>,
+        record_type: Option<&str>,
+    ) -> Result<Vec<RecordSet>, HuaweiCloudError> {
+        let path = format!("/v2/zones/{}/recordsets", uri_encode(zone_id, true));
+        let limit = PAGE_LIMIT.to_string();
+        let mut record_sets = Vec::new();
+
+        loop {
+            let offset = record_sets.len().to_string();
+            let mut params = vec![("limit", limit.as_str()), ("offset", offset.as_str())];
+            if let Some(name) = name {
+                params.push(("name", name));
+                params.push(("search_mode", "equal"));
+            }
+            if let Some(record_type) = record_type {
+                params.push(("type", record_type));
+            }
+
+            let page: RecordSetList = self
+                .request_json(reqwest::Method::GET, &path, &params, None)
+                .await?;
+            let count = page.recordsets.len();
+            record_sets.extend(page.recordsets);
+
+            if count == 0 || record_sets.len() as u64 >= page.metadata.total_count {
+                break;
+            }
+        }
+
+        Ok(record_sets)
+    }
+
+    /// Gets the record set with an exact owner name and type, if it exists.
+    pub async fn find_record_set(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: &str,
+    ) -> Result<Option<RecordSet>, HuaweiCloudError> {
+        let record_sets = self
+            .list_record_sets(zone_id, Some(name), Some(record_type))
+            .await?;
+        Ok(record_sets.into_iter().find(|rs| {
+            rs.name.eq_ignore_ascii_case(name) && rs.record_type.eq_ignore_ascii_case(record_type)
+        }))
+    }
+
+    /// Creates a record set.
+    pub async fn create_record_set(
+        &self,
+        zone_id: &str,
+        request: &RecordSetRequest,
+    ) -> Result<RecordSet, HuaweiCloudError> {
+        let path = format!("/v2/zones/{}/recordsets", uri_encode(zone_id, true));
+        let body = serde_json::to_string(request)?;
+        self.request_json(reqwest::Method::POST, &path, &[], Some(body))
+            .await
+    }
+
+    /// Replaces the values and TTL of a record set.
+    pub async fn update_record_set(
+        &self,
+        zone_id: &str,
+        record_set_id: &str,
+        request: &RecordSetRequest,
+    ) -> Result<RecordSet, HuaweiCloudError> {
+        let path = format!(
+            "/v2/zones/{}/recordsets/{}",
+            uri_encode(zone_id, true),
+            uri_encode(record_set_id, true)
+        );
+        let body = serde_json::to_string(request)?;
+        self.request_json(reqwest::Method::PUT, &path, &[], Some(body))
+            .await
+    }
+
+    /// Deletes a record set.
+    pub async fn delete_record_set(
+        &self,
+        zone_id: &str,
+        record_set_id: &str,
+    ) -> Result<(), HuaweiCloudError> {
+        let path = format!(
+            "/v2/zones/{}/recordsets/{}",
+            uri_encode(zone_id, true),
+            uri_encode(record_set_id, true)
+        );
+        self.request(reqwest::Method::DELETE, &path, &[], None)
+            .await?;
+        Ok(())
+    }
+}
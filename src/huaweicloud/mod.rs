@@ -0,0 +1,554 @@
+//! Huawei Cloud DNS provider implementation.
+//!
+//! This provider uses the Huawei Cloud DNS v2 API for public zones, with
+//! AK/SK (`SDK-HMAC-SHA256`) request signing.
+//!
+//! # Authentication
+//!
+//! Requires an access key (AK) and secret key (SK):
+//! - Create them under "My Credentials" > "Access Keys" in the console
+//! - The IAM user needs the `DNS FullAccess` policy
+//!
+//! IAM users whose permissions are granted per project must also set the
+//! project ID with [`Credentials::with_project_id`]; it is sent as the
+//! `X-Project-Id` header.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::huaweicloud::{Credentials, HuaweiCloudProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("your-access-key", "your-secret-key");
+//! let provider = HuaweiCloudProvider::new(credentials, "ap-southeast-1")?;
+//!
+//! // List all public zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Regions
+//!
+//! The API endpoint is `https://dns.{region}.myhuaweicloud.com`. Public zones
+//! are global, so any region the account can use works.
+//!
+//! # Zones
+//!
+//! The zone ID is the ID assigned by Huawei Cloud;
+//! [`get_zone`](crate::Provider::get_zone) also accepts a domain name.
+//!
+//! # RRset-based API
+//!
+//! Huawei Cloud manages records as record sets. Creating a record adds its
+//! value to the record set of the same name and type (creating the set if
+//! needed), and deleting a record removes the value, deleting the set once it
+//! is empty. All records in a set share one TTL, so creating a record also
+//! updates the TTL of its siblings.
+//!
+//! Record IDs use the format `"name/type/value"`, similar to the Hetzner
+//! provider.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CAA, CNAME, MX, NS, SRV and TXT
+//!
+//! # API Reference
+//!
+//! - [DNS API](https://support.huaweicloud.com/intl/en-us/api-dns/dns_api_60001.html)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, Credentials, HuaweiCloudError};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &["A", "AAAA", "CAA", "CNAME", "MX", "NS", "SRV", "TXT"];
+
+fn is_unauthorized(err: &HuaweiCloudError) -> bool {
+    matches!(err.status(), Some(401 | 403))
+}
+
+/// Huawei Cloud DNS provider.
+#[derive(Clone)]
+pub struct HuaweiCloudProvider {
+    api_client: Arc<Client>,
+}
+
+/// A public zone hosted by Huawei Cloud DNS.
+pub struct HuaweiCloudZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+    /// Zone name without the trailing dot.
+    domain: String,
+}
+
+impl HuaweiCloudProvider {
+    /// Creates a new Huawei Cloud DNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AK/SK credentials
+    /// * `region` - Region ID of the API endpoint (e.g., `ap-southeast-1`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::huaweicloud::{Credentials, HuaweiCloudProvider};
+    ///
+    /// let credentials = Credentials::new("access-key", "secret-key")
+    ///     .with_project_id("0123456789abcdef0123456789abcdef");
+    /// let provider = HuaweiCloudProvider::new(credentials, "cn-north-4").unwrap();
+    /// ```
+    pub fn new(
+        credentials: Credentials,
+        region: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials, region)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Huawei Cloud DNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AK/SK credentials
+    /// * `region` - Region ID of the API endpoint (e.g., `ap-southeast-1`)
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::huaweicloud::{Credentials, HuaweiCloudProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let credentials = Credentials::new("access-key", "secret-key");
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     HuaweiCloudProvider::with_config(credentials, "ap-southeast-1", config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        region: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, region, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Huawei Cloud DNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AK/SK credentials
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for HuaweiCloudProvider {
+    type Zone = HuaweiCloudZone;
+    type CustomRetrieveError = HuaweiCloudError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let map_err = |err: HuaweiCloudError| match err.status() {
+            _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+            Some(404) => RetrieveZoneError::NotFound,
+            _ => RetrieveZoneError::Custom(err),
+        };
+
+        // Zone IDs are hexadecimal, so anything with a dot is a domain name.
+        let zone = if zone_id.contains('.') {
+            let name = format!("{}.", zone_id.trim_end_matches('.'));
+            self.api_client
+                .list_zones(Some(&name))
+                .await
+                .map_err(map_err)?
+                .into_iter()
+                .find(|zone| zone.name.eq_ignore_ascii_case(&name))
+                .ok_or(RetrieveZoneError::NotFound)?
+        } else {
+            self.api_client.get_zone(zone_id).await.map_err(map_err)?
+        };
+
+        Ok(HuaweiCloudZone::from_api(self.api_client.clone(), zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones(None)
+            .await
+            .map_err(|err| match err {
+                _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| HuaweiCloudZone::from_api(self.api_client.clone(), zone))
+            .collect())
+    }
+}
+
+impl CreateZone for HuaweiCloudProvider {
+    type CustomCreateError = HuaweiCloudError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let zone = self
+            .api_client
+            .create_zone(&api::CreateZoneRequest::public(domain))
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => CreateZoneError::Unauthorized,
+                Some(400) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(HuaweiCloudZone::from_api(self.api_client.clone(), zone))
+    }
+}
+
+impl DeleteZone for HuaweiCloudProvider {
+    type CustomDeleteError = HuaweiCloudError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_zone(zone_id)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => DeleteZoneError::Unauthorized,
+                Some(404) => DeleteZoneError::NotFound,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl HuaweiCloudZone {
+    /// Creates a new HuaweiCloudZone from API response data.
+    fn from_api(api_client: Arc<Client>, zone: api::Zone) -> Self {
+        let domain = zone.name.trim_end_matches('.').to_string();
+        Self {
+            api_client,
+            repr: zone,
+            domain,
+        }
+    }
+
+    /// Returns the zone status (e.g., `ACTIVE`).
+    pub fn status(&self) -> Option<&str> {
+        self.repr.status.as_deref()
+    }
+
+    /// Returns the administrator email of the SOA record.
+    pub fn email(&self) -> Option<&str> {
+        self.repr.email.as_deref()
+    }
+
+    /// Returns the name servers of the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.masters
+    }
+
+    /// Converts a host relative to the zone into a fully-qualified owner name.
+    fn owner_name(&self, host: &str) -> String {
+        if host == "@" || host.is_empty() {
+            format!("{}.", self.domain)
+        } else if host.trim_end_matches('.') == self.domain
+            || host
+                .trim_end_matches('.')
+                .ends_with(&format!(".{}", self.domain))
+        {
+            format!("{}.", host.trim_end_matches('.'))
+        } else {
+            format!("{}.{}.", host, self.domain)
+        }
+    }
+
+    /// Converts a fully-qualified owner name into a host relative to the zone.
+    fn host(&self, name: &str) -> String {
+        let name = name.trim_end_matches('.');
+        if name == self.domain {
+            "@".to_string()
+        } else if let Some(sub) = name.strip_suffix(&format!(".{}", self.domain)) {
+            sub.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Converts all records of a record set into generic records.
+    fn records_from_rrset(&self, rrset: &api::RecordSet) -> Vec<Record> {
+        let host = self.host(&rrset.name);
+        rrset
+            .records
+            .iter()
+            .map(|value| Record {
+                id: format!("{}/{}/{}", host, rrset.record_type, value),
+                host: host.clone(),
+                data: record_data_from_value(&rrset.record_type, value),
+                ttl: rrset.ttl,
+            })
+            .collect()
+    }
+}
+
+impl Zone for HuaweiCloudZone {
+    type CustomRetrieveError = HuaweiCloudError;
+
+    fn id(&self) -> &str {
+        &self.repr.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .api_client
+            .list_record_sets(&self.repr.id, None, None)
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets
+            .iter()
+            .flat_map(|rrset| self.records_from_rrset(rrset))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let rrset = self
+            .api_client
+            .find_record_set(&self.repr.id, &self.owner_name(parts[0]), parts[1])
+            .await
+            .map_err(|err| match err.status() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        self.records_from_rrset(&rrset)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for HuaweiCloudZone {
+    type CustomCreateError = HuaweiCloudError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.owner_name(host);
+        let value = value_from_record_data(data);
+
+        let map_err = |err: HuaweiCloudError| match err.status() {
+            _ if is_unauthorized(&err) => CreateRecordError::Unauthorized,
+            Some(400) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        let existing = self
+            .api_client
+            .find_record_set(&self.repr.id, &name, typ)
+            .await
+            .map_err(map_err)?;
+
+        match existing {
+            Some(rrset) => {
+                if rrset.records.contains(&value) {
+                    return Err(CreateRecordError::InvalidRecord);
+                }
+                let mut records = rrset.records.clone();
+                records.push(value.clone());
+
+                let request = api::RecordSetRequest {
+                    name: rrset.name.clone(),
+                    record_type: rrset.record_type.clone(),
+                    ttl,
+                    records,
+                };
+                self.api_client
+                    .update_record_set(&self.repr.id, &rrset.id, &request)
+                    .await
+                    .map_err(map_err)?;
+            }
+            None => {
+                let request = api::RecordSetRequest {
+                    name: name.clone(),
+                    record_type: typ.to_string(),
+                    ttl,
+                    records: vec![value.clone()],
+                };
+                self.api_client
+                    .create_record_set(&self.repr.id, &request)
+                    .await
+                    .map_err(map_err)?;
+            }
+        }
+
+        let host = self.host(&name);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, value),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for HuaweiCloudZone {
+    type CustomDeleteError = HuaweiCloudError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, value) = (parts[0], parts[1], parts[2]);
+
+        let map_err = |err: HuaweiCloudError| match err.status() {
+            _ if is_unauthorized(&err) => DeleteRecordError::Unauthorized,
+            Some(404) => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let rrset = self
+            .api_client
+            .find_record_set(&self.repr.id, &self.owner_name(host), typ)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let remaining: Vec<String> = rrset
+            .records
+            .iter()
+            .filter(|v| *v != value)
+            .cloned()
+            .collect();
+        if remaining.len() == rrset.records.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        if remaining.is_empty() {
+            self.api_client
+                .delete_record_set(&self.repr.id, &rrset.id)
+                .await
+                .map_err(map_err)
+        } else {
+            let request = api::RecordSetRequest {
+                name: rrset.name.clone(),
+                record_type: rrset.record_type.clone(),
+                ttl: rrset.ttl,
+                records: remaining,
+            };
+            self.api_client
+                .update_record_set(&self.repr.id, &rrset.id, &request)
+                .await
+                .map_err(map_err)?;
+            Ok(())
+        }
+    }
+}
+
+/// Converts a Huawei Cloud record value into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT character strings are unquoted
+/// and concatenated.
+fn record_data_from_value(typ: &str, value: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, value.trim_end_matches('.')),
+        "TXT" => RecordData::from_raw(typ, &unquote_txt(value)),
+        _ => RecordData::from_raw(typ, value),
+    }
+}
+
+/// Converts [`RecordData`] into a Huawei Cloud record value.
+///
+/// Host names are made fully qualified with a trailing dot; TXT values are
+/// quoted.
+fn value_from_record_data(data: &RecordData) -> String {
+    match data {
+        RecordData::CNAME(_)
+        | RecordData::NS(_)
+        | RecordData::MX { .. }
+        | RecordData::SRV { .. } => {
+            format!("{}.", data.get_value().trim_end_matches('.'))
+        }
+        RecordData::TXT(txt) => quote_txt(txt),
+        _ => data.get_value(),
+    }
+}
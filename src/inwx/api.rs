@@ -0,0 +1,669 @@
+//! Low-level INWX DomRobot API client.
+//!
+//! This module provides direct access to the INWX DomRobot XML-RPC API.
+//! For most use cases, prefer using [`InwxProvider`](super::InwxProvider) instead.
+//!
+//! # Environments
+//!
+//! - OTE (Operational Test Environment): `https://api.ote.domrobot.com/xmlrpc/`
+//! - Production: `https://api.domrobot.com/xmlrpc/`
+//!
+//! # Sessions
+//!
+//! DomRobot is session based: the client calls `account.login` before its
+//! first request and sends the returned `domrobot` session cookie with every
+//! following call. Accounts with two-factor authentication additionally
+//! unlock the session with `account.unlock`, using a TOTP code computed from
+//! the shared secret configured with [`ClientConfig::with_totp_secret`].
+//!
+//! When a call is rejected because the session expired, the client logs in
+//! again and retries the call once.
+//!
+//! # API Reference
+//!
+//! - [DomRobot API documentation](https://www.inwx.com/en/help/apidoc)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::inwx::api::{Client, ClientConfig};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let config = ClientConfig::production("username", "password");
+//! let client = Client::new(config)?;
+//!
+//! let info = client.nameserver_info("example.com").await?;
+//! for record in &info.records {
+//!     println!("{} {} {}", record.name, record.record_type, record.content);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use hmac::{Hmac, Mac};
+use reqwest::header::{CONTENT_TYPE, COOKIE, SET_COOKIE};
+use sha1::Sha1;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+use crate::types::Environment;
+use crate::xmlrpc::{self, Value, XmlRpcError};
+use crate::HttpClientConfig;
+
+/// The INWX OTE API URL.
+const OTE_API_URL: &str = "https://api.ote.domrobot.com/xmlrpc/";
+
+/// The INWX production API URL.
+const PRODUCTION_API_URL: &str = "https://api.domrobot.com/xmlrpc/";
+
+/// Number of domains requested per `nameserver.list` page.
+const PAGE_LIMIT: i64 = 1000;
+
+/// Errors that may occur when interacting with the INWX API.
+#[derive(Debug, Error)]
+pub enum InwxError {
+    /// The API returned an error result code.
+    #[error("API error {code}: {message}")]
+    Api {
+        /// DomRobot result code (e.g., `2303` for "Object does not exist").
+        code: i64,
+        /// Error message, including the reason if one was given.
+        message: String,
+    },
+
+    /// The account requires two-factor authentication, but no TOTP secret
+    /// was configured.
+    #[error("Two-factor authentication required")]
+    TotpRequired,
+
+    /// The configured TOTP secret is not valid base32.
+    #[error("Invalid TOTP secret")]
+    InvalidTotpSecret,
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// The server returned an XML-RPC fault.
+    #[error("XML-RPC fault {code}: {message}")]
+    Fault {
+        /// Fault code.
+        code: i64,
+        /// Fault message.
+        message: String,
+    },
+
+    /// The response did not have the expected structure.
+    #[error("Parse error: {0}")]
+    Parse(String),
+}
+
+impl From<XmlRpcError> for InwxError {
+    fn from(err: XmlRpcError) -> Self {
+        match err {
+            XmlRpcError::Fault { code, message } => InwxError::Fault { code, message },
+            XmlRpcError::Parse(message) => InwxError::Parse(message),
+        }
+    }
+}
+
+impl InwxError {
+    /// Returns the DomRobot result code if this is an API error.
+    pub fn code(&self) -> Option<i64> {
+        match self {
+            InwxError::Api { code, .. } => Some(*code),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// Configuration
+// =============================================================================
+
+/// Configuration for the INWX API client.
+#[derive(Clone)]
+pub struct ClientConfig {
+    /// Account username.
+    pub username: String,
+    /// Account password.
+    pub password: String,
+    /// Base32 encoded TOTP secret for accounts with two-factor authentication.
+    pub totp_secret: Option<String>,
+    /// API environment (OTE or production).
+    pub environment: Environment,
+}
+
+impl ClientConfig {
+    /// Creates a new client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Account username
+    /// * `password` - Account password
+    /// * `environment` - [`Environment::Sandbox`] for OTE, or production
+    pub fn new(
+        username: impl Into<String>,
+        password: impl Into<String>,
+        environment: Environment,
+    ) -> Self {
+        Self {
+            username: username.into(),
+            password: password.into(),
+            totp_secret: None,
+            environment,
+        }
+    }
+
+    /// Creates a configuration for the OTE test environment.
+    pub fn ote(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::new(username, password, Environment::Sandbox)
+    }
+
+    /// Creates a configuration for the production environment.
+    pub fn production(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self::new(username, password, Environment::Production)
+    }
+
+    /// Sets the base32 encoded TOTP secret used to unlock sessions of
+    /// accounts with two-factor authentication.
+    pub fn with_totp_secret(mut self, secret: impl Into<String>) -> Self {
+        self.totp_secret = Some(secret.into());
+        self
+    }
+
+    /// Returns the API URL for the configured environment.
+    pub fn api_url(&self) -> &'static str {
+        match self.environment {
+            Environment::Production => PRODUCTION_API_URL,
+            Environment::Sandbox => OTE_API_URL,
+        }
+    }
+}
+
+impl fmt::Debug for ClientConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field(
+                "totp_secret",
+                &self.totp_secret.as_ref().map(|_| "<redacted>"),
+            )
+            .field("environment", &self.environment)
+            .finish()
+    }
+}
+
+// =============================================================================
+// TOTP
+// =============================================================================
+
+/// Computes the 6-digit TOTP code (RFC 6238, HMAC-SHA1, 30 second steps) for
+/// a base32 encoded secret at the given Unix timestamp.
+pub fn totp(secret: &str, timestamp: u64) -> Result<String, InwxError> {
+    let key = decode_base32(secret).ok_or(InwxError::InvalidTotpSecret)?;
+
+    let mut mac = Hmac::<Sha1>::new_from_slice(&key).map_err(|_| InwxError::InvalidTotpSecret)?;
+    mac.update(&(timestamp / 30).to_be_bytes());
+    let hash = mac.finalize().into_bytes();
+
+    let offset = (hash[hash.len() - 1] & 0x0f) as usize;
+    let code = u32::from_be_bytes([
+        hash[offset] & 0x7f,
+        hash[offset + 1],
+        hash[offset + 2],
+        hash[offset + 3],
+    ]);
+    Ok(format!("{:06}", code % 1_000_000))
+}
+
+/// Decodes RFC 4648 base32, ignoring case, spaces and padding.
+fn decode_base32(input: &str) -> Option<Vec<u8>> {
+    let mut output = Vec::with_capacity(input.len() * 5 / 8);
+    let mut buffer: u64 = 0;
+    let mut bits = 0;
+
+    for c in input.chars().filter(|c| !c.is_whitespace() && *c != '=') {
+        let value = match c.to_ascii_uppercase() {
+            c @ 'A'..='Z' => c as u64 - 'A' as u64,
+            c @ '2'..='7' => c as u64 - '2' as u64 + 26,
+            _ => return None,
+        };
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            output.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+
+    if output.is_empty() {
+        None
+    } else {
+        Some(output)
+    }
+}
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// A domain listed by `nameserver.list`.
+#[derive(Debug, Clone)]
+pub struct DomainSummary {
+    /// Domain name.
+    pub domain: String,
+    /// Repository object ID.
+    pub ro_id: Option<i64>,
+    /// Zone type (`MASTER` or `SLAVE`).
+    pub zone_type: Option<String>,
+}
+
+/// A domain with its records, as returned by `nameserver.info`.
+#[derive(Debug, Clone)]
+pub struct DomainInfo {
+    /// Domain name.
+    pub domain: String,
+    /// Repository object ID.
+    pub ro_id: Option<i64>,
+    /// Zone type (`MASTER` or `SLAVE`).
+    pub zone_type: Option<String>,
+    /// Records of the domain.
+    pub records: Vec<DnsRecord>,
+}
+
+/// An INWX DNS record.
+#[derive(Debug, Clone)]
+pub struct DnsRecord {
+    /// Record ID.
+    pub id: i64,
+    /// Fully qualified record name.
+    pub name: String,
+    /// Record type.
+    pub record_type: String,
+    /// Record content; for MX and SRV without the priority.
+    pub content: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority (MX and SRV).
+    pub prio: u16,
+}
+
+impl DnsRecord {
+    /// Decodes a record struct.
+    fn from_value(value: &Value) -> Result<Self, InwxError> {
+        let missing = |field: &str| InwxError::Parse(format!("record without {}", field));
+        Ok(Self {
+            id: value
+                .get("id")
+                .and_then(Value::as_i64)
+                .ok_or_else(|| missing("id"))?,
+            name: value.get_string("name").ok_or_else(|| missing("name"))?,
+            record_type: value.get_string("type").ok_or_else(|| missing("type"))?,
+            content: value.get_string("content").unwrap_or_default(),
+            ttl: value
+                .get("ttl")
+                .and_then(Value::as_i64)
+                .unwrap_or_default()
+                .max(0) as u64,
+            prio: value
+                .get("prio")
+                .and_then(Value::as_i64)
+                .unwrap_or_default()
+                .clamp(0, u16::MAX as i64) as u16,
+        })
+    }
+}
+
+/// Parameters for `nameserver.createRecord`.
+#[derive(Debug, Clone)]
+pub struct CreateRecordRequest {
+    /// Domain name.
+    pub domain: String,
+    /// Record name relative to the domain; empty for the apex.
+    pub name: String,
+    /// Record type.
+    pub record_type: String,
+    /// Record content; for MX and SRV without the priority.
+    pub content: String,
+    /// TTL in seconds (at least 300).
+    pub ttl: u64,
+    /// Priority (MX and SRV).
+    pub prio: u16,
+}
+
+/// DomRobot result code for a command that cannot be used, such as a call
+/// within an expired session.
+const CODE_SESSION_ERROR: i64 = 2002;
+
+/// DomRobot result code for authentication errors.
+const CODE_AUTHENTICATION_ERROR: i64 = 2200;
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// INWX DomRobot API client.
+#[derive(Debug)]
+pub struct Client {
+    http_client: reqwest::Client,
+    config: ClientConfig,
+    base_url: String,
+    session: Mutex<Option<String>>,
+}
+
+impl Client {
+    /// Creates a new INWX API client.
+    pub fn new(config: ClientConfig) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_config(config, HttpClientConfig::default())
+    }
+
+    /// Creates a new INWX API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - INWX client configuration
+    /// * `http_config` - HTTP client configuration for network binding
+    pub fn with_config(
+        config: ClientConfig,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let base_url = config.api_url();
+        Self::with_base_url(config, base_url, http_config)
+    }
+
+    /// Creates a new INWX API client with a custom API URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - INWX client configuration
+    /// * `base_url` - Custom XML-RPC endpoint URL
+    /// * `http_config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        config: ClientConfig,
+        base_url: &str,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder().timeout(
+            http_config
+                .timeout
+                .unwrap_or(std::time::Duration::from_secs(30)),
+        );
+
+        if let Some(addr) = http_config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = http_config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            config,
+            base_url: base_url.to_string(),
+            session: Mutex::new(None),
+        })
+    }
+
+    /// Returns the configured environment.
+    pub fn environment(&self) -> Environment {
+        self.config.environment
+    }
+
+    /// Sends a method call and returns the `resData` of a successful result,
+    /// along with the session cookie set by the response, if any.
+    async fn send(
+        &self,
+        method: &str,
+        params: &[Value],
+        session: Option<&str>,
+    ) -> Result<(Value, Option<String>), InwxError> {
+        let mut request = self
+            .http_client
+            .post(&self.base_url)
+            .header(CONTENT_TYPE, "text/xml")
+            .body(xmlrpc::encode_call(method, params));
+        if let Some(session) = session {
+            request = request.header(COOKIE, session);
+        }
+
+        let response = request.send().await?;
+
+        let cookies: Vec<String> = response
+            .headers()
+            .get_all(SET_COOKIE)
+            .iter()
+            .filter_map(|value| value.to_str().ok())
+            .filter_map(|value| value.split(';').next())
+            .map(|cookie| cookie.trim().to_string())
+            .collect();
+        let cookie = (!cookies.is_empty()).then(|| cookies.join("; "));
+
+        let text = response.error_for_status()?.text().await?;
+        let result = xmlrpc::decode_response(&text)?;
+
+        let code = result
+            .get("code")
+            .and_then(Value::as_i64)
+            .ok_or_else(|| InwxError::Parse("response without code".to_string()))?;
+        if code >= 2000 {
+            let mut message = result.get_string("msg").unwrap_or_default();
+            if let Some(reason) = result.get_string("reason") {
+                message = format!("{} ({})", message, reason);
+            }
+            return Err(InwxError::Api { code, message });
+        }
+
+        let data = result
+            .get("resData")
+            .cloned()
+            .unwrap_or(Value::Struct(Default::default()));
+        Ok((data, cookie))
+    }
+
+    /// Logs in, unlocking the session with a TOTP code if required, and
+    /// returns the session cookie.
+    async fn login(&self) -> Result<String, InwxError> {
+        let params = Value::from([
+            ("user", self.config.username.as_str()),
+            ("pass", self.config.password.as_str()),
+        ]);
+        let (data, cookie) = self.send("account.login", &[params], None).await?;
+        let session =
+            cookie.ok_or_else(|| InwxError::Parse("login without session cookie".to_string()))?;
+
+        let tfa = data.get_string("tfa").unwrap_or_default();
+        if !tfa.is_empty() && tfa != "0" {
+            let secret = self
+                .config
+                .totp_secret
+                .as_deref()
+                .ok_or(InwxError::TotpRequired)?;
+            let timestamp = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .unwrap()
+                .as_secs();
+            let params = Value::from([("tan", totp(secret, timestamp)?)]);
+            self.send("account.unlock", &[params], Some(&session))
+                .await?;
+        }
+
+        Ok(session)
+    }
+
+    /// Returns the session cookie, logging in first if there is no session.
+    ///
+    /// The lock is held while logging in, so concurrent callers share one
+    /// login.
+    async fn session(&self) -> Result<String, InwxError> {
+        let mut session = self.session.lock().await;
+        if let Some(session) = session.as_ref() {
+            return Ok(session.clone());
+        }
+
+        let cookie = self.login().await?;
+        *session = Some(cookie.clone());
+        Ok(cookie)
+    }
+
+    /// Calls a method within a logged in session.
+    ///
+    /// If the session was rejected, logs in again and retries once.
+    async fn call(&self, method: &str, params: Value) -> Result<Value, InwxError> {
+        let session = self.session().await?;
+
+        match self
+            .send(method, std::slice::from_ref(&params), Some(&session))
+            .await
+        {
+            Err(err)
+                if matches!(
+                    err.code(),
+                    Some(CODE_SESSION_ERROR | CODE_AUTHENTICATION_ERROR)
+                ) =>
+            {
+                {
+                    // Another caller may already have replaced the session.
+                    let mut current = self.session.lock().await;
+                    if current.as_deref() == Some(session.as_str()) {
+                        *current = None;
+                    }
+                }
+
+                let session = self.session().await?;
+                let (data, _) = self.send(method, &[params], Some(&session)).await?;
+                Ok(data)
+            }
+            result => result.map(|(data, _)| data),
+        }
+    }
+
+    /// Ends the current session, if any.
+    pub async fn logout(&self) -> Result<(), InwxError> {
+        let session = self.session.lock().await.take();
+        if let Some(session) = session {
+            self.send("account.logout", &[], Some(&session)).await?;
+        }
+        Ok(())
+    }
+
+    // =========================================================================
+    // Nameserver APIs
+    // =========================================================================
+
+    /// Lists all domains with nameserver entries, following pagination.
+    pub async fn nameserver_list(&self) -> Result<Vec<DomainSummary>, InwxError> {
+        let mut domains = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let params = Value::from([("page", page), ("pagelimit", PAGE_LIMIT)]);
+            let data = self.call("nameserver.list", params).await?;
+
+            let items = data
+                .get("domains")
+                .and_then(Value::as_array)
+                .unwrap_or_default();
+            domains.extend(items.iter().filter_map(|item| {
+                Some(DomainSummary {
+                    domain: item.get_string("domain")?,
+                    ro_id: item.get("roId").and_then(Value::as_i64),
+                    zone_type: item.get_string("type"),
+                })
+            }));
+
+            let count = data
+                .get("count")
+                .and_then(Value::as_i64)
+                .unwrap_or_default();
+            if items.is_empty() || domains.len() as i64 >= count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(domains)
+    }
+
+    /// Gets a domain with all of its records.
+    pub async fn nameserver_info(&self, domain: &str) -> Result<DomainInfo, InwxError> {
+        let data = self
+            .call("nameserver.info", Value::from([("domain", domain)]))
+            .await?;
+
+        let records = data
+            .get("record")
+            .and_then(Value::as_array)
+            .unwrap_or_default()
+            .iter()
+            .map(DnsRecord::from_value)
+            .collect::<Result<_, _>>()?;
+
+        Ok(DomainInfo {
+            domain: data
+                .get_string("domain")
+                .unwrap_or_else(|| domain.to_string()),
+            ro_id: data.get("roId").and_then(Value::as_i64),
+            zone_type: data.get_string("type"),
+            records,
+        })
+    }
+
+    /// Creates a record and returns its ID.
+    pub async fn nameserver_create_record(
+        &self,
+        request: &CreateRecordRequest,
+    ) -> Result<i64, InwxError> {
+        let mut params = vec![
+            ("domain".to_string(), Value::from(request.domain.as_str())),
+            ("name".to_string(), Value::from(request.name.as_str())),
+            (
+                "type".to_string(),
+                Value::from(request.record_type.as_str()),
+            ),
+            ("content".to_string(), Value::from(request.content.as_str())),
+            ("ttl".to_string(), Value::Int(request.ttl as i64)),
+        ];
+        if request.prio != 0 {
+            params.push(("prio".to_string(), Value::from(request.prio)));
+        }
+
+        let data = self
+            .call(
+                "nameserver.createRecord",
+                Value::Struct(params.into_iter().collect()),
+            )
+            .await?;
+        data.get("id")
+            .and_then(Value::as_i64)
+            .ok_or_else(|| InwxError::Parse("createRecord without id".to_string()))
+    }
+
+    /// Deletes a record.
+    pub async fn nameserver_delete_record(&self, id: i64) -> Result<(), InwxError> {
+        self.call("nameserver.deleteRecord", Value::from([("id", id)]))
+            .await?;
+        Ok(())
+    }
+}
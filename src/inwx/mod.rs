@@ -0,0 +1,402 @@
+//! INWX DNS provider implementation.
+//!
+//! This provider uses the INWX DomRobot XML-RPC API.
+//!
+//! # Authentication
+//!
+//! Uses the INWX account username and password. The client logs in on its
+//! first request and keeps the session for later calls. For accounts with
+//! two-factor authentication, configure the base32 TOTP secret shown when
+//! enabling 2FA with [`ClientConfig::with_totp_secret`]; the client then
+//! unlocks each new session with a freshly computed code.
+//!
+//! # Environments
+//!
+//! INWX provides an OTE (Operational Test Environment) and production API,
+//! selected with [`Environment`](crate::types::Environment):
+//! - OTE ([`Environment::Sandbox`](crate::types::Environment::Sandbox)):
+//!   `https://api.ote.domrobot.com/xmlrpc/`
+//! - Production: `https://api.domrobot.com/xmlrpc/`
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::inwx::{ClientConfig, InwxProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let config = ClientConfig::production("username", "password")
+//!     .with_totp_secret("JBSWY3DPEHPK3PXP");
+//! let provider = InwxProvider::new(config)?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ALIAS, CAA, NAPTR, PTR, SSHFP and TLSA, as [`RecordData::Other`]
+//!
+//! INWX requires a TTL of at least 300 seconds.
+//!
+//! # API Reference
+//!
+//! - [DomRobot API documentation](https://www.inwx.com/en/help/apidoc)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, ClientConfig, InwxError};
+
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ALIAS", "CAA", "NAPTR", "PTR", "SSHFP", "TLSA"];
+
+/// DomRobot result code for authentication errors.
+const CODE_AUTHENTICATION_ERROR: i64 = 2200;
+
+/// DomRobot result code for authorization errors.
+const CODE_AUTHORIZATION_ERROR: i64 = 2201;
+
+/// DomRobot result code for "Object does not exist".
+const CODE_OBJECT_NOT_FOUND: i64 = 2303;
+
+/// Whether a result code reports invalid parameters.
+fn is_parameter_error(code: i64) -> bool {
+    // 2003 missing parameter, 2004 value range, 2005 value syntax,
+    // 2302 object exists, 2306 parameter value policy error
+    matches!(code, 2003 | 2004 | 2005 | 2302 | 2306)
+}
+
+/// INWX DNS provider.
+#[derive(Clone)]
+pub struct InwxProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone managed by INWX.
+pub struct InwxZone {
+    api_client: Arc<Client>,
+    domain: String,
+    ro_id: Option<i64>,
+}
+
+impl InwxProvider {
+    /// Creates a new INWX provider with the given configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::inwx::{ClientConfig, InwxProvider};
+    ///
+    /// // For OTE testing
+    /// let provider = InwxProvider::new(ClientConfig::ote("username", "password")).unwrap();
+    /// ```
+    pub fn new(config: ClientConfig) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new INWX provider with custom HTTP client configuration.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::inwx::{ClientConfig, InwxProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = ClientConfig::production("username", "password");
+    /// let http_config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = InwxProvider::with_config(config, http_config).unwrap();
+    /// ```
+    pub fn with_config(
+        config: ClientConfig,
+        http_config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(config, http_config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new INWX provider with a custom API URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - INWX client configuration
+    /// * `base_url` - Custom XML-RPC endpoint URL
+    pub fn with_base_url(
+        config: ClientConfig,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(config, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for InwxProvider {
+    type Zone = InwxZone;
+    type CustomRetrieveError = InwxError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let info = self
+            .api_client
+            .nameserver_info(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.code() {
+                Some(CODE_OBJECT_NOT_FOUND) => RetrieveZoneError::NotFound,
+                Some(CODE_AUTHENTICATION_ERROR | CODE_AUTHORIZATION_ERROR) => {
+                    RetrieveZoneError::Unauthorized
+                }
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(InwxZone {
+            api_client: self.api_client.clone(),
+            domain: info.domain,
+            ro_id: info.ro_id,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .nameserver_list()
+            .await
+            .map_err(|err| match err.code() {
+                Some(CODE_AUTHENTICATION_ERROR | CODE_AUTHORIZATION_ERROR) => {
+                    RetrieveZoneError::Unauthorized
+                }
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| InwxZone {
+                api_client: self.api_client.clone(),
+                domain: domain.domain,
+                ro_id: domain.ro_id,
+            })
+            .collect())
+    }
+}
+
+impl InwxZone {
+    /// Returns the repository object ID of the domain.
+    pub fn ro_id(&self) -> Option<i64> {
+        self.ro_id
+    }
+
+    /// Converts a fully qualified record name into a host relative to the zone.
+    fn host(&self, name: &str) -> String {
+        let name = name.trim_end_matches('.');
+        if name.eq_ignore_ascii_case(&self.domain) {
+            "@".to_string()
+        } else if let Some(sub) = name.strip_suffix(&format!(".{}", self.domain)) {
+            sub.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Converts an API record into a generic record.
+    fn record_from_api(&self, record: api::DnsRecord) -> Record {
+        let data = match record.record_type.as_str() {
+            "MX" => RecordData::MX {
+                priority: record.prio,
+                mail_server: record.content.trim_end_matches('.').to_string(),
+            },
+            "SRV" => RecordData::from_raw(
+                "SRV",
+                &format!("{} {}", record.prio, record.content.trim_end_matches('.')),
+            ),
+            "CNAME" | "NS" => {
+                RecordData::from_raw(&record.record_type, record.content.trim_end_matches('.'))
+            }
+            typ => RecordData::from_raw(typ, &record.content),
+        };
+
+        Record {
+            id: record.id.to_string(),
+            host: self.host(&record.name),
+            data,
+            ttl: record.ttl,
+        }
+    }
+
+    /// Converts a host into a record name relative to the zone, empty for the apex.
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host == "@" || host.eq_ignore_ascii_case(&self.domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+}
+
+impl Zone for InwxZone {
+    type CustomRetrieveError = InwxError;
+
+    fn id(&self) -> &str {
+        &self.domain
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let info = self
+            .api_client
+            .nameserver_info(&self.domain)
+            .await
+            .map_err(|err| match err.code() {
+                Some(CODE_OBJECT_NOT_FOUND) => RetrieveRecordError::NotFound,
+                Some(CODE_AUTHENTICATION_ERROR | CODE_AUTHORIZATION_ERROR) => {
+                    RetrieveRecordError::Unauthorized
+                }
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(info
+            .records
+            .into_iter()
+            .map(|record| self.record_from_api(record))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        self.list_records()
+            .await?
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for InwxZone {
+    type CustomCreateError = InwxError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let (content, prio) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (mail_server.trim_end_matches('.').to_string(), *priority),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (
+                format!("{} {} {}", weight, port, target.trim_end_matches('.')),
+                *priority,
+            ),
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                (target.trim_end_matches('.').to_string(), 0)
+            }
+            RecordData::Other { typ, value } => {
+                if !OTHER_RECORD_TYPES.contains(&typ.as_str()) {
+                    return Err(CreateRecordError::UnsupportedType);
+                }
+                (value.clone(), 0)
+            }
+            other => (other.get_value(), 0),
+        };
+
+        let request = api::CreateRecordRequest {
+            domain: self.domain.clone(),
+            name: self.relative_name(host),
+            record_type: data.get_type().to_string(),
+            content,
+            ttl,
+            prio,
+        };
+
+        let id = self
+            .api_client
+            .nameserver_create_record(&request)
+            .await
+            .map_err(|err| match err.code() {
+                Some(CODE_AUTHENTICATION_ERROR | CODE_AUTHORIZATION_ERROR) => {
+                    CreateRecordError::Unauthorized
+                }
+                Some(code) if is_parameter_error(code) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(Record {
+            id: id.to_string(),
+            host: if request.name.is_empty() {
+                "@".to_string()
+            } else {
+                request.name
+            },
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for InwxZone {
+    type CustomDeleteError = InwxError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let id = record_id.parse().map_err(|_| DeleteRecordError::NotFound)?;
+
+        self.api_client
+            .nameserver_delete_record(id)
+            .await
+            .map_err(|err| match err.code() {
+                Some(CODE_OBJECT_NOT_FOUND) => DeleteRecordError::NotFound,
+                Some(CODE_AUTHENTICATION_ERROR | CODE_AUTHORIZATION_ERROR) => {
+                    DeleteRecordError::Unauthorized
+                }
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -157,6 +157,124 @@ pub mod namecheap;
 #[cfg(feature = "namecrane")]
 pub mod namecrane;
 
+#[cfg(feature = "axfr")]
+pub mod axfr;
+
+#[cfg(feature = "powerdns")]
+pub mod powerdns;
+
+#[cfg(feature = "route53")]
+pub mod route53;
+
+#[cfg(feature = "digitalocean")]
+pub mod digitalocean;
+
+#[cfg(feature = "gandi")]
+pub mod gandi;
+
+#[cfg(feature = "porkbun")]
+pub mod porkbun;
+
+#[cfg(feature = "gcloud-dns")]
+pub mod gcloud;
+
+#[cfg(feature = "azure-dns")]
+pub mod azure;
+
+#[cfg(feature = "desec")]
+pub mod desec;
+
+#[cfg(feature = "linode")]
+pub mod linode;
+
+#[cfg(feature = "vultr")]
+pub mod vultr;
+
+#[cfg(feature = "ovh")]
+pub mod ovh;
+
+#[cfg(feature = "alidns")]
+pub mod alidns;
+
+#[cfg(feature = "huaweicloud")]
+pub mod huaweicloud;
+
+#[cfg(feature = "godaddy")]
+pub mod godaddy;
+
+#[cfg(feature = "bunny")]
+pub mod bunny;
+
+#[cfg(feature = "namecom")]
+pub mod namecom;
+
+#[cfg(feature = "dnsimple")]
+pub mod dnsimple;
+
+#[cfg(feature = "ns1")]
+pub mod ns1;
+
+#[cfg(feature = "acmedns")]
+pub mod acmedns;
+
+#[cfg(any(feature = "route53", feature = "inwx", feature = "loopia"))]
+mod xml;
+
+#[cfg(any(
+    feature = "powerdns",
+    feature = "route53",
+    feature = "gandi",
+    feature = "gcloud-dns",
+    feature = "azure-dns",
+    feature = "desec",
+    feature = "vultr",
+    feature = "ovh",
+    feature = "huaweicloud",
+    feature = "akamai"
+))]
+mod txt;
+
+#[cfg(any(feature = "inwx", feature = "loopia"))]
+pub(crate) mod xmlrpc;
+
+#[cfg(feature = "inwx")]
+pub mod inwx;
+
+#[cfg(feature = "loopia")]
+pub mod loopia;
+
+#[cfg(feature = "cloudns")]
+pub mod cloudns;
+
+#[cfg(feature = "he-dyndns")]
+pub mod hedyndns;
+
+#[cfg(feature = "scaleway")]
+pub mod scaleway;
+
+#[cfg(feature = "exoscale")]
+pub mod exoscale;
+
+#[cfg(any(
+    feature = "route53",
+    feature = "tencent",
+    feature = "huaweicloud",
+    feature = "exoscale",
+    feature = "volcengine",
+    feature = "baiducloud",
+    feature = "akamai"
+))]
+pub(crate) mod signing;
+
+#[cfg(feature = "volcengine")]
+pub mod volcengine;
+
+#[cfg(feature = "baiducloud")]
+pub mod baiducloud;
+
+#[cfg(feature = "akamai")]
+pub mod akamai;
+
 /// Represents a DNS zone provider.
 ///
 /// Providers implement [`Zone`] management, which in turn implement [`Record`] management.
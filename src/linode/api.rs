@@ -0,0 +1,485 @@
+//! Low-level Linode Domains API client.
+//!
+//! This module provides direct access to the Linode API v4 Domains endpoints
+//! (`/v4/domains`).
+//! For most use cases, prefer using [`LinodeProvider`](super::LinodeProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [Domains](https://techdocs.akamai.com/linode-api/reference/get-domains)
+//! - [Domain Records](https://techdocs.akamai.com/linode-api/reference/get-domain-records)
+//! - [Pagination](https://techdocs.akamai.com/linode-api/reference/pagination)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::linode::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-api-token")?;
+//!
+//! for domain in client.list_domains().await? {
+//!     println!("Domain: {} (ID: {})", domain.domain, domain.id);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Linode API base URL.
+const LINODE_API_URL: &str = "https://api.linode.com";
+
+/// Page size requested from list endpoints (the API maximum).
+const PAGE_SIZE: u32 = 500;
+
+/// Errors that may occur when interacting with the Linode API.
+#[derive(Debug, Error)]
+pub enum LinodeError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {reason}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error reasons, joined with `; ` (prefixed with the field if any).
+        reason: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl LinodeError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            LinodeError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Linode error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    errors: Vec<ErrorEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorEntry {
+    #[serde(default)]
+    field: Option<String>,
+    #[serde(default)]
+    reason: String,
+}
+
+/// A page of a paginated list response.
+#[derive(Debug, Deserialize)]
+struct Page<T> {
+    data: Vec<T>,
+    #[serde(default)]
+    page: u32,
+    #[serde(default)]
+    pages: u32,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A Linode domain (DNS zone).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain ID.
+    pub id: u64,
+    /// Domain name (e.g., `example.com`).
+    pub domain: String,
+    /// Zone type (`master` or `slave`).
+    #[serde(rename = "type")]
+    pub domain_type: String,
+    /// Status (`active`, `disabled` or `edit_mode`).
+    #[serde(default)]
+    pub status: String,
+    /// SOA responsible person mailbox.
+    #[serde(default)]
+    pub soa_email: Option<String>,
+    /// Description.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Default TTL of the domain's records (0 for the Linode default).
+    #[serde(default)]
+    pub ttl_sec: u64,
+    /// Primary servers, for `slave` zones.
+    #[serde(default)]
+    pub master_ips: Vec<String>,
+    /// Addresses allowed to transfer the zone.
+    #[serde(default)]
+    pub axfr_ips: Vec<String>,
+    /// Tags.
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+/// Request body for creating a domain.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateDomainRequest {
+    /// Domain name.
+    pub domain: String,
+    /// Zone type (`master` or `slave`).
+    #[serde(rename = "type")]
+    pub domain_type: String,
+    /// SOA responsible person mailbox (required for `master` zones).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub soa_email: Option<String>,
+    /// Primary servers (required for `slave` zones).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub master_ips: Vec<String>,
+}
+
+// =============================================================================
+// Domain Record Types
+// =============================================================================
+
+/// A DNS record of a Linode domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the domain (empty for the apex).
+    #[serde(default)]
+    pub name: String,
+    /// Record value; for MX, CNAME, NS and SRV the target host name.
+    #[serde(default)]
+    pub target: String,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: u16,
+    /// Weight for SRV records.
+    #[serde(default)]
+    pub weight: u16,
+    /// Port for SRV records.
+    #[serde(default)]
+    pub port: u16,
+    /// Service name for SRV records, without the leading underscore.
+    #[serde(default)]
+    pub service: Option<String>,
+    /// Protocol for SRV records (e.g., `tcp`), without the leading underscore.
+    #[serde(default)]
+    pub protocol: Option<String>,
+    /// Tag for CAA records (`issue`, `issuewild` or `iodef`).
+    #[serde(default)]
+    pub tag: Option<String>,
+    /// TTL in seconds (0 for the domain default).
+    #[serde(default)]
+    pub ttl_sec: u64,
+}
+
+/// Request body for creating or updating a domain record.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordRequest {
+    /// Record type (ignored by updates).
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the domain (empty for the apex).
+    pub name: String,
+    /// Record value.
+    pub target: String,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+    /// Weight for SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub weight: Option<u16>,
+    /// Port for SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    /// Service name for SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    /// Protocol for SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protocol: Option<String>,
+    /// Tag for CAA records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag: Option<String>,
+    /// TTL in seconds; Linode rounds it up to the next supported value.
+    pub ttl_sec: u64,
+}
+
+impl RecordRequest {
+    /// Creates a request for a record without type-specific fields.
+    pub fn new(record_type: &str, name: &str, target: &str, ttl_sec: u64) -> Self {
+        Self {
+            record_type: record_type.to_string(),
+            name: name.to_string(),
+            target: target.to_string(),
+            priority: None,
+            weight: None,
+            port: None,
+            service: None,
+            protocol: None,
+            tag: None,
+            ttl_sec,
+        }
+    }
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Linode API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new Linode API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with the `domains:read_write` scope
+    pub fn new(api_token: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_token, LINODE_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new Linode API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with the `domains:read_write` scope
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        api_token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_token, LINODE_API_URL, config)
+    }
+
+    /// Creates a new Linode API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with the `domains:read_write` scope
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        api_token: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", api_token))?;
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`LinodeError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, LinodeError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let reason = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => e
+                    .errors
+                    .into_iter()
+                    .map(|e| match e.field {
+                        Some(field) => format!("{}: {}", field, e.reason),
+                        None => e.reason,
+                    })
+                    .collect::<Vec<_>>()
+                    .join("; "),
+                Err(_) => text,
+            };
+            return Err(LinodeError::Api {
+                status: status.as_u16(),
+                reason,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, LinodeError> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Fetches all pages of a list endpoint, optionally with an `X-Filter`.
+    async fn get_all<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        filter: Option<&str>,
+    ) -> Result<Vec<T>, LinodeError> {
+        let mut items = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let url = format!(
+                "{}{}?page={}&page_size={}",
+                self.base_url, path, page, PAGE_SIZE
+            );
+            let mut request = self.http_client.get(url);
+            if let Some(filter) = filter {
+                request = request.header("X-Filter", filter);
+            }
+
+            let text = self.send(request).await?;
+            let response: Page<T> = serde_json::from_str(&text)?;
+            items.extend(response.data);
+
+            if response.page >= response.pages {
+                break;
+            }
+            page = response.page + 1;
+        }
+
+        Ok(items)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, LinodeError> {
+        self.get_all("/v4/domains", None).await
+    }
+
+    /// Finds a domain by name.
+    pub async fn find_domain(&self, name: &str) -> Result<Option<Domain>, LinodeError> {
+        let filter = serde_json::json!({ "domain": name }).to_string();
+        let domains: Vec<Domain> = self.get_all("/v4/domains", Some(&filter)).await?;
+        Ok(domains
+            .into_iter()
+            .find(|domain| domain.domain.eq_ignore_ascii_case(name)))
+    }
+
+    /// Gets a domain by ID.
+    pub async fn get_domain(&self, domain_id: u64) -> Result<Domain, LinodeError> {
+        self.get(&format!("/v4/domains/{}", domain_id)).await
+    }
+
+    /// Creates a domain.
+    pub async fn create_domain(
+        &self,
+        request: &CreateDomainRequest,
+    ) -> Result<Domain, LinodeError> {
+        let url = format!("{}/v4/domains", self.base_url);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a domain and all of its records.
+    pub async fn delete_domain(&self, domain_id: u64) -> Result<(), LinodeError> {
+        let url = format!("{}/v4/domains/{}", self.base_url, domain_id);
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Domain Record APIs
+    // =========================================================================
+
+    /// Lists all records of a domain, following pagination.
+    pub async fn list_records(&self, domain_id: u64) -> Result<Vec<DomainRecord>, LinodeError> {
+        self.get_all(&format!("/v4/domains/{}/records", domain_id), None)
+            .await
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        domain_id: u64,
+        record_id: u64,
+    ) -> Result<DomainRecord, LinodeError> {
+        self.get(&format!("/v4/domains/{}/records/{}", domain_id, record_id))
+            .await
+    }
+
+    /// Creates a record.
+    pub async fn create_record(
+        &self,
+        domain_id: u64,
+        request: &RecordRequest,
+    ) -> Result<DomainRecord, LinodeError> {
+        let url = format!("{}/v4/domains/{}/records", self.base_url, domain_id);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Updates a record.
+    pub async fn update_record(
+        &self,
+        domain_id: u64,
+        record_id: u64,
+        request: &RecordRequest,
+    ) -> Result<DomainRecord, LinodeError> {
+        let url = format!(
+            "{}/v4/domains/{}/records/{}",
+            self.base_url, domain_id, record_id
+        );
+        let text = self.send(self.http_client.put(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(&self, domain_id: u64, record_id: u64) -> Result<(), LinodeError> {
+        let url = format!(
+            "{}/v4/domains/{}/records/{}",
+            self.base_url, domain_id, record_id
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,588 @@
+//! Linode DNS provider implementation.
+//!
+//! This provider uses the Linode API v4 Domains endpoints with Bearer token
+//! authentication.
+//!
+//! # Authentication
+//!
+//! Requires a Linode personal access token:
+//! - Create a token at: <https://cloud.linode.com/profile/tokens>
+//! - The token needs the "Domains" scope with read/write access
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::linode::LinodeProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = LinodeProvider::new("your-api-token")?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone IDs
+//!
+//! Zone IDs are Linode's numeric domain IDs.
+//! [`get_zone`](crate::Provider::get_zone) and
+//! [`delete_zone`](crate::DeleteZone::delete_zone) also accept the domain name.
+//! New zones are created as primary (`master`) zones with the SOA email
+//! `hostmaster@{domain}` unless [`LinodeProvider::with_soa_email`] is used.
+//!
+//! # SRV Records
+//!
+//! Linode stores the service and protocol of SRV records in separate fields.
+//! SRV hosts must therefore have the form `_service._protocol` (optionally
+//! followed by a subdomain), which is split on creation and joined again when
+//! reading records.
+//!
+//! # TTLs
+//!
+//! Linode rounds TTLs up to the next supported value (e.g., 300, 3600 or
+//! 86400 seconds); returned records carry the TTL that was stored.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, as [`RecordData::Other`] with the value `flags tag "value"` (Linode
+//!   does not store flags, they are always 0)
+//! - PTR, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [Domains](https://techdocs.akamai.com/linode-api/reference/get-domains)
+//! - [Domain Records](https://techdocs.akamai.com/linode-api/reference/get-domain-records)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, LinodeError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Linode DNS provider.
+#[derive(Clone)]
+pub struct LinodeProvider {
+    api_client: Arc<Client>,
+    soa_email: Option<String>,
+}
+
+/// A domain managed by Linode.
+pub struct LinodeZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+    /// Domain ID as a string.
+    id: String,
+}
+
+impl LinodeProvider {
+    /// Creates a new Linode provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with read/write access to Domains
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::linode::LinodeProvider;
+    ///
+    /// let provider = LinodeProvider::new("your-api-token").unwrap();
+    /// ```
+    pub fn new(api_token: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(api_token)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            soa_email: None,
+        })
+    }
+
+    /// Creates a new Linode provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with read/write access to Domains
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::linode::LinodeProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = LinodeProvider::with_config("your-api-token", config).unwrap();
+    /// ```
+    pub fn with_config(
+        api_token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(api_token, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            soa_email: None,
+        })
+    }
+
+    /// Creates a new Linode provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_token` - Personal access token with read/write access to Domains
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        api_token: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(api_token, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            soa_email: None,
+        })
+    }
+
+    /// Sets the SOA email used for zones created through [`CreateZone`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::linode::LinodeProvider;
+    ///
+    /// let provider = LinodeProvider::new("your-api-token")
+    ///     .unwrap()
+    ///     .with_soa_email("dns@example.net");
+    /// ```
+    pub fn with_soa_email(mut self, soa_email: impl Into<String>) -> Self {
+        self.soa_email = Some(soa_email.into());
+        self
+    }
+
+    /// Resolves a zone ID or domain name into a domain.
+    async fn find_domain(&self, zone_id: &str) -> Result<Option<api::Domain>, LinodeError> {
+        match zone_id.parse::<u64>() {
+            Ok(id) => match self.api_client.get_domain(id).await {
+                Ok(domain) => Ok(Some(domain)),
+                Err(err) if err.status() == Some(404) => Ok(None),
+                Err(err) => Err(err),
+            },
+            Err(_) => {
+                self.api_client
+                    .find_domain(zone_id.trim_end_matches('.'))
+                    .await
+            }
+        }
+    }
+}
+
+impl Provider for LinodeProvider {
+    type Zone = LinodeZone;
+    type CustomRetrieveError = LinodeError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .find_domain(zone_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?
+            .ok_or(RetrieveZoneError::NotFound)?;
+
+        Ok(LinodeZone::from_api(self.api_client.clone(), domain))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| LinodeZone::from_api(self.api_client.clone(), domain))
+            .collect())
+    }
+}
+
+impl CreateZone for LinodeProvider {
+    type CustomCreateError = LinodeError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let domain = domain.trim_end_matches('.');
+        let request = api::CreateDomainRequest {
+            domain: domain.to_string(),
+            domain_type: "master".to_string(),
+            soa_email: Some(
+                self.soa_email
+                    .clone()
+                    .unwrap_or_else(|| format!("hostmaster@{}", domain)),
+            ),
+            master_ips: Vec::new(),
+        };
+
+        let domain = self
+            .api_client
+            .create_domain(&request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateZoneError::Unauthorized,
+                Some(400) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(LinodeZone::from_api(self.api_client.clone(), domain))
+    }
+}
+
+impl DeleteZone for LinodeProvider {
+    type CustomDeleteError = LinodeError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        let map_err = |err: LinodeError| match err.status() {
+            Some(404) => DeleteZoneError::NotFound,
+            Some(401 | 403) => DeleteZoneError::Unauthorized,
+            _ => DeleteZoneError::Custom(err),
+        };
+
+        let domain = self
+            .find_domain(zone_id)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteZoneError::NotFound)?;
+
+        self.api_client
+            .delete_domain(domain.id)
+            .await
+            .map_err(map_err)
+    }
+}
+
+impl LinodeZone {
+    /// Creates a new LinodeZone from API response data.
+    fn from_api(api_client: Arc<Client>, domain: api::Domain) -> Self {
+        let id = domain.id.to_string();
+        Self {
+            api_client,
+            repr: domain,
+            id,
+        }
+    }
+
+    /// Returns the zone type (`master` or `slave`).
+    pub fn zone_type(&self) -> &str {
+        &self.repr.domain_type
+    }
+
+    /// Returns the status of the domain (`active`, `disabled` or `edit_mode`).
+    pub fn status(&self) -> &str {
+        &self.repr.status
+    }
+
+    /// Returns the SOA email of the domain, if set.
+    pub fn soa_email(&self) -> Option<&str> {
+        self.repr.soa_email.as_deref()
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    ///
+    /// The record type cannot be changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<LinodeError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| CreateRecordError::InvalidRecord)?;
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .update_record(self.repr.id, record_id, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(self.record_from_api(record))
+    }
+
+    /// Converts a host into a name relative to the domain (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(&self.repr.domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts an API record into a generic record.
+    fn record_from_api(&self, record: api::DomainRecord) -> Record {
+        let target = record.target.trim_end_matches('.').to_string();
+        let data = match record.record_type.as_str() {
+            "CNAME" => RecordData::CNAME(target),
+            "NS" => RecordData::NS(target),
+            "MX" => RecordData::MX {
+                priority: record.priority,
+                mail_server: target,
+            },
+            "SRV" => RecordData::SRV {
+                priority: record.priority,
+                weight: record.weight,
+                port: record.port,
+                target,
+            },
+            "TXT" => RecordData::TXT(record.target.clone()),
+            "CAA" => RecordData::Other {
+                typ: "CAA".to_string(),
+                value: format!(
+                    "0 {} \"{}\"",
+                    record.tag.as_deref().unwrap_or_default(),
+                    record.target
+                ),
+            },
+            typ => RecordData::from_raw(typ, &target),
+        };
+
+        Record {
+            id: record.id.to_string(),
+            host: srv_host(&record),
+            data,
+            ttl: if record.ttl_sec == 0 {
+                self.repr.ttl_sec
+            } else {
+                record.ttl_sec
+            },
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<LinodeError>> {
+        let name = self.relative_name(host);
+
+        let request = match data {
+            RecordData::A(_) | RecordData::AAAA(_) | RecordData::TXT(_) => {
+                api::RecordRequest::new(data.get_type(), &name, &data.get_value(), ttl)
+            }
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                api::RecordRequest::new(data.get_type(), &name, target, ttl)
+            }
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => api::RecordRequest {
+                priority: Some(*priority),
+                ..api::RecordRequest::new("MX", &name, mail_server, ttl)
+            },
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => {
+                let (service, protocol, name) =
+                    split_srv_name(&name).ok_or(CreateRecordError::InvalidRecord)?;
+                api::RecordRequest {
+                    priority: Some(*priority),
+                    weight: Some(*weight),
+                    port: Some(*port),
+                    service: Some(service.to_string()),
+                    protocol: Some(protocol.to_string()),
+                    ..api::RecordRequest::new("SRV", name, target, ttl)
+                }
+            }
+            RecordData::Other { typ, value } if typ == "CAA" => {
+                let mut parts = value.splitn(3, ' ');
+                let (tag, value) = match (parts.next(), parts.next(), parts.next()) {
+                    (Some(_flags), Some(tag), Some(value)) => (tag, value.trim_matches('"')),
+                    _ => return Err(CreateRecordError::InvalidRecord),
+                };
+                api::RecordRequest {
+                    tag: Some(tag.to_string()),
+                    ..api::RecordRequest::new("CAA", &name, value, ttl)
+                }
+            }
+            RecordData::Other { typ, value } if typ == "PTR" => {
+                api::RecordRequest::new("PTR", &name, value, ttl)
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+        };
+
+        Ok(request)
+    }
+}
+
+/// Returns the host of a record, joining the service and protocol of SRV records.
+fn srv_host(record: &api::DomainRecord) -> String {
+    match (&record.service, &record.protocol) {
+        (Some(service), Some(protocol))
+            if record.record_type == "SRV" && !record.name.starts_with('_') =>
+        {
+            let prefix = format!(
+                "_{}._{}",
+                service.trim_start_matches('_'),
+                protocol.trim_start_matches('_')
+            );
+            if record.name.is_empty() {
+                prefix
+            } else {
+                format!("{}.{}", prefix, record.name)
+            }
+        }
+        _ if record.name.is_empty() => "@".to_string(),
+        _ => record.name.clone(),
+    }
+}
+
+/// Splits an SRV name `_service._protocol[.name]` into its parts.
+fn split_srv_name(name: &str) -> Option<(&str, &str, &str)> {
+    let mut labels = name.splitn(3, '.');
+    let service = labels.next()?.strip_prefix('_')?;
+    let protocol = labels.next()?.strip_prefix('_')?;
+    Some((service, protocol, labels.next().unwrap_or_default()))
+}
+
+impl Zone for LinodeZone {
+    type CustomRetrieveError = LinodeError;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(self.repr.id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| self.record_from_api(record))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| RetrieveRecordError::NotFound)?;
+
+        let record = self
+            .api_client
+            .get_record(self.repr.id, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl CreateRecord for LinodeZone {
+    type CustomCreateError = LinodeError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .create_record(self.repr.id, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl DeleteRecord for LinodeZone {
+    type CustomDeleteError = LinodeError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| DeleteRecordError::NotFound)?;
+
+        self.api_client
+            .delete_record(self.repr.id, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,462 @@
+//! Low-level Loopia API client.
+//!
+//! This module provides direct access to the Loopia XML-RPC API.
+//! For most use cases, prefer using [`LoopiaProvider`](super::LoopiaProvider) instead.
+//!
+//! Every call takes the API username and password as its first parameters,
+//! followed by the customer number when acting on behalf of a reseller
+//! customer. Write calls return the status string `OK`, failures return
+//! status strings such as `AUTH_ERROR` or `BAD_INDATA`.
+//!
+//! # API Reference
+//!
+//! - [Loopia API documentation](https://www.loopia.com/api/)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::loopia::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("user@loopiaapi", "password")?;
+//!
+//! for subdomain in client.get_subdomains("example.com").await? {
+//!     for record in client.get_zone_records("example.com", &subdomain).await? {
+//!         println!("{} {} {}", subdomain, record.record_type, record.rdata);
+//!     }
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use reqwest::header::CONTENT_TYPE;
+use thiserror::Error;
+
+use crate::xmlrpc::{self, Value, XmlRpcError};
+use crate::HttpClientConfig;
+
+/// The Loopia API URL.
+const LOOPIA_API_URL: &str = "https://api.loopia.se/RPCSERV";
+
+/// Status strings returned by the API in place of a result.
+const STATUS_CODES: &[&str] = &[
+    "AUTH_ERROR",
+    "DOMAIN_OCCUPIED",
+    "RATE_LIMITED",
+    "BAD_INDATA",
+    "UNKNOWN_ERROR",
+    "INSUFFICIENT_FUNDS",
+];
+
+/// Errors that may occur when interacting with the Loopia API.
+#[derive(Debug, Error)]
+pub enum LoopiaError {
+    /// The API returned an error status.
+    #[error("API error: {status}")]
+    Api {
+        /// Status string (e.g., `AUTH_ERROR` or `BAD_INDATA`).
+        status: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// The server returned an XML-RPC fault.
+    #[error("XML-RPC fault {code}: {message}")]
+    Fault {
+        /// Fault code.
+        code: i64,
+        /// Fault message.
+        message: String,
+    },
+
+    /// The response did not have the expected structure.
+    #[error("Parse error: {0}")]
+    Parse(String),
+}
+
+impl From<XmlRpcError> for LoopiaError {
+    fn from(err: XmlRpcError) -> Self {
+        match err {
+            XmlRpcError::Fault { code, message } => LoopiaError::Fault { code, message },
+            XmlRpcError::Parse(message) => LoopiaError::Parse(message),
+        }
+    }
+}
+
+impl LoopiaError {
+    /// Returns the status string if this is an API error.
+    pub fn status(&self) -> Option<&str> {
+        match self {
+            LoopiaError::Api { status } => Some(status),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// Types
+// =============================================================================
+
+/// A domain of the account.
+#[derive(Debug, Clone)]
+pub struct Domain {
+    /// Domain name.
+    pub domain: String,
+    /// Whether the domain has been paid.
+    pub paid: bool,
+    /// Whether the domain is registered with Loopia.
+    pub registered: bool,
+    /// Renewal status (e.g., `NORMAL`).
+    pub renewal_status: Option<String>,
+    /// Expiration date (`YYYY-MM-DD`).
+    pub expiration_date: Option<String>,
+}
+
+impl Domain {
+    /// Decodes a domain struct.
+    fn from_value(value: &Value) -> Option<Self> {
+        Some(Self {
+            domain: value.get_string("domain")?,
+            paid: value.get("paid").is_some_and(is_true),
+            registered: value.get("registered").is_some_and(is_true),
+            renewal_status: value.get_string("renewal_status"),
+            expiration_date: value.get_string("expiration_date"),
+        })
+    }
+}
+
+/// A Loopia zone record.
+#[derive(Debug, Clone)]
+pub struct ZoneRecord {
+    /// Record ID (`0` for new records).
+    pub record_id: i64,
+    /// Record type.
+    pub record_type: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority (MX and SRV).
+    pub priority: u16,
+    /// Record data; for MX and SRV without the priority.
+    pub rdata: String,
+}
+
+impl ZoneRecord {
+    /// Decodes a record struct.
+    fn from_value(value: &Value) -> Result<Self, LoopiaError> {
+        Ok(Self {
+            record_id: value
+                .get("record_id")
+                .and_then(Value::as_i64)
+                .unwrap_or_default(),
+            record_type: value
+                .get_string("type")
+                .ok_or_else(|| LoopiaError::Parse("record without type".to_string()))?,
+            ttl: value
+                .get("ttl")
+                .and_then(Value::as_i64)
+                .unwrap_or_default()
+                .max(0) as u64,
+            priority: value
+                .get("priority")
+                .and_then(Value::as_i64)
+                .unwrap_or_default()
+                .clamp(0, u16::MAX as i64) as u16,
+            rdata: value.get_string("rdata").unwrap_or_default(),
+        })
+    }
+
+    /// Encodes the record as a struct.
+    fn to_value(&self) -> Value {
+        Value::Struct(
+            [
+                ("type".to_string(), Value::from(self.record_type.as_str())),
+                ("ttl".to_string(), Value::Int(self.ttl as i64)),
+                ("priority".to_string(), Value::from(self.priority)),
+                ("rdata".to_string(), Value::from(self.rdata.as_str())),
+                ("record_id".to_string(), Value::Int(self.record_id)),
+            ]
+            .into_iter()
+            .collect(),
+        )
+    }
+}
+
+/// Whether an XML-RPC value is a true boolean, or the integer `1`.
+fn is_true(value: &Value) -> bool {
+    value.as_bool().unwrap_or_else(|| value.as_i64() == Some(1))
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Loopia API client.
+#[derive(Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    username: String,
+    password: String,
+    customer_number: Option<String>,
+    base_url: String,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("username", &self.username)
+            .field("password", &"<redacted>")
+            .field("customer_number", &self.customer_number)
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl Client {
+    /// Creates a new Loopia API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - API username (e.g., `user@loopiaapi`)
+    /// * `password` - API password
+    pub fn new(
+        username: &str,
+        password: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            username,
+            password,
+            LOOPIA_API_URL,
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Loopia API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - API username (e.g., `user@loopiaapi`)
+    /// * `password` - API password
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        username: &str,
+        password: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(username, password, LOOPIA_API_URL, config)
+    }
+
+    /// Creates a new Loopia API client with a custom API URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - API username (e.g., `user@loopiaapi`)
+    /// * `password` - API password
+    /// * `base_url` - Custom XML-RPC endpoint URL
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        username: &str,
+        password: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            username: username.to_string(),
+            password: password.to_string(),
+            customer_number: None,
+            base_url: base_url.to_string(),
+        })
+    }
+
+    /// Acts on behalf of a reseller customer.
+    pub fn with_customer_number(mut self, customer_number: impl Into<String>) -> Self {
+        self.customer_number = Some(customer_number.into());
+        self
+    }
+
+    /// Calls a method with the credentials prepended to the parameters, and
+    /// turns status string results into [`LoopiaError::Api`].
+    async fn call(&self, method: &str, params: Vec<Value>) -> Result<Value, LoopiaError> {
+        let mut all_params = vec![
+            Value::from(self.username.as_str()),
+            Value::from(self.password.as_str()),
+        ];
+        if let Some(ref customer_number) = self.customer_number {
+            all_params.push(Value::from(customer_number.as_str()));
+        }
+        all_params.extend(params);
+
+        let text = self
+            .http_client
+            .post(&self.base_url)
+            .header(CONTENT_TYPE, "text/xml")
+            .body(xmlrpc::encode_call(method, &all_params))
+            .send()
+            .await?
+            .error_for_status()?
+            .text()
+            .await?;
+        let result = xmlrpc::decode_response(&text)?;
+
+        // Calls returning lists report errors as a single status string.
+        let status = match &result {
+            Value::String(s) => Some(s.as_str()),
+            Value::Array(values) if values.len() == 1 => values[0].as_str(),
+            _ => None,
+        };
+        if let Some(status) = status.filter(|s| STATUS_CODES.contains(s)) {
+            return Err(LoopiaError::Api {
+                status: status.to_string(),
+            });
+        }
+
+        Ok(result)
+    }
+
+    /// Calls a write method expecting the status `OK`.
+    async fn call_ok(&self, method: &str, params: Vec<Value>) -> Result<(), LoopiaError> {
+        match self.call(method, params).await? {
+            Value::String(s) if s == "OK" => Ok(()),
+            other => Err(LoopiaError::Parse(format!(
+                "unexpected {} result: {:?}",
+                method, other
+            ))),
+        }
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account.
+    pub async fn get_domains(&self) -> Result<Vec<Domain>, LoopiaError> {
+        let result = self.call("getDomains", Vec::new()).await?;
+        Ok(result
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(Domain::from_value)
+            .collect())
+    }
+
+    /// Lists the subdomains of a domain; the apex is `@`.
+    pub async fn get_subdomains(&self, domain: &str) -> Result<Vec<String>, LoopiaError> {
+        let result = self
+            .call("getSubdomains", vec![Value::from(domain)])
+            .await?;
+        Ok(result
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .filter_map(|value| value.as_str().map(str::to_string))
+            .collect())
+    }
+
+    /// Adds a subdomain.
+    pub async fn add_subdomain(&self, domain: &str, subdomain: &str) -> Result<(), LoopiaError> {
+        self.call_ok(
+            "addSubdomain",
+            vec![Value::from(domain), Value::from(subdomain)],
+        )
+        .await
+    }
+
+    /// Removes a subdomain and all of its records.
+    pub async fn remove_subdomain(&self, domain: &str, subdomain: &str) -> Result<(), LoopiaError> {
+        self.call_ok(
+            "removeSubdomain",
+            vec![Value::from(domain), Value::from(subdomain)],
+        )
+        .await
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists the records of a subdomain.
+    pub async fn get_zone_records(
+        &self,
+        domain: &str,
+        subdomain: &str,
+    ) -> Result<Vec<ZoneRecord>, LoopiaError> {
+        let result = self
+            .call(
+                "getZoneRecords",
+                vec![Value::from(domain), Value::from(subdomain)],
+            )
+            .await?;
+        result
+            .as_array()
+            .unwrap_or_default()
+            .iter()
+            .map(ZoneRecord::from_value)
+            .collect()
+    }
+
+    /// Adds a record to a subdomain.
+    pub async fn add_zone_record(
+        &self,
+        domain: &str,
+        subdomain: &str,
+        record: &ZoneRecord,
+    ) -> Result<(), LoopiaError> {
+        self.call_ok(
+            "addZoneRecord",
+            vec![
+                Value::from(domain),
+                Value::from(subdomain),
+                record.to_value(),
+            ],
+        )
+        .await
+    }
+
+    /// Removes a record from a subdomain.
+    pub async fn remove_zone_record(
+        &self,
+        domain: &str,
+        subdomain: &str,
+        record_id: i64,
+    ) -> Result<(), LoopiaError> {
+        self.call_ok(
+            "removeZoneRecord",
+            vec![
+                Value::from(domain),
+                Value::from(subdomain),
+                Value::Int(record_id),
+            ],
+        )
+        .await
+    }
+}
@@ -0,0 +1,458 @@
+//! Loopia DNS provider implementation.
+//!
+//! This provider uses the Loopia XML-RPC API.
+//!
+//! # Authentication
+//!
+//! Requires a Loopia API user, created in the customer zone under
+//! "Account settings" > "LoopiaAPI", with permission for the `getDomains`,
+//! `getSubdomains`, `addSubdomain`, `removeSubdomain`, `getZoneRecords`,
+//! `addZoneRecord` and `removeZoneRecord` methods. Resellers can act on behalf
+//! of a customer with [`LoopiaProvider::with_customer_number`].
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::loopia::LoopiaProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = LoopiaProvider::new("user@loopiaapi", "password")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 3600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Subdomains and Record IDs
+//!
+//! Loopia groups records by subdomain, with `@` for the apex. Creating a
+//! record on a new host adds the subdomain first, and deleting the last
+//! record of a subdomain removes it again. Record IDs are only unique within
+//! a subdomain, so they use the format `"subdomain/record_id"`.
+//!
+//! `addZoneRecord` does not return the new record's ID; it is looked up by
+//! listing the subdomain's records afterwards.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, NAPTR, PTR, SSHFP and TLSA, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [Loopia API documentation](https://www.loopia.com/api/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, LoopiaError};
+
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["CAA", "NAPTR", "PTR", "SSHFP", "TLSA"];
+
+/// Loopia DNS provider.
+#[derive(Clone)]
+pub struct LoopiaProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone managed by Loopia.
+pub struct LoopiaZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl LoopiaProvider {
+    /// Creates a new Loopia provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - API username (e.g., `user@loopiaapi`)
+    /// * `password` - API password
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::loopia::LoopiaProvider;
+    ///
+    /// let provider = LoopiaProvider::new("user@loopiaapi", "password").unwrap();
+    /// ```
+    pub fn new(username: &str, password: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(username, password)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Loopia provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - API username (e.g., `user@loopiaapi`)
+    /// * `password` - API password
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::loopia::LoopiaProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = LoopiaProvider::with_config("user@loopiaapi", "password", config).unwrap();
+    /// ```
+    pub fn with_config(
+        username: &str,
+        password: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(username, password, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Loopia provider with a custom API URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - API username (e.g., `user@loopiaapi`)
+    /// * `password` - API password
+    /// * `base_url` - Custom XML-RPC endpoint URL
+    pub fn with_base_url(
+        username: &str,
+        password: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client =
+            Client::with_base_url(username, password, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Acts on behalf of a reseller customer.
+    pub fn with_customer_number(self, customer_number: impl Into<String>) -> Self {
+        let api_client = (*self.api_client)
+            .clone()
+            .with_customer_number(customer_number);
+        Self {
+            api_client: Arc::new(api_client),
+        }
+    }
+}
+
+/// Maps API errors of record listing calls.
+fn map_retrieve_record_error(err: LoopiaError) -> RetrieveRecordError<LoopiaError> {
+    match err.status() {
+        Some("AUTH_ERROR") => RetrieveRecordError::Unauthorized,
+        Some("UNKNOWN_ERROR") => RetrieveRecordError::NotFound,
+        _ => RetrieveRecordError::Custom(err),
+    }
+}
+
+impl Provider for LoopiaProvider {
+    type Zone = LoopiaZone;
+    type CustomRetrieveError = LoopiaError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone_id = zone_id.trim_end_matches('.');
+
+        self.list_zones()
+            .await?
+            .into_iter()
+            .find(|zone| zone.repr.domain.eq_ignore_ascii_case(zone_id))
+            .ok_or(RetrieveZoneError::NotFound)
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .get_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some("AUTH_ERROR") => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| LoopiaZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl LoopiaZone {
+    /// Returns the expiration date of the domain (`YYYY-MM-DD`).
+    pub fn expiration_date(&self) -> Option<&str> {
+        self.repr.expiration_date.as_deref()
+    }
+
+    /// Converts a host into a Loopia subdomain, `@` for the apex.
+    fn subdomain(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.domain;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts an API record into a generic record.
+    fn record_from_api(subdomain: &str, record: api::ZoneRecord) -> Record {
+        let data = match record.record_type.as_str() {
+            "MX" => RecordData::MX {
+                priority: record.priority,
+                mail_server: record.rdata.trim_end_matches('.').to_string(),
+            },
+            "SRV" => RecordData::from_raw(
+                "SRV",
+                &format!("{} {}", record.priority, record.rdata.trim_end_matches('.')),
+            ),
+            "CNAME" | "NS" => {
+                RecordData::from_raw(&record.record_type, record.rdata.trim_end_matches('.'))
+            }
+            typ => RecordData::from_raw(typ, &record.rdata),
+        };
+
+        Record {
+            id: format!("{}/{}", subdomain, record.record_id),
+            host: subdomain.to_string(),
+            data,
+            ttl: record.ttl,
+        }
+    }
+
+    /// Parses a `"subdomain/record_id"` record ID.
+    fn parse_record_id(record_id: &str) -> Option<(&str, i64)> {
+        let (subdomain, id) = record_id.rsplit_once('/')?;
+        Some((subdomain, id.parse().ok()?))
+    }
+}
+
+impl Zone for LoopiaZone {
+    type CustomRetrieveError = LoopiaError;
+
+    fn id(&self) -> &str {
+        &self.repr.domain
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let subdomains = self
+            .api_client
+            .get_subdomains(&self.repr.domain)
+            .await
+            .map_err(map_retrieve_record_error)?;
+
+        let mut records = Vec::new();
+        for subdomain in subdomains {
+            let zone_records = self
+                .api_client
+                .get_zone_records(&self.repr.domain, &subdomain)
+                .await
+                .map_err(map_retrieve_record_error)?;
+            records.extend(
+                zone_records
+                    .into_iter()
+                    .map(|record| Self::record_from_api(&subdomain, record)),
+            );
+        }
+
+        Ok(records)
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let (subdomain, id) =
+            Self::parse_record_id(record_id).ok_or(RetrieveRecordError::NotFound)?;
+
+        self.api_client
+            .get_zone_records(&self.repr.domain, subdomain)
+            .await
+            .map_err(map_retrieve_record_error)?
+            .into_iter()
+            .find(|record| record.record_id == id)
+            .map(|record| Self::record_from_api(subdomain, record))
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for LoopiaZone {
+    type CustomCreateError = LoopiaError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let (rdata, priority) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (mail_server.trim_end_matches('.').to_string(), *priority),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (
+                format!("{} {} {}", weight, port, target.trim_end_matches('.')),
+                *priority,
+            ),
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                (target.trim_end_matches('.').to_string(), 0)
+            }
+            RecordData::Other { typ, value } => {
+                if !OTHER_RECORD_TYPES.contains(&typ.as_str()) {
+                    return Err(CreateRecordError::UnsupportedType);
+                }
+                (value.clone(), 0)
+            }
+            other => (other.get_value(), 0),
+        };
+
+        let map_err = |err: LoopiaError| match err.status() {
+            Some("AUTH_ERROR") => CreateRecordError::Unauthorized,
+            Some("BAD_INDATA") => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        let domain = &self.repr.domain;
+        let subdomain = self.subdomain(host);
+
+        let subdomains = self
+            .api_client
+            .get_subdomains(domain)
+            .await
+            .map_err(map_err)?;
+        if !subdomains
+            .iter()
+            .any(|s| s.eq_ignore_ascii_case(&subdomain))
+        {
+            self.api_client
+                .add_subdomain(domain, &subdomain)
+                .await
+                .map_err(map_err)?;
+        }
+
+        let record = api::ZoneRecord {
+            record_id: 0,
+            record_type: data.get_type().to_string(),
+            ttl,
+            priority,
+            rdata,
+        };
+        self.api_client
+            .add_zone_record(domain, &subdomain, &record)
+            .await
+            .map_err(map_err)?;
+
+        // The new record has the highest ID among matching records.
+        let created = self
+            .api_client
+            .get_zone_records(domain, &subdomain)
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .filter(|r| {
+                r.record_type == record.record_type
+                    && r.rdata.trim_end_matches('.') == record.rdata
+                    && r.priority == record.priority
+            })
+            .max_by_key(|r| r.record_id)
+            .ok_or_else(|| {
+                CreateRecordError::Custom(LoopiaError::Parse(
+                    "created record not found".to_string(),
+                ))
+            })?;
+
+        Ok(Record {
+            id: format!("{}/{}", subdomain, created.record_id),
+            host: subdomain,
+            data: data.clone(),
+            ttl: created.ttl,
+        })
+    }
+}
+
+impl DeleteRecord for LoopiaZone {
+    type CustomDeleteError = LoopiaError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let (subdomain, id) =
+            Self::parse_record_id(record_id).ok_or(DeleteRecordError::NotFound)?;
+
+        let map_err = |err: LoopiaError| match err.status() {
+            Some("AUTH_ERROR") => DeleteRecordError::Unauthorized,
+            Some("UNKNOWN_ERROR") => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let domain = &self.repr.domain;
+        let records = self
+            .api_client
+            .get_zone_records(domain, subdomain)
+            .await
+            .map_err(map_err)?;
+        if !records.iter().any(|record| record.record_id == id) {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        self.api_client
+            .remove_zone_record(domain, subdomain, id)
+            .await
+            .map_err(map_err)?;
+
+        // Remove subdomains left without records; the apex always stays.
+        if records.len() == 1 && subdomain != "@" {
+            self.api_client
+                .remove_subdomain(domain, subdomain)
+                .await
+                .map_err(map_err)?;
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,464 @@
+//! Low-level Name.com API client.
+//!
+//! This module provides direct access to the Name.com API v4 domain and DNS
+//! record endpoints (`/v4/domains`) using HTTP basic authentication.
+//! For most use cases, prefer using [`NameComProvider`](super::NameComProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [Domains](https://docs.name.com/docs/api-reference/domains)
+//! - [DNS records](https://docs.name.com/docs/api-reference/dns)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::namecom::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-username", "your-api-token")?;
+//!
+//! for record in client.list_records("example.com").await? {
+//!     println!("{} {} {}", record.host, record.record_type, record.answer);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Name.com API base URL.
+const NAMECOM_API_URL: &str = "https://api.name.com";
+
+/// Page size requested from list endpoints (the API maximum).
+const PAGE_SIZE: u32 = 1000;
+
+/// Errors that may occur when interacting with the Name.com API.
+#[derive(Debug, Error)]
+pub enum NameComError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message, followed by the details if any.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl NameComError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            NameComError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Name.com error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    message: String,
+    #[serde(default)]
+    details: Option<String>,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A domain of the account.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Domain {
+    /// Domain name.
+    pub domain_name: String,
+    /// Name servers of the domain.
+    #[serde(default)]
+    pub nameservers: Vec<String>,
+    /// Whether the domain is locked against transfers.
+    #[serde(default)]
+    pub locked: bool,
+    /// Whether the domain renews automatically.
+    #[serde(default)]
+    pub autorenew_enabled: bool,
+    /// Expiration date (RFC 3339).
+    #[serde(default)]
+    pub expire_date: Option<String>,
+    /// Registration date (RFC 3339).
+    #[serde(default)]
+    pub create_date: Option<String>,
+}
+
+/// Response of the domain list endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DomainsPage {
+    #[serde(default)]
+    domains: Vec<Domain>,
+    #[serde(default)]
+    next_page: Option<u32>,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a domain.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DnsRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Domain name the record belongs to.
+    #[serde(default)]
+    pub domain_name: String,
+    /// Host name relative to the domain (empty for the apex).
+    #[serde(default)]
+    pub host: String,
+    /// Fully qualified name of the record, with a trailing dot.
+    #[serde(default)]
+    pub fqdn: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record answer; for SRV records `weight port target`.
+    #[serde(default)]
+    pub answer: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: Option<u16>,
+}
+
+/// Response of the record list endpoint.
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RecordsPage {
+    #[serde(default)]
+    records: Vec<DnsRecord>,
+    #[serde(default)]
+    next_page: Option<u32>,
+}
+
+/// Request body for creating or updating a record.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordRequest {
+    /// Host name relative to the domain (empty for the apex).
+    pub host: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record answer; for SRV records `weight port target`.
+    pub answer: String,
+    /// TTL in seconds (at least 300).
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Name.com API client.
+#[derive(Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    username: String,
+    api_token: String,
+    base_url: String,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("username", &self.username)
+            .field("api_token", &"<redacted>")
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl Client {
+    /// Creates a new Name.com API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name.com account username
+    /// * `api_token` - API token for the account
+    pub fn new(
+        username: &str,
+        api_token: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            username,
+            api_token,
+            NAMECOM_API_URL,
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Name.com API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name.com account username
+    /// * `api_token` - API token for the account
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        username: &str,
+        api_token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(username, api_token, NAMECOM_API_URL, config)
+    }
+
+    /// Creates a new Name.com API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers, or for the
+    /// Name.com test environment (`https://api.dev.name.com`).
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name.com account username
+    /// * `api_token` - API token for the account
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        username: &str,
+        api_token: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            username: username.to_string(),
+            api_token: api_token.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends an authenticated request and returns the response body.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<&RecordRequest>,
+    ) -> Result<String, NameComError> {
+        let mut request = self
+            .http_client
+            .request(method, format!("{}{}", self.base_url, path))
+            .basic_auth(&self.username, Some(&self.api_token));
+        if let Some(body) = body {
+            request = request.json(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(ErrorResponse {
+                    message,
+                    details: Some(details),
+                }) if !details.is_empty() => format!("{}: {}", message, details),
+                Ok(e) => e.message,
+                Err(_) => text,
+            };
+            return Err(NameComError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a GET request and deserializes the JSON response.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, NameComError> {
+        let text = self.request(reqwest::Method::GET, path, None).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, NameComError> {
+        let mut domains = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response: DomainsPage = self
+                .get(&format!("/v4/domains?page={}&perPage={}", page, PAGE_SIZE))
+                .await?;
+            domains.extend(response.domains);
+
+            match response.next_page {
+                Some(next) if next > page => page = next,
+                _ => break,
+            }
+        }
+
+        Ok(domains)
+    }
+
+    /// Gets a domain.
+    pub async fn get_domain(&self, domain: &str) -> Result<Domain, NameComError> {
+        self.get(&format!("/v4/domains/{}", encode_component(domain)))
+            .await
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a domain, following pagination.
+    pub async fn list_records(&self, domain: &str) -> Result<Vec<DnsRecord>, NameComError> {
+        let mut records = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response: RecordsPage = self
+                .get(&format!(
+                    "/v4/domains/{}/records?page={}&perPage={}",
+                    encode_component(domain),
+                    page,
+                    PAGE_SIZE
+                ))
+                .await?;
+            records.extend(response.records);
+
+            match response.next_page {
+                Some(next) if next > page => page = next,
+                _ => break,
+            }
+        }
+
+        Ok(records)
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        domain: &str,
+        record_id: u64,
+    ) -> Result<DnsRecord, NameComError> {
+        self.get(&format!(
+            "/v4/domains/{}/records/{}",
+            encode_component(domain),
+            record_id
+        ))
+        .await
+    }
+
+    /// Creates a record.
+    pub async fn create_record(
+        &self,
+        domain: &str,
+        request: &RecordRequest,
+    ) -> Result<DnsRecord, NameComError> {
+        let text = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/v4/domains/{}/records", encode_component(domain)),
+                Some(request),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Replaces a record.
+    pub async fn update_record(
+        &self,
+        domain: &str,
+        record_id: u64,
+        request: &RecordRequest,
+    ) -> Result<DnsRecord, NameComError> {
+        let text = self
+            .request(
+                reqwest::Method::PUT,
+                &format!(
+                    "/v4/domains/{}/records/{}",
+                    encode_component(domain),
+                    record_id
+                ),
+                Some(request),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(&self, domain: &str, record_id: u64) -> Result<(), NameComError> {
+        self.request(
+            reqwest::Method::DELETE,
+            &format!(
+                "/v4/domains/{}/records/{}",
+                encode_component(domain),
+                record_id
+            ),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Percent-encodes a path segment.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
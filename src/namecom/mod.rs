@@ -0,0 +1,426 @@
+//! Name.com DNS provider implementation.
+//!
+//! This provider uses the Name.com API v4 for DNS record management.
+//!
+//! # Authentication
+//!
+//! Requires the account username and an API token:
+//! - Create a token at: <https://www.name.com/account/settings/api>
+//!
+//! Requests use HTTP basic authentication with the username and token.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::namecom::NameComProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = NameComProvider::new("your-username", "your-api-token")?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone Management
+//!
+//! Domains are registered or transferred as Name.com products, so this
+//! provider does not implement [`CreateZone`](crate::CreateZone) or
+//! [`DeleteZone`](crate::DeleteZone). The zone ID is the domain name.
+//!
+//! # SRV Records
+//!
+//! Name.com stores the priority of SRV records separately and the rest of
+//! the record as the answer `weight port target`. SRV hosts include the
+//! service and protocol labels (e.g., `_sip._tcp`).
+//!
+//! # TTLs
+//!
+//! Name.com requires a TTL of at least 300 seconds.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ANAME and CAA, as [`RecordData::Other`] (CAA in zone file format)
+//!
+//! # API Reference
+//!
+//! - [DNS records](https://docs.name.com/docs/api-reference/dns)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, NameComError};
+
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ANAME", "CAA"];
+
+/// Name.com DNS provider.
+#[derive(Clone)]
+pub struct NameComProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain whose DNS is hosted by Name.com.
+pub struct NameComZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl NameComProvider {
+    /// Creates a new Name.com provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name.com account username
+    /// * `api_token` - API token for the account
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::namecom::NameComProvider;
+    ///
+    /// let provider = NameComProvider::new("your-username", "your-api-token").unwrap();
+    /// ```
+    pub fn new(username: &str, api_token: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(username, api_token)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Name.com provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name.com account username
+    /// * `api_token` - API token for the account
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::namecom::NameComProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     NameComProvider::with_config("your-username", "your-api-token", config).unwrap();
+    /// ```
+    pub fn with_config(
+        username: &str,
+        api_token: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(username, api_token, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Name.com provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `username` - Name.com account username
+    /// * `api_token` - API token for the account
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        username: &str,
+        api_token: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client =
+            Client::with_base_url(username, api_token, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for NameComProvider {
+    type Zone = NameComZone;
+    type CustomRetrieveError = NameComError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .get_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(NameComZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| NameComZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl NameComZone {
+    /// Returns the name servers of the domain.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.nameservers
+    }
+
+    /// Returns the expiration date of the domain (RFC 3339), if known.
+    pub fn expire_date(&self) -> Option<&str> {
+        self.repr.expire_date.as_deref()
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<NameComError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| CreateRecordError::InvalidRecord)?;
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .update_record(&self.repr.domain_name, record_id, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400 | 422) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+
+    /// Converts a host into a name relative to the domain (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.domain_name;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<NameComError>> {
+        let (answer, priority) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (
+                mail_server.trim_end_matches('.').to_string(),
+                Some(*priority),
+            ),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (
+                format!("{} {} {}", weight, port, target.trim_end_matches('.')),
+                Some(*priority),
+            ),
+            RecordData::CNAME(target) | RecordData::NS(target) => {
+                (target.trim_end_matches('.').to_string(), None)
+            }
+            RecordData::TXT(text) => (text.clone(), None),
+            RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+                (value.clone(), None)
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+            _ => (data.get_value(), None),
+        };
+
+        Ok(api::RecordRequest {
+            host: self.relative_name(host),
+            record_type: data.get_type().to_string(),
+            answer,
+            ttl,
+            priority,
+        })
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::DnsRecord) -> Record {
+    let priority = record.priority.unwrap_or_default();
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority,
+            mail_server: record.answer.trim_end_matches('.').to_string(),
+        },
+        "SRV" => RecordData::from_raw(
+            "SRV",
+            &format!("{} {}", priority, record.answer.trim_end_matches('.')),
+        ),
+        "TXT" => RecordData::TXT(record.answer.clone()),
+        "CNAME" | "NS" => {
+            RecordData::from_raw(&record.record_type, record.answer.trim_end_matches('.'))
+        }
+        typ => RecordData::from_raw(typ, &record.answer),
+    };
+
+    Record {
+        id: record.id.to_string(),
+        host: if record.host.is_empty() {
+            "@".to_string()
+        } else {
+            record.host
+        },
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for NameComZone {
+    type CustomRetrieveError = NameComError;
+
+    fn id(&self) -> &str {
+        &self.repr.domain_name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain_name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.domain_name)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| RetrieveRecordError::NotFound)?;
+
+        let record = self
+            .api_client
+            .get_record(&self.repr.domain_name, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for NameComZone {
+    type CustomCreateError = NameComError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .create_record(&self.repr.domain_name, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400 | 422) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl DeleteRecord for NameComZone {
+    type CustomDeleteError = NameComError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let record_id = record_id
+            .parse::<u64>()
+            .map_err(|_| DeleteRecordError::NotFound)?;
+
+        self.api_client
+            .delete_record(&self.repr.domain_name, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,444 @@
+//! Low-level NS1 API client.
+//!
+//! This module provides direct access to the NS1 (IBM NS1 Connect) REST API
+//! v1 zone and record endpoints with `X-NSONE-Key` authentication.
+//! For most use cases, prefer using [`Ns1Provider`](super::Ns1Provider) instead.
+//!
+//! # Records
+//!
+//! An NS1 record holds all answers of a name and type, together with
+//! per-answer metadata and a filter chain used for traffic steering.
+//! [`DnsRecord`] and [`Answer`] keep every field returned by the API, so a
+//! record that is read, modified and written back keeps its configuration.
+//!
+//! # API Reference
+//!
+//! - [Zones](https://developer.ibm.com/apis/catalog/ns1--ibm-ns1-connect-api/api/API--ns1--ibm-ns1-connect-api#Zones)
+//! - [Records](https://developer.ibm.com/apis/catalog/ns1--ibm-ns1-connect-api/api/API--ns1--ibm-ns1-connect-api#Records)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::ns1::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-api-key")?;
+//!
+//! let record = client.get_record("example.com", "www.example.com", "A").await?;
+//! for answer in &record.answers {
+//!     println!("{} (meta: {:?})", answer.rdata(), answer.extra.get("meta"));
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::{Map, Value};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The NS1 API base URL.
+const NS1_API_URL: &str = "https://api.nsone.net";
+
+/// Errors that may occur when interacting with the NS1 API.
+#[derive(Debug, Error)]
+pub enum Ns1Error {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl Ns1Error {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Ns1Error::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// NS1 error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// An NS1 DNS zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsZone {
+    /// Zone ID.
+    #[serde(default)]
+    pub id: String,
+    /// Zone name (e.g., `example.com`).
+    pub zone: String,
+    /// Default TTL of the zone's records.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Negative caching TTL.
+    #[serde(default)]
+    pub nx_ttl: u64,
+    /// SOA responsible person mailbox.
+    #[serde(default)]
+    pub hostmaster: Option<String>,
+    /// Name servers serving the zone.
+    #[serde(default)]
+    pub dns_servers: Vec<String>,
+    /// Summaries of the zone's records; only returned when getting a single zone.
+    #[serde(default)]
+    pub records: Vec<ZoneRecord>,
+}
+
+/// Summary of a record, as listed in a zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ZoneRecord {
+    /// Record ID.
+    #[serde(default)]
+    pub id: String,
+    /// Fully qualified record name, without a trailing dot.
+    pub domain: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Answers as zone file rdata, without metadata.
+    #[serde(default)]
+    pub short_answers: Vec<String>,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Target of a linked record, whose answers are those of the target.
+    #[serde(default)]
+    pub link: Option<String>,
+}
+
+/// Request body for creating a zone.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateZoneRequest {
+    /// Zone name.
+    pub zone: String,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// An NS1 record with all of its answers.
+///
+/// Fields that are not modeled explicitly (record metadata, regions, `tier`,
+/// `use_client_subnet`, ...) are kept in [`extra`](Self::extra) and sent
+/// back unchanged when the record is written.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DnsRecord {
+    /// Record ID (not sent back on writes).
+    #[serde(default, skip_serializing)]
+    pub id: String,
+    /// Zone name.
+    pub zone: String,
+    /// Fully qualified record name, without a trailing dot.
+    pub domain: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Answers of the record.
+    #[serde(default)]
+    pub answers: Vec<Answer>,
+    /// Filter chain applied to the answers at query time.
+    #[serde(default)]
+    pub filters: Vec<Value>,
+    /// All other fields of the record.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl DnsRecord {
+    /// Creates a record without filters or metadata.
+    pub fn new(zone: &str, domain: &str, record_type: &str, ttl: u64) -> Self {
+        Self {
+            id: String::new(),
+            zone: zone.to_string(),
+            domain: domain.to_string(),
+            record_type: record_type.to_string(),
+            ttl,
+            answers: Vec::new(),
+            filters: Vec::new(),
+            extra: Map::new(),
+        }
+    }
+}
+
+/// An answer of an NS1 record.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Answer {
+    /// Rdata fields (e.g., `[10, "mail.example.com"]` for MX).
+    pub answer: Vec<Value>,
+    /// All other fields of the answer, such as `meta`, `region` and `id`.
+    #[serde(flatten)]
+    pub extra: Map<String, Value>,
+}
+
+impl Answer {
+    /// Creates an answer without metadata.
+    pub fn new(answer: Vec<Value>) -> Self {
+        Self {
+            answer,
+            extra: Map::new(),
+        }
+    }
+
+    /// Returns the rdata fields joined with spaces.
+    pub fn rdata(&self) -> String {
+        self.answer
+            .iter()
+            .map(|field| match field {
+                Value::String(s) => s.clone(),
+                other => other.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// NS1 API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new NS1 API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key with DNS zone and record permissions
+    pub fn new(api_key: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, NS1_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new NS1 API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key with DNS zone and record permissions
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, NS1_API_URL, config)
+    }
+
+    /// Creates a new NS1 API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers, or for
+    /// private NS1 deployments.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key with DNS zone and record permissions
+    /// * `base_url` - Custom base URL for the API (without `/v1`)
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        api_key: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut key_value = HeaderValue::from_str(api_key)?;
+        key_value.set_sensitive(true);
+        headers.insert("X-NSONE-Key", key_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`Ns1Error::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, Ns1Error> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => e.message,
+                Err(_) => text,
+            };
+            return Err(Ns1Error::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, Ns1Error> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists all zones of the account, without their records.
+    pub async fn list_zones(&self) -> Result<Vec<DnsZone>, Ns1Error> {
+        self.get("/v1/zones").await
+    }
+
+    /// Gets a zone, including summaries of its records.
+    pub async fn get_zone(&self, zone: &str) -> Result<DnsZone, Ns1Error> {
+        self.get(&format!("/v1/zones/{}", encode_component(zone)))
+            .await
+    }
+
+    /// Creates a zone.
+    pub async fn create_zone(&self, request: &CreateZoneRequest) -> Result<DnsZone, Ns1Error> {
+        let url = format!(
+            "{}/v1/zones/{}",
+            self.base_url,
+            encode_component(&request.zone)
+        );
+        let text = self.send(self.http_client.put(url).json(request)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a zone and all of its records.
+    pub async fn delete_zone(&self, zone: &str) -> Result<(), Ns1Error> {
+        let url = format!("{}/v1/zones/{}", self.base_url, encode_component(zone));
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Gets a record with all of its answers, metadata and filters.
+    pub async fn get_record(
+        &self,
+        zone: &str,
+        domain: &str,
+        record_type: &str,
+    ) -> Result<DnsRecord, Ns1Error> {
+        self.get(&record_path(zone, domain, record_type)).await
+    }
+
+    /// Creates a record.
+    pub async fn create_record(&self, record: &DnsRecord) -> Result<DnsRecord, Ns1Error> {
+        let url = format!(
+            "{}{}",
+            self.base_url,
+            record_path(&record.zone, &record.domain, &record.record_type)
+        );
+        let text = self.send(self.http_client.put(url).json(record)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Replaces the answers, filters and other fields of an existing record.
+    pub async fn update_record(&self, record: &DnsRecord) -> Result<DnsRecord, Ns1Error> {
+        let url = format!(
+            "{}{}",
+            self.base_url,
+            record_path(&record.zone, &record.domain, &record.record_type)
+        );
+        let text = self.send(self.http_client.post(url).json(record)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a record with all of its answers.
+    pub async fn delete_record(
+        &self,
+        zone: &str,
+        domain: &str,
+        record_type: &str,
+    ) -> Result<(), Ns1Error> {
+        let url = format!(
+            "{}{}",
+            self.base_url,
+            record_path(zone, domain, record_type)
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+/// Builds the `/v1/zones/{zone}/{domain}/{type}` path.
+fn record_path(zone: &str, domain: &str, record_type: &str) -> String {
+    format!(
+        "/v1/zones/{}/{}/{}",
+        encode_component(zone),
+        encode_component(domain),
+        encode_component(record_type)
+    )
+}
+
+/// Percent-encodes a path segment.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' | b'*' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
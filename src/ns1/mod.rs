@@ -0,0 +1,587 @@
+//! NS1 (IBM NS1 Connect) DNS provider implementation.
+//!
+//! This provider uses the NS1 REST API v1 with `X-NSONE-Key` authentication.
+//!
+//! # Authentication
+//!
+//! Requires an NS1 API key:
+//! - Create a key under "Account Settings" > "Users & Teams" > "API Keys"
+//! - The key needs the "Manage zones" DNS permission
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::ns1::Ns1Provider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = Ns1Provider::new("your-api-key")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Answer-based Records
+//!
+//! An NS1 record holds all answers of a name and type, and may carry
+//! per-answer metadata (regions, weights, up/down state, ...) and a filter
+//! chain for traffic steering. Each plain answer is mapped to one [`Record`]:
+//! creating a record adds an answer to the NS1 record of the same name and
+//! type (creating it if needed), and deleting a record removes the answer,
+//! deleting the NS1 record once it has no answers left. Metadata of the
+//! other answers, the filter chain and all other record fields are written
+//! back unchanged. All answers share one TTL, so creating a record also
+//! updates the TTL of its siblings.
+//!
+//! The full NS1 record can be read and written with
+//! [`Ns1Zone::get_record_set`] and [`Ns1Zone::update_record_set`].
+//!
+//! Record IDs use the format `"name/type/value"`, similar to the Hetzner
+//! provider, where the value is [`RecordData::get_value`].
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ALIAS, CAA, NAPTR, PTR and SSHFP, as [`RecordData::Other`] (CAA in zone
+//!   file format)
+//!
+//! # API Reference
+//!
+//! - [NS1 Connect API](https://developer.ibm.com/apis/catalog/ns1--ibm-ns1-connect-api/Introduction)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+use serde_json::Value;
+
+pub use api::{Client, Ns1Error};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ALIAS", "CAA", "NAPTR", "PTR", "SSHFP"];
+
+/// NS1 DNS provider.
+#[derive(Clone)]
+pub struct Ns1Provider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone managed by NS1.
+pub struct Ns1Zone {
+    api_client: Arc<Client>,
+    repr: api::DnsZone,
+}
+
+impl Ns1Provider {
+    /// Creates a new NS1 provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key with DNS zone and record permissions
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::ns1::Ns1Provider;
+    ///
+    /// let provider = Ns1Provider::new("your-api-key").unwrap();
+    /// ```
+    pub fn new(api_key: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(api_key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new NS1 provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key with DNS zone and record permissions
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::ns1::Ns1Provider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = Ns1Provider::with_config("your-api-key", config).unwrap();
+    /// ```
+    pub fn with_config(
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(api_key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new NS1 provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key with DNS zone and record permissions
+    /// * `base_url` - Custom base URL for the API (without `/v1`)
+    pub fn with_base_url(
+        api_key: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(api_key, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for Ns1Provider {
+    type Zone = Ns1Zone;
+    type CustomRetrieveError = Ns1Error;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(Ns1Zone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| Ns1Zone {
+                api_client: self.api_client.clone(),
+                repr: zone,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for Ns1Provider {
+    type CustomCreateError = Ns1Error;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let request = api::CreateZoneRequest {
+            zone: domain.trim_end_matches('.').to_string(),
+        };
+
+        let zone =
+            self.api_client
+                .create_zone(&request)
+                .await
+                .map_err(|err| match err.status() {
+                    Some(401 | 403) => CreateZoneError::Unauthorized,
+                    Some(400) => CreateZoneError::InvalidDomainName,
+                    _ => CreateZoneError::Custom(err),
+                })?;
+
+        Ok(Ns1Zone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+}
+
+impl DeleteZone for Ns1Provider {
+    type CustomDeleteError = Ns1Error;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteZoneError::NotFound,
+                Some(401 | 403) => DeleteZoneError::Unauthorized,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl Ns1Zone {
+    /// Returns the name servers serving the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.dns_servers
+    }
+
+    /// Returns the default TTL of the zone's records.
+    pub fn default_ttl(&self) -> u64 {
+        self.repr.ttl
+    }
+
+    /// Gets the full NS1 record of a host and type, including answer
+    /// metadata and filters.
+    pub async fn get_record_set(
+        &self,
+        host: &str,
+        typ: &str,
+    ) -> Result<api::DnsRecord, RetrieveRecordError<Ns1Error>> {
+        self.api_client
+            .get_record(&self.repr.zone, &self.fqdn(host), typ)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })
+    }
+
+    /// Writes a full NS1 record, as returned by [`get_record_set`](Self::get_record_set),
+    /// back to the zone, creating it if it does not exist.
+    pub async fn update_record_set(
+        &self,
+        record: &api::DnsRecord,
+    ) -> Result<api::DnsRecord, CreateRecordError<Ns1Error>> {
+        let map_err = |err: Ns1Error| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(400) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        match self.api_client.update_record(record).await {
+            Err(err) if err.status() == Some(404) => {
+                self.api_client.create_record(record).await.map_err(map_err)
+            }
+            result => result.map_err(map_err),
+        }
+    }
+
+    /// Converts a host into a fully qualified name without a trailing dot.
+    fn fqdn(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let zone = &self.repr.zone;
+        if host.is_empty() || host == "@" {
+            zone.clone()
+        } else if host.eq_ignore_ascii_case(zone) || host.ends_with(&format!(".{}", zone)) {
+            host.to_string()
+        } else {
+            format!("{}.{}", host, zone)
+        }
+    }
+
+    /// Converts a fully qualified name into a host relative to the zone.
+    fn host(&self, domain: &str) -> String {
+        let domain = domain.trim_end_matches('.');
+        let zone = &self.repr.zone;
+        if domain.eq_ignore_ascii_case(zone) {
+            "@".to_string()
+        } else if let Some(sub) = domain.strip_suffix(&format!(".{}", zone)) {
+            sub.to_string()
+        } else {
+            domain.to_string()
+        }
+    }
+
+    /// Converts all answers of a record into generic records.
+    fn records_from_answers<'a>(
+        &self,
+        domain: &str,
+        typ: &str,
+        ttl: u64,
+        answers: impl Iterator<Item = &'a str>,
+    ) -> Vec<Record> {
+        let host = self.host(domain);
+        answers
+            .map(|rdata| {
+                let data = record_data_from_rdata(typ, rdata);
+                Record {
+                    id: format!("{}/{}/{}", host, typ, data.get_value()),
+                    host: host.clone(),
+                    data,
+                    ttl,
+                }
+            })
+            .collect()
+    }
+}
+
+/// Converts answer rdata into generic record data.
+fn record_data_from_rdata(typ: &str, rdata: &str) -> RecordData {
+    match typ {
+        "TXT" => RecordData::TXT(rdata.to_string()),
+        "CAA" => {
+            let mut parts = rdata.splitn(3, ' ');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(flags), Some(tag), Some(value)) => RecordData::Other {
+                    typ: "CAA".to_string(),
+                    value: format!("{} {} \"{}\"", flags, tag, value.trim_matches('"')),
+                },
+                _ => RecordData::from_raw(typ, rdata),
+            }
+        }
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, rdata.trim_end_matches('.')),
+        _ => RecordData::from_raw(typ, rdata),
+    }
+}
+
+/// Converts generic record data into NS1 answer fields.
+fn answer_from_record_data(data: &RecordData) -> Result<Vec<Value>, CreateRecordError<Ns1Error>> {
+    let answer = match data {
+        RecordData::A(addr) => vec![Value::from(addr.to_string())],
+        RecordData::AAAA(addr) => vec![Value::from(addr.to_string())],
+        RecordData::CNAME(target) | RecordData::NS(target) => {
+            vec![Value::from(target.trim_end_matches('.'))]
+        }
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => vec![
+            Value::from(*priority),
+            Value::from(mail_server.trim_end_matches('.')),
+        ],
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => vec![
+            Value::from(*priority),
+            Value::from(*weight),
+            Value::from(*port),
+            Value::from(target.trim_end_matches('.')),
+        ],
+        RecordData::TXT(text) => vec![Value::from(text.as_str())],
+        RecordData::Other { typ, value } if typ == "CAA" => {
+            let mut parts = value.splitn(3, ' ');
+            match (parts.next(), parts.next(), parts.next()) {
+                (Some(flags), Some(tag), Some(value)) => vec![
+                    Value::from(
+                        flags
+                            .parse::<u8>()
+                            .map_err(|_| CreateRecordError::InvalidRecord)?,
+                    ),
+                    Value::from(tag),
+                    Value::from(value.trim_matches('"')),
+                ],
+                _ => return Err(CreateRecordError::InvalidRecord),
+            }
+        }
+        RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+            vec![Value::from(value.as_str())]
+        }
+        RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+    };
+    Ok(answer)
+}
+
+impl Zone for Ns1Zone {
+    type CustomRetrieveError = Ns1Error;
+
+    // Zones are addressed by name in API paths; the internal ID is not used.
+    #[allow(clippy::misnamed_getters)]
+    fn id(&self) -> &str {
+        &self.repr.zone
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.zone
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(&self.repr.zone)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(zone
+            .records
+            .iter()
+            .flat_map(|record| {
+                self.records_from_answers(
+                    &record.domain,
+                    &record.record_type,
+                    record.ttl,
+                    record.short_answers.iter().map(String::as_str),
+                )
+            })
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let record = self.get_record_set(parts[0], parts[1]).await?;
+        let rdata: Vec<String> = record.answers.iter().map(api::Answer::rdata).collect();
+
+        self.records_from_answers(
+            &record.domain,
+            &record.record_type,
+            record.ttl,
+            rdata.iter().map(String::as_str),
+        )
+        .into_iter()
+        .find(|record| record.id == record_id)
+        .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for Ns1Zone {
+    type CustomCreateError = Ns1Error;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        let answer = api::Answer::new(answer_from_record_data(data)?);
+        let domain = self.fqdn(host);
+        let value = record_data_from_rdata(typ, &answer.rdata()).get_value();
+
+        let map_err = |err: Ns1Error| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(400) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        match self
+            .api_client
+            .get_record(&self.repr.zone, &domain, typ)
+            .await
+        {
+            Ok(mut record) => {
+                if record.answers.iter().any(|existing| {
+                    record_data_from_rdata(typ, &existing.rdata()).get_value() == value
+                }) {
+                    return Err(CreateRecordError::InvalidRecord);
+                }
+                record.answers.push(answer);
+                record.ttl = ttl;
+                self.api_client
+                    .update_record(&record)
+                    .await
+                    .map_err(map_err)?;
+            }
+            Err(err) if err.status() == Some(404) => {
+                let mut record = api::DnsRecord::new(&self.repr.zone, &domain, typ, ttl);
+                record.answers.push(answer);
+                self.api_client
+                    .create_record(&record)
+                    .await
+                    .map_err(map_err)?;
+            }
+            Err(err) => return Err(map_err(err)),
+        }
+
+        let host = self.host(&domain);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, value),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for Ns1Zone {
+    type CustomDeleteError = Ns1Error;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, value) = (parts[0], parts[1], parts[2]);
+
+        let map_err = |err: Ns1Error| match err.status() {
+            Some(404) => DeleteRecordError::NotFound,
+            Some(401 | 403) => DeleteRecordError::Unauthorized,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let mut record = self
+            .api_client
+            .get_record(&self.repr.zone, &self.fqdn(host), typ)
+            .await
+            .map_err(map_err)?;
+
+        let count = record.answers.len();
+        record
+            .answers
+            .retain(|answer| record_data_from_rdata(typ, &answer.rdata()).get_value() != value);
+        if record.answers.len() == count {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        if record.answers.is_empty() {
+            self.api_client
+                .delete_record(&record.zone, &record.domain, &record.record_type)
+                .await
+                .map_err(map_err)
+        } else {
+            self.api_client
+                .update_record(&record)
+                .await
+                .map(|_| ())
+                .map_err(map_err)
+        }
+    }
+}
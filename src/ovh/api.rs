@@ -0,0 +1,599 @@
+//! Low-level OVHcloud API client.
+//!
+//! This module provides direct access to the OVHcloud API v1 DNS zone
+//! endpoints (`/domain/zone`).
+//! For most use cases, prefer using [`OvhProvider`](super::OvhProvider) instead.
+//!
+//! # Request Signing
+//!
+//! Every authenticated request carries the application key, the consumer key,
+//! a timestamp and a signature:
+//!
+//! ```text
+//! X-Ovh-Signature: "$1$" + hex(sha1(AS + "+" + CK + "+" + METHOD + "+" + URL + "+" + BODY + "+" + TIMESTAMP))
+//! ```
+//!
+//! The timestamp must be close to the API server's clock, so the client
+//! queries `/auth/time` once and keeps the offset to the local clock.
+//!
+//! # API Reference
+//!
+//! - [First steps with the API](https://help.ovhcloud.com/csm/en-gb-api-getting-started-ovhcloud-api)
+//! - [API console](https://eu.api.ovh.com/console/?section=%2Fdomain)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::ovh::api::{Client, Credentials, Endpoint};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+//! let client = Client::new(Endpoint::OvhEu, credentials)?;
+//!
+//! for zone in client.list_zones().await? {
+//!     println!("Zone: {}", zone);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::sync::{Arc, Mutex};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use reqwest::header::CONTENT_TYPE;
+use reqwest::Method;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use sha1::{Digest, Sha1};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// Errors that may occur when interacting with the OVHcloud API.
+#[derive(Debug, Error)]
+pub enum OvhError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error class (e.g., `Client::NotFound`), if provided.
+        class: Option<String>,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl OvhError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            OvhError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error class if this is an API error.
+    pub fn class(&self) -> Option<&str> {
+        match self {
+            OvhError::Api { class, .. } => class.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+/// OVHcloud error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(default)]
+    class: Option<String>,
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// OVHcloud API endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Endpoint {
+    /// OVHcloud Europe (`ovh-eu`).
+    #[default]
+    OvhEu,
+    /// OVHcloud Canada (`ovh-ca`).
+    OvhCa,
+    /// OVHcloud US (`ovh-us`).
+    OvhUs,
+}
+
+impl Endpoint {
+    /// Returns the API base URL of the endpoint.
+    pub fn base_url(&self) -> &'static str {
+        match self {
+            Endpoint::OvhEu => "https://eu.api.ovh.com/1.0",
+            Endpoint::OvhCa => "https://ca.api.ovh.com/1.0",
+            Endpoint::OvhUs => "https://api.us.ovhcloud.com/1.0",
+        }
+    }
+
+    /// Returns the endpoint name as used by the official SDKs (e.g., `ovh-eu`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Endpoint::OvhEu => "ovh-eu",
+            Endpoint::OvhCa => "ovh-ca",
+            Endpoint::OvhUs => "ovh-us",
+        }
+    }
+
+    /// Looks up an endpoint by name (`ovh-eu`, `ovh-ca` or `ovh-us`).
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "ovh-eu" => Some(Endpoint::OvhEu),
+            "ovh-ca" => Some(Endpoint::OvhCa),
+            "ovh-us" => Some(Endpoint::OvhUs),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Endpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Application and consumer keys used to sign requests.
+#[derive(Clone)]
+pub struct Credentials {
+    /// Application key.
+    pub application_key: String,
+    /// Application secret.
+    pub application_secret: String,
+    /// Consumer key, bound to the granted access rules.
+    pub consumer_key: String,
+}
+
+impl std::fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Credentials")
+            .field("application_key", &self.application_key)
+            .field("application_secret", &"<redacted>")
+            .field("consumer_key", &"<redacted>")
+            .finish()
+    }
+}
+
+impl Credentials {
+    /// Creates credentials from an application key, application secret and
+    /// consumer key.
+    pub fn new(application_key: &str, application_secret: &str, consumer_key: &str) -> Self {
+        Self {
+            application_key: application_key.to_string(),
+            application_secret: application_secret.to_string(),
+            consumer_key: consumer_key.to_string(),
+        }
+    }
+
+    /// Reads credentials from the `OVH_APPLICATION_KEY`,
+    /// `OVH_APPLICATION_SECRET` and `OVH_CONSUMER_KEY` environment variables.
+    ///
+    /// Returns `None` if any of the variables is missing.
+    pub fn from_env() -> Option<Self> {
+        Some(Self::new(
+            &std::env::var("OVH_APPLICATION_KEY").ok()?,
+            &std::env::var("OVH_APPLICATION_SECRET").ok()?,
+            &std::env::var("OVH_CONSUMER_KEY").ok()?,
+        ))
+    }
+
+    /// Computes the `X-Ovh-Signature` header value of a request.
+    pub fn sign(&self, method: &str, url: &str, body: &str, timestamp: i64) -> String {
+        let mut hasher = Sha1::new();
+        hasher.update(
+            format!(
+                "{}+{}+{}+{}+{}+{}",
+                self.application_secret, self.consumer_key, method, url, body, timestamp
+            )
+            .as_bytes(),
+        );
+        format!("$1${}", hex::encode(hasher.finalize()))
+    }
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A DNS zone hosted by OVHcloud.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Zone {
+    /// Zone name (e.g., `example.com`).
+    pub name: String,
+    /// Whether DNSSEC is supported for the zone.
+    #[serde(default)]
+    pub dnssec_supported: bool,
+    /// Whether the zone is served by the anycast DNS offer.
+    #[serde(default)]
+    pub has_dns_anycast: bool,
+    /// Authoritative name servers.
+    #[serde(default)]
+    pub name_servers: Vec<String>,
+    /// Last modification date.
+    #[serde(default)]
+    pub last_update: Option<String>,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ZoneRecord {
+    /// Record ID.
+    pub id: u64,
+    /// Zone name.
+    #[serde(default)]
+    pub zone: String,
+    /// Sub-domain relative to the zone (empty for the apex).
+    #[serde(default)]
+    pub sub_domain: String,
+    /// Record type.
+    pub field_type: String,
+    /// Record value in zone file format (e.g., `10 mail.example.com.` for MX).
+    pub target: String,
+    /// TTL in seconds (0 for the zone default).
+    #[serde(default)]
+    pub ttl: u64,
+}
+
+/// Request body for creating a record.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateRecordRequest {
+    /// Record type.
+    pub field_type: String,
+    /// Sub-domain relative to the zone (empty for the apex).
+    pub sub_domain: String,
+    /// Record value in zone file format.
+    pub target: String,
+    /// TTL in seconds (0 for the zone default).
+    pub ttl: u64,
+}
+
+/// Request body for updating a record.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UpdateRecordRequest {
+    /// Sub-domain relative to the zone (empty for the apex).
+    pub sub_domain: String,
+    /// Record value in zone file format.
+    pub target: String,
+    /// TTL in seconds (0 for the zone default).
+    pub ttl: u64,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// OVHcloud API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    credentials: Credentials,
+    /// Offset between the API server clock and the local clock, once known.
+    time_delta: Arc<Mutex<Option<i64>>>,
+}
+
+impl Client {
+    /// Creates a new OVHcloud API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - API endpoint of the account's region
+    /// * `credentials` - Application and consumer keys
+    pub fn new(
+        endpoint: Endpoint,
+        credentials: Credentials,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            credentials,
+            endpoint.base_url(),
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new OVHcloud API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - API endpoint of the account's region
+    /// * `credentials` - Application and consumer keys
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        endpoint: Endpoint,
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, endpoint.base_url(), config)
+    }
+
+    /// Creates a new OVHcloud API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Application and consumer keys
+    /// * `base_url` - Custom base URL for the API (including `/1.0`)
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            credentials,
+            time_delta: Arc::new(Mutex::new(None)),
+        })
+    }
+
+    /// Returns the current API server time (`/auth/time`).
+    pub async fn server_time(&self) -> Result<i64, OvhError> {
+        let url = format!("{}/auth/time", self.base_url);
+        let response = self.http_client.get(url).send().await?;
+        let text = Self::check(response).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Returns the timestamp to sign requests with, synchronizing the clock
+    /// offset with the API server on first use.
+    async fn timestamp(&self) -> Result<i64, OvhError> {
+        let local = unix_time();
+        if let Some(delta) = *self.time_delta.lock().unwrap() {
+            return Ok(local + delta);
+        }
+
+        let server = self.server_time().await?;
+        let delta = server - unix_time();
+        *self.time_delta.lock().unwrap() = Some(delta);
+        Ok(unix_time() + delta)
+    }
+
+    /// Forgets the clock offset so that the next request synchronizes again.
+    pub fn invalidate_time_delta(&self) {
+        *self.time_delta.lock().unwrap() = None;
+    }
+
+    /// Turns non-success responses into [`OvhError::Api`].
+    async fn check(response: reqwest::Response) -> Result<String, OvhError> {
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (class, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.class, e.message),
+                Err(_) => (None, text),
+            };
+            return Err(OvhError::Api {
+                status: status.as_u16(),
+                class,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a signed request.
+    ///
+    /// A request rejected because its timestamp is out of range is retried
+    /// once after synchronizing the clock again.
+    async fn send(&self, method: Method, path: &str, body: &str) -> Result<String, OvhError> {
+        let url = format!("{}{}", self.base_url, path);
+        let mut retried = false;
+
+        loop {
+            let timestamp = self.timestamp().await?;
+            let signature = self
+                .credentials
+                .sign(method.as_str(), &url, body, timestamp);
+
+            let mut request = self
+                .http_client
+                .request(method.clone(), &url)
+                .header("X-Ovh-Application", &self.credentials.application_key)
+                .header("X-Ovh-Consumer", &self.credentials.consumer_key)
+                .header("X-Ovh-Timestamp", timestamp.to_string())
+                .header("X-Ovh-Signature", signature);
+            if !body.is_empty() {
+                request = request
+                    .header(CONTENT_TYPE, "application/json")
+                    .body(body.to_string());
+            }
+
+            match Self::check(request.send().await?).await {
+                Err(OvhError::Api {
+                    status: 400,
+                    ref message,
+                    ..
+                }) if !retried && message.contains("out of time") => {
+                    self.invalidate_time_delta();
+                    retried = true;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Make a signed GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, OvhError> {
+        let text = self.send(Method::GET, path, "").await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists the names of all zones of the account.
+    pub async fn list_zones(&self) -> Result<Vec<String>, OvhError> {
+        self.get("/domain/zone").await
+    }
+
+    /// Gets a zone by name.
+    pub async fn get_zone(&self, zone: &str) -> Result<Zone, OvhError> {
+        self.get(&format!("/domain/zone/{}", zone)).await
+    }
+
+    /// Applies pending record changes to the zone served by the name servers.
+    pub async fn refresh_zone(&self, zone: &str) -> Result<(), OvhError> {
+        self.send(Method::POST, &format!("/domain/zone/{}/refresh", zone), "")
+            .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists record IDs of a zone, optionally filtered by type and sub-domain.
+    pub async fn list_record_ids(
+        &self,
+        zone: &str,
+        field_type: Option<&str>,
+        sub_domain: Option<&str>,
+    ) -> Result<Vec<u64>, OvhError> {
+        let mut query = Vec::new();
+        if let Some(field_type) = field_type {
+            query.push(format!("fieldType={}", encode_component(field_type)));
+        }
+        if let Some(sub_domain) = sub_domain {
+            query.push(format!("subDomain={}", encode_component(sub_domain)));
+        }
+
+        let mut path = format!("/domain/zone/{}/record", zone);
+        if !query.is_empty() {
+            path.push('?');
+            path.push_str(&query.join("&"));
+        }
+        self.get(&path).await
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(&self, zone: &str, record_id: u64) -> Result<ZoneRecord, OvhError> {
+        self.get(&format!("/domain/zone/{}/record/{}", zone, record_id))
+            .await
+    }
+
+    /// Creates a record.
+    ///
+    /// The change is only served after [`refresh_zone`](Self::refresh_zone).
+    pub async fn create_record(
+        &self,
+        zone: &str,
+        request: &CreateRecordRequest,
+    ) -> Result<ZoneRecord, OvhError> {
+        let body = serde_json::to_string(request)?;
+        let text = self
+            .send(
+                Method::POST,
+                &format!("/domain/zone/{}/record", zone),
+                &body,
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Updates a record.
+    ///
+    /// The change is only served after [`refresh_zone`](Self::refresh_zone).
+    pub async fn update_record(
+        &self,
+        zone: &str,
+        record_id: u64,
+        request: &UpdateRecordRequest,
+    ) -> Result<(), OvhError> {
+        let body = serde_json::to_string(request)?;
+        self.send(
+            Method::PUT,
+            &format!("/domain/zone/{}/record/{}", zone, record_id),
+            &body,
+        )
+        .await?;
+        Ok(())
+    }
+
+    /// Deletes a record.
+    ///
+    /// The change is only served after [`refresh_zone`](Self::refresh_zone).
+    pub async fn delete_record(&self, zone: &str, record_id: u64) -> Result<(), OvhError> {
+        self.send(
+            Method::DELETE,
+            &format!("/domain/zone/{}/record/{}", zone, record_id),
+            "",
+        )
+        .await?;
+        Ok(())
+    }
+}
+
+/// Returns the local Unix time in seconds.
+fn unix_time() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or_default()
+}
+
+/// Percent-encodes a query string component.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,483 @@
+//! OVHcloud DNS provider implementation.
+//!
+//! This provider uses the OVHcloud API v1 (`/domain/zone`) with signed
+//! application key requests.
+//!
+//! # Authentication
+//!
+//! Requires an application key, application secret and consumer key:
+//! - Create them at `https://{eu,ca}.api.ovh.com/createToken/` (or
+//!   `https://api.us.ovhcloud.com/createToken/`)
+//! - Grant at least `GET`, `POST`, `PUT` and `DELETE` on `/domain/zone/*`
+//!
+//! Requests are signed with the application secret and consumer key; the
+//! client synchronizes its clock with the API server (`/auth/time`) before
+//! the first signed request.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::ovh::{Credentials, Endpoint, OvhProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+//! let provider = OvhProvider::new(Endpoint::OvhEu, credentials)?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record (the zone is refreshed automatically)
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 3600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Endpoints
+//!
+//! Accounts live in one of three API regions, selected with [`Endpoint`]:
+//! `ovh-eu` (default), `ovh-ca` and `ovh-us`.
+//!
+//! # Zone Refresh
+//!
+//! Record changes are staged by OVHcloud until the zone is refreshed.
+//! [`create_record`](crate::CreateRecord::create_record),
+//! [`delete_record`](crate::DeleteRecord::delete_record) and
+//! [`OvhZone::update_record`] call `/domain/zone/{zone}/refresh` after each
+//! change.
+//!
+//! # Zone Management
+//!
+//! DNS zones are ordered as OVHcloud products, so this provider does not
+//! implement [`CreateZone`](crate::CreateZone) or
+//! [`DeleteZone`](crate::DeleteZone).
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA, DKIM, DMARC, DNAME, LOC, NAPTR, PTR, SPF, SSHFP and TLSA, as
+//!   [`RecordData::Other`] with the value in zone file format
+//!
+//! A TTL of 0 means the zone's default TTL.
+//!
+//! # API Reference
+//!
+//! - [API console](https://eu.api.ovh.com/console/?section=%2Fdomain)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, Credentials, Endpoint, OvhError};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Record types that can be passed through as [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &[
+    "CAA", "DKIM", "DMARC", "DNAME", "LOC", "NAPTR", "PTR", "SPF", "SSHFP", "TLSA",
+];
+
+/// OVHcloud DNS provider.
+#[derive(Clone)]
+pub struct OvhProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone hosted by OVHcloud.
+pub struct OvhZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+}
+
+impl OvhProvider {
+    /// Creates a new OVHcloud provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - API endpoint of the account's region
+    /// * `credentials` - Application and consumer keys
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::ovh::{Credentials, Endpoint, OvhProvider};
+    ///
+    /// let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    /// let provider = OvhProvider::new(Endpoint::OvhCa, credentials).unwrap();
+    /// ```
+    pub fn new(
+        endpoint: Endpoint,
+        credentials: Credentials,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(endpoint, credentials)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new OVHcloud provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `endpoint` - API endpoint of the account's region
+    /// * `credentials` - Application and consumer keys
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::ovh::{Credentials, Endpoint, OvhProvider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = OvhProvider::with_config(Endpoint::OvhEu, credentials, config).unwrap();
+    /// ```
+    pub fn with_config(
+        endpoint: Endpoint,
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(endpoint, credentials, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new OVHcloud provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Application and consumer keys
+    /// * `base_url` - Custom base URL for the API (including `/1.0`)
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for OvhProvider {
+    type Zone = OvhZone;
+    type CustomRetrieveError = OvhError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(OvhZone {
+            api_client: self.api_client.clone(),
+            repr: zone,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let names = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        let mut zones = Vec::with_capacity(names.len());
+        for name in names {
+            zones.push(self.get_zone(&name).await?);
+        }
+
+        Ok(zones)
+    }
+}
+
+impl OvhZone {
+    /// Returns the authoritative name servers of the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.name_servers
+    }
+
+    /// Returns whether DNSSEC is supported for the zone.
+    pub fn dnssec_supported(&self) -> bool {
+        self.repr.dnssec_supported
+    }
+
+    /// Applies staged record changes to the served zone.
+    ///
+    /// Record changes made through this provider already refresh the zone;
+    /// this is useful after changes made through the low-level [`Client`].
+    pub async fn refresh(&self) -> Result<(), OvhError> {
+        self.api_client.refresh_zone(&self.repr.name).await
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID,
+    /// and refreshes the zone.
+    ///
+    /// The record type cannot be changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<OvhError>> {
+        let id = record_id
+            .parse::<u64>()
+            .map_err(|_| CreateRecordError::InvalidRecord)?;
+        let request = api::UpdateRecordRequest {
+            sub_domain: self.sub_domain(host),
+            target: target_from_record_data(data)?,
+            ttl,
+        };
+
+        self.api_client
+            .update_record(&self.repr.name, id, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400 | 404) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+        self.refresh().await.map_err(CreateRecordError::Custom)?;
+
+        Ok(Record {
+            id: record_id.to_string(),
+            host: host_from_sub_domain(&request.sub_domain),
+            data: data.clone(),
+            ttl,
+        })
+    }
+
+    /// Converts a host into a sub-domain relative to the zone (empty for the apex).
+    fn sub_domain(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(&self.repr.name) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.name)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+}
+
+/// Converts a sub-domain into a host ("@" for the apex).
+fn host_from_sub_domain(sub_domain: &str) -> String {
+    if sub_domain.is_empty() {
+        "@".to_string()
+    } else {
+        sub_domain.to_string()
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::ZoneRecord) -> Record {
+    let data = match RecordData::from_raw(&record.field_type, &record.target) {
+        RecordData::CNAME(target) => RecordData::CNAME(target.trim_end_matches('.').to_string()),
+        RecordData::NS(target) => RecordData::NS(target.trim_end_matches('.').to_string()),
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => RecordData::MX {
+            priority,
+            mail_server: mail_server.trim_end_matches('.').to_string(),
+        },
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target: target.trim_end_matches('.').to_string(),
+        },
+        RecordData::TXT(text) => RecordData::TXT(unquote_txt(&text)),
+        data => data,
+    };
+
+    Record {
+        id: record.id.to_string(),
+        host: host_from_sub_domain(&record.sub_domain),
+        data,
+        ttl: record.ttl,
+    }
+}
+
+/// Converts generic record data into an OVHcloud target.
+///
+/// Host names are made fully qualified, since OVHcloud treats names without a
+/// trailing dot as relative to the zone.
+fn target_from_record_data(data: &RecordData) -> Result<String, CreateRecordError<OvhError>> {
+    Ok(match data {
+        RecordData::A(_) | RecordData::AAAA(_) => data.get_value(),
+        RecordData::CNAME(target) | RecordData::NS(target) => fqdn(target),
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => format!("{} {}", priority, fqdn(mail_server)),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => format!("{} {} {} {}", priority, weight, port, fqdn(target)),
+        RecordData::TXT(text) => quote_txt(text),
+        RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+            value.clone()
+        }
+        RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+    })
+}
+
+/// Appends the trailing dot to a host name.
+fn fqdn(name: &str) -> String {
+    if name.ends_with('.') {
+        name.to_string()
+    } else {
+        format!("{}.", name)
+    }
+}
+
+impl Zone for OvhZone {
+    type CustomRetrieveError = OvhError;
+
+    fn id(&self) -> &str {
+        &self.repr.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let map_err = |err: OvhError| match err.status() {
+            Some(404) => RetrieveRecordError::NotFound,
+            Some(401 | 403) => RetrieveRecordError::Unauthorized,
+            _ => RetrieveRecordError::Custom(err),
+        };
+
+        let ids = self
+            .api_client
+            .list_record_ids(&self.repr.name, None, None)
+            .await
+            .map_err(map_err)?;
+
+        let mut records = Vec::with_capacity(ids.len());
+        for id in ids {
+            let record = self
+                .api_client
+                .get_record(&self.repr.name, id)
+                .await
+                .map_err(map_err)?;
+            records.push(record_from_api(record));
+        }
+
+        Ok(records)
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let id = record_id
+            .parse::<u64>()
+            .map_err(|_| RetrieveRecordError::NotFound)?;
+
+        let record = self
+            .api_client
+            .get_record(&self.repr.name, id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for OvhZone {
+    type CustomCreateError = OvhError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = api::CreateRecordRequest {
+            field_type: data.get_type().to_string(),
+            sub_domain: self.sub_domain(host),
+            target: target_from_record_data(data)?,
+            ttl,
+        };
+
+        let record = self
+            .api_client
+            .create_record(&self.repr.name, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+        self.refresh().await.map_err(CreateRecordError::Custom)?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl DeleteRecord for OvhZone {
+    type CustomDeleteError = OvhError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let id = record_id
+            .parse::<u64>()
+            .map_err(|_| DeleteRecordError::NotFound)?;
+
+        self.api_client
+            .delete_record(&self.repr.name, id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })?;
+        self.refresh().await.map_err(DeleteRecordError::Custom)
+    }
+}
@@ -0,0 +1,455 @@
+//! Low-level Porkbun API client.
+//!
+//! This module provides direct access to the Porkbun JSON API (v3).
+//! For most use cases, prefer using [`PorkbunProvider`](super::PorkbunProvider) instead.
+//!
+//! Every endpoint is a `POST` whose JSON body carries the `apikey` and
+//! `secretapikey` credentials next to the request parameters.
+//!
+//! # API Reference
+//!
+//! - [Porkbun API](https://porkbun.com/api/json/v3/documentation)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::porkbun::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("pk1_your_api_key", "sk1_your_secret_api_key")?;
+//!
+//! for record in client.retrieve_records("example.com").await? {
+//!     println!("{} {} {}", record.name, record.record_type, record.content);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::{de::DeserializeOwned, Deserialize, Deserializer, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Porkbun API base URL.
+const PORKBUN_API_URL: &str = "https://api.porkbun.com/api/json/v3";
+
+/// Number of domains returned per page by `domain/listAll`.
+const LIST_ALL_PAGE_SIZE: usize = 1000;
+
+/// Errors that may occur when interacting with the Porkbun API.
+#[derive(Debug, Error)]
+pub enum PorkbunError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl PorkbunError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            PorkbunError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Response envelope shared by all endpoints.
+#[derive(Debug, Deserialize)]
+struct StatusResponse {
+    status: String,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+/// Request body with the API credentials added to the endpoint parameters.
+#[derive(Serialize)]
+struct Authenticated<'a, B: Serialize> {
+    apikey: &'a str,
+    secretapikey: &'a str,
+    #[serde(flatten)]
+    body: B,
+}
+
+/// Porkbun returns most numbers as strings; accept both representations.
+fn string_or_number<'de, D: Deserializer<'de>>(deserializer: D) -> Result<String, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Raw {
+        String(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(match Raw::deserialize(deserializer)? {
+        Raw::String(value) => value,
+        Raw::Number(value) => value.to_string(),
+    })
+}
+
+fn optional_string_or_number<'de, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    struct Wrapper(#[serde(deserialize_with = "string_or_number")] String);
+
+    Ok(Option::<Wrapper>::deserialize(deserializer)?.map(|Wrapper(value)| value))
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A domain registered in the Porkbun account.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain name (e.g., `example.com`).
+    pub domain: String,
+    /// Registration status (e.g., `ACTIVE`).
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Top-level domain.
+    #[serde(default)]
+    pub tld: Option<String>,
+    /// Registration date (`YYYY-MM-DD HH:MM:SS`).
+    #[serde(default, rename = "createDate")]
+    pub create_date: Option<String>,
+    /// Expiration date (`YYYY-MM-DD HH:MM:SS`).
+    #[serde(default, rename = "expireDate")]
+    pub expire_date: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ListAllRequest {
+    start: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ListAllResponse {
+    #[serde(default)]
+    domains: Vec<Domain>,
+}
+
+// =============================================================================
+// DNS Record Types
+// =============================================================================
+
+/// A DNS record of a Porkbun domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsRecord {
+    /// Record ID.
+    #[serde(deserialize_with = "string_or_number")]
+    pub id: String,
+    /// Fully-qualified record name (e.g., `www.example.com`).
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record content; for SRV records `weight port target`.
+    pub content: String,
+    /// TTL in seconds.
+    #[serde(deserialize_with = "string_or_number")]
+    pub ttl: String,
+    /// Priority for MX and SRV records.
+    #[serde(default, deserialize_with = "optional_string_or_number")]
+    pub prio: Option<String>,
+    /// Free-form notes attached to the record.
+    #[serde(default)]
+    pub notes: Option<String>,
+}
+
+/// Request body for creating a DNS record.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateRecordRequest {
+    /// Subdomain relative to the domain; empty for the apex.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record content.
+    pub content: String,
+    /// TTL in seconds (the API enforces a minimum of 600).
+    pub ttl: String,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prio: Option<String>,
+}
+
+/// Request body for editing all records matching a subdomain and type.
+#[derive(Debug, Clone, Serialize)]
+pub struct EditRecordRequest {
+    /// New record content.
+    pub content: String,
+    /// New TTL in seconds.
+    pub ttl: String,
+    /// New priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prio: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct EmptyRequest {}
+
+#[derive(Debug, Deserialize)]
+struct RecordsResponse {
+    #[serde(default)]
+    records: Vec<DnsRecord>,
+}
+
+#[derive(Debug, Deserialize)]
+struct CreateRecordResponse {
+    #[serde(deserialize_with = "string_or_number")]
+    id: String,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Porkbun API client.
+#[derive(Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    api_key: String,
+    secret_api_key: String,
+}
+
+impl std::fmt::Debug for Client {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Client")
+            .field("base_url", &self.base_url)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Client {
+    /// Creates a new Porkbun API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key (`pk1_...`)
+    /// * `secret_api_key` - Secret API key (`sk1_...`)
+    pub fn new(
+        api_key: &str,
+        secret_api_key: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(
+            api_key,
+            secret_api_key,
+            PORKBUN_API_URL,
+            HttpClientConfig::default(),
+        )
+    }
+
+    /// Creates a new Porkbun API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key (`pk1_...`)
+    /// * `secret_api_key` - Secret API key (`sk1_...`)
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        api_key: &str,
+        secret_api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, secret_api_key, PORKBUN_API_URL, config)
+    }
+
+    /// Creates a new Porkbun API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key (`pk1_...`)
+    /// * `secret_api_key` - Secret API key (`sk1_...`)
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        api_key: &str,
+        secret_api_key: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            api_key: api_key.to_string(),
+            secret_api_key: secret_api_key.to_string(),
+        })
+    }
+
+    /// Sends an authenticated POST request and returns the response body.
+    ///
+    /// Non-success HTTP statuses and `"status": "ERROR"` responses are turned
+    /// into [`PorkbunError::Api`].
+    async fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        path: &str,
+        body: B,
+    ) -> Result<T, PorkbunError> {
+        let url = format!("{}{}", self.base_url, path);
+        let body = Authenticated {
+            apikey: &self.api_key,
+            secretapikey: &self.secret_api_key,
+            body,
+        };
+
+        let response = self.http_client.post(url).json(&body).send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        match serde_json::from_str::<StatusResponse>(&text) {
+            Ok(envelope) if status.is_success() && envelope.status == "SUCCESS" => {}
+            Ok(envelope) => {
+                return Err(PorkbunError::Api {
+                    status: status.as_u16(),
+                    message: envelope.message.unwrap_or(envelope.status),
+                })
+            }
+            Err(_) if status.is_success() => {}
+            Err(_) => {
+                return Err(PorkbunError::Api {
+                    status: status.as_u16(),
+                    message: text,
+                })
+            }
+        }
+
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, PorkbunError> {
+        let mut domains = Vec::new();
+
+        loop {
+            let request = ListAllRequest {
+                start: domains.len().to_string(),
+            };
+            let page: ListAllResponse = self.post("/domain/listAll", request).await?;
+            let count = page.domains.len();
+            domains.extend(page.domains);
+
+            if count < LIST_ALL_PAGE_SIZE {
+                break;
+            }
+        }
+
+        Ok(domains)
+    }
+
+    // =========================================================================
+    // DNS Record APIs
+    // =========================================================================
+
+    /// Retrieves all records of a domain.
+    pub async fn retrieve_records(&self, domain: &str) -> Result<Vec<DnsRecord>, PorkbunError> {
+        let response: RecordsResponse = self
+            .post(&format!("/dns/retrieve/{}", domain), EmptyRequest {})
+            .await?;
+        Ok(response.records)
+    }
+
+    /// Retrieves a single record by ID; returns `None` if it does not exist.
+    pub async fn retrieve_record(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<Option<DnsRecord>, PorkbunError> {
+        let response: RecordsResponse = self
+            .post(
+                &format!("/dns/retrieve/{}/{}", domain, record_id),
+                EmptyRequest {},
+            )
+            .await?;
+        Ok(response.records.into_iter().next())
+    }
+
+    /// Creates a record and returns its ID.
+    pub async fn create_record(
+        &self,
+        domain: &str,
+        request: &CreateRecordRequest,
+    ) -> Result<String, PorkbunError> {
+        let response: CreateRecordResponse = self
+            .post(&format!("/dns/create/{}", domain), request)
+            .await?;
+        Ok(response.id)
+    }
+
+    /// Deletes a record by ID.
+    pub async fn delete_record(&self, domain: &str, record_id: &str) -> Result<(), PorkbunError> {
+        let _: StatusResponse = self
+            .post(
+                &format!("/dns/delete/{}/{}", domain, record_id),
+                EmptyRequest {},
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// Edits all records matching a subdomain and type.
+    ///
+    /// `subdomain` is relative to the domain; pass an empty string for the apex.
+    pub async fn edit_by_name_type(
+        &self,
+        domain: &str,
+        record_type: &str,
+        subdomain: &str,
+        request: &EditRecordRequest,
+    ) -> Result<(), PorkbunError> {
+        let path = if subdomain.is_empty() {
+            format!("/dns/editByNameType/{}/{}", domain, record_type)
+        } else {
+            format!(
+                "/dns/editByNameType/{}/{}/{}",
+                domain, record_type, subdomain
+            )
+        };
+        let _: StatusResponse = self.post(&path, request).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,428 @@
+//! Porkbun DNS provider implementation.
+//!
+//! This provider uses the Porkbun JSON API (v3), which authenticates every
+//! request with an API key and secret API key sent in the POST body.
+//!
+//! # Authentication
+//!
+//! Requires a Porkbun API key pair:
+//! - Create the keys at: <https://porkbun.com/account/api>
+//! - API access must be enabled per domain in the domain management page
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::porkbun::PorkbunProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = PorkbunProvider::new("pk1_your_api_key", "sk1_your_secret_api_key")?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone IDs
+//!
+//! Porkbun identifies domains by name, so the zone ID is the domain name.
+//! Zones are looked up in the account's domain listing, as the API has no
+//! endpoint for retrieving a single domain.
+//!
+//! # Record Names and Priorities
+//!
+//! Record hosts are returned relative to the domain (`@` for the apex).
+//! Porkbun keeps the priority of MX and SRV records in a separate `prio`
+//! field; SRV content holds the remaining `weight port target`.
+//!
+//! Porkbun enforces a minimum TTL of 600 seconds; lower values are raised by
+//! the API.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, ALIAS, CAA, CNAME, HTTPS, MX, NS, SRV, SSHFP, SVCB, TLSA and TXT
+//!
+//! # API Reference
+//!
+//! - [Porkbun API](https://porkbun.com/api/json/v3/documentation)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, PorkbunError};
+
+use crate::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, HttpClientConfig, Provider,
+    Record, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "ALIAS", "CAA", "CNAME", "HTTPS", "MX", "NS", "SRV", "SSHFP", "SVCB", "TLSA",
+    "TXT",
+];
+
+/// TTL reported for records whose TTL cannot be parsed (the Porkbun minimum).
+const DEFAULT_TTL: u64 = 600;
+
+/// Porkbun DNS provider.
+#[derive(Clone)]
+pub struct PorkbunProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain managed by Porkbun.
+pub struct PorkbunZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl PorkbunProvider {
+    /// Creates a new Porkbun provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key (`pk1_...`)
+    /// * `secret_api_key` - Secret API key (`sk1_...`)
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::porkbun::PorkbunProvider;
+    ///
+    /// let provider = PorkbunProvider::new("pk1_your_api_key", "sk1_your_secret_api_key").unwrap();
+    /// ```
+    pub fn new(api_key: &str, secret_api_key: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(api_key, secret_api_key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Porkbun provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key (`pk1_...`)
+    /// * `secret_api_key` - Secret API key (`sk1_...`)
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::porkbun::PorkbunProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     PorkbunProvider::with_config("pk1_your_api_key", "sk1_your_secret_api_key", config)
+    ///         .unwrap();
+    /// ```
+    pub fn with_config(
+        api_key: &str,
+        secret_api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(api_key, secret_api_key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Porkbun provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - API key (`pk1_...`)
+    /// * `secret_api_key` - Secret API key (`sk1_...`)
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        api_key: &str,
+        secret_api_key: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(
+            api_key,
+            secret_api_key,
+            base_url,
+            HttpClientConfig::default(),
+        )?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for PorkbunProvider {
+    type Zone = PorkbunZone;
+    type CustomRetrieveError = PorkbunError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let name = zone_id.trim_end_matches('.');
+
+        self.list_zones()
+            .await?
+            .into_iter()
+            .find(|zone| zone.repr.domain.eq_ignore_ascii_case(name))
+            .ok_or(RetrieveZoneError::NotFound)
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| PorkbunZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl PorkbunZone {
+    /// Returns the registration status of the domain, if reported.
+    pub fn status(&self) -> Option<&str> {
+        self.repr.status.as_deref()
+    }
+
+    /// Returns the expiration date of the domain, if reported.
+    pub fn expire_date(&self) -> Option<&str> {
+        self.repr.expire_date.as_deref()
+    }
+
+    /// Replaces the content of all records matching a host and type.
+    ///
+    /// This maps to Porkbun's `editByNameType` endpoint and keeps the record
+    /// IDs intact.
+    pub async fn edit_by_name_type(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<(), PorkbunError> {
+        let (content, prio) = content_and_prio(data);
+        let request = api::EditRecordRequest {
+            content,
+            ttl: ttl.to_string(),
+            prio,
+        };
+
+        self.api_client
+            .edit_by_name_type(
+                &self.repr.domain,
+                data.get_type(),
+                &self.subdomain(host),
+                &request,
+            )
+            .await
+    }
+
+    /// Converts a host into the subdomain expected by the API (empty for the apex).
+    fn subdomain(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(&self.repr.domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts an API record into a generic record.
+    fn record_from_api(&self, record: api::DnsRecord) -> Record {
+        let prio = record
+            .prio
+            .as_deref()
+            .and_then(|prio| prio.parse::<u16>().ok())
+            .unwrap_or(0);
+
+        let data = match record.record_type.as_str() {
+            "MX" => RecordData::MX {
+                priority: prio,
+                mail_server: record.content.trim_end_matches('.').to_string(),
+            },
+            "SRV" => RecordData::from_raw("SRV", &format!("{} {}", prio, record.content)),
+            typ => RecordData::from_raw(typ, &record.content),
+        };
+
+        let host = match self.subdomain(&record.name) {
+            sub if sub.is_empty() => "@".to_string(),
+            sub => sub,
+        };
+
+        Record {
+            id: record.id,
+            host,
+            data,
+            ttl: record.ttl.parse().unwrap_or(DEFAULT_TTL),
+        }
+    }
+}
+
+/// Splits generic record data into Porkbun `content` and `prio` fields.
+fn content_and_prio(data: &RecordData) -> (String, Option<String>) {
+    match data {
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => (mail_server.clone(), Some(priority.to_string())),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => (
+            format!("{} {} {}", weight, port, target),
+            Some(priority.to_string()),
+        ),
+        _ => (data.get_value(), None),
+    }
+}
+
+impl Zone for PorkbunZone {
+    type CustomRetrieveError = PorkbunError;
+
+    fn id(&self) -> &str {
+        &self.repr.domain
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .retrieve_records(&self.repr.domain)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records
+            .into_iter()
+            .map(|record| self.record_from_api(record))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        if record_id.parse::<u64>().is_err() {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let record = self
+            .api_client
+            .retrieve_record(&self.repr.domain, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        Ok(self.record_from_api(record))
+    }
+}
+
+impl CreateRecord for PorkbunZone {
+    type CustomCreateError = PorkbunError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let (content, prio) = content_and_prio(data);
+        let request = api::CreateRecordRequest {
+            name: self.subdomain(host),
+            record_type: typ.to_string(),
+            content,
+            ttl: ttl.to_string(),
+            prio,
+        };
+
+        let id = self
+            .api_client
+            .create_record(&self.repr.domain, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(Record {
+            id,
+            host: if request.name.is_empty() {
+                "@".to_string()
+            } else {
+                request.name
+            },
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for PorkbunZone {
+    type CustomDeleteError = PorkbunError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        if record_id.parse::<u64>().is_err() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        self.api_client
+            .delete_record(&self.repr.domain, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
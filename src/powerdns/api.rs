@@ -0,0 +1,407 @@
+//! Low-level PowerDNS Authoritative HTTP API client.
+//!
+//! This module provides direct access to the PowerDNS Authoritative Server
+//! HTTP API under `/api/v1/servers/{server_id}`.
+//! For most use cases, prefer using [`PowerDnsProvider`](super::PowerDnsProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [HTTP API](https://doc.powerdns.com/authoritative/http-api/index.html)
+//! - [Zones](https://doc.powerdns.com/authoritative/http-api/zone.html)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::powerdns::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("http://127.0.0.1:8081", "your-api-key")?;
+//!
+//! for zone in client.list_zones().await? {
+//!     println!("Zone: {} (kind: {})", zone.name, zone.kind);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The server ID used by a standard PowerDNS installation.
+pub const DEFAULT_SERVER_ID: &str = "localhost";
+
+/// Errors that may occur when interacting with the PowerDNS API.
+#[derive(Debug, Error)]
+pub enum PowerDnsError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message from the `error` field of the response.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl PowerDnsError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            PowerDnsError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// PowerDNS error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A PowerDNS zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Zone {
+    /// Zone ID (usually the canonical name, e.g. `example.com.`).
+    pub id: String,
+    /// Canonical zone name with trailing dot.
+    pub name: String,
+    /// Zone kind (`Native`, `Master`, `Slave`, ...).
+    #[serde(default)]
+    pub kind: String,
+    /// SOA serial.
+    #[serde(default)]
+    pub serial: u32,
+    /// Whether DNSSEC signing is enabled.
+    #[serde(default)]
+    pub dnssec: bool,
+    /// Resource record sets; only present when retrieving a single zone.
+    #[serde(default)]
+    pub rrsets: Vec<RRset>,
+}
+
+/// Request body for creating a zone.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateZoneRequest {
+    /// Canonical zone name with trailing dot.
+    pub name: String,
+    /// Zone kind (`Native`, `Master`, `Slave`, ...).
+    pub kind: String,
+    /// Name servers to create NS records for at the apex.
+    pub nameservers: Vec<String>,
+    /// Primary servers, for `Slave` zones.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub masters: Vec<String>,
+}
+
+impl CreateZoneRequest {
+    /// Creates a request for a `Native` zone without name servers.
+    pub fn native(name: &str) -> Self {
+        Self {
+            name: canonical(name),
+            kind: "Native".to_string(),
+            nameservers: Vec::new(),
+            masters: Vec::new(),
+        }
+    }
+}
+
+// =============================================================================
+// RRset Types
+// =============================================================================
+
+/// A resource record set: all records sharing a name and type.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RRset {
+    /// Fully-qualified owner name with trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// TTL shared by all records in the set.
+    pub ttl: u64,
+    /// The records in this set.
+    #[serde(default)]
+    pub records: Vec<RecordEntry>,
+}
+
+/// A single record within an RRset.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct RecordEntry {
+    /// Record content in zone-file presentation format.
+    pub content: String,
+    /// Whether the record is disabled.
+    #[serde(default)]
+    pub disabled: bool,
+}
+
+impl RecordEntry {
+    /// Creates an enabled record entry.
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            disabled: false,
+        }
+    }
+}
+
+/// Change type for an RRset PATCH.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChangeType {
+    /// Replace all records of the RRset with the given records.
+    Replace,
+    /// Delete the whole RRset.
+    Delete,
+}
+
+/// A single RRset change in a PATCH request.
+#[derive(Debug, Clone, Serialize)]
+pub struct RRsetChange {
+    /// Fully-qualified owner name with trailing dot.
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Change to apply.
+    pub changetype: ChangeType,
+    /// TTL for the RRset (ignored for deletions).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u64>,
+    /// New records of the RRset (ignored for deletions).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub records: Vec<RecordEntry>,
+}
+
+impl RRsetChange {
+    /// Creates a change that replaces the RRset with the given records.
+    pub fn replace(name: &str, record_type: &str, ttl: u64, records: Vec<RecordEntry>) -> Self {
+        Self {
+            name: name.to_string(),
+            record_type: record_type.to_string(),
+            changetype: ChangeType::Replace,
+            ttl: Some(ttl),
+            records,
+        }
+    }
+
+    /// Creates a change that deletes the RRset.
+    pub fn delete(name: &str, record_type: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            record_type: record_type.to_string(),
+            changetype: ChangeType::Delete,
+            ttl: None,
+            records: Vec::new(),
+        }
+    }
+}
+
+/// Request body for patching RRsets.
+#[derive(Debug, Serialize)]
+struct PatchRequest<'a> {
+    rrsets: &'a [RRsetChange],
+}
+
+/// Returns the canonical form of a name (with trailing dot).
+pub(crate) fn canonical(name: &str) -> String {
+    if name.ends_with('.') {
+        name.to_string()
+    } else {
+        format!("{}.", name)
+    }
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// PowerDNS Authoritative API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+    server_id: String,
+}
+
+impl Client {
+    /// Creates a new PowerDNS API client for the `localhost` server.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the PowerDNS webserver (e.g., `http://127.0.0.1:8081`)
+    /// * `api_key` - Value of the `api-key` setting
+    pub fn new(
+        base_url: &str,
+        api_key: &str,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_config(base_url, api_key, HttpClientConfig::default())
+    }
+
+    /// Creates a new PowerDNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the PowerDNS webserver
+    /// * `api_key` - Value of the `api-key` setting
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        base_url: &str,
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut key_value = HeaderValue::from_str(api_key)?;
+        key_value.set_sensitive(true);
+        headers.insert("X-API-Key", key_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            server_id: DEFAULT_SERVER_ID.to_string(),
+        })
+    }
+
+    /// Sets the server ID used in request paths.
+    ///
+    /// Defaults to [`DEFAULT_SERVER_ID`].
+    pub fn with_server_id(mut self, server_id: impl Into<String>) -> Self {
+        self.server_id = server_id.into();
+        self
+    }
+
+    /// Returns the server ID used in request paths.
+    pub fn server_id(&self) -> &str {
+        &self.server_id
+    }
+
+    /// Builds the URL for a path below `/api/v1/servers/{server_id}`.
+    fn url(&self, path: &str) -> String {
+        format!(
+            "{}/api/v1/servers/{}{}",
+            self.base_url, self.server_id, path
+        )
+    }
+
+    /// Sends a request and turns non-success responses into [`PowerDnsError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, PowerDnsError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = serde_json::from_str::<ErrorResponse>(&text)
+                .map(|e| e.error)
+                .unwrap_or(text);
+            return Err(PowerDnsError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, PowerDnsError> {
+        let text = self.send(self.http_client.get(self.url(path))).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists all zones on the server (without RRsets).
+    pub async fn list_zones(&self) -> Result<Vec<Zone>, PowerDnsError> {
+        self.get("/zones").await
+    }
+
+    /// Gets a zone including all of its RRsets.
+    ///
+    /// # Arguments
+    ///
+    /// * `zone_id` - Zone ID, e.g. `example.com.`
+    pub async fn get_zone(&self, zone_id: &str) -> Result<Zone, PowerDnsError> {
+        self.get(&format!("/zones/{}", zone_id)).await
+    }
+
+    /// Creates a new zone.
+    pub async fn create_zone(&self, request: &CreateZoneRequest) -> Result<Zone, PowerDnsError> {
+        let text = self
+            .send(self.http_client.post(self.url("/zones")).json(request))
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a zone and all of its records.
+    pub async fn delete_zone(&self, zone_id: &str) -> Result<(), PowerDnsError> {
+        self.send(
+            self.http_client
+                .delete(self.url(&format!("/zones/{}", zone_id))),
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // RRset APIs
+    // =========================================================================
+
+    /// Applies RRset changes to a zone in a single PATCH request.
+    ///
+    /// All changes are applied atomically by the server.
+    pub async fn patch_rrsets(
+        &self,
+        zone_id: &str,
+        changes: &[RRsetChange],
+    ) -> Result<(), PowerDnsError> {
+        self.send(
+            self.http_client
+                .patch(self.url(&format!("/zones/{}", zone_id)))
+                .json(&PatchRequest { rrsets: changes }),
+        )
+        .await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,507 @@
+//! PowerDNS Authoritative Server provider implementation.
+//!
+//! This provider uses the PowerDNS Authoritative HTTP API with API key
+//! authentication.
+//!
+//! # Authentication
+//!
+//! Requires the webserver and API to be enabled on the PowerDNS server
+//! (`api=yes`, `webserver=yes`) and the configured `api-key`, which is sent in
+//! the `X-API-Key` header.
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::powerdns::PowerDnsProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = PowerDnsProvider::new("http://127.0.0.1:8081", "your-api-key")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # RRset-based API
+//!
+//! PowerDNS manages records as RRsets (all records sharing a name and type)
+//! and only supports replacing or deleting a whole RRset. Creating or deleting
+//! a single record therefore reads the current RRset and writes it back with
+//! `changetype: REPLACE`, or `DELETE` once the last record is removed. All
+//! records in an RRset share one TTL, so creating a record also updates the
+//! TTL of its siblings.
+//!
+//! Record IDs use the format `"name/type/content"` where `content` is the
+//! PowerDNS presentation value, similar to the Hetzner provider.
+//!
+//! # Server ID
+//!
+//! Requests go to `/api/v1/servers/localhost` by default. Use
+//! [`PowerDnsProvider::with_server_id`] for setups with a different server ID.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CNAME, MX, NS, TXT, SRV, CAA, PTR, DS, TLSA, SSHFP, NAPTR,
+//! HTTPS, SVCB, DNAME, LOC and SPF
+//!
+//! # API Reference
+//!
+//! - [HTTP API](https://doc.powerdns.com/authoritative/http-api/index.html)
+//! - [Zones](https://doc.powerdns.com/authoritative/http-api/zone.html)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, PowerDnsError};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "CNAME", "MX", "NS", "TXT", "SRV", "CAA", "PTR", "DS", "TLSA", "SSHFP", "NAPTR",
+    "HTTPS", "SVCB", "DNAME", "LOC", "SPF",
+];
+
+/// PowerDNS Authoritative Server provider.
+#[derive(Clone)]
+pub struct PowerDnsProvider {
+    api_client: Arc<Client>,
+}
+
+/// A DNS zone hosted on a PowerDNS server.
+pub struct PowerDnsZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+    /// Zone name without the trailing dot.
+    domain: String,
+}
+
+impl PowerDnsProvider {
+    /// Creates a new PowerDNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the PowerDNS webserver (e.g., `http://127.0.0.1:8081`)
+    /// * `api_key` - Value of the `api-key` setting
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::powerdns::PowerDnsProvider;
+    ///
+    /// let provider = PowerDnsProvider::new("http://127.0.0.1:8081", "your-api-key").unwrap();
+    /// ```
+    pub fn new(base_url: &str, api_key: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(base_url, api_key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new PowerDNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `base_url` - URL of the PowerDNS webserver
+    /// * `api_key` - Value of the `api-key` setting
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::powerdns::PowerDnsProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     PowerDnsProvider::with_config("http://10.0.0.53:8081", "your-api-key", config).unwrap();
+    /// ```
+    pub fn with_config(
+        base_url: &str,
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(base_url, api_key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Sets the server ID used in API paths (defaults to `localhost`).
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::powerdns::PowerDnsProvider;
+    ///
+    /// let provider = PowerDnsProvider::new("http://127.0.0.1:8081", "your-api-key")
+    ///     .unwrap()
+    ///     .with_server_id("ns1");
+    /// ```
+    pub fn with_server_id(self, server_id: impl Into<String>) -> Self {
+        let api_client = (*self.api_client).clone().with_server_id(server_id);
+        Self {
+            api_client: Arc::new(api_client),
+        }
+    }
+}
+
+impl Provider for PowerDnsProvider {
+    type Zone = PowerDnsZone;
+    type CustomRetrieveError = PowerDnsError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .api_client
+            .get_zone(&api::canonical(zone_id))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404 | 422) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(PowerDnsZone::from_api(self.api_client.clone(), zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| PowerDnsZone::from_api(self.api_client.clone(), zone))
+            .collect())
+    }
+}
+
+impl CreateZone for PowerDnsProvider {
+    type CustomCreateError = PowerDnsError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let zone = self
+            .api_client
+            .create_zone(&api::CreateZoneRequest::native(domain))
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateZoneError::Unauthorized,
+                Some(422) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(PowerDnsZone::from_api(self.api_client.clone(), zone))
+    }
+}
+
+impl DeleteZone for PowerDnsProvider {
+    type CustomDeleteError = PowerDnsError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_zone(&api::canonical(zone_id))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404 | 422) => DeleteZoneError::NotFound,
+                Some(401 | 403) => DeleteZoneError::Unauthorized,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl PowerDnsZone {
+    /// Creates a new PowerDnsZone from API response data.
+    fn from_api(api_client: Arc<Client>, zone: api::Zone) -> Self {
+        let domain = zone.name.trim_end_matches('.').to_string();
+        Self {
+            api_client,
+            repr: zone,
+            domain,
+        }
+    }
+
+    /// Returns the zone kind (`Native`, `Master`, `Slave`, ...).
+    pub fn kind(&self) -> &str {
+        &self.repr.kind
+    }
+
+    /// Returns the SOA serial reported when the zone was retrieved.
+    pub fn serial(&self) -> u32 {
+        self.repr.serial
+    }
+
+    /// Fetches the current RRsets of the zone.
+    async fn fetch_rrsets(&self) -> Result<Vec<api::RRset>, PowerDnsError> {
+        Ok(self.api_client.get_zone(&self.repr.id).await?.rrsets)
+    }
+
+    /// Converts a host relative to the zone into a canonical owner name.
+    fn owner_name(&self, host: &str) -> String {
+        if host == "@" || host.is_empty() {
+            self.repr.name.clone()
+        } else if host.trim_end_matches('.') == self.domain
+            || host
+                .trim_end_matches('.')
+                .ends_with(&format!(".{}", self.domain))
+        {
+            api::canonical(host)
+        } else {
+            format!("{}.{}", host, self.repr.name)
+        }
+    }
+
+    /// Converts a canonical owner name into a host relative to the zone.
+    fn host(&self, name: &str) -> String {
+        let name = name.trim_end_matches('.');
+        if name == self.domain {
+            "@".to_string()
+        } else if let Some(sub) = name.strip_suffix(&format!(".{}", self.domain)) {
+            sub.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Converts all records of an RRset into generic records.
+    fn records_from_rrset(&self, rrset: &api::RRset) -> Vec<Record> {
+        let host = self.host(&rrset.name);
+        rrset
+            .records
+            .iter()
+            .map(|entry| Record {
+                id: format!("{}/{}/{}", host, rrset.record_type, entry.content),
+                host: host.clone(),
+                data: record_data_from_content(&rrset.record_type, &entry.content),
+                ttl: rrset.ttl,
+            })
+            .collect()
+    }
+}
+
+impl Zone for PowerDnsZone {
+    type CustomRetrieveError = PowerDnsError;
+
+    fn id(&self) -> &str {
+        &self.repr.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .fetch_rrsets()
+            .await
+            .map_err(|err| match err.status() {
+                Some(404 | 422) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets
+            .iter()
+            .flat_map(|rrset| self.records_from_rrset(rrset))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/content"
+        if record_id.splitn(3, '/').count() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        self.list_records()
+            .await?
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for PowerDnsZone {
+    type CustomCreateError = PowerDnsError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.owner_name(host);
+        let content = content_from_record_data(data);
+
+        let map_err = |err: PowerDnsError| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(422) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        // REPLACE overwrites the whole RRset, so merge with the existing records.
+        let mut records = self
+            .fetch_rrsets()
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .find(|rrset| rrset.name == name && rrset.record_type == typ)
+            .map(|rrset| rrset.records)
+            .unwrap_or_default();
+
+        if records.iter().any(|entry| entry.content == content) {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+        records.push(api::RecordEntry::new(content.clone()));
+
+        self.api_client
+            .patch_rrsets(
+                &self.repr.id,
+                &[api::RRsetChange::replace(&name, typ, ttl, records)],
+            )
+            .await
+            .map_err(map_err)?;
+
+        let host = self.host(&name);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, content),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for PowerDnsZone {
+    type CustomDeleteError = PowerDnsError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/content"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, content) = (parts[0], parts[1], parts[2]);
+        let name = self.owner_name(host);
+
+        let map_err = |err: PowerDnsError| match err.status() {
+            Some(404 | 422) => DeleteRecordError::NotFound,
+            Some(401 | 403) => DeleteRecordError::Unauthorized,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let rrset = self
+            .fetch_rrsets()
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .find(|rrset| rrset.name == name && rrset.record_type == typ)
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let remaining: Vec<_> = rrset
+            .records
+            .iter()
+            .filter(|entry| entry.content != content)
+            .cloned()
+            .collect();
+        if remaining.len() == rrset.records.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        let change = if remaining.is_empty() {
+            api::RRsetChange::delete(&name, typ)
+        } else {
+            api::RRsetChange::replace(&name, typ, rrset.ttl, remaining)
+        };
+
+        self.api_client
+            .patch_rrsets(&self.repr.id, &[change])
+            .await
+            .map_err(map_err)
+    }
+}
+
+/// Converts PowerDNS record content into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT character strings are unquoted
+/// and concatenated.
+fn record_data_from_content(typ: &str, content: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, content.trim_end_matches('.')),
+        "TXT" => RecordData::TXT(unquote_txt(content)),
+        _ => RecordData::from_raw(typ, content),
+    }
+}
+
+/// Converts [`RecordData`] into PowerDNS record content.
+///
+/// Host names are made absolute and TXT values are quoted.
+fn content_from_record_data(data: &RecordData) -> String {
+    match data {
+        RecordData::CNAME(target) => api::canonical(target),
+        RecordData::NS(ns) => api::canonical(ns),
+        RecordData::MX {
+            priority,
+            mail_server,
+        } => format!("{} {}", priority, api::canonical(mail_server)),
+        RecordData::SRV {
+            priority,
+            weight,
+            port,
+            target,
+        } => format!(
+            "{} {} {} {}",
+            priority,
+            weight,
+            port,
+            api::canonical(target)
+        ),
+        RecordData::TXT(txt) => quote_txt(txt),
+        _ => data.get_value(),
+    }
+}
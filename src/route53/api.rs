@@ -0,0 +1,943 @@
+//! Low-level Amazon Route 53 API client.
+//!
+//! This module provides direct access to the Route 53 REST/XML API, signing
+//! every request with AWS Signature Version 4.
+//! For most use cases, prefer using [`Route53Provider`](super::Route53Provider) instead.
+//!
+//! # API Reference
+//!
+//! - [Route 53 API Reference](https://docs.aws.amazon.com/Route53/latest/APIReference/Welcome.html)
+//! - [ChangeResourceRecordSets](https://docs.aws.amazon.com/Route53/latest/APIReference/API_ChangeResourceRecordSets.html)
+//! - [Signature Version 4](https://docs.aws.amazon.com/IAM/latest/UserGuide/reference_sigv.html)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::route53::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new(Credentials::new("AKIA...", "secret"))?;
+//!
+//! for zone in client.list_hosted_zones().await? {
+//!     println!("{} ({}, private: {})", zone.name, zone.id, zone.private_zone);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use quick_xml::escape::escape;
+use thiserror::Error;
+
+use crate::signing::{canonical_query, hmac_sha256, hmac_sha256_hex, sha256_hex};
+use crate::xml::Element;
+use crate::HttpClientConfig;
+
+/// The Route 53 API endpoint.
+const ROUTE53_API_URL: &str = "https://route53.amazonaws.com";
+
+/// API version used as the path prefix.
+const API_VERSION: &str = "2013-04-01";
+
+/// XML namespace of request bodies.
+const XMLNS: &str = "https://route53.amazonaws.com/doc/2013-04-01/";
+
+/// Route 53 is a global service signed in `us-east-1`.
+const SIGNING_REGION: &str = "us-east-1";
+
+/// Service name for signature calculation.
+const SERVICE: &str = "route53";
+
+/// Errors that may occur when interacting with the Route 53 API.
+#[derive(Debug, Error)]
+pub enum Route53Error {
+    /// The API returned an error response.
+    #[error("API error ({status}) {code}: {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Route 53 error code (e.g., `NoSuchHostedZone`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// The response could not be parsed.
+    #[error("Parse error: {0}")]
+    Parse(String),
+}
+
+impl Route53Error {
+    /// Returns the Route 53 error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Route53Error::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            Route53Error::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+// =============================================================================
+// Credentials and Signing
+// =============================================================================
+
+/// AWS credentials used to sign requests.
+#[derive(Clone)]
+pub struct Credentials {
+    /// Access key ID.
+    pub access_key_id: String,
+    /// Secret access key.
+    pub secret_access_key: String,
+    /// Session token for temporary (STS) credentials.
+    pub session_token: Option<String>,
+}
+
+impl Credentials {
+    /// Creates static credentials from an access key pair.
+    pub fn new(access_key_id: impl Into<String>, secret_access_key: impl Into<String>) -> Self {
+        Self {
+            access_key_id: access_key_id.into(),
+            secret_access_key: secret_access_key.into(),
+            session_token: None,
+        }
+    }
+
+    /// Adds a session token, as issued with temporary credentials.
+    pub fn with_session_token(mut self, session_token: impl Into<String>) -> Self {
+        self.session_token = Some(session_token.into());
+        self
+    }
+
+    /// Reads credentials from `AWS_ACCESS_KEY_ID`, `AWS_SECRET_ACCESS_KEY`
+    /// and the optional `AWS_SESSION_TOKEN` environment variables.
+    pub fn from_env() -> Option<Self> {
+        let access_key_id = std::env::var("AWS_ACCESS_KEY_ID").ok()?;
+        let secret_access_key = std::env::var("AWS_SECRET_ACCESS_KEY").ok()?;
+        let session_token = std::env::var("AWS_SESSION_TOKEN").ok();
+
+        Some(Self {
+            access_key_id,
+            secret_access_key,
+            session_token,
+        })
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key_id", &self.access_key_id)
+            .field("secret_access_key", &"<redacted>")
+            .field(
+                "session_token",
+                &self.session_token.as_ref().map(|_| "<redacted>"),
+            )
+            .finish()
+    }
+}
+
+/// A request to be signed with AWS Signature Version 4.
+#[derive(Debug, Clone)]
+pub struct SigningRequest<'a> {
+    /// HTTP method (e.g., `GET`).
+    pub method: &'a str,
+    /// Host header value (including a non-default port).
+    pub host: &'a str,
+    /// URI path, already percent-encoded.
+    pub path: &'a str,
+    /// Canonical query string (sorted and percent-encoded), or empty.
+    pub query: &'a str,
+    /// Additional headers to sign as lowercase `(name, value)` pairs.
+    ///
+    /// `x-amz-date` must be included.
+    pub headers: &'a [(&'a str, &'a str)],
+    /// Request body.
+    pub payload: &'a [u8],
+    /// Signing region.
+    pub region: &'a str,
+    /// Signing service name.
+    pub service: &'a str,
+    /// Request timestamp in `YYYYMMDDTHHMMSSZ` format.
+    pub amz_date: &'a str,
+}
+
+/// Computes the SigV4 `Authorization` header value for a request.
+pub fn sign_v4(credentials: &Credentials, request: &SigningRequest<'_>) -> String {
+    // Step 1: Build canonical request
+    let mut headers: Vec<(&str, &str)> = vec![("host", request.host)];
+    headers.extend_from_slice(request.headers);
+    headers.sort_by(|a, b| a.0.cmp(b.0));
+
+    let canonical_headers: String = headers
+        .iter()
+        .map(|(name, value)| format!("{}:{}\n", name, value.trim()))
+        .collect();
+    let signed_headers = headers
+        .iter()
+        .map(|(name, _)| *name)
+        .collect::<Vec<_>>()
+        .join(";");
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        request.path,
+        request.query,
+        canonical_headers,
+        signed_headers,
+        sha256_hex(request.payload)
+    );
+
+    // Step 2: Build string to sign
+    let algorithm = "AWS4-HMAC-SHA256";
+    let date = &request.amz_date[..8];
+    let credential_scope = format!(
+        "{}/{}/{}/aws4_request",
+        date, request.region, request.service
+    );
+
+    let string_to_sign = format!(
+        "{}\n{}\n{}\n{}",
+        algorithm,
+        request.amz_date,
+        credential_scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+
+    // Step 3: Calculate signature
+    let secret_date = hmac_sha256(
+        format!("AWS4{}", credentials.secret_access_key).as_bytes(),
+        date,
+    );
+    let secret_region = hmac_sha256(&secret_date, request.region);
+    let secret_service = hmac_sha256(&secret_region, request.service);
+    let secret_signing = hmac_sha256(&secret_service, "aws4_request");
+    let signature = hmac_sha256_hex(&secret_signing, &string_to_sign);
+
+    // Step 4: Build authorization header
+    format!(
+        "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+        algorithm, credentials.access_key_id, credential_scope, signed_headers, signature
+    )
+}
+
+/// Returns the current time in SigV4 `YYYYMMDDTHHMMSSZ` format.
+fn amz_date_now() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+// =============================================================================
+// Hosted Zone Types
+// =============================================================================
+
+/// A Route 53 hosted zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HostedZone {
+    /// Zone ID without the `/hostedzone/` prefix (e.g., `Z1D633PJN98FT9`).
+    pub id: String,
+    /// Zone name with trailing dot.
+    pub name: String,
+    /// Caller reference used when the zone was created.
+    pub caller_reference: String,
+    /// Zone comment, if any.
+    pub comment: Option<String>,
+    /// Whether this is a private hosted zone associated with VPCs.
+    pub private_zone: bool,
+    /// Number of record sets in the zone.
+    pub record_count: Option<u64>,
+}
+
+impl HostedZone {
+    fn from_element(e: &Element) -> Self {
+        let config = e.child("Config");
+        Self {
+            id: strip_id_prefix(e.child_text("Id").unwrap_or_default()).to_string(),
+            name: e.child_text("Name").unwrap_or_default().to_string(),
+            caller_reference: e
+                .child_text("CallerReference")
+                .unwrap_or_default()
+                .to_string(),
+            comment: config
+                .and_then(|c| c.child_text("Comment"))
+                .map(str::to_string),
+            private_zone: config
+                .and_then(|c| c.child_text("PrivateZone"))
+                .is_some_and(|v| v == "true"),
+            record_count: e
+                .child_text("ResourceRecordSetCount")
+                .and_then(|v| v.parse().ok()),
+        }
+    }
+}
+
+/// A VPC to associate with a private hosted zone.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Vpc {
+    /// VPC region (e.g., `eu-central-1`).
+    pub region: String,
+    /// VPC ID (e.g., `vpc-0123456789abcdef0`).
+    pub id: String,
+}
+
+/// Request for creating a hosted zone.
+#[derive(Debug, Clone)]
+pub struct CreateHostedZoneRequest {
+    /// Zone name.
+    pub name: String,
+    /// Unique string identifying the request, for safe retries.
+    pub caller_reference: String,
+    /// Optional comment.
+    pub comment: Option<String>,
+    /// VPC to associate; creates a private hosted zone when set.
+    pub vpc: Option<Vpc>,
+}
+
+impl CreateHostedZoneRequest {
+    /// Creates a request for a public hosted zone.
+    pub fn public(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            caller_reference: caller_reference(name),
+            comment: None,
+            vpc: None,
+        }
+    }
+
+    /// Creates a request for a private hosted zone associated with a VPC.
+    pub fn private(name: &str, vpc: Vpc) -> Self {
+        Self {
+            vpc: Some(vpc),
+            ..Self::public(name)
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!(
+            r#"<?xml version="1.0" encoding="UTF-8"?><CreateHostedZoneRequest xmlns="{}"><Name>{}</Name><CallerReference>{}</CallerReference>"#,
+            XMLNS,
+            escape(&self.name),
+            escape(&self.caller_reference)
+        );
+        if let Some(vpc) = &self.vpc {
+            xml.push_str(&format!(
+                "<VPC><VPCRegion>{}</VPCRegion><VPCId>{}</VPCId></VPC>",
+                escape(&vpc.region),
+                escape(&vpc.id)
+            ));
+        }
+        xml.push_str("<HostedZoneConfig>");
+        if let Some(comment) = &self.comment {
+            xml.push_str(&format!("<Comment>{}</Comment>", escape(comment)));
+        }
+        xml.push_str(&format!(
+            "<PrivateZone>{}</PrivateZone></HostedZoneConfig></CreateHostedZoneRequest>",
+            self.vpc.is_some()
+        ));
+        xml
+    }
+}
+
+/// Builds a caller reference unique per call.
+fn caller_reference(name: &str) -> String {
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!("manydns-{}-{}", name.trim_end_matches('.'), nanos)
+}
+
+fn strip_id_prefix(id: &str) -> &str {
+    id.rsplit('/').next().unwrap_or(id)
+}
+
+/// Decodes the `\ddd` octal escapes Route 53 uses in record names
+/// (e.g., `\052` for `*`).
+pub fn decode_name(name: &str) -> String {
+    let bytes = name.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] == b'\\'
+            && i + 3 < bytes.len()
+            && bytes[i + 1..i + 4]
+                .iter()
+                .all(|b| (b'0'..=b'7').contains(b))
+        {
+            let value = bytes[i + 1..i + 4]
+                .iter()
+                .fold(0u32, |acc, b| acc * 8 + (b - b'0') as u32);
+            if let Ok(b) = u8::try_from(value) {
+                out.push(b);
+                i += 4;
+                continue;
+            }
+        }
+        out.push(bytes[i]);
+        i += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+// =============================================================================
+// Record Set Types
+// =============================================================================
+
+/// Alias target of an alias record set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AliasTarget {
+    /// Hosted zone ID of the target.
+    pub hosted_zone_id: String,
+    /// DNS name of the target.
+    pub dns_name: String,
+    /// Whether Route 53 evaluates the target's health.
+    pub evaluate_target_health: bool,
+}
+
+/// A Route 53 resource record set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ResourceRecordSet {
+    /// Owner name with trailing dot.
+    pub name: String,
+    /// Record type.
+    pub record_type: String,
+    /// TTL in seconds (absent for alias record sets).
+    pub ttl: Option<u64>,
+    /// Record values in presentation format.
+    pub records: Vec<String>,
+    /// Identifier distinguishing record sets with a routing policy.
+    pub set_identifier: Option<String>,
+    /// Alias target for alias record sets.
+    pub alias_target: Option<AliasTarget>,
+}
+
+impl ResourceRecordSet {
+    /// Creates a simple (non-alias) record set.
+    pub fn new(name: &str, record_type: &str, ttl: u64, records: Vec<String>) -> Self {
+        Self {
+            name: name.to_string(),
+            record_type: record_type.to_string(),
+            ttl: Some(ttl),
+            records,
+            set_identifier: None,
+            alias_target: None,
+        }
+    }
+
+    fn from_element(e: &Element) -> Self {
+        Self {
+            name: e.child_text("Name").unwrap_or_default().to_string(),
+            record_type: e.child_text("Type").unwrap_or_default().to_string(),
+            ttl: e.child_text("TTL").and_then(|v| v.parse().ok()),
+            records: e
+                .child("ResourceRecords")
+                .map(|rrs| {
+                    rrs.children("ResourceRecord")
+                        .filter_map(|rr| rr.child_text("Value"))
+                        .map(str::to_string)
+                        .collect()
+                })
+                .unwrap_or_default(),
+            set_identifier: e.child_text("SetIdentifier").map(str::to_string),
+            alias_target: e.child("AliasTarget").map(|a| AliasTarget {
+                hosted_zone_id: a.child_text("HostedZoneId").unwrap_or_default().to_string(),
+                dns_name: a.child_text("DNSName").unwrap_or_default().to_string(),
+                evaluate_target_health: a.child_text("EvaluateTargetHealth") == Some("true"),
+            }),
+        }
+    }
+
+    fn to_xml(&self) -> String {
+        let mut xml = format!(
+            "<ResourceRecordSet><Name>{}</Name><Type>{}</Type>",
+            escape(&self.name),
+            escape(&self.record_type)
+        );
+        if let Some(id) = &self.set_identifier {
+            xml.push_str(&format!("<SetIdentifier>{}</SetIdentifier>", escape(id)));
+        }
+        if let Some(alias) = &self.alias_target {
+            xml.push_str(&format!(
+                "<AliasTarget><HostedZoneId>{}</HostedZoneId><DNSName>{}</DNSName><EvaluateTargetHealth>{}</EvaluateTargetHealth></AliasTarget>",
+                escape(&alias.hosted_zone_id),
+                escape(&alias.dns_name),
+                alias.evaluate_target_health
+            ));
+        }
+        if let Some(ttl) = self.ttl {
+            xml.push_str(&format!("<TTL>{}</TTL>", ttl));
+        }
+        if !self.records.is_empty() {
+            xml.push_str("<ResourceRecords>");
+            for value in &self.records {
+                xml.push_str(&format!(
+                    "<ResourceRecord><Value>{}</Value></ResourceRecord>",
+                    escape(value)
+                ));
+            }
+            xml.push_str("</ResourceRecords>");
+        }
+        xml.push_str("</ResourceRecordSet>");
+        xml
+    }
+}
+
+/// Action of a record set change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChangeAction {
+    /// Create a record set; fails if it already exists.
+    Create,
+    /// Delete a record set; must match the existing set exactly.
+    Delete,
+    /// Create or replace a record set.
+    Upsert,
+}
+
+impl ChangeAction {
+    fn as_str(&self) -> &'static str {
+        match self {
+            ChangeAction::Create => "CREATE",
+            ChangeAction::Delete => "DELETE",
+            ChangeAction::Upsert => "UPSERT",
+        }
+    }
+}
+
+/// A single change in a `ChangeResourceRecordSets` batch.
+#[derive(Debug, Clone)]
+pub struct Change {
+    /// Action to perform.
+    pub action: ChangeAction,
+    /// Record set the action applies to.
+    pub record_set: ResourceRecordSet,
+}
+
+/// Status of a submitted change batch.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChangeInfo {
+    /// Change ID without the `/change/` prefix.
+    pub id: String,
+    /// `PENDING` or `INSYNC`.
+    pub status: String,
+}
+
+impl ChangeInfo {
+    fn from_element(e: &Element) -> Self {
+        Self {
+            id: strip_id_prefix(e.child_text("Id").unwrap_or_default()).to_string(),
+            status: e.child_text("Status").unwrap_or_default().to_string(),
+        }
+    }
+}
+
+fn change_batch_xml(changes: &[Change], comment: Option<&str>) -> String {
+    let mut xml = format!(
+        r#"<?xml version="1.0" encoding="UTF-8"?><ChangeResourceRecordSetsRequest xmlns="{}"><ChangeBatch>"#,
+        XMLNS
+    );
+    if let Some(comment) = comment {
+        xml.push_str(&format!("<Comment>{}</Comment>", escape(comment)));
+    }
+    xml.push_str("<Changes>");
+    for change in changes {
+        xml.push_str(&format!(
+            "<Change><Action>{}</Action>{}</Change>",
+            change.action.as_str(),
+            change.record_set.to_xml()
+        ));
+    }
+    xml.push_str("</Changes></ChangeBatch></ChangeResourceRecordSetsRequest>");
+    xml
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Route 53 API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    credentials: Credentials,
+    base_url: String,
+    host: String,
+}
+
+impl Client {
+    /// Creates a new Route 53 API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AWS credentials used to sign requests
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, ROUTE53_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new Route 53 API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AWS credentials used to sign requests
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, ROUTE53_API_URL, config)
+    }
+
+    /// Creates a new Route 53 API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AWS credentials used to sign requests
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(base_url)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("base URL has no host".into()),
+        };
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            credentials,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            host,
+        })
+    }
+
+    /// Sends a signed request and returns the parsed response document.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        params: &[(&str, &str)],
+        body: Option<String>,
+    ) -> Result<Element, Route53Error> {
+        let path = format!("/{}{}", API_VERSION, path);
+        let query = canonical_query(params);
+        let payload = body.unwrap_or_default();
+        let amz_date = amz_date_now();
+
+        let mut signed: Vec<(&str, &str)> = vec![("x-amz-date", &amz_date)];
+        if let Some(token) = &self.credentials.session_token {
+            signed.push(("x-amz-security-token", token));
+        }
+
+        let authorization = sign_v4(
+            &self.credentials,
+            &SigningRequest {
+                method: method.as_str(),
+                host: &self.host,
+                path: &path,
+                query: &query,
+                headers: &signed,
+                payload: payload.as_bytes(),
+                region: SIGNING_REGION,
+                service: SERVICE,
+                amz_date: &amz_date,
+            },
+        );
+
+        let url = if query.is_empty() {
+            format!("{}{}", self.base_url, path)
+        } else {
+            format!("{}{}?{}", self.base_url, path, query)
+        };
+
+        let mut request = self
+            .http_client
+            .request(method, url)
+            .header("Authorization", authorization);
+        for (name, value) in &signed {
+            request = request.header(*name, *value);
+        }
+        if !payload.is_empty() {
+            request = request.header("Content-Type", "text/xml").body(payload);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (code, message) = match Element::parse(&text) {
+                Ok(root) => {
+                    let code = root
+                        .find("Code")
+                        .map(|c| c.text.clone())
+                        .unwrap_or_else(|| root.name.clone());
+                    let message = root
+                        .find("Message")
+                        .map(|m| m.text.clone())
+                        .unwrap_or_default();
+                    (code, message)
+                }
+                Err(_) => (String::new(), text),
+            };
+            return Err(Route53Error::Api {
+                status: status.as_u16(),
+                code,
+                message,
+            });
+        }
+
+        if text.trim().is_empty() {
+            return Ok(Element::default());
+        }
+        Element::parse(&text).map_err(Route53Error::Parse)
+    }
+
+    // =========================================================================
+    // Hosted Zone APIs
+    // =========================================================================
+
+    /// Lists all hosted zones, following pagination.
+    pub async fn list_hosted_zones(&self) -> Result<Vec<HostedZone>, Route53Error> {
+        let mut zones = Vec::new();
+        let mut marker: Option<String> = None;
+
+        loop {
+            let mut params = vec![("maxitems", "100")];
+            if let Some(marker) = &marker {
+                params.push(("marker", marker));
+            }
+
+            let root = self
+                .request(reqwest::Method::GET, "/hostedzone", &params, None)
+                .await?;
+
+            if let Some(list) = root.child("HostedZones") {
+                zones.extend(list.children("HostedZone").map(HostedZone::from_element));
+            }
+
+            if root.child_text("IsTruncated") != Some("true") {
+                break;
+            }
+            match root.child_text("NextMarker") {
+                Some(next) => marker = Some(next.to_string()),
+                None => break,
+            }
+        }
+
+        Ok(zones)
+    }
+
+    /// Gets a hosted zone by ID.
+    pub async fn get_hosted_zone(&self, zone_id: &str) -> Result<HostedZone, Route53Error> {
+        let root = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/hostedzone/{}", strip_id_prefix(zone_id)),
+                &[],
+                None,
+            )
+            .await?;
+
+        root.child("HostedZone")
+            .map(HostedZone::from_element)
+            .ok_or_else(|| Route53Error::Parse("missing HostedZone element".to_string()))
+    }
+
+    /// Creates a hosted zone.
+    pub async fn create_hosted_zone(
+        &self,
+        request: &CreateHostedZoneRequest,
+    ) -> Result<HostedZone, Route53Error> {
+        let root = self
+            .request(
+                reqwest::Method::POST,
+                "/hostedzone",
+                &[],
+                Some(request.to_xml()),
+            )
+            .await?;
+
+        root.child("HostedZone")
+            .map(HostedZone::from_element)
+            .ok_or_else(|| Route53Error::Parse("missing HostedZone element".to_string()))
+    }
+
+    /// Deletes a hosted zone. The zone must not contain records other than
+    /// the apex SOA and NS records.
+    pub async fn delete_hosted_zone(&self, zone_id: &str) -> Result<ChangeInfo, Route53Error> {
+        let root = self
+            .request(
+                reqwest::Method::DELETE,
+                &format!("/hostedzone/{}", strip_id_prefix(zone_id)),
+                &[],
+                None,
+            )
+            .await?;
+
+        Ok(root
+            .child("ChangeInfo")
+            .map(ChangeInfo::from_element)
+            .unwrap_or(ChangeInfo {
+                id: String::new(),
+                status: String::new(),
+            }))
+    }
+
+    // =========================================================================
+    // Record Set APIs
+    // =========================================================================
+
+    /// Lists all record sets in a hosted zone, following pagination.
+    pub async fn list_resource_record_sets(
+        &self,
+        zone_id: &str,
+    ) -> Result<Vec<ResourceRecordSet>, Route53Error> {
+        let mut record_sets = Vec::new();
+        let mut start: Option<(String, String, Option<String>)> = None;
+
+        loop {
+            let mut params = vec![("maxitems", "300")];
+            if let Some((name, typ, identifier)) = &start {
+                params.push(("name", name));
+                params.push(("type", typ));
+                if let Some(identifier) = identifier {
+                    params.push(("identifier", identifier));
+                }
+            }
+
+            let root = self
+                .request(
+                    reqwest::Method::GET,
+                    &format!("/hostedzone/{}/rrset", strip_id_prefix(zone_id)),
+                    &params,
+                    None,
+                )
+                .await?;
+
+            if let Some(list) = root.child("ResourceRecordSets") {
+                record_sets.extend(
+                    list.children("ResourceRecordSet")
+                        .map(ResourceRecordSet::from_element),
+                );
+            }
+
+            if root.child_text("IsTruncated") != Some("true") {
+                break;
+            }
+            match (
+                root.child_text("NextRecordName"),
+                root.child_text("NextRecordType"),
+            ) {
+                (Some(name), Some(typ)) => {
+                    start = Some((
+                        name.to_string(),
+                        typ.to_string(),
+                        root.child_text("NextRecordIdentifier").map(str::to_string),
+                    ))
+                }
+                _ => break,
+            }
+        }
+
+        Ok(record_sets)
+    }
+
+    /// Gets the simple record set with the given name and type, if it exists.
+    pub async fn get_resource_record_set(
+        &self,
+        zone_id: &str,
+        name: &str,
+        record_type: &str,
+    ) -> Result<Option<ResourceRecordSet>, Route53Error> {
+        let root = self
+            .request(
+                reqwest::Method::GET,
+                &format!("/hostedzone/{}/rrset", strip_id_prefix(zone_id)),
+                &[("name", name), ("type", record_type), ("maxitems", "1")],
+                None,
+            )
+            .await?;
+
+        let record_set = root
+            .child("ResourceRecordSets")
+            .into_iter()
+            .flat_map(|list| list.children("ResourceRecordSet"))
+            .map(ResourceRecordSet::from_element)
+            .find(|rrset| {
+                decode_name(&rrset.name).eq_ignore_ascii_case(&decode_name(name))
+                    && rrset.record_type == record_type
+                    && rrset.set_identifier.is_none()
+            });
+        Ok(record_set)
+    }
+
+    /// Submits a batch of record set changes, applied atomically.
+    pub async fn change_resource_record_sets(
+        &self,
+        zone_id: &str,
+        changes: &[Change],
+        comment: Option<&str>,
+    ) -> Result<ChangeInfo, Route53Error> {
+        let root = self
+            .request(
+                reqwest::Method::POST,
+                &format!("/hostedzone/{}/rrset/", strip_id_prefix(zone_id)),
+                &[],
+                Some(change_batch_xml(changes, comment)),
+            )
+            .await?;
+
+        root.child("ChangeInfo")
+            .map(ChangeInfo::from_element)
+            .ok_or_else(|| Route53Error::Parse("missing ChangeInfo element".to_string()))
+    }
+}
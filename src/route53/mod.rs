@@ -0,0 +1,600 @@
+//! Amazon Route 53 provider implementation.
+//!
+//! This provider uses the Route 53 REST/XML API with AWS Signature Version 4
+//! request signing.
+//!
+//! # Authentication
+//!
+//! Requires an IAM access key pair with Route 53 permissions. Temporary
+//! credentials (e.g., from STS or an assumed role) additionally need the
+//! session token:
+//!
+//! ```no_run
+//! use manydns::route53::Credentials;
+//!
+//! let static_keys = Credentials::new("AKIA...", "secret");
+//! let temporary = Credentials::new("ASIA...", "secret").with_session_token("token");
+//! let from_env = Credentials::from_env();
+//! ```
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::route53::{Credentials, Route53Provider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = Route53Provider::new(Credentials::new("AKIA...", "secret"))?;
+//!
+//! // List all hosted zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Hosted Zones
+//!
+//! [`get_zone`](crate::Provider::get_zone) accepts either a hosted zone ID
+//! (`Z1D633PJN98FT9`) or a domain name. Since a public and a private hosted
+//! zone may share a name, lookups by name prefer the public zone; use the ID
+//! to address a private zone unambiguously. [`CreateZone`] creates public
+//! zones, while [`Route53Provider::create_private_zone`] creates a private
+//! zone associated with a VPC.
+//!
+//! # RRset-based API
+//!
+//! Route 53 manages records as record sets (all records sharing a name and
+//! type). Creating or deleting a single record reads the current record set
+//! and submits a `ChangeResourceRecordSets` batch with `UPSERT`, or `DELETE`
+//! once the last record is removed. All records in a set share one TTL, so
+//! creating a record also updates the TTL of its siblings.
+//!
+//! Record IDs use the format `"name/type/value"`, similar to the Hetzner
+//! provider. Alias record sets and record sets with a routing policy
+//! (weighted, latency, failover, ...) are not listed.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CAA, CNAME, DS, HTTPS, MX, NAPTR, NS, PTR, SPF, SRV, SSHFP, SVCB,
+//! TLSA and TXT
+//!
+//! # API Reference
+//!
+//! - [Route 53 API Reference](https://docs.aws.amazon.com/Route53/latest/APIReference/Welcome.html)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, Credentials, Route53Error, Vpc};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &[
+    "A", "AAAA", "CAA", "CNAME", "DS", "HTTPS", "MX", "NAPTR", "NS", "PTR", "SPF", "SRV", "SSHFP",
+    "SVCB", "TLSA", "TXT",
+];
+
+/// Error codes indicating invalid or insufficient credentials.
+const UNAUTHORIZED_CODES: &[&str] = &[
+    "AccessDenied",
+    "AccessDeniedException",
+    "ExpiredToken",
+    "IncompleteSignature",
+    "InvalidClientTokenId",
+    "MissingAuthenticationToken",
+    "SignatureDoesNotMatch",
+];
+
+fn is_unauthorized(err: &Route53Error) -> bool {
+    err.status() == Some(403) || err.code().is_some_and(|c| UNAUTHORIZED_CODES.contains(&c))
+}
+
+/// Amazon Route 53 DNS provider.
+#[derive(Clone)]
+pub struct Route53Provider {
+    api_client: Arc<Client>,
+}
+
+/// A Route 53 hosted zone.
+pub struct Route53Zone {
+    api_client: Arc<Client>,
+    repr: api::HostedZone,
+    /// Zone name without the trailing dot.
+    domain: String,
+}
+
+impl Route53Provider {
+    /// Creates a new Route 53 provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AWS credentials used to sign requests
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::route53::{Credentials, Route53Provider};
+    ///
+    /// let provider = Route53Provider::new(Credentials::new("AKIA...", "secret")).unwrap();
+    /// ```
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Route 53 provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AWS credentials used to sign requests
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::route53::{Credentials, Route53Provider};
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider =
+    ///     Route53Provider::with_config(Credentials::new("AKIA...", "secret"), config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Route 53 provider with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - AWS credentials used to sign requests
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a private hosted zone associated with a VPC.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::route53::{Credentials, Route53Provider, Vpc};
+    ///
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let provider = Route53Provider::new(Credentials::new("AKIA...", "secret"))?;
+    /// let vpc = Vpc {
+    ///     region: "eu-central-1".to_string(),
+    ///     id: "vpc-0123456789abcdef0".to_string(),
+    /// };
+    /// let zone = provider.create_private_zone("internal.example.com", vpc).await?;
+    /// assert!(zone.is_private());
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_private_zone(
+        &self,
+        domain: &str,
+        vpc: Vpc,
+    ) -> Result<Route53Zone, CreateZoneError<Route53Error>> {
+        self.create(&api::CreateHostedZoneRequest::private(domain, vpc))
+            .await
+    }
+
+    async fn create(
+        &self,
+        request: &api::CreateHostedZoneRequest,
+    ) -> Result<Route53Zone, CreateZoneError<Route53Error>> {
+        let zone = self
+            .api_client
+            .create_hosted_zone(request)
+            .await
+            .map_err(|err| match err.code() {
+                _ if is_unauthorized(&err) => CreateZoneError::Unauthorized,
+                Some("InvalidDomainName") => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(Route53Zone::from_api(self.api_client.clone(), zone))
+    }
+}
+
+impl Provider for Route53Provider {
+    type Zone = Route53Zone;
+    type CustomRetrieveError = Route53Error;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let map_err = |err: Route53Error| match err.code() {
+            _ if is_unauthorized(&err) => RetrieveZoneError::Unauthorized,
+            Some("NoSuchHostedZone" | "InvalidInput") => RetrieveZoneError::NotFound,
+            _ => RetrieveZoneError::Custom(err),
+        };
+
+        // Hosted zone IDs never contain dots, domain names (almost) always do.
+        if !zone_id.contains('.') {
+            let zone = self
+                .api_client
+                .get_hosted_zone(zone_id)
+                .await
+                .map_err(map_err)?;
+            return Ok(Route53Zone::from_api(self.api_client.clone(), zone));
+        }
+
+        let name = api::decode_name(zone_id.trim_end_matches('.'));
+        let mut matching: Vec<_> = self
+            .api_client
+            .list_hosted_zones()
+            .await
+            .map_err(map_err)?
+            .into_iter()
+            .filter(|zone| {
+                api::decode_name(zone.name.trim_end_matches('.')).eq_ignore_ascii_case(&name)
+            })
+            .collect();
+        matching.sort_by_key(|zone| zone.private_zone);
+
+        matching
+            .into_iter()
+            .next()
+            .map(|zone| Route53Zone::from_api(self.api_client.clone(), zone))
+            .ok_or(RetrieveZoneError::NotFound)
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self.api_client.list_hosted_zones().await.map_err(|err| {
+            if is_unauthorized(&err) {
+                RetrieveZoneError::Unauthorized
+            } else {
+                RetrieveZoneError::Custom(err)
+            }
+        })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| Route53Zone::from_api(self.api_client.clone(), zone))
+            .collect())
+    }
+}
+
+impl CreateZone for Route53Provider {
+    type CustomCreateError = Route53Error;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        self.create(&api::CreateHostedZoneRequest::public(domain))
+            .await
+    }
+}
+
+impl DeleteZone for Route53Provider {
+    type CustomDeleteError = Route53Error;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_hosted_zone(zone_id)
+            .await
+            .map_err(|err| match err.code() {
+                _ if is_unauthorized(&err) => DeleteZoneError::Unauthorized,
+                Some("NoSuchHostedZone" | "InvalidInput") => DeleteZoneError::NotFound,
+                _ => DeleteZoneError::Custom(err),
+            })?;
+        Ok(())
+    }
+}
+
+impl Route53Zone {
+    /// Creates a new Route53Zone from API response data.
+    fn from_api(api_client: Arc<Client>, zone: api::HostedZone) -> Self {
+        let domain = api::decode_name(zone.name.trim_end_matches('.'));
+        Self {
+            api_client,
+            repr: zone,
+            domain,
+        }
+    }
+
+    /// Returns whether this is a private hosted zone.
+    pub fn is_private(&self) -> bool {
+        self.repr.private_zone
+    }
+
+    /// Returns the zone comment, if any.
+    pub fn comment(&self) -> Option<&str> {
+        self.repr.comment.as_deref()
+    }
+
+    /// Returns the number of record sets reported when the zone was retrieved.
+    pub fn record_count(&self) -> Option<u64> {
+        self.repr.record_count
+    }
+
+    /// Converts a host relative to the zone into a fully-qualified owner name.
+    fn owner_name(&self, host: &str) -> String {
+        if host == "@" || host.is_empty() {
+            format!("{}.", self.domain)
+        } else if host.trim_end_matches('.') == self.domain
+            || host
+                .trim_end_matches('.')
+                .ends_with(&format!(".{}", self.domain))
+        {
+            format!("{}.", host.trim_end_matches('.'))
+        } else {
+            format!("{}.{}.", host, self.domain)
+        }
+    }
+
+    /// Converts a fully-qualified owner name into a host relative to the zone.
+    fn host(&self, name: &str) -> String {
+        let name = api::decode_name(name);
+        let name = name.trim_end_matches('.');
+        if name == self.domain {
+            "@".to_string()
+        } else if let Some(sub) = name.strip_suffix(&format!(".{}", self.domain)) {
+            sub.to_string()
+        } else {
+            name.to_string()
+        }
+    }
+
+    /// Converts all records of a simple record set into generic records.
+    fn records_from_rrset(&self, rrset: &api::ResourceRecordSet) -> Vec<Record> {
+        if rrset.alias_target.is_some() || rrset.set_identifier.is_some() {
+            return Vec::new();
+        }
+
+        let host = self.host(&rrset.name);
+        rrset
+            .records
+            .iter()
+            .map(|value| Record {
+                id: format!("{}/{}/{}", host, rrset.record_type, value),
+                host: host.clone(),
+                data: record_data_from_value(&rrset.record_type, value),
+                ttl: rrset.ttl.unwrap_or_default(),
+            })
+            .collect()
+    }
+}
+
+impl Zone for Route53Zone {
+    type CustomRetrieveError = Route53Error;
+
+    fn id(&self) -> &str {
+        &self.repr.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let rrsets = self
+            .api_client
+            .list_resource_record_sets(&self.repr.id)
+            .await
+            .map_err(|err| match err.code() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some("NoSuchHostedZone") => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(rrsets
+            .iter()
+            .flat_map(|rrset| self.records_from_rrset(rrset))
+            .collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(RetrieveRecordError::NotFound);
+        }
+
+        let rrset = self
+            .api_client
+            .get_resource_record_set(&self.repr.id, &self.owner_name(parts[0]), parts[1])
+            .await
+            .map_err(|err| match err.code() {
+                _ if is_unauthorized(&err) => RetrieveRecordError::Unauthorized,
+                Some("NoSuchHostedZone") => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?
+            .ok_or(RetrieveRecordError::NotFound)?;
+
+        self.records_from_rrset(&rrset)
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for Route53Zone {
+    type CustomCreateError = Route53Error;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let name = self.owner_name(host);
+        let value = value_from_record_data(data);
+
+        let map_err = |err: Route53Error| match err.code() {
+            _ if is_unauthorized(&err) => CreateRecordError::Unauthorized,
+            Some("InvalidChangeBatch" | "InvalidInput") => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        // UPSERT overwrites the whole record set, so merge with the existing records.
+        let mut records = self
+            .api_client
+            .get_resource_record_set(&self.repr.id, &name, typ)
+            .await
+            .map_err(map_err)?
+            .map(|rrset| rrset.records)
+            .unwrap_or_default();
+
+        if records.contains(&value) {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+        records.push(value.clone());
+
+        let change = api::Change {
+            action: api::ChangeAction::Upsert,
+            record_set: api::ResourceRecordSet::new(&name, typ, ttl, records),
+        };
+        self.api_client
+            .change_resource_record_sets(&self.repr.id, &[change], None)
+            .await
+            .map_err(map_err)?;
+
+        let host = self.host(&name);
+        Ok(Record {
+            id: format!("{}/{}/{}", host, typ, value),
+            host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for Route53Zone {
+    type CustomDeleteError = Route53Error;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        // Parse record ID format: "name/type/value"
+        let parts: Vec<&str> = record_id.splitn(3, '/').collect();
+        if parts.len() != 3 {
+            return Err(DeleteRecordError::NotFound);
+        }
+        let (host, typ, value) = (parts[0], parts[1], parts[2]);
+        let name = self.owner_name(host);
+
+        let map_err = |err: Route53Error| match err.code() {
+            _ if is_unauthorized(&err) => DeleteRecordError::Unauthorized,
+            Some("NoSuchHostedZone") => DeleteRecordError::NotFound,
+            _ => DeleteRecordError::Custom(err),
+        };
+
+        let rrset = self
+            .api_client
+            .get_resource_record_set(&self.repr.id, &name, typ)
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteRecordError::NotFound)?;
+
+        let remaining: Vec<String> = rrset
+            .records
+            .iter()
+            .filter(|v| *v != value)
+            .cloned()
+            .collect();
+        if remaining.len() == rrset.records.len() {
+            return Err(DeleteRecordError::NotFound);
+        }
+
+        // DELETE must match the existing record set exactly.
+        let change = if remaining.is_empty() {
+            api::Change {
+                action: api::ChangeAction::Delete,
+                record_set: rrset,
+            }
+        } else {
+            api::Change {
+                action: api::ChangeAction::Upsert,
+                record_set: api::ResourceRecordSet {
+                    records: remaining,
+                    ..rrset
+                },
+            }
+        };
+
+        self.api_client
+            .change_resource_record_sets(&self.repr.id, &[change], None)
+            .await
+            .map_err(map_err)?;
+        Ok(())
+    }
+}
+
+/// Converts a Route 53 record value into [`RecordData`].
+///
+/// Host names lose their trailing dot and TXT character strings are unquoted
+/// and concatenated.
+fn record_data_from_value(typ: &str, value: &str) -> RecordData {
+    match typ {
+        // The host name is the last field of all of these types.
+        "CNAME" | "NS" | "MX" | "SRV" => RecordData::from_raw(typ, value.trim_end_matches('.')),
+        "TXT" | "SPF" => RecordData::from_raw(typ, &unquote_txt(value)),
+        _ => RecordData::from_raw(typ, value),
+    }
+}
+
+/// Converts [`RecordData`] into a Route 53 record value.
+///
+/// Route 53 treats all host names as fully qualified; TXT values are quoted.
+fn value_from_record_data(data: &RecordData) -> String {
+    match data {
+        RecordData::TXT(txt) => quote_txt(txt),
+        RecordData::Other { typ, value } if typ == "SPF" => quote_txt(value),
+        _ => data.get_value(),
+    }
+}
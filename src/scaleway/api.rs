@@ -0,0 +1,512 @@
+//! Low-level Scaleway Domains and DNS API client.
+//!
+//! This module provides direct access to the Scaleway Domains and DNS API
+//! (`/domain/v2beta1`), authenticated with the `X-Auth-Token` header.
+//! For most use cases, prefer using [`ScalewayProvider`](super::ScalewayProvider) instead.
+//!
+//! # Record Changes
+//!
+//! Records are modified through a single PATCH endpoint taking a list of
+//! changes, applied atomically:
+//!
+//! ```text
+//! PATCH /domain/v2beta1/dns-zones/{dns_zone}/records
+//! {"changes": [{"add": {"records": [...]}}, {"set": {"id": ..., "records": [...]}}, {"delete": {"id": ...}}]}
+//! ```
+//!
+//! # API Reference
+//!
+//! - [Domains and DNS API](https://www.scaleway.com/en/developers/api/domains-and-dns/)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::scaleway::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-secret-key")?;
+//!
+//! for record in client.list_records("example.com").await? {
+//!     println!("{} {} {}", record.name, record.record_type, record.data);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Scaleway API base URL.
+const SCALEWAY_API_URL: &str = "https://api.scaleway.com";
+
+/// Path prefix of the DNS zone endpoints.
+const DNS_ZONES_PATH: &str = "/domain/v2beta1/dns-zones";
+
+/// Page size requested from list endpoints.
+const PAGE_SIZE: usize = 100;
+
+/// Errors that may occur when interacting with the Scaleway API.
+#[derive(Debug, Error)]
+pub enum ScalewayError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error type (e.g., `not_found`, `invalid_arguments`).
+        error_type: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// Creating a zone requires a project ID, but none was configured.
+    #[error("No project ID configured")]
+    MissingProjectId,
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl ScalewayError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            ScalewayError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error type if this is an API error.
+    pub fn error_type(&self) -> Option<&str> {
+        match self {
+            ScalewayError::Api { error_type, .. } => Some(error_type),
+            _ => None,
+        }
+    }
+}
+
+/// Scaleway error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    #[serde(rename = "type", default)]
+    error_type: String,
+    #[serde(default)]
+    message: String,
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A DNS zone.
+///
+/// A zone is identified by its domain and an optional subdomain, e.g.
+/// `sub.example.com` is the `sub` zone of `example.com`.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsZone {
+    /// Domain of the zone.
+    pub domain: String,
+    /// Subdomain of the zone (empty for the domain itself).
+    #[serde(default)]
+    pub subdomain: String,
+    /// Name servers of the zone.
+    #[serde(default)]
+    pub ns: Vec<String>,
+    /// Zone status (e.g., `active`, `pending`, `error`).
+    #[serde(default)]
+    pub status: String,
+    /// Project the zone belongs to.
+    #[serde(default)]
+    pub project_id: String,
+    /// Last update time (RFC 3339).
+    #[serde(default)]
+    pub updated_at: Option<String>,
+}
+
+impl DnsZone {
+    /// Returns the full name of the zone, as used in API paths.
+    pub fn name(&self) -> String {
+        if self.subdomain.is_empty() {
+            self.domain.clone()
+        } else {
+            format!("{}.{}", self.subdomain, self.domain)
+        }
+    }
+}
+
+/// Response of the zone list endpoint.
+#[derive(Debug, Deserialize)]
+struct ZonesPage {
+    #[serde(default)]
+    dns_zones: Vec<DnsZone>,
+    #[serde(default)]
+    total_count: usize,
+}
+
+/// Request body for creating a zone.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateZoneRequest {
+    /// Domain of the zone.
+    pub domain: String,
+    /// Subdomain of the zone (empty for the domain itself).
+    pub subdomain: String,
+    /// Project to create the zone in.
+    pub project_id: String,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a zone.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DnsRecord {
+    /// Record ID.
+    pub id: String,
+    /// Name relative to the zone (empty for the apex).
+    #[serde(default)]
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record data; for SRV records `weight port target`.
+    #[serde(default)]
+    pub data: String,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(default)]
+    pub priority: u16,
+    /// Optional comment.
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// Response of the record list endpoint.
+#[derive(Debug, Deserialize)]
+struct RecordsPage {
+    #[serde(default)]
+    records: Vec<DnsRecord>,
+    #[serde(default)]
+    total_count: usize,
+}
+
+/// A record to add or set.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordInput {
+    /// Name relative to the zone (empty for the apex).
+    pub name: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Record data; for SRV records `weight port target`.
+    pub data: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    pub priority: u16,
+}
+
+/// A change applied by [`Client::update_records`].
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum RecordChange {
+    /// Adds records.
+    Add {
+        /// Records to add.
+        records: Vec<RecordInput>,
+    },
+    /// Replaces the record with the given ID.
+    Set {
+        /// ID of the record to replace.
+        id: String,
+        /// Replacement records.
+        records: Vec<RecordInput>,
+    },
+    /// Deletes the record with the given ID.
+    Delete {
+        /// ID of the record to delete.
+        id: String,
+    },
+}
+
+/// Request body of the record PATCH endpoint.
+#[derive(Debug, Serialize)]
+struct UpdateRecordsRequest<'a> {
+    changes: &'a [RecordChange],
+    return_all_records: bool,
+}
+
+/// Response of the record PATCH endpoint.
+#[derive(Debug, Deserialize)]
+struct UpdateRecordsResponse {
+    #[serde(default)]
+    records: Vec<DnsRecord>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Scaleway Domains and DNS API client.
+#[derive(Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    secret_key: String,
+    base_url: String,
+}
+
+impl fmt::Debug for Client {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Client")
+            .field("secret_key", &"<redacted>")
+            .field("base_url", &self.base_url)
+            .finish()
+    }
+}
+
+impl Client {
+    /// Creates a new Scaleway API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` - Secret key of an API key
+    pub fn new(secret_key: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(secret_key, SCALEWAY_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new Scaleway API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` - Secret key of an API key
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        secret_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(secret_key, SCALEWAY_API_URL, config)
+    }
+
+    /// Creates a new Scaleway API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` - Secret key of an API key
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        secret_key: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            secret_key: secret_key.to_string(),
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends an authenticated request and returns the response body.
+    async fn request(
+        &self,
+        method: reqwest::Method,
+        path: &str,
+        body: Option<String>,
+    ) -> Result<String, ScalewayError> {
+        let mut request = self
+            .http_client
+            .request(method, format!("{}{}", self.base_url, path))
+            .header("X-Auth-Token", &self.secret_key);
+        if let Some(body) = body {
+            request = request
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body);
+        }
+
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let (error_type, message) = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => (e.error_type, e.message),
+                Err(_) => (String::new(), text),
+            };
+            return Err(ScalewayError::Api {
+                status: status.as_u16(),
+                error_type,
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Sends a GET request and deserializes the JSON response.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ScalewayError> {
+        let text = self.request(reqwest::Method::GET, path, None).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists DNS zones, following pagination.
+    ///
+    /// `dns_zone` restricts the result to the zone with the given full name.
+    pub async fn list_zones(&self, dns_zone: Option<&str>) -> Result<Vec<DnsZone>, ScalewayError> {
+        let mut zones = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let mut path = format!("{}?page={}&page_size={}", DNS_ZONES_PATH, page, PAGE_SIZE);
+            if let Some(dns_zone) = dns_zone {
+                path.push_str(&format!("&dns_zone={}", encode_component(dns_zone)));
+            }
+
+            let response: ZonesPage = self.get(&path).await?;
+            let count = response.dns_zones.len();
+            zones.extend(response.dns_zones);
+
+            if count == 0 || zones.len() >= response.total_count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(zones)
+    }
+
+    /// Creates a DNS zone.
+    pub async fn create_zone(&self, request: &CreateZoneRequest) -> Result<DnsZone, ScalewayError> {
+        let text = self
+            .request(
+                reqwest::Method::POST,
+                DNS_ZONES_PATH,
+                Some(serde_json::to_string(request)?),
+            )
+            .await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Deletes a DNS zone.
+    pub async fn delete_zone(&self, dns_zone: &str, project_id: &str) -> Result<(), ScalewayError> {
+        self.request(
+            reqwest::Method::DELETE,
+            &format!(
+                "{}/{}?project_id={}",
+                DNS_ZONES_PATH,
+                encode_component(dns_zone),
+                encode_component(project_id)
+            ),
+            None,
+        )
+        .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a zone, following pagination.
+    pub async fn list_records(&self, dns_zone: &str) -> Result<Vec<DnsRecord>, ScalewayError> {
+        let mut records = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let response: RecordsPage = self
+                .get(&format!(
+                    "{}/{}/records?page={}&page_size={}",
+                    DNS_ZONES_PATH,
+                    encode_component(dns_zone),
+                    page,
+                    PAGE_SIZE
+                ))
+                .await?;
+            let count = response.records.len();
+            records.extend(response.records);
+
+            if count == 0 || records.len() >= response.total_count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(records)
+    }
+
+    /// Applies record changes atomically and returns the added or set records.
+    pub async fn update_records(
+        &self,
+        dns_zone: &str,
+        changes: &[RecordChange],
+    ) -> Result<Vec<DnsRecord>, ScalewayError> {
+        let body = UpdateRecordsRequest {
+            changes,
+            return_all_records: false,
+        };
+
+        let text = self
+            .request(
+                reqwest::Method::PATCH,
+                &format!("{}/{}/records", DNS_ZONES_PATH, encode_component(dns_zone)),
+                Some(serde_json::to_string(&body)?),
+            )
+            .await?;
+        let response: UpdateRecordsResponse = serde_json::from_str(&text)?;
+        Ok(response.records)
+    }
+}
+
+/// Percent-encodes a path segment or query value.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
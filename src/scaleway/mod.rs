@@ -0,0 +1,525 @@
+//! Scaleway DNS provider implementation.
+//!
+//! This provider uses the Scaleway Domains and DNS API (`v2beta1`) for zone
+//! and record management.
+//!
+//! # Authentication
+//!
+//! Requires the secret key of an API key, sent as the `X-Auth-Token` header:
+//! - Create an API key at: <https://console.scaleway.com/iam/api-keys>
+//! - The key needs the `DomainsDNSFullAccess` permission set
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::scaleway::ScalewayProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = ScalewayProvider::new("your-secret-key")?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones
+//!
+//! The zone ID is the full zone name (e.g., `example.com` or
+//! `sub.example.com`). Creating zones requires the project to create them
+//! in, set with [`ScalewayProvider::with_project_id`]; zones are deleted
+//! from the project they belong to.
+//!
+//! # Record Changes
+//!
+//! Scaleway modifies records through change lists. Creating a record sends
+//! an `add` change, [`ScalewayZone::update_record`] a `set` change and
+//! deleting a record a `delete` change, each addressing records by the ID
+//! assigned by Scaleway.
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - ALIAS, CAA, PTR, SSHFP and TLSA, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [Domains and DNS API](https://www.scaleway.com/en/developers/api/domains-and-dns/)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, ScalewayError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Additional record types supported through [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["ALIAS", "CAA", "PTR", "SSHFP", "TLSA"];
+
+/// Scaleway DNS provider.
+#[derive(Clone)]
+pub struct ScalewayProvider {
+    api_client: Arc<Client>,
+    project_id: Option<String>,
+}
+
+/// A DNS zone hosted by Scaleway.
+pub struct ScalewayZone {
+    api_client: Arc<Client>,
+    repr: api::DnsZone,
+    /// Full zone name.
+    name: String,
+}
+
+impl ScalewayProvider {
+    /// Creates a new Scaleway provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` - Secret key of an API key
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::scaleway::ScalewayProvider;
+    ///
+    /// let provider = ScalewayProvider::new("your-secret-key")
+    ///     .unwrap()
+    ///     .with_project_id("00000000-0000-0000-0000-000000000000");
+    /// ```
+    pub fn new(secret_key: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(secret_key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            project_id: None,
+        })
+    }
+
+    /// Creates a new Scaleway provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` - Secret key of an API key
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::scaleway::ScalewayProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = ScalewayProvider::with_config("your-secret-key", config).unwrap();
+    /// ```
+    pub fn with_config(
+        secret_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(secret_key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            project_id: None,
+        })
+    }
+
+    /// Creates a new Scaleway provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `secret_key` - Secret key of an API key
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        secret_key: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(secret_key, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+            project_id: None,
+        })
+    }
+
+    /// Sets the project new zones are created in.
+    pub fn with_project_id(mut self, project_id: &str) -> Self {
+        self.project_id = Some(project_id.to_string());
+        self
+    }
+
+    /// Looks up a zone by its full name.
+    async fn find_zone(&self, name: &str) -> Result<Option<api::DnsZone>, ScalewayError> {
+        Ok(self
+            .api_client
+            .list_zones(Some(name))
+            .await?
+            .into_iter()
+            .find(|zone| zone.name().eq_ignore_ascii_case(name)))
+    }
+
+    fn zone(&self, repr: api::DnsZone) -> ScalewayZone {
+        ScalewayZone {
+            api_client: self.api_client.clone(),
+            name: repr.name(),
+            repr,
+        }
+    }
+}
+
+impl Provider for ScalewayProvider {
+    type Zone = ScalewayZone;
+    type CustomRetrieveError = ScalewayError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zone = self
+            .find_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                Some(404) => RetrieveZoneError::NotFound,
+                _ => RetrieveZoneError::Custom(err),
+            })?
+            .ok_or(RetrieveZoneError::NotFound)?;
+
+        Ok(self.zone(zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones(None)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones.into_iter().map(|zone| self.zone(zone)).collect())
+    }
+}
+
+impl CreateZone for ScalewayProvider {
+    type CustomCreateError = ScalewayError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let project_id = self
+            .project_id
+            .clone()
+            .ok_or(CreateZoneError::Custom(ScalewayError::MissingProjectId))?;
+
+        let request = api::CreateZoneRequest {
+            domain: domain.trim_end_matches('.').to_string(),
+            subdomain: String::new(),
+            project_id,
+        };
+        let zone =
+            self.api_client
+                .create_zone(&request)
+                .await
+                .map_err(|err| match err.status() {
+                    Some(401 | 403) => CreateZoneError::Unauthorized,
+                    Some(400) => CreateZoneError::InvalidDomainName,
+                    _ => CreateZoneError::Custom(err),
+                })?;
+
+        Ok(self.zone(zone))
+    }
+}
+
+impl DeleteZone for ScalewayProvider {
+    type CustomDeleteError = ScalewayError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        let map_err = |err: ScalewayError| match err.status() {
+            Some(401 | 403) => DeleteZoneError::Unauthorized,
+            Some(404) => DeleteZoneError::NotFound,
+            _ => DeleteZoneError::Custom(err),
+        };
+
+        let zone = self
+            .find_zone(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(map_err)?
+            .ok_or(DeleteZoneError::NotFound)?;
+
+        self.api_client
+            .delete_zone(&zone.name(), &zone.project_id)
+            .await
+            .map_err(map_err)
+    }
+}
+
+impl ScalewayZone {
+    /// Returns the name servers of the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.ns
+    }
+
+    /// Returns the ID of the project the zone belongs to.
+    pub fn project_id(&self) -> &str {
+        &self.repr.project_id
+    }
+
+    /// Replaces the host, value and TTL of an existing record.
+    ///
+    /// The returned record carries the ID assigned to the replacement.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<ScalewayError>> {
+        let input = self.input_from_record_data(host, data, ttl)?;
+        let change = api::RecordChange::Set {
+            id: record_id.to_string(),
+            records: vec![input.clone()],
+        };
+
+        let records = self
+            .api_client
+            .update_records(&self.name, &[change])
+            .await
+            .map_err(map_create_error)?;
+
+        self.changed_record(records, &input).await
+    }
+
+    /// Picks the record matching an input from the records returned by a
+    /// change, falling back to the full record list.
+    async fn changed_record(
+        &self,
+        records: Vec<api::DnsRecord>,
+        input: &api::RecordInput,
+    ) -> Result<Record, CreateRecordError<ScalewayError>> {
+        let normalize = |data: &str| data.trim_matches('"').trim_end_matches('.').to_string();
+        let matches = |record: &api::DnsRecord| {
+            record.name == input.name
+                && record.record_type == input.record_type
+                && normalize(&record.data) == normalize(&input.data)
+        };
+
+        let record = match records.into_iter().find(matches) {
+            Some(record) => record,
+            None => self
+                .api_client
+                .list_records(&self.name)
+                .await
+                .map_err(map_create_error)?
+                .into_iter()
+                .find(matches)
+                .ok_or(CreateRecordError::InvalidRecord)?,
+        };
+
+        Ok(record_from_api(record))
+    }
+
+    /// Converts a host into a name relative to the zone (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(&self.name) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.name)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into a record input.
+    fn input_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordInput, CreateRecordError<ScalewayError>> {
+        let (data_value, priority) = match data {
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (mail_server.clone(), *priority),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (format!("{} {} {}", weight, port, target), *priority),
+            RecordData::TXT(text) => (text.clone(), 0),
+            RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+                (value.clone(), 0)
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+            _ => (data.get_value(), 0),
+        };
+
+        Ok(api::RecordInput {
+            name: self.relative_name(host),
+            record_type: data.get_type().to_string(),
+            data: data_value,
+            ttl,
+            priority,
+        })
+    }
+}
+
+/// Maps an API error of a record change.
+fn map_create_error(err: ScalewayError) -> CreateRecordError<ScalewayError> {
+    match err.status() {
+        Some(401 | 403) => CreateRecordError::Unauthorized,
+        Some(400) => CreateRecordError::InvalidRecord,
+        _ => CreateRecordError::Custom(err),
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::DnsRecord) -> Record {
+    let data = match record.record_type.as_str() {
+        "MX" => RecordData::MX {
+            priority: record.priority,
+            mail_server: record.data.trim_end_matches('.').to_string(),
+        },
+        // The data holds `weight port target`, or the full RDATA including
+        // the priority.
+        "SRV" if record.data.split_whitespace().count() == 3 => RecordData::from_raw(
+            "SRV",
+            &format!("{} {}", record.priority, record.data.trim_end_matches('.')),
+        ),
+        "SRV" => RecordData::from_raw("SRV", record.data.trim_end_matches('.')),
+        "TXT" => RecordData::TXT(
+            record
+                .data
+                .strip_prefix('"')
+                .and_then(|data| data.strip_suffix('"'))
+                .unwrap_or(&record.data)
+                .to_string(),
+        ),
+        "CNAME" | "NS" => {
+            RecordData::from_raw(&record.record_type, record.data.trim_end_matches('.'))
+        }
+        typ => RecordData::from_raw(typ, &record.data),
+    };
+
+    Record {
+        id: record.id,
+        host: if record.name.is_empty() {
+            "@".to_string()
+        } else {
+            record.name
+        },
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for ScalewayZone {
+    type CustomRetrieveError = ScalewayError;
+
+    fn id(&self) -> &str {
+        &self.name
+    }
+
+    fn domain(&self) -> &str {
+        &self.name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.name)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                Some(404) => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        // Scaleway has no endpoint for a single record.
+        self.list_records()
+            .await?
+            .into_iter()
+            .find(|record| record.id == record_id)
+            .ok_or(RetrieveRecordError::NotFound)
+    }
+}
+
+impl CreateRecord for ScalewayZone {
+    type CustomCreateError = ScalewayError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let input = self.input_from_record_data(host, data, ttl)?;
+        let change = api::RecordChange::Add {
+            records: vec![input.clone()],
+        };
+
+        let records = self
+            .api_client
+            .update_records(&self.name, &[change])
+            .await
+            .map_err(map_create_error)?;
+
+        self.changed_record(records, &input).await
+    }
+}
+
+impl DeleteRecord for ScalewayZone {
+    type CustomDeleteError = ScalewayError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        let change = api::RecordChange::Delete {
+            id: record_id.to_string(),
+        };
+
+        self.api_client
+            .update_records(&self.name, &[change])
+            .await
+            .map(|_| ())
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                Some(404) => DeleteRecordError::NotFound,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,173 @@
+//! Shared helpers for HMAC-SHA256 request signing.
+//!
+//! Most cloud APIs sign requests with variations of AWS Signature V4: a
+//! canonical request built from the method, the URI-encoded path and query
+//! and a set of headers, hashed with SHA-256 and signed with HMAC-SHA256.
+//! Others (Akamai EdgeGrid, Exoscale) only need the HMAC. This module holds
+//! the building blocks shared by those providers; each provider composes them
+//! into its own signing scheme, and each helper is only compiled for the
+//! providers that use it.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Computes the SHA-256 hash of data as a lowercase hex string.
+#[cfg(any(
+    feature = "route53",
+    feature = "tencent",
+    feature = "volcengine",
+    feature = "huaweicloud"
+))]
+pub fn sha256_hex(data: &[u8]) -> String {
+    use sha2::Digest;
+    hex::encode(Sha256::digest(data))
+}
+
+/// Computes HMAC-SHA256 and returns the raw bytes.
+pub fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(key).expect("HMAC can take key of any size");
+    mac.update(data.as_bytes());
+    mac.finalize().into_bytes().to_vec()
+}
+
+/// Computes HMAC-SHA256 and returns it as a lowercase hex string.
+#[cfg(any(
+    feature = "route53",
+    feature = "tencent",
+    feature = "volcengine",
+    feature = "baiducloud",
+    feature = "huaweicloud"
+))]
+pub fn hmac_sha256_hex(key: &[u8], data: &str) -> String {
+    hex::encode(hmac_sha256(key, data))
+}
+
+/// Percent-encodes a value, keeping only the RFC 3986 unreserved set.
+///
+/// With `encode_slash` set to `false`, `/` is kept as is, which is how URI
+/// paths are canonicalized.
+#[cfg(any(
+    feature = "route53",
+    feature = "volcengine",
+    feature = "baiducloud",
+    feature = "huaweicloud"
+))]
+pub fn uri_encode(value: &str, encode_slash: bool) -> String {
+    let mut out = String::with_capacity(value.len());
+    for b in value.bytes() {
+        match b {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                out.push(b as char)
+            }
+            b'/' if !encode_slash => out.push('/'),
+            _ => out.push_str(&format!("%{:02X}", b)),
+        }
+    }
+    out
+}
+
+/// Builds a canonical query string from key/value pairs.
+///
+/// Keys and values are URI-encoded and the pairs sorted by key, then value.
+/// The result can be used both for signing and as the actual query string.
+#[cfg(any(
+    feature = "route53",
+    feature = "volcengine",
+    feature = "baiducloud",
+    feature = "huaweicloud"
+))]
+pub fn canonical_query(params: &[(&str, &str)]) -> String {
+    let mut encoded: Vec<(String, String)> = params
+        .iter()
+        .map(|(k, v)| (uri_encode(k, true), uri_encode(v, true)))
+        .collect();
+    encoded.sort();
+    encoded
+        .iter()
+        .map(|(k, v)| format!("{}={}", k, v))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(
+        feature = "route53",
+        feature = "volcengine",
+        feature = "baiducloud",
+        feature = "huaweicloud"
+    ))]
+    #[test]
+    fn test_uri_encode() {
+        assert_eq!(uri_encode("AZaz09-_.~", true), "AZaz09-_.~");
+        assert_eq!(uri_encode("a b+c=d", true), "a%20b%2Bc%3Dd");
+        assert_eq!(
+            uri_encode("/v1/dns/zone/例子.com", false),
+            "/v1/dns/zone/%E4%BE%8B%E5%AD%90.com"
+        );
+        assert_eq!(uri_encode("/v1", true), "%2Fv1");
+        assert_eq!(uri_encode("*.example.com", true), "%2A.example.com");
+    }
+
+    #[cfg(any(
+        feature = "route53",
+        feature = "volcengine",
+        feature = "baiducloud",
+        feature = "huaweicloud"
+    ))]
+    #[test]
+    fn test_canonical_query_sorts_and_encodes() {
+        assert_eq!(canonical_query(&[]), "");
+        assert_eq!(
+            canonical_query(&[("name", "a b"), ("maxKeys", "1000"), ("marker", "")]),
+            "marker=&maxKeys=1000&name=a%20b"
+        );
+        assert_eq!(
+            canonical_query(&[("Version", "2018-08-01"), ("Action", "ListZones")]),
+            "Action=ListZones&Version=2018-08-01"
+        );
+    }
+
+    #[cfg(any(
+        feature = "route53",
+        feature = "tencent",
+        feature = "volcengine",
+        feature = "huaweicloud"
+    ))]
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+        assert_eq!(
+            sha256_hex(b"abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+    }
+
+    #[test]
+    fn test_hmac_sha256() {
+        // RFC 4231 test case 2.
+        let mac = hmac_sha256(b"Jefe", "what do ya want for nothing?");
+        assert_eq!(mac.len(), 32);
+        assert_eq!(mac[..4], [0x5b, 0xdc, 0xc1, 0x46]);
+    }
+
+    #[cfg(any(
+        feature = "route53",
+        feature = "tencent",
+        feature = "volcengine",
+        feature = "baiducloud",
+        feature = "huaweicloud"
+    ))]
+    #[test]
+    fn test_hmac_sha256_hex() {
+        assert_eq!(
+            hmac_sha256_hex(b"Jefe", "what do ya want for nothing?"),
+            "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+}
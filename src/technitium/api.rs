@@ -214,11 +214,75 @@ impl Client {
         response.into_result()
     }
 
+    /// Gets the zone transfer and notify options of a zone.
+    pub async fn get_zone_transfer_options(
+        &self,
+        zone: &str,
+    ) -> Result<ZoneTransferOptions, ApiError> {
+        let response: ApiResponse<ZoneTransferOptions> = self
+            .http_client
+            .get(self.url_with_token(&format!("/api/zones/options/get?zone={}", zone)))
+            .send()
+            .await
+            .map_err(ApiError::Request)?
+            .json()
+            .await
+            .map_err(ApiError::Request)?;
+
+        response.into_result()
+    }
+
+    /// Sets the zone transfer and notify options of a zone.
+    ///
+    /// Only options that are `Some` are changed; an empty list clears the
+    /// existing values.
+    pub async fn set_zone_transfer_options(
+        &self,
+        zone: &str,
+        options: &ZoneTransferOptions,
+    ) -> Result<(), ApiError> {
+        let mut url = format!("/api/zones/options/set?zone={}", zone);
+        for (name, value) in options.query_params() {
+            url.push_str(&format!("&{}={}", name, value));
+        }
+
+        let response: EmptyApiResponse = self
+            .http_client
+            .get(self.url_with_token(&url))
+            .send()
+            .await
+            .map_err(ApiError::Request)?
+            .json()
+            .await
+            .map_err(ApiError::Request)?;
+
+        response.into_result()
+    }
+
     /// Creates a new primary zone.
     pub async fn create_zone(&self, zone: &str) -> Result<CreateZoneResponse, ApiError> {
+        self.create_zone_with_options(zone, &CreateZoneOptions::Primary)
+            .await
+    }
+
+    /// Creates a new zone of the type described by `options`.
+    pub async fn create_zone_with_options(
+        &self,
+        zone: &str,
+        options: &CreateZoneOptions,
+    ) -> Result<CreateZoneResponse, ApiError> {
+        let mut url = format!(
+            "/api/zones/create?zone={}&type={}",
+            zone,
+            options.zone_type()
+        );
+        for (name, value) in options.query_params() {
+            url.push_str(&format!("&{}={}", name, value));
+        }
+
         let response: ApiResponse<CreateZoneResponse> = self
             .http_client
-            .get(self.url_with_token(&format!("/api/zones/create?zone={}&type=Primary", zone)))
+            .get(self.url_with_token(&url))
             .send()
             .await
             .map_err(ApiError::Request)?
@@ -324,71 +388,80 @@ impl Client {
             domain, zone, record_type, ttl
         );
 
-        // Add record-type specific parameters
-        match record_params {
-            RecordParams::A { ip_address } => {
-                url.push_str(&format!("&ipAddress={}", ip_address));
-            }
-            RecordParams::AAAA { ip_address } => {
-                url.push_str(&format!("&ipAddress={}", ip_address));
-            }
-            RecordParams::CNAME { cname } => {
-                url.push_str(&format!("&cname={}", cname));
-            }
-            RecordParams::MX {
-                preference,
-                exchange,
-            } => {
-                url.push_str(&format!("&preference={}&exchange={}", preference, exchange));
-            }
-            RecordParams::NS { name_server } => {
-                url.push_str(&format!("&nameServer={}", name_server));
-            }
-            RecordParams::TXT { text } => {
-                url.push_str(&format!("&text={}", url_encode(text)));
-            }
-            RecordParams::SRV {
-                priority,
-                weight,
-                port,
-                target,
-            } => {
-                url.push_str(&format!(
-                    "&priority={}&weight={}&port={}&target={}",
-                    priority, weight, port, target
-                ));
-            }
-            RecordParams::PTR { ptr_name } => {
-                url.push_str(&format!("&ptrName={}", ptr_name));
-            }
-            RecordParams::CAA { flags, tag, value } => {
-                url.push_str(&format!(
-                    "&flags={}&tag={}&value={}",
-                    flags,
-                    tag,
-                    url_encode(value)
-                ));
-            }
-            RecordParams::DS {
-                key_tag,
-                algorithm,
-                digest_type,
-                digest,
-            } => {
-                url.push_str(&format!(
-                    "&keyTag={}&algorithm={}&digestType={}&digest={}",
-                    key_tag, algorithm, digest_type, digest
-                ));
-            }
-            RecordParams::DNAME { dname } => {
-                url.push_str(&format!("&dname={}", dname));
+        for (name, value) in record_params.query_params() {
+            url.push_str(&format!("&{}={}", name, value));
+        }
+
+        let response: ApiResponse<AddRecordResponse> = self
+            .http_client
+            .get(self.url_with_token(&url))
+            .send()
+            .await
+            .map_err(ApiError::Request)?
+            .json()
+            .await
+            .map_err(ApiError::Request)?;
+
+        response.into_result()
+    }
+
+    /// Updates an existing DNS record in place.
+    ///
+    /// The record is identified by `domain`, `record_type` and `old_params`,
+    /// and receives the values of `new_params` and the given TTL without being
+    /// deleted first. Passing `new_domain` also renames the record.
+    ///
+    /// Both parameter sets must be of the same record type; otherwise
+    /// [`ApiError::InvalidRecord`] is returned.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn update_record(
+        &self,
+        zone: &str,
+        domain: &str,
+        new_domain: Option<&str>,
+        record_type: &str,
+        ttl: u64,
+        old_params: &RecordParams,
+        new_params: &RecordParams,
+    ) -> Result<UpdateRecordResponse, ApiError> {
+        if std::mem::discriminant(old_params) != std::mem::discriminant(new_params) {
+            return Err(ApiError::InvalidRecord);
+        }
+
+        let mut url = format!(
+            "/api/zones/records/update?domain={}&zone={}&type={}&ttl={}",
+            domain, zone, record_type, ttl
+        );
+        if let Some(new_domain) = new_domain {
+            url.push_str(&format!("&newDomain={}", new_domain));
+        }
+
+        match old_params {
+            // CNAME and DNAME records exist once per name, so only the new
+            // value is passed.
+            RecordParams::CNAME { .. } | RecordParams::DNAME { .. } => {
+                for (name, value) in new_params.query_params() {
+                    url.push_str(&format!("&{}={}", name, value));
+                }
             }
-            RecordParams::Other { value } => {
-                url.push_str(&format!("&rdata={}", url_encode(value)));
+            _ => {
+                for ((name, old), (_, new)) in old_params
+                    .query_params()
+                    .into_iter()
+                    .zip(new_params.query_params())
+                {
+                    url.push_str(&format!(
+                        "&{}={}&{}={}",
+                        name,
+                        old,
+                        update_param_name(name),
+                        new
+                    ));
+                }
             }
         }
 
-        let response: ApiResponse<AddRecordResponse> = self
+        let response: ApiResponse<UpdateRecordResponse> = self
             .http_client
             .get(self.url_with_token(&url))
             .send()
@@ -414,67 +487,10 @@ impl Client {
             domain, zone, record_type
         );
 
-        // Add record-type specific parameters for identifying the record
-        match record_params {
-            RecordParams::A { ip_address } => {
-                url.push_str(&format!("&ipAddress={}", ip_address));
-            }
-            RecordParams::AAAA { ip_address } => {
-                url.push_str(&format!("&ipAddress={}", ip_address));
-            }
-            RecordParams::CNAME { cname: _ } => {
-                // CNAME doesn't need additional params for delete
-            }
-            RecordParams::MX {
-                preference,
-                exchange,
-            } => {
-                url.push_str(&format!("&preference={}&exchange={}", preference, exchange));
-            }
-            RecordParams::NS { name_server } => {
-                url.push_str(&format!("&nameServer={}", name_server));
-            }
-            RecordParams::TXT { text } => {
-                url.push_str(&format!("&text={}", url_encode(text)));
-            }
-            RecordParams::SRV {
-                priority,
-                weight,
-                port,
-                target,
-            } => {
-                url.push_str(&format!(
-                    "&priority={}&weight={}&port={}&target={}",
-                    priority, weight, port, target
-                ));
-            }
-            RecordParams::PTR { ptr_name } => {
-                url.push_str(&format!("&ptrName={}", ptr_name));
-            }
-            RecordParams::CAA { flags, tag, value } => {
-                url.push_str(&format!(
-                    "&flags={}&tag={}&value={}",
-                    flags,
-                    tag,
-                    url_encode(value)
-                ));
-            }
-            RecordParams::DS {
-                key_tag,
-                algorithm,
-                digest_type,
-                digest,
-            } => {
-                url.push_str(&format!(
-                    "&keyTag={}&algorithm={}&digestType={}&digest={}",
-                    key_tag, algorithm, digest_type, digest
-                ));
-            }
-            RecordParams::DNAME { dname } => {
-                url.push_str(&format!("&dname={}", dname));
-            }
-            RecordParams::Other { value } => {
-                url.push_str(&format!("&rdata={}", url_encode(value)));
+        // CNAME records exist once per name, so they need no value to be identified.
+        if !matches!(record_params, RecordParams::CNAME { .. }) {
+            for (name, value) in record_params.query_params() {
+                url.push_str(&format!("&{}={}", name, value));
             }
         }
 
@@ -542,6 +558,74 @@ pub enum RecordParams {
     },
 }
 
+impl RecordParams {
+    /// Returns the query parameters identifying the record data.
+    ///
+    /// Free-form values are URL-encoded.
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        match self {
+            RecordParams::A { ip_address } | RecordParams::AAAA { ip_address } => {
+                vec![("ipAddress", ip_address.clone())]
+            }
+            RecordParams::CNAME { cname } => vec![("cname", cname.clone())],
+            RecordParams::MX {
+                preference,
+                exchange,
+            } => vec![
+                ("preference", preference.to_string()),
+                ("exchange", exchange.clone()),
+            ],
+            RecordParams::NS { name_server } => vec![("nameServer", name_server.clone())],
+            RecordParams::TXT { text } => vec![("text", url_encode(text))],
+            RecordParams::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => vec![
+                ("priority", priority.to_string()),
+                ("weight", weight.to_string()),
+                ("port", port.to_string()),
+                ("target", target.clone()),
+            ],
+            RecordParams::PTR { ptr_name } => vec![("ptrName", ptr_name.clone())],
+            RecordParams::CAA { flags, tag, value } => vec![
+                ("flags", flags.to_string()),
+                ("tag", tag.clone()),
+                ("value", url_encode(value)),
+            ],
+            RecordParams::DS {
+                key_tag,
+                algorithm,
+                digest_type,
+                digest,
+            } => vec![
+                ("keyTag", key_tag.to_string()),
+                ("algorithm", algorithm.clone()),
+                ("digestType", digest_type.clone()),
+                ("digest", digest.clone()),
+            ],
+            RecordParams::DNAME { dname } => vec![("dname", dname.clone())],
+            RecordParams::Other { value } => vec![("rdata", url_encode(value))],
+        }
+    }
+}
+
+/// Returns the name of the parameter carrying the new value in an update
+/// (e.g., `newIpAddress` for `ipAddress`).
+fn update_param_name(name: &str) -> String {
+    match name {
+        "rdata" => "newRData".to_string(),
+        _ => {
+            let mut chars = name.chars();
+            match chars.next() {
+                Some(first) => format!("new{}{}", first.to_ascii_uppercase(), chars.as_str()),
+                None => String::new(),
+            }
+        }
+    }
+}
+
 /// API response status as documented by Technitium.
 ///
 /// The `status` property can have the following values:
@@ -712,6 +796,301 @@ pub struct ZoneOptionsResponse {
     pub disabled: bool,
 }
 
+/// Type and type-specific options of a zone to create.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CreateZoneOptions {
+    /// A primary zone whose records are managed on this server.
+    Primary,
+    /// A read-only copy of a zone, kept in sync through zone transfers.
+    Secondary {
+        /// Addresses of the primary name servers. If empty, they are
+        /// resolved from the SOA record of the zone.
+        primary_name_server_addresses: Vec<String>,
+        /// Protocol used for zone transfers.
+        zone_transfer_protocol: ZoneTransferProtocol,
+        /// Name of the TSIG key used to sign zone transfers.
+        tsig_key_name: Option<String>,
+    },
+    /// A zone holding only the NS records of the primary name servers.
+    Stub {
+        /// Addresses of the primary name servers. If empty, they are
+        /// resolved from the SOA record of the zone.
+        primary_name_server_addresses: Vec<String>,
+    },
+    /// A zone forwarding all queries for its names to one forwarder.
+    Forwarder {
+        /// Forwarder address (e.g., `192.0.2.1`, `dns.example.net:853` or
+        /// `https://dns.example.net/dns-query`).
+        forwarder: String,
+        /// Protocol used to reach the forwarder.
+        protocol: ForwarderProtocol,
+        /// Whether responses from the forwarder are DNSSEC-validated.
+        dnssec_validation: bool,
+    },
+    /// An empty conditional forwarder zone.
+    ///
+    /// Forwarding is configured per name afterwards with FWD records, and
+    /// names without one are resolved normally.
+    ConditionalForwarder,
+}
+
+impl CreateZoneOptions {
+    /// Returns the zone type as reported by the API (e.g., `Secondary`).
+    pub fn zone_type(&self) -> &'static str {
+        match self {
+            CreateZoneOptions::Primary => "Primary",
+            CreateZoneOptions::Secondary { .. } => "Secondary",
+            CreateZoneOptions::Stub { .. } => "Stub",
+            // Both kinds are conditional forwarder zones to Technitium.
+            CreateZoneOptions::Forwarder { .. } | CreateZoneOptions::ConditionalForwarder => {
+                "Forwarder"
+            }
+        }
+    }
+
+    /// Returns the type-specific query parameters.
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        let mut params = Vec::new();
+        match self {
+            CreateZoneOptions::Primary => {}
+            CreateZoneOptions::Secondary {
+                primary_name_server_addresses,
+                zone_transfer_protocol,
+                tsig_key_name,
+            } => {
+                if !primary_name_server_addresses.is_empty() {
+                    params.push((
+                        "primaryNameServerAddresses",
+                        url_encode(&primary_name_server_addresses.join(",")),
+                    ));
+                }
+                params.push((
+                    "zoneTransferProtocol",
+                    zone_transfer_protocol.as_str().to_string(),
+                ));
+                if let Some(tsig_key_name) = tsig_key_name {
+                    params.push(("tsigKeyName", url_encode(tsig_key_name)));
+                }
+            }
+            CreateZoneOptions::Stub {
+                primary_name_server_addresses,
+            } => {
+                if !primary_name_server_addresses.is_empty() {
+                    params.push((
+                        "primaryNameServerAddresses",
+                        url_encode(&primary_name_server_addresses.join(",")),
+                    ));
+                }
+            }
+            CreateZoneOptions::Forwarder {
+                forwarder,
+                protocol,
+                dnssec_validation,
+            } => {
+                params.push(("initializeForwarder", "true".to_string()));
+                params.push(("protocol", protocol.as_str().to_string()));
+                params.push(("forwarder", url_encode(forwarder)));
+                params.push(("dnssecValidation", dnssec_validation.to_string()));
+            }
+            CreateZoneOptions::ConditionalForwarder => {
+                params.push(("initializeForwarder", "false".to_string()));
+            }
+        }
+        params
+    }
+}
+
+/// Protocol used to reach a forwarder.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ForwarderProtocol {
+    /// Plain DNS over UDP (falling back to TCP).
+    Udp,
+    /// Plain DNS over TCP.
+    Tcp,
+    /// DNS-over-TLS (DoT).
+    Tls,
+    /// DNS-over-HTTPS (DoH).
+    Https,
+    /// DNS-over-QUIC (DoQ).
+    Quic,
+}
+
+impl ForwarderProtocol {
+    /// Returns the API name of the protocol.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ForwarderProtocol::Udp => "Udp",
+            ForwarderProtocol::Tcp => "Tcp",
+            ForwarderProtocol::Tls => "Tls",
+            ForwarderProtocol::Https => "Https",
+            ForwarderProtocol::Quic => "Quic",
+        }
+    }
+}
+
+/// Protocol used for zone transfers from a primary name server.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum ZoneTransferProtocol {
+    /// Zone transfer over TCP (AXFR/IXFR).
+    #[default]
+    Tcp,
+    /// Zone transfer over TLS (XFR-over-TLS).
+    Tls,
+    /// Zone transfer over QUIC (XFR-over-QUIC).
+    Quic,
+}
+
+impl ZoneTransferProtocol {
+    /// Returns the API name of the protocol.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZoneTransferProtocol::Tcp => "Tcp",
+            ZoneTransferProtocol::Tls => "Tls",
+            ZoneTransferProtocol::Quic => "Quic",
+        }
+    }
+}
+
+/// Which servers may transfer a zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum ZoneTransferPolicy {
+    /// Zone transfers are denied.
+    Deny,
+    /// Any server may transfer the zone.
+    Allow,
+    /// Only the name servers of the zone may transfer it.
+    AllowOnlyZoneNameServers,
+    /// Only addresses matching the zone transfer network ACL may transfer it.
+    #[serde(rename = "UseSpecifiedNetworkACL")]
+    UseSpecifiedNetworkAcl,
+    /// The name servers of the zone and addresses matching the network ACL
+    /// may transfer it.
+    #[serde(rename = "AllowZoneNameServersAndUseSpecifiedNetworkACL")]
+    AllowZoneNameServersAndUseSpecifiedNetworkAcl,
+}
+
+impl ZoneTransferPolicy {
+    /// Returns the API name of the policy.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ZoneTransferPolicy::Deny => "Deny",
+            ZoneTransferPolicy::Allow => "Allow",
+            ZoneTransferPolicy::AllowOnlyZoneNameServers => "AllowOnlyZoneNameServers",
+            ZoneTransferPolicy::UseSpecifiedNetworkAcl => "UseSpecifiedNetworkACL",
+            ZoneTransferPolicy::AllowZoneNameServersAndUseSpecifiedNetworkAcl => {
+                "AllowZoneNameServersAndUseSpecifiedNetworkACL"
+            }
+        }
+    }
+}
+
+/// Which servers are sent NOTIFY messages when a zone changes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub enum NotifyPolicy {
+    /// No NOTIFY messages are sent.
+    None,
+    /// The name servers of the zone are notified.
+    ZoneNameServers,
+    /// The notify name servers are notified.
+    SpecifiedNameServers,
+    /// Both the name servers of the zone and the notify name servers are
+    /// notified.
+    BothZoneAndSpecifiedNameServers,
+    /// Catalog and member zones notify separate sets of name servers.
+    SeparateNameServersForCatalogAndMemberZones,
+}
+
+impl NotifyPolicy {
+    /// Returns the API name of the policy.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            NotifyPolicy::None => "None",
+            NotifyPolicy::ZoneNameServers => "ZoneNameServers",
+            NotifyPolicy::SpecifiedNameServers => "SpecifiedNameServers",
+            NotifyPolicy::BothZoneAndSpecifiedNameServers => "BothZoneAndSpecifiedNameServers",
+            NotifyPolicy::SeparateNameServersForCatalogAndMemberZones => {
+                "SeparateNameServersForCatalogAndMemberZones"
+            }
+        }
+    }
+}
+
+/// Zone transfer and notify options of a zone.
+///
+/// Returned by [`Client::get_zone_transfer_options`] with the options that
+/// apply to the zone type, and passed to
+/// [`Client::set_zone_transfer_options`] with the options to change.
+/// Options left as `None` are not changed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ZoneTransferOptions {
+    /// Which servers may transfer the zone.
+    #[serde(rename = "zoneTransfer", default)]
+    pub zone_transfer: Option<ZoneTransferPolicy>,
+    /// Addresses or networks allowed to transfer the zone (e.g., `192.0.2.0/24`,
+    /// or `!192.0.2.1` to deny one address).
+    #[serde(rename = "zoneTransferNetworkACL", default)]
+    pub zone_transfer_network_acl: Option<Vec<String>>,
+    /// TSIG keys accepted for zone transfers.
+    #[serde(rename = "zoneTransferTsigKeyNames", default)]
+    pub zone_transfer_tsig_key_names: Option<Vec<String>>,
+    /// Which servers are notified when the zone changes.
+    #[serde(rename = "notify", default)]
+    pub notify: Option<NotifyPolicy>,
+    /// Name servers notified in addition to or instead of those of the zone.
+    #[serde(rename = "notifyNameServers", default)]
+    pub notify_name_servers: Option<Vec<String>>,
+    /// Addresses of the primary name servers (secondary and stub zones).
+    #[serde(rename = "primaryNameServerAddresses", default)]
+    pub primary_name_server_addresses: Option<Vec<String>>,
+    /// Protocol used for zone transfers from the primary (secondary zones).
+    #[serde(rename = "primaryZoneTransferProtocol", default)]
+    pub primary_zone_transfer_protocol: Option<ZoneTransferProtocol>,
+    /// TSIG key used for zone transfers from the primary (secondary zones).
+    #[serde(rename = "primaryZoneTransferTsigKeyName", default)]
+    pub primary_zone_transfer_tsig_key_name: Option<String>,
+}
+
+impl ZoneTransferOptions {
+    /// Returns the query parameters for the options that are set.
+    fn query_params(&self) -> Vec<(&'static str, String)> {
+        // The API clears a list when it is passed `false`.
+        fn list(values: &[String]) -> String {
+            if values.is_empty() {
+                "false".to_string()
+            } else {
+                url_encode(&values.join(","))
+            }
+        }
+
+        let mut params = Vec::new();
+        if let Some(zone_transfer) = self.zone_transfer {
+            params.push(("zoneTransfer", zone_transfer.as_str().to_string()));
+        }
+        if let Some(acl) = &self.zone_transfer_network_acl {
+            params.push(("zoneTransferNetworkACL", list(acl)));
+        }
+        if let Some(keys) = &self.zone_transfer_tsig_key_names {
+            params.push(("zoneTransferTsigKeyNames", list(keys)));
+        }
+        if let Some(notify) = self.notify {
+            params.push(("notify", notify.as_str().to_string()));
+        }
+        if let Some(servers) = &self.notify_name_servers {
+            params.push(("notifyNameServers", list(servers)));
+        }
+        if let Some(addresses) = &self.primary_name_server_addresses {
+            params.push(("primaryNameServerAddresses", list(addresses)));
+        }
+        if let Some(protocol) = self.primary_zone_transfer_protocol {
+            params.push(("primaryZoneTransferProtocol", protocol.as_str().to_string()));
+        }
+        if let Some(key) = &self.primary_zone_transfer_tsig_key_name {
+            params.push(("primaryZoneTransferTsigKeyName", url_encode(key)));
+        }
+        params
+    }
+}
+
 /// Create zone response.
 #[derive(Debug, Deserialize)]
 pub struct CreateZoneResponse {
@@ -929,3 +1308,11 @@ pub struct AddRecordResponse {
     #[serde(rename = "addedRecord")]
     pub added_record: Record,
 }
+
+/// Update record response.
+#[derive(Debug, Deserialize)]
+pub struct UpdateRecordResponse {
+    pub zone: ZoneInfo,
+    #[serde(rename = "updatedRecord")]
+    pub updated_record: Record,
+}
@@ -40,6 +40,18 @@
 //! Technitium doesn't provide unique record IDs. Instead, records are identified by a combination
 //! of domain name, record type, and record data. This implementation generates a composite ID
 //! in the format `{domain}:{type}:{data_hash}` for compatibility with the generic Record interface.
+//!
+//! # Zone Types
+//!
+//! [`CreateZone::create_zone`] creates primary zones.
+//! [`TechnitiumProvider::create_zone_with_options`] also creates secondary, stub, forwarder
+//! and conditional forwarder zones, and [`TechnitiumZone::set_transfer_options`] configures
+//! zone transfers and NOTIFY.
+//!
+//! # Record Updates
+//!
+//! [`TechnitiumZone::update_record`] changes the value, TTL or name of a record through
+//! Technitium's update endpoint, so resolvers never see the record missing in between.
 
 use std::sync::Arc;
 
@@ -190,6 +202,57 @@ impl TechnitiumProvider {
     }
 }
 
+impl TechnitiumProvider {
+    /// Creates a zone of the type described by `options`.
+    ///
+    /// [`CreateZone::create_zone`] always creates a primary zone; this also
+    /// creates secondary, stub and forwarder zones.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::technitium::api::{CreateZoneOptions, ZoneTransferProtocol};
+    /// use manydns::technitium::TechnitiumProvider;
+    ///
+    /// # async fn example(provider: TechnitiumProvider) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let zone = provider
+    ///     .create_zone_with_options(
+    ///         "example.com",
+    ///         &CreateZoneOptions::Secondary {
+    ///             primary_name_server_addresses: vec!["192.0.2.1".to_string()],
+    ///             zone_transfer_protocol: ZoneTransferProtocol::Tcp,
+    ///             tsig_key_name: Some("transfer-key".to_string()),
+    ///         },
+    ///     )
+    ///     .await?;
+    /// assert_eq!(zone.zone_type(), "Secondary");
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn create_zone_with_options(
+        &self,
+        domain: &str,
+        options: &api::CreateZoneOptions,
+    ) -> Result<TechnitiumZone, CreateZoneError<api::ApiError>> {
+        let response = self
+            .api_client
+            .create_zone_with_options(domain, options)
+            .await
+            .map_err(|err| match &err {
+                api::ApiError::Unauthorized => CreateZoneError::Unauthorized,
+                api::ApiError::InvalidDomainName => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(TechnitiumZone {
+            api_client: self.api_client.clone(),
+            name: response.domain,
+            zone_type: options.zone_type().to_string(),
+            disabled: false,
+        })
+    }
+}
+
 impl Provider for TechnitiumProvider {
     type Zone = TechnitiumZone;
     type CustomRetrieveError = api::ApiError;
@@ -249,22 +312,8 @@ impl CreateZone for TechnitiumProvider {
         &self,
         domain: &str,
     ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
-        let response = self
-            .api_client
-            .create_zone(domain)
+        self.create_zone_with_options(domain, &api::CreateZoneOptions::Primary)
             .await
-            .map_err(|err| match &err {
-                api::ApiError::Unauthorized => CreateZoneError::Unauthorized,
-                api::ApiError::InvalidDomainName => CreateZoneError::InvalidDomainName,
-                _ => CreateZoneError::Custom(err),
-            })?;
-
-        Ok(TechnitiumZone {
-            api_client: self.api_client.clone(),
-            name: response.domain,
-            zone_type: "Primary".to_string(),
-            disabled: false,
-        })
     }
 }
 
@@ -315,6 +364,107 @@ impl TechnitiumZone {
     pub async fn disable(&self) -> Result<(), api::ApiError> {
         self.api_client.disable_zone(&self.name).await
     }
+
+    /// Returns the zone transfer and notify options of the zone.
+    pub async fn transfer_options(&self) -> Result<api::ZoneTransferOptions, api::ApiError> {
+        self.api_client.get_zone_transfer_options(&self.name).await
+    }
+
+    /// Changes the zone transfer and notify options of the zone.
+    ///
+    /// Only options that are `Some` are changed.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::technitium::api::{NotifyPolicy, ZoneTransferOptions, ZoneTransferPolicy};
+    /// use manydns::technitium::TechnitiumProvider;
+    /// use manydns::Provider;
+    ///
+    /// # async fn example(provider: TechnitiumProvider) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+    /// let zone = provider.get_zone("example.com").await?;
+    /// zone.set_transfer_options(&ZoneTransferOptions {
+    ///     zone_transfer: Some(ZoneTransferPolicy::UseSpecifiedNetworkAcl),
+    ///     zone_transfer_network_acl: Some(vec!["192.0.2.0/24".to_string()]),
+    ///     zone_transfer_tsig_key_names: Some(vec!["transfer-key".to_string()]),
+    ///     notify: Some(NotifyPolicy::SpecifiedNameServers),
+    ///     notify_name_servers: Some(vec!["192.0.2.53".to_string()]),
+    ///     ..Default::default()
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn set_transfer_options(
+        &self,
+        options: &api::ZoneTransferOptions,
+    ) -> Result<(), api::ApiError> {
+        self.api_client
+            .set_zone_transfer_options(&self.name, options)
+            .await
+    }
+
+    /// Updates the host, value and TTL of an existing record in place.
+    ///
+    /// Unlike deleting and re-adding the record, this uses Technitium's
+    /// update endpoint, so the record never disappears from the zone while
+    /// it changes. The record type cannot be changed. The returned record
+    /// has a new ID if the host or value changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<api::ApiError>> {
+        let typ = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&typ) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        let record = self.get_record(record_id).await.map_err(|err| match err {
+            RetrieveRecordError::Unauthorized => CreateRecordError::Unauthorized,
+            RetrieveRecordError::NotFound => CreateRecordError::Custom(api::ApiError::NotFound),
+            RetrieveRecordError::Custom(e) => CreateRecordError::Custom(e),
+        })?;
+        if record.data.get_type() != typ {
+            return Err(CreateRecordError::InvalidRecord);
+        }
+
+        let domain = self.record_domain(host);
+        let new_domain = (!domain.eq_ignore_ascii_case(&record.host)).then_some(domain.as_str());
+
+        let response = self
+            .api_client
+            .update_record(
+                &self.name,
+                &record.host,
+                new_domain,
+                typ,
+                ttl,
+                &record_data_to_params(&record.data),
+                &record_data_to_params(data),
+            )
+            .await
+            .map_err(|err| match &err {
+                api::ApiError::Unauthorized => CreateRecordError::Unauthorized,
+                api::ApiError::InvalidRecord => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(Record::from(response.updated_record))
+    }
+
+    /// Converts a host relative to the zone into a fully-qualified domain name.
+    fn record_domain(&self, host: &str) -> String {
+        if host == "@" || host.is_empty() {
+            self.name.clone()
+        } else if host.ends_with('.') {
+            host.trim_end_matches('.').to_string()
+        } else {
+            format!("{}.{}", host, self.name)
+        }
+    }
 }
 
 impl Zone for TechnitiumZone {
@@ -396,13 +546,7 @@ impl CreateRecord for TechnitiumZone {
         }
 
         let record_params = record_data_to_params(data);
-        let domain = if host == "@" || host.is_empty() {
-            self.name.clone()
-        } else if host.ends_with('.') {
-            host.trim_end_matches('.').to_string()
-        } else {
-            format!("{}.{}", host, self.name)
-        };
+        let domain = self.record_domain(host);
 
         let response = self
             .api_client
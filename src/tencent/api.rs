@@ -14,6 +14,7 @@ use serde::{Deserialize, Serialize};
 use std::time::{SystemTime, UNIX_EPOCH};
 use thiserror::Error;
 
+use crate::signing::{hmac_sha256, hmac_sha256_hex, sha256_hex};
 use crate::HttpClientConfig;
 
 /// The Tencent Cloud DNSPod API endpoint.
@@ -329,25 +330,6 @@ pub struct DeleteDomainResponse {
 // TC3-HMAC-SHA256 Signature Implementation
 // =============================================================================
 
-/// Computes SHA256 hash and returns it as a lowercase hex string.
-fn sha256_hex(data: &str) -> String {
-    use sha2::{Digest, Sha256};
-    let mut hasher = Sha256::new();
-    hasher.update(data.as_bytes());
-    hex::encode(hasher.finalize())
-}
-
-/// Computes HMAC-SHA256 and returns the raw bytes.
-fn hmac_sha256(key: &[u8], data: &str) -> Vec<u8> {
-    use hmac::{Hmac, Mac};
-    use sha2::Sha256;
-
-    type HmacSha256 = Hmac<Sha256>;
-    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC can take key of any size");
-    mac.update(data.as_bytes());
-    mac.finalize().into_bytes().to_vec()
-}
-
 /// Generates the TC3-HMAC-SHA256 signature for a request.
 fn generate_signature(
     secret_id: &str,
@@ -363,7 +345,7 @@ fn generate_signature(
     let content_type = "application/json; charset=utf-8";
     let canonical_headers = format!("content-type:{}\nhost:{}\n", content_type, TENCENT_API_HOST);
     let signed_headers = "content-type;host";
-    let hashed_request_payload = sha256_hex(payload);
+    let hashed_request_payload = sha256_hex(payload.as_bytes());
 
     let canonical_request = format!(
         "{}\n{}\n{}\n{}\n{}\n{}",
@@ -382,7 +364,7 @@ fn generate_signature(
         .format("%Y-%m-%d")
         .to_string();
     let credential_scope = format!("{}/{}/tc3_request", date, SERVICE);
-    let hashed_canonical_request = sha256_hex(&canonical_request);
+    let hashed_canonical_request = sha256_hex(canonical_request.as_bytes());
 
     let string_to_sign = format!(
         "{}\n{}\n{}\n{}",
@@ -393,7 +375,7 @@ fn generate_signature(
     let secret_date = hmac_sha256(format!("TC3{}", secret_key).as_bytes(), &date);
     let secret_service = hmac_sha256(&secret_date, SERVICE);
     let secret_signing = hmac_sha256(&secret_service, "tc3_request");
-    let signature = hex::encode(hmac_sha256(&secret_signing, &string_to_sign));
+    let signature = hmac_sha256_hex(&secret_signing, &string_to_sign);
 
     // Step 4: Build authorization header
     let authorization = format!(
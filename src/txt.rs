@@ -0,0 +1,164 @@
+//! TXT record value helpers shared by the providers.
+//!
+//! Many APIs exchange TXT values in zone file presentation format: one or
+//! more quoted character strings of at most 255 bytes each, with `"` and
+//! `\` escaped. [`RecordData::TXT`](crate::RecordData::TXT) holds the plain
+//! concatenated value, so providers convert between the two here.
+
+/// Splits a TXT value into character strings of at most 255 bytes.
+///
+/// Strings are split on character boundaries, so multi-byte characters are
+/// never broken up.
+#[cfg(any(
+    feature = "powerdns",
+    feature = "route53",
+    feature = "gcloud-dns",
+    feature = "azure-dns",
+    feature = "desec",
+    feature = "vultr",
+    feature = "ovh",
+    feature = "huaweicloud",
+    feature = "akamai"
+))]
+pub(crate) fn split_txt(value: &str) -> Vec<String> {
+    let mut chunks = vec![String::new()];
+    for c in value.chars() {
+        if chunks.last().unwrap().len() + c.len_utf8() > 255 {
+            chunks.push(String::new());
+        }
+        chunks.last_mut().unwrap().push(c);
+    }
+    chunks
+}
+
+/// Quotes a TXT value, splitting it into character strings of at most 255 bytes.
+///
+/// A value that is already quoted is returned as is.
+#[cfg(any(
+    feature = "powerdns",
+    feature = "route53",
+    feature = "gcloud-dns",
+    feature = "desec",
+    feature = "vultr",
+    feature = "ovh",
+    feature = "huaweicloud",
+    feature = "akamai"
+))]
+pub(crate) fn quote_txt(value: &str) -> String {
+    if value.len() > 1 && value.starts_with('"') && value.ends_with('"') {
+        return value.to_string();
+    }
+
+    split_txt(value)
+        .iter()
+        .map(|chunk| format!("\"{}\"", chunk.replace('\\', "\\\\").replace('"', "\\\"")))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Unquotes TXT content consisting of one or more quoted character strings.
+///
+/// Content that does not start with a quote is returned as is.
+#[cfg(any(
+    feature = "powerdns",
+    feature = "route53",
+    feature = "gandi",
+    feature = "gcloud-dns",
+    feature = "desec",
+    feature = "vultr",
+    feature = "ovh",
+    feature = "huaweicloud",
+    feature = "akamai"
+))]
+pub(crate) fn unquote_txt(content: &str) -> String {
+    if !content.starts_with('"') {
+        return content.to_string();
+    }
+
+    let mut result = String::new();
+    let mut chars = content.chars();
+    let mut in_quotes = false;
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => in_quotes = !in_quotes,
+            '\\' if in_quotes => {
+                if let Some(next) = chars.next() {
+                    result.push(next);
+                }
+            }
+            c if in_quotes => result.push(c),
+            _ => {}
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(any(
+        feature = "powerdns",
+        feature = "route53",
+        feature = "gcloud-dns",
+        feature = "azure-dns",
+        feature = "desec",
+        feature = "vultr",
+        feature = "ovh",
+        feature = "huaweicloud",
+        feature = "akamai"
+    ))]
+    #[test]
+    fn test_split_txt() {
+        assert_eq!(split_txt(""), vec![""]);
+        let chunks = split_txt(&"é".repeat(200));
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 254);
+        assert_eq!(chunks[1].len(), 146);
+    }
+
+    #[cfg(any(
+        feature = "powerdns",
+        feature = "route53",
+        feature = "gcloud-dns",
+        feature = "desec",
+        feature = "vultr",
+        feature = "ovh",
+        feature = "huaweicloud",
+        feature = "akamai"
+    ))]
+    #[test]
+    fn test_quote_txt() {
+        assert_eq!(quote_txt("v=spf1 -all"), "\"v=spf1 -all\"");
+        assert_eq!(quote_txt("say \"hi\" \\o/"), "\"say \\\"hi\\\" \\\\o/\"");
+        assert_eq!(quote_txt("\"already quoted\""), "\"already quoted\"");
+
+        let long = "a".repeat(300);
+        assert_eq!(
+            quote_txt(&long),
+            format!("\"{}\" \"{}\"", "a".repeat(255), "a".repeat(45))
+        );
+        assert_eq!(unquote_txt(&quote_txt(&long)), long);
+    }
+
+    #[cfg(any(
+        feature = "powerdns",
+        feature = "route53",
+        feature = "gandi",
+        feature = "gcloud-dns",
+        feature = "desec",
+        feature = "vultr",
+        feature = "ovh",
+        feature = "huaweicloud",
+        feature = "akamai"
+    ))]
+    #[test]
+    fn test_unquote_txt() {
+        assert_eq!(unquote_txt("\"v=spf1 -all\""), "v=spf1 -all");
+        assert_eq!(unquote_txt("\"part one\" \"part two\""), "part onepart two");
+        assert_eq!(unquote_txt("\"say \\\"hi\\\" \\\\o/\""), "say \"hi\" \\o/");
+        assert_eq!(unquote_txt("unquoted"), "unquoted");
+    }
+}
@@ -0,0 +1,641 @@
+//! Low-level Volcengine TrafficRoute DNS API client.
+//!
+//! This module provides direct access to the TrafficRoute DNS OpenAPI
+//! (version `2018-08-01`) using Volcengine's `HMAC-SHA256` request signing.
+//! For most use cases, prefer using [`VolcengineProvider`](super::VolcengineProvider) instead.
+//!
+//! # Request Signing
+//!
+//! Requests are signed like Tencent Cloud's TC3 scheme (AWS SigV4 with a
+//! derived key), without a key prefix:
+//!
+//! ```text
+//! CanonicalRequest = METHOD \n / \n QUERY \n HEADERS \n SIGNED_HEADERS \n hex(sha256(body))
+//! StringToSign     = HMAC-SHA256 \n X-Date \n DATE/REGION/DNS/request \n hex(sha256(CanonicalRequest))
+//! SigningKey       = hmac(hmac(hmac(hmac(SK, DATE), REGION), DNS), "request")
+//! Signature        = hex(hmac_sha256(SigningKey, StringToSign))
+//! ```
+//!
+//! Every action is a `POST` to `/?Action={action}&Version=2018-08-01` with a
+//! JSON body.
+//!
+//! # API Reference
+//!
+//! - [TrafficRoute DNS API](https://www.volcengine.com/docs/6758/155086)
+//! - [Request signing](https://www.volcengine.com/docs/6369/67269)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::volcengine::api::{Client, Credentials};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("your-access-key-id", "your-secret-access-key");
+//! let client = Client::new(credentials)?;
+//!
+//! for zone in client.list_zones(None).await? {
+//!     println!("{} ({})", zone.zone_name, zone.zid);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use serde_json::json;
+use thiserror::Error;
+
+use crate::signing::{canonical_query, hmac_sha256, hmac_sha256_hex, sha256_hex};
+use crate::HttpClientConfig;
+
+/// Default API endpoint.
+const VOLCENGINE_API_URL: &str = "https://open.volcengineapi.com";
+
+/// Signing algorithm name.
+const ALGORITHM: &str = "HMAC-SHA256";
+
+/// Service name used in the credential scope.
+const SERVICE: &str = "DNS";
+
+/// Region used in the credential scope. TrafficRoute DNS is global.
+const REGION: &str = "cn-north-1";
+
+/// API version.
+const API_VERSION: &str = "2018-08-01";
+
+/// Content type of all requests, which is part of the signature.
+const CONTENT_TYPE: &str = "application/json";
+
+/// Page size requested from list actions.
+const PAGE_SIZE: u64 = 100;
+
+/// Errors that may occur when interacting with the TrafficRoute DNS API.
+#[derive(Debug, Error)]
+pub enum VolcengineError {
+    /// The API returned an error response.
+    #[error("API error {code} ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error code (e.g., `InvalidAccessKey`, `ZoneNotFound`).
+        code: String,
+        /// Error message.
+        message: String,
+    },
+
+    /// A successful response carried no result.
+    #[error("Response of {0} has no result")]
+    MissingResult(String),
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl VolcengineError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            VolcengineError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+
+    /// Returns the error code if this is an API error.
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            VolcengineError::Api { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    /// Returns `true` if the credentials or signature were rejected.
+    pub fn is_unauthorized(&self) -> bool {
+        matches!(self.status(), Some(401 | 403))
+            || matches!(
+                self.code(),
+                Some("InvalidAccessKey" | "InvalidCredential" | "SignatureDoesNotMatch")
+            )
+    }
+
+    /// Returns `true` if the zone or record does not exist.
+    pub fn is_not_found(&self) -> bool {
+        self.status() == Some(404) || self.code().is_some_and(|code| code.ends_with("NotFound"))
+    }
+}
+
+/// Response envelope of all actions.
+#[derive(Debug, Deserialize)]
+struct Response<T> {
+    #[serde(rename = "ResponseMetadata")]
+    metadata: ResponseMetadata,
+    #[serde(rename = "Result")]
+    result: Option<T>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseMetadata {
+    #[serde(rename = "Error")]
+    error: Option<ErrorBody>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorBody {
+    #[serde(rename = "Code", default)]
+    code: String,
+    #[serde(rename = "Message", default)]
+    message: String,
+}
+
+// =============================================================================
+// Authentication
+// =============================================================================
+
+/// Access key credentials.
+#[derive(Clone)]
+pub struct Credentials {
+    /// Access key ID.
+    pub access_key: String,
+    /// Secret access key.
+    pub secret_key: String,
+}
+
+impl Credentials {
+    /// Creates credentials from an access key pair.
+    pub fn new(access_key: impl Into<String>, secret_key: impl Into<String>) -> Self {
+        Self {
+            access_key: access_key.into(),
+            secret_key: secret_key.into(),
+        }
+    }
+
+    /// Reads credentials from the `VOLCENGINE_ACCESS_KEY` and
+    /// `VOLCENGINE_SECRET_KEY` environment variables.
+    pub fn from_env() -> Option<Self> {
+        let access_key = std::env::var("VOLCENGINE_ACCESS_KEY").ok()?;
+        let secret_key = std::env::var("VOLCENGINE_SECRET_KEY").ok()?;
+        Some(Self::new(access_key, secret_key))
+    }
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("access_key", &self.access_key)
+            .field("secret_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// A request to be signed with Volcengine `HMAC-SHA256`.
+#[derive(Debug, Clone)]
+pub struct SigningRequest<'a> {
+    /// HTTP method (e.g., `POST`).
+    pub method: &'a str,
+    /// Host header value (including a non-default port).
+    pub host: &'a str,
+    /// URI path, already percent-encoded.
+    pub path: &'a str,
+    /// Canonical query string (sorted and percent-encoded), or empty.
+    pub query: &'a str,
+    /// Request body.
+    pub payload: &'a [u8],
+    /// Region of the credential scope (e.g., `cn-north-1`).
+    pub region: &'a str,
+    /// Request timestamp in `YYYYMMDDTHHMMSSZ` format (the `X-Date` header).
+    pub x_date: &'a str,
+}
+
+/// Computes the `Authorization` header value for a request.
+///
+/// The signed headers are `content-type` (always `application/json`),
+/// `host`, `x-content-sha256` (the hex SHA-256 of the body) and `x-date`.
+pub fn sign(credentials: &Credentials, request: &SigningRequest<'_>) -> String {
+    let payload_hash = sha256_hex(request.payload);
+    let canonical_headers = format!(
+        "content-type:{}\nhost:{}\nx-content-sha256:{}\nx-date:{}\n",
+        CONTENT_TYPE, request.host, payload_hash, request.x_date
+    );
+    let signed_headers = "content-type;host;x-content-sha256;x-date";
+
+    let canonical_request = format!(
+        "{}\n{}\n{}\n{}\n{}\n{}",
+        request.method,
+        request.path,
+        request.query,
+        canonical_headers,
+        signed_headers,
+        payload_hash
+    );
+
+    let date = &request.x_date[..8];
+    let credential_scope = format!("{}/{}/{}/request", date, request.region, SERVICE);
+    let string_to_sign = format!(
+        "{}\n{}\n{}\n{}",
+        ALGORITHM,
+        request.x_date,
+        credential_scope,
+        sha256_hex(canonical_request.as_bytes())
+    );
+
+    let secret_date = hmac_sha256(credentials.secret_key.as_bytes(), date);
+    let secret_region = hmac_sha256(&secret_date, request.region);
+    let secret_service = hmac_sha256(&secret_region, SERVICE);
+    let secret_signing = hmac_sha256(&secret_service, "request");
+    let signature = hmac_sha256_hex(&secret_signing, &string_to_sign);
+
+    format!(
+        "{} Credential={}/{}, SignedHeaders={}, Signature={}",
+        ALGORITHM, credentials.access_key, credential_scope, signed_headers, signature
+    )
+}
+
+/// Returns the current time in `YYYYMMDDTHHMMSSZ` format.
+fn x_date_now() -> String {
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_secs();
+    chrono::DateTime::from_timestamp(timestamp as i64, 0)
+        .unwrap()
+        .format("%Y%m%dT%H%M%SZ")
+        .to_string()
+}
+
+// =============================================================================
+// Zone Types
+// =============================================================================
+
+/// A hosted zone.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Zone {
+    /// Zone ID.
+    #[serde(rename = "ZID", default)]
+    pub zid: u64,
+    /// Zone name (e.g., `example.com`).
+    pub zone_name: String,
+    /// Number of records in the zone.
+    #[serde(default)]
+    pub record_count: Option<u64>,
+    /// Name servers assigned to the zone.
+    #[serde(rename = "AllocateDNSServerList", default)]
+    pub dns_servers: Vec<String>,
+    /// Remark.
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct ZoneList {
+    #[serde(default)]
+    zones: Vec<Zone>,
+    #[serde(default)]
+    total: u64,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Record {
+    /// Record ID.
+    #[serde(rename = "RecordID")]
+    pub record_id: String,
+    /// Host relative to the zone (`@` for the apex).
+    pub host: String,
+    /// Record type.
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    /// Record value; MX and SRV values include the priority.
+    pub value: String,
+    /// TTL in seconds.
+    #[serde(rename = "TTL", default)]
+    pub ttl: u64,
+    /// Resolution line (`default` for all resolvers).
+    #[serde(default)]
+    pub line: String,
+    /// Whether the record is enabled.
+    #[serde(default)]
+    pub enable: Option<bool>,
+    /// Remark.
+    #[serde(default)]
+    pub remark: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct RecordList {
+    #[serde(default)]
+    records: Vec<Record>,
+    #[serde(default)]
+    total_count: u64,
+}
+
+/// Request for creating or updating a record.
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RecordRequest {
+    /// Host relative to the zone (`@` for the apex).
+    pub host: String,
+    /// Record type.
+    #[serde(rename = "Type")]
+    pub record_type: String,
+    /// Record value; MX and SRV values include the priority.
+    pub value: String,
+    /// TTL in seconds.
+    #[serde(rename = "TTL")]
+    pub ttl: u64,
+    /// Resolution line (`default` for all resolvers).
+    pub line: String,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Volcengine TrafficRoute DNS API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    credentials: Credentials,
+    base_url: String,
+    host: String,
+}
+
+impl Client {
+    /// Creates a new TrafficRoute DNS API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair used to sign requests
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, VOLCENGINE_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new TrafficRoute DNS API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair used to sign requests
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(credentials, VOLCENGINE_API_URL, config)
+    }
+
+    /// Creates a new TrafficRoute DNS API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair used to sign requests
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let url = reqwest::Url::parse(base_url)?;
+        let host = match (url.host_str(), url.port()) {
+            (Some(host), Some(port)) => format!("{}:{}", host, port),
+            (Some(host), None) => host.to_string(),
+            (None, _) => return Err("base URL has no host".into()),
+        };
+
+        let mut builder = reqwest::Client::builder()
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            credentials,
+            base_url: base_url.trim_end_matches('/').to_string(),
+            host,
+        })
+    }
+
+    /// Calls an action and returns its result.
+    async fn call<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        body: &serde_json::Value,
+    ) -> Result<Option<T>, VolcengineError> {
+        let query = canonical_query(&[("Action", action), ("Version", API_VERSION)]);
+        let payload = body.to_string();
+        let x_date = x_date_now();
+
+        let authorization = sign(
+            &self.credentials,
+            &SigningRequest {
+                method: "POST",
+                host: &self.host,
+                path: "/",
+                query: &query,
+                payload: payload.as_bytes(),
+                region: REGION,
+                x_date: &x_date,
+            },
+        );
+
+        let response = self
+            .http_client
+            .post(format!("{}/?{}", self.base_url, query))
+            .header("Authorization", authorization)
+            .header("Content-Type", CONTENT_TYPE)
+            .header("X-Date", &x_date)
+            .header("X-Content-Sha256", sha256_hex(payload.as_bytes()))
+            .body(payload)
+            .send()
+            .await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        let response: Response<T> = match serde_json::from_str(&text) {
+            Ok(response) => response,
+            Err(_) if !status.is_success() => {
+                return Err(VolcengineError::Api {
+                    status: status.as_u16(),
+                    code: String::new(),
+                    message: text,
+                })
+            }
+            Err(err) => return Err(err.into()),
+        };
+
+        if let Some(error) = response.metadata.error {
+            return Err(VolcengineError::Api {
+                status: status.as_u16(),
+                code: error.code,
+                message: error.message,
+            });
+        }
+        if !status.is_success() {
+            return Err(VolcengineError::Api {
+                status: status.as_u16(),
+                code: String::new(),
+                message: text,
+            });
+        }
+
+        Ok(response.result)
+    }
+
+    /// Calls an action whose result is required.
+    async fn call_result<T: DeserializeOwned>(
+        &self,
+        action: &str,
+        body: &serde_json::Value,
+    ) -> Result<T, VolcengineError> {
+        self.call(action, body)
+            .await?
+            .ok_or_else(|| VolcengineError::MissingResult(action.to_string()))
+    }
+
+    // =========================================================================
+    // Zone APIs
+    // =========================================================================
+
+    /// Lists zones, following pagination.
+    ///
+    /// `key` filters zones whose name contains the given value.
+    pub async fn list_zones(&self, key: Option<&str>) -> Result<Vec<Zone>, VolcengineError> {
+        let mut zones = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let mut body = json!({ "PageNumber": page, "PageSize": PAGE_SIZE });
+            if let Some(key) = key {
+                body["Key"] = key.into();
+            }
+
+            let list: ZoneList = self.call_result("ListZones", &body).await?;
+            let count = list.zones.len();
+            zones.extend(list.zones);
+
+            if count == 0 || zones.len() as u64 >= list.total {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(zones)
+    }
+
+    /// Gets a zone by ID.
+    pub async fn query_zone(&self, zid: u64) -> Result<Zone, VolcengineError> {
+        let mut zone: Zone = self
+            .call_result("QueryZone", &json!({ "ZID": zid }))
+            .await?;
+        zone.zid = zid;
+        Ok(zone)
+    }
+
+    /// Creates a zone.
+    pub async fn create_zone(&self, zone_name: &str) -> Result<Zone, VolcengineError> {
+        self.call_result("CreateZone", &json!({ "ZoneName": zone_name }))
+            .await
+    }
+
+    /// Deletes a zone.
+    pub async fn delete_zone(&self, zid: u64) -> Result<(), VolcengineError> {
+        self.call::<serde_json::Value>("DeleteZone", &json!({ "ZID": zid }))
+            .await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists the records of a zone, following pagination.
+    pub async fn list_records(&self, zid: u64) -> Result<Vec<Record>, VolcengineError> {
+        let mut records = Vec::new();
+        let mut page = 1;
+
+        loop {
+            let body = json!({ "ZID": zid, "PageNumber": page, "PageSize": PAGE_SIZE });
+            let list: RecordList = self.call_result("ListRecords", &body).await?;
+            let count = list.records.len();
+            records.extend(list.records);
+
+            if count == 0 || records.len() as u64 >= list.total_count {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(records)
+    }
+
+    /// Gets a record by ID.
+    pub async fn query_record(&self, record_id: &str) -> Result<Record, VolcengineError> {
+        self.call_result("QueryRecord", &json!({ "RecordID": record_id }))
+            .await
+    }
+
+    /// Creates a record in a zone.
+    pub async fn create_record(
+        &self,
+        zid: u64,
+        request: &RecordRequest,
+    ) -> Result<Record, VolcengineError> {
+        let mut body = serde_json::to_value(request)?;
+        body["ZID"] = zid.into();
+        self.call_result("CreateRecord", &body).await
+    }
+
+    /// Updates the host, value, TTL and line of a record.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        request: &RecordRequest,
+    ) -> Result<(), VolcengineError> {
+        let mut body = serde_json::to_value(request)?;
+        body["RecordID"] = record_id.into();
+        self.call::<serde_json::Value>("UpdateRecord", &body)
+            .await?;
+        Ok(())
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(&self, record_id: &str) -> Result<(), VolcengineError> {
+        self.call::<serde_json::Value>("DeleteRecord", &json!({ "RecordID": record_id }))
+            .await?;
+        Ok(())
+    }
+}
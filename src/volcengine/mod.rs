@@ -0,0 +1,448 @@
+//! Volcengine TrafficRoute DNS provider implementation.
+//!
+//! This provider uses the TrafficRoute DNS OpenAPI with Volcengine's
+//! `HMAC-SHA256` request signing, a V4-style scheme close to Tencent Cloud's
+//! TC3.
+//!
+//! # Authentication
+//!
+//! Requires an access key ID and secret access key:
+//! - Create them under "Access Control" > "API Access Keys" in the console
+//! - The IAM user needs the `TrafficRouteFullAccess` policy
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::volcengine::{Credentials, VolcengineProvider};
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let credentials = Credentials::new("your-access-key-id", "your-secret-access-key");
+//! let provider = VolcengineProvider::new(credentials)?;
+//!
+//! // List all zones
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {} (ID: {})", zone.domain(), zone.id());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 600).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zones
+//!
+//! The zone ID is the numeric `ZID` assigned by TrafficRoute;
+//! [`get_zone`](crate::Provider::get_zone) also accepts a domain name.
+//!
+//! # Record Values
+//!
+//! TrafficRoute keeps the priority of MX and SRV records in the value
+//! (`10 mail.example.com`, `10 5 5060 sip.example.com`). Records are created
+//! on the `default` resolution line.
+//!
+//! # Supported Record Types
+//!
+//! A, AAAA, CAA, CNAME, MX, NS, SRV and TXT
+//!
+//! # API Reference
+//!
+//! - [TrafficRoute DNS API](https://www.volcengine.com/docs/6758/155086)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, Credentials, VolcengineError};
+
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Supported record types for record creation.
+const SUPPORTED_RECORD_TYPES: &[&str] = &["A", "AAAA", "CAA", "CNAME", "MX", "NS", "SRV", "TXT"];
+
+/// Resolution line records are created on.
+const DEFAULT_LINE: &str = "default";
+
+/// Volcengine TrafficRoute DNS provider.
+#[derive(Clone)]
+pub struct VolcengineProvider {
+    api_client: Arc<Client>,
+}
+
+/// A zone hosted by Volcengine TrafficRoute DNS.
+pub struct VolcengineZone {
+    api_client: Arc<Client>,
+    repr: api::Zone,
+    /// Zone ID as a string.
+    id: String,
+}
+
+impl VolcengineProvider {
+    /// Creates a new Volcengine TrafficRoute DNS provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::volcengine::{Credentials, VolcengineProvider};
+    ///
+    /// let credentials = Credentials::new("access-key-id", "secret-access-key");
+    /// let provider = VolcengineProvider::new(credentials).unwrap();
+    /// ```
+    pub fn new(credentials: Credentials) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(credentials)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Volcengine TrafficRoute DNS provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::volcengine::{Credentials, VolcengineProvider};
+    /// use manydns::HttpClientConfig;
+    /// use std::time::Duration;
+    ///
+    /// let config = HttpClientConfig {
+    ///     timeout: Some(Duration::from_secs(60)),
+    ///     ..Default::default()
+    /// };
+    /// let credentials = Credentials::new("access-key-id", "secret-access-key");
+    /// let provider = VolcengineProvider::with_config(credentials, config).unwrap();
+    /// ```
+    pub fn with_config(
+        credentials: Credentials,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(credentials, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Volcengine TrafficRoute DNS provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `credentials` - Access key pair
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        credentials: Credentials,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(credentials, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for VolcengineProvider {
+    type Zone = VolcengineZone;
+    type CustomRetrieveError = VolcengineError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let map_err = |err: VolcengineError| match err {
+            _ if err.is_unauthorized() => RetrieveZoneError::Unauthorized,
+            _ if err.is_not_found() => RetrieveZoneError::NotFound,
+            _ => RetrieveZoneError::Custom(err),
+        };
+
+        // Zone IDs are numeric, so anything else is a domain name.
+        let zone = match zone_id.parse::<u64>() {
+            Ok(zid) => self.api_client.query_zone(zid).await.map_err(map_err)?,
+            Err(_) => {
+                let name = zone_id.trim_end_matches('.');
+                self.api_client
+                    .list_zones(Some(name))
+                    .await
+                    .map_err(map_err)?
+                    .into_iter()
+                    .find(|zone| zone.zone_name.eq_ignore_ascii_case(name))
+                    .ok_or(RetrieveZoneError::NotFound)?
+            }
+        };
+
+        Ok(VolcengineZone::from_api(self.api_client.clone(), zone))
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let zones = self
+            .api_client
+            .list_zones(None)
+            .await
+            .map_err(|err| match err {
+                _ if err.is_unauthorized() => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(zones
+            .into_iter()
+            .map(|zone| VolcengineZone::from_api(self.api_client.clone(), zone))
+            .collect())
+    }
+}
+
+impl CreateZone for VolcengineProvider {
+    type CustomCreateError = VolcengineError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let zone = self
+            .api_client
+            .create_zone(domain.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err {
+                _ if err.is_unauthorized() => CreateZoneError::Unauthorized,
+                _ if err
+                    .code()
+                    .is_some_and(|code| code.starts_with("InvalidParameter")) =>
+                {
+                    CreateZoneError::InvalidDomainName
+                }
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(VolcengineZone::from_api(self.api_client.clone(), zone))
+    }
+}
+
+impl DeleteZone for VolcengineProvider {
+    type CustomDeleteError = VolcengineError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        let zid = zone_id.parse().map_err(|_| DeleteZoneError::NotFound)?;
+
+        self.api_client
+            .delete_zone(zid)
+            .await
+            .map_err(|err| match err {
+                _ if err.is_unauthorized() => DeleteZoneError::Unauthorized,
+                _ if err.is_not_found() => DeleteZoneError::NotFound,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl VolcengineZone {
+    /// Creates a new VolcengineZone from API response data.
+    fn from_api(api_client: Arc<Client>, zone: api::Zone) -> Self {
+        Self {
+            api_client,
+            id: zone.zid.to_string(),
+            repr: zone,
+        }
+    }
+
+    /// Returns the name servers assigned to the zone.
+    pub fn name_servers(&self) -> &[String] {
+        &self.repr.dns_servers
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<VolcengineError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        self.api_client
+            .update_record(record_id, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(Record {
+            id: record_id.to_string(),
+            host: request.host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+
+    /// Converts a host into a name relative to the zone (`@` for the apex).
+    fn relative_host(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        let domain = &self.repr.zone_name;
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(domain) {
+            "@".to_string()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<VolcengineError>> {
+        let record_type = data.get_type();
+        if !SUPPORTED_RECORD_TYPES.contains(&record_type) {
+            return Err(CreateRecordError::UnsupportedType);
+        }
+
+        Ok(api::RecordRequest {
+            host: self.relative_host(host),
+            record_type: record_type.to_string(),
+            value: data.get_value(),
+            ttl,
+            line: DEFAULT_LINE.to_string(),
+        })
+    }
+}
+
+/// Maps an API error of a record modification.
+fn map_create_error(err: VolcengineError) -> CreateRecordError<VolcengineError> {
+    match err {
+        _ if err.is_unauthorized() => CreateRecordError::Unauthorized,
+        _ if err
+            .code()
+            .is_some_and(|code| code.starts_with("InvalidParameter")) =>
+        {
+            CreateRecordError::InvalidRecord
+        }
+        _ => CreateRecordError::Custom(err),
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::Record) -> Record {
+    let data = match record.record_type.as_str() {
+        "CNAME" | "NS" | "MX" | "SRV" => {
+            RecordData::from_raw(&record.record_type, record.value.trim_end_matches('.'))
+        }
+        typ => RecordData::from_raw(typ, &record.value),
+    };
+
+    Record {
+        id: record.record_id,
+        host: record.host,
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for VolcengineZone {
+    type CustomRetrieveError = VolcengineError;
+
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.zone_name
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records =
+            self.api_client
+                .list_records(self.repr.zid)
+                .await
+                .map_err(|err| match err {
+                    _ if err.is_unauthorized() => RetrieveRecordError::Unauthorized,
+                    _ if err.is_not_found() => RetrieveRecordError::NotFound,
+                    _ => RetrieveRecordError::Custom(err),
+                })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record = self
+            .api_client
+            .query_record(record_id)
+            .await
+            .map_err(|err| match err {
+                _ if err.is_unauthorized() => RetrieveRecordError::Unauthorized,
+                _ if err.is_not_found() => RetrieveRecordError::NotFound,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for VolcengineZone {
+    type CustomCreateError = VolcengineError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .create_record(self.repr.zid, &request)
+            .await
+            .map_err(map_create_error)?;
+
+        Ok(Record {
+            id: record.record_id,
+            host: request.host,
+            data: data.clone(),
+            ttl,
+        })
+    }
+}
+
+impl DeleteRecord for VolcengineZone {
+    type CustomDeleteError = VolcengineError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_record(record_id)
+            .await
+            .map_err(|err| match err {
+                _ if err.is_unauthorized() => DeleteRecordError::Unauthorized,
+                _ if err.is_not_found() => DeleteRecordError::NotFound,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
@@ -0,0 +1,441 @@
+//! Low-level Vultr DNS API client.
+//!
+//! This module provides direct access to the Vultr API v2 DNS endpoints
+//! (`/v2/domains`).
+//! For most use cases, prefer using [`VultrProvider`](super::VultrProvider) instead.
+//!
+//! # API Reference
+//!
+//! - [DNS](https://www.vultr.com/api/#tag/dns)
+//! - [Pagination](https://www.vultr.com/api/#section/Introduction/Meta-and-Pagination)
+//!
+//! # Example
+//!
+//! ```rust,no_run
+//! use manydns::vultr::api::Client;
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let client = Client::new("your-api-key")?;
+//!
+//! for domain in client.list_domains().await? {
+//!     println!("Domain: {}", domain.domain);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION};
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::HttpClientConfig;
+
+/// The Vultr API base URL.
+const VULTR_API_URL: &str = "https://api.vultr.com";
+
+/// Page size requested from list endpoints (the API maximum).
+const PAGE_SIZE: u32 = 500;
+
+/// Errors that may occur when interacting with the Vultr API.
+#[derive(Debug, Error)]
+pub enum VultrError {
+    /// The API returned an error response.
+    #[error("API error ({status}): {message}")]
+    Api {
+        /// HTTP status code.
+        status: u16,
+        /// Error message.
+        message: String,
+    },
+
+    /// An HTTP request error occurred.
+    #[error("HTTP request error: {0}")]
+    Request(#[from] reqwest::Error),
+
+    /// Failed to serialize/deserialize.
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+impl VultrError {
+    /// Returns the HTTP status code if this is an API error.
+    pub fn status(&self) -> Option<u16> {
+        match self {
+            VultrError::Api { status, .. } => Some(*status),
+            _ => None,
+        }
+    }
+}
+
+/// Vultr error response body.
+#[derive(Debug, Deserialize)]
+struct ErrorResponse {
+    error: String,
+}
+
+/// Pagination metadata of list responses.
+#[derive(Debug, Default, Deserialize)]
+struct Meta {
+    #[serde(default)]
+    links: Links,
+}
+
+#[derive(Debug, Default, Deserialize)]
+struct Links {
+    /// Cursor of the next page (empty on the last page).
+    #[serde(default)]
+    next: String,
+}
+
+// =============================================================================
+// Domain Types
+// =============================================================================
+
+/// A Vultr DNS domain (zone).
+#[derive(Debug, Clone, Deserialize)]
+pub struct Domain {
+    /// Domain name (e.g., `example.com`).
+    pub domain: String,
+    /// Creation date.
+    #[serde(default)]
+    pub date_created: Option<String>,
+    /// DNSSEC status (`enabled` or `disabled`).
+    #[serde(default)]
+    pub dns_sec: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainList {
+    domains: Vec<Domain>,
+    #[serde(default)]
+    meta: Meta,
+}
+
+#[derive(Debug, Deserialize)]
+struct DomainResponse {
+    domain: Domain,
+}
+
+/// Request body for creating a domain.
+#[derive(Debug, Clone, Serialize)]
+pub struct CreateDomainRequest {
+    /// Domain name.
+    pub domain: String,
+    /// Optional IP address for default A records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ip: Option<String>,
+}
+
+// =============================================================================
+// Record Types
+// =============================================================================
+
+/// A DNS record of a Vultr domain.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DomainRecord {
+    /// Record ID.
+    pub id: String,
+    /// Record type.
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the domain (empty for the apex).
+    #[serde(default)]
+    pub name: String,
+    /// Record data; for SRV records `weight port target`.
+    #[serde(default)]
+    pub data: String,
+    /// Priority for MX and SRV records (`-1` for other types).
+    #[serde(default)]
+    pub priority: i32,
+    /// TTL in seconds.
+    #[serde(default)]
+    pub ttl: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordList {
+    records: Vec<DomainRecord>,
+    #[serde(default)]
+    meta: Meta,
+}
+
+#[derive(Debug, Deserialize)]
+struct RecordResponse {
+    record: DomainRecord,
+}
+
+/// Request body for creating or updating a record.
+#[derive(Debug, Clone, Serialize)]
+pub struct RecordRequest {
+    /// Record type (ignored by updates).
+    #[serde(rename = "type")]
+    pub record_type: String,
+    /// Host name relative to the domain (empty for the apex).
+    pub name: String,
+    /// Record data.
+    pub data: String,
+    /// TTL in seconds.
+    pub ttl: u64,
+    /// Priority for MX and SRV records.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub priority: Option<u16>,
+}
+
+// =============================================================================
+// API Client
+// =============================================================================
+
+/// Vultr API client.
+#[derive(Debug, Clone)]
+pub struct Client {
+    http_client: reqwest::Client,
+    base_url: String,
+}
+
+impl Client {
+    /// Creates a new Vultr API client.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Vultr API key
+    pub fn new(api_key: &str) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, VULTR_API_URL, HttpClientConfig::default())
+    }
+
+    /// Creates a new Vultr API client with custom HTTP configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Vultr API key
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_config(
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        Self::with_base_url(api_key, VULTR_API_URL, config)
+    }
+
+    /// Creates a new Vultr API client with a custom base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Vultr API key
+    /// * `base_url` - Custom base URL for the API
+    /// * `config` - HTTP client configuration for network binding
+    pub fn with_base_url(
+        api_key: &str,
+        base_url: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn std::error::Error + Send + Sync>> {
+        let mut headers = HeaderMap::new();
+        let mut auth_value = HeaderValue::from_str(&format!("Bearer {}", api_key))?;
+        auth_value.set_sensitive(true);
+        headers.insert(AUTHORIZATION, auth_value);
+
+        let mut builder = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(config.timeout.unwrap_or(std::time::Duration::from_secs(30)));
+
+        if let Some(addr) = config.local_address {
+            builder = builder.local_address(addr);
+        }
+
+        #[cfg(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux",
+            target_os = "macos",
+            target_os = "ios",
+            target_os = "tvos",
+            target_os = "watchos",
+            target_os = "illumos",
+            target_os = "solaris",
+        ))]
+        if let Some(ref iface) = config.interface {
+            builder = builder.interface(iface);
+        }
+
+        let http_client = builder.build()?;
+
+        Ok(Self {
+            http_client,
+            base_url: base_url.trim_end_matches('/').to_string(),
+        })
+    }
+
+    /// Sends a request and turns non-success responses into [`VultrError::Api`].
+    async fn send(&self, request: reqwest::RequestBuilder) -> Result<String, VultrError> {
+        let response = request.send().await?;
+        let status = response.status();
+        let text = response.text().await?;
+
+        if !status.is_success() {
+            let message = match serde_json::from_str::<ErrorResponse>(&text) {
+                Ok(e) => e.error,
+                Err(_) => text,
+            };
+            return Err(VultrError::Api {
+                status: status.as_u16(),
+                message,
+            });
+        }
+
+        Ok(text)
+    }
+
+    /// Make a GET request.
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, VultrError> {
+        let url = format!("{}{}", self.base_url, path);
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    /// Fetches one page of a list endpoint.
+    async fn get_page<T: DeserializeOwned>(
+        &self,
+        path: &str,
+        cursor: &str,
+    ) -> Result<T, VultrError> {
+        let mut url = format!("{}{}?per_page={}", self.base_url, path, PAGE_SIZE);
+        if !cursor.is_empty() {
+            url.push_str("&cursor=");
+            url.push_str(&encode_component(cursor));
+        }
+        let text = self.send(self.http_client.get(url)).await?;
+        Ok(serde_json::from_str(&text)?)
+    }
+
+    // =========================================================================
+    // Domain APIs
+    // =========================================================================
+
+    /// Lists all domains of the account, following pagination.
+    pub async fn list_domains(&self) -> Result<Vec<Domain>, VultrError> {
+        let mut domains = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            let page: DomainList = self.get_page("/v2/domains", &cursor).await?;
+            domains.extend(page.domains);
+
+            if page.meta.links.next.is_empty() {
+                break;
+            }
+            cursor = page.meta.links.next;
+        }
+
+        Ok(domains)
+    }
+
+    /// Gets a domain by name.
+    pub async fn get_domain(&self, domain: &str) -> Result<Domain, VultrError> {
+        let response: DomainResponse = self.get(&format!("/v2/domains/{}", domain)).await?;
+        Ok(response.domain)
+    }
+
+    /// Creates a domain.
+    pub async fn create_domain(&self, request: &CreateDomainRequest) -> Result<Domain, VultrError> {
+        let url = format!("{}/v2/domains", self.base_url);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        let response: DomainResponse = serde_json::from_str(&text)?;
+        Ok(response.domain)
+    }
+
+    /// Deletes a domain and all of its records.
+    pub async fn delete_domain(&self, domain: &str) -> Result<(), VultrError> {
+        let url = format!("{}/v2/domains/{}", self.base_url, domain);
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+
+    // =========================================================================
+    // Record APIs
+    // =========================================================================
+
+    /// Lists all records of a domain, following pagination.
+    pub async fn list_records(&self, domain: &str) -> Result<Vec<DomainRecord>, VultrError> {
+        let path = format!("/v2/domains/{}/records", domain);
+        let mut records = Vec::new();
+        let mut cursor = String::new();
+
+        loop {
+            let page: RecordList = self.get_page(&path, &cursor).await?;
+            records.extend(page.records);
+
+            if page.meta.links.next.is_empty() {
+                break;
+            }
+            cursor = page.meta.links.next;
+        }
+
+        Ok(records)
+    }
+
+    /// Gets a record by ID.
+    pub async fn get_record(
+        &self,
+        domain: &str,
+        record_id: &str,
+    ) -> Result<DomainRecord, VultrError> {
+        let response: RecordResponse = self
+            .get(&format!("/v2/domains/{}/records/{}", domain, record_id))
+            .await?;
+        Ok(response.record)
+    }
+
+    /// Creates a record.
+    pub async fn create_record(
+        &self,
+        domain: &str,
+        request: &RecordRequest,
+    ) -> Result<DomainRecord, VultrError> {
+        let url = format!("{}/v2/domains/{}/records", self.base_url, domain);
+        let text = self.send(self.http_client.post(url).json(request)).await?;
+        let response: RecordResponse = serde_json::from_str(&text)?;
+        Ok(response.record)
+    }
+
+    /// Updates a record.
+    ///
+    /// The API answers with an empty body; fetch the record again to see the
+    /// stored values.
+    pub async fn update_record(
+        &self,
+        domain: &str,
+        record_id: &str,
+        request: &RecordRequest,
+    ) -> Result<(), VultrError> {
+        let url = format!(
+            "{}/v2/domains/{}/records/{}",
+            self.base_url, domain, record_id
+        );
+        self.send(self.http_client.patch(url).json(request)).await?;
+        Ok(())
+    }
+
+    /// Deletes a record.
+    pub async fn delete_record(&self, domain: &str, record_id: &str) -> Result<(), VultrError> {
+        let url = format!(
+            "{}/v2/domains/{}/records/{}",
+            self.base_url, domain, record_id
+        );
+        self.send(self.http_client.delete(url)).await?;
+        Ok(())
+    }
+}
+
+/// Percent-encodes a query string component.
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
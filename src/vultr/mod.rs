@@ -0,0 +1,451 @@
+//! Vultr DNS provider implementation.
+//!
+//! This provider uses the Vultr API v2 DNS endpoints with Bearer token
+//! authentication.
+//!
+//! # Authentication
+//!
+//! Requires a Vultr API key:
+//! - Enable API access at: <https://my.vultr.com/settings/#settingsapi>
+//! - Make sure the access control list allows the addresses you connect from
+//!
+//! # Example
+//!
+//! ```no_run
+//! use manydns::vultr::VultrProvider;
+//! use manydns::{CreateRecord, Provider, RecordData, Zone};
+//!
+//! # async fn example() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+//! let provider = VultrProvider::new("your-api-key")?;
+//!
+//! // List all domains
+//! let zones = provider.list_zones().await?;
+//! for zone in &zones {
+//!     println!("Zone: {}", zone.domain());
+//! }
+//!
+//! // Add an A record
+//! let zone = provider.get_zone("example.com").await?;
+//! zone.create_record("www", &RecordData::A("192.0.2.1".parse()?), 300).await?;
+//! # Ok(())
+//! # }
+//! ```
+//!
+//! # Zone IDs
+//!
+//! Vultr identifies domains by name, so zone IDs are domain names
+//! (e.g., `example.com`).
+//!
+//! # Supported Record Types
+//!
+//! - A, AAAA, CNAME, MX, NS, SRV and TXT
+//! - CAA and SSHFP, as [`RecordData::Other`]
+//!
+//! # API Reference
+//!
+//! - [DNS](https://www.vultr.com/api/#tag/dns)
+
+pub mod api;
+
+use std::error::Error as StdErr;
+use std::sync::Arc;
+
+pub use api::{Client, VultrError};
+
+use crate::txt::{quote_txt, unquote_txt};
+use crate::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, HttpClientConfig, Provider, Record, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+
+/// Record types that can be passed through as [`RecordData::Other`].
+const OTHER_RECORD_TYPES: &[&str] = &["CAA", "SSHFP"];
+
+/// Vultr DNS provider.
+#[derive(Clone)]
+pub struct VultrProvider {
+    api_client: Arc<Client>,
+}
+
+/// A domain managed by Vultr.
+pub struct VultrZone {
+    api_client: Arc<Client>,
+    repr: api::Domain,
+}
+
+impl VultrProvider {
+    /// Creates a new Vultr provider.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Vultr API key
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::vultr::VultrProvider;
+    ///
+    /// let provider = VultrProvider::new("your-api-key").unwrap();
+    /// ```
+    pub fn new(api_key: &str) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::new(api_key)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Vultr provider with custom HTTP client configuration.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Vultr API key
+    /// * `config` - HTTP client configuration
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use manydns::vultr::VultrProvider;
+    /// use manydns::HttpClientConfig;
+    ///
+    /// let config = HttpClientConfig::new()
+    ///     .local_address("192.168.1.100".parse().unwrap());
+    /// let provider = VultrProvider::with_config("your-api-key", config).unwrap();
+    /// ```
+    pub fn with_config(
+        api_key: &str,
+        config: HttpClientConfig,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_config(api_key, config)?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+
+    /// Creates a new Vultr provider with a custom API base URL.
+    ///
+    /// This is primarily useful for testing with mock servers.
+    ///
+    /// # Arguments
+    ///
+    /// * `api_key` - Vultr API key
+    /// * `base_url` - Custom base URL for the API
+    pub fn with_base_url(
+        api_key: &str,
+        base_url: &str,
+    ) -> Result<Self, Box<dyn StdErr + Send + Sync>> {
+        let api_client = Client::with_base_url(api_key, base_url, HttpClientConfig::default())?;
+        Ok(Self {
+            api_client: Arc::new(api_client),
+        })
+    }
+}
+
+impl Provider for VultrProvider {
+    type Zone = VultrZone;
+    type CustomRetrieveError = VultrError;
+
+    async fn get_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<Self::Zone, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domain = self
+            .api_client
+            .get_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveZoneError::NotFound,
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(VultrZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+
+    async fn list_zones(
+        &self,
+    ) -> Result<Vec<Self::Zone>, RetrieveZoneError<Self::CustomRetrieveError>> {
+        let domains = self
+            .api_client
+            .list_domains()
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => RetrieveZoneError::Unauthorized,
+                _ => RetrieveZoneError::Custom(err),
+            })?;
+
+        Ok(domains
+            .into_iter()
+            .map(|domain| VultrZone {
+                api_client: self.api_client.clone(),
+                repr: domain,
+            })
+            .collect())
+    }
+}
+
+impl CreateZone for VultrProvider {
+    type CustomCreateError = VultrError;
+
+    async fn create_zone(
+        &self,
+        domain: &str,
+    ) -> Result<Self::Zone, CreateZoneError<Self::CustomCreateError>> {
+        let request = api::CreateDomainRequest {
+            domain: domain.trim_end_matches('.').to_string(),
+            ip: None,
+        };
+
+        let domain = self
+            .api_client
+            .create_domain(&request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateZoneError::Unauthorized,
+                Some(400 | 422) => CreateZoneError::InvalidDomainName,
+                _ => CreateZoneError::Custom(err),
+            })?;
+
+        Ok(VultrZone {
+            api_client: self.api_client.clone(),
+            repr: domain,
+        })
+    }
+}
+
+impl DeleteZone for VultrProvider {
+    type CustomDeleteError = VultrError;
+
+    async fn delete_zone(
+        &self,
+        zone_id: &str,
+    ) -> Result<(), DeleteZoneError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_domain(zone_id.trim_end_matches('.'))
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteZoneError::NotFound,
+                Some(401 | 403) => DeleteZoneError::Unauthorized,
+                _ => DeleteZoneError::Custom(err),
+            })
+    }
+}
+
+impl VultrZone {
+    /// Returns the DNSSEC status of the domain (`enabled` or `disabled`).
+    pub fn dns_sec(&self) -> Option<&str> {
+        self.repr.dns_sec.as_deref()
+    }
+
+    /// Replaces the host, value and TTL of an existing record, keeping its ID.
+    ///
+    /// The record type cannot be changed.
+    pub async fn update_record(
+        &self,
+        record_id: &str,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<VultrError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+        let map_err = |err: VultrError| match err.status() {
+            Some(401 | 403) => CreateRecordError::Unauthorized,
+            Some(400 | 422) => CreateRecordError::InvalidRecord,
+            _ => CreateRecordError::Custom(err),
+        };
+
+        self.api_client
+            .update_record(&self.repr.domain, record_id, &request)
+            .await
+            .map_err(map_err)?;
+
+        let record = self
+            .api_client
+            .get_record(&self.repr.domain, record_id)
+            .await
+            .map_err(map_err)?;
+
+        Ok(record_from_api(record))
+    }
+
+    /// Converts a host into a name relative to the domain (empty for the apex).
+    fn relative_name(&self, host: &str) -> String {
+        let host = host.trim_end_matches('.');
+        if host.is_empty() || host == "@" || host.eq_ignore_ascii_case(&self.repr.domain) {
+            String::new()
+        } else if let Some(sub) = host.strip_suffix(&format!(".{}", self.repr.domain)) {
+            sub.to_string()
+        } else {
+            host.to_string()
+        }
+    }
+
+    /// Converts generic record data into an API request.
+    fn request_from_record_data(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<api::RecordRequest, CreateRecordError<VultrError>> {
+        let (value, priority) = match data {
+            RecordData::A(_) | RecordData::AAAA(_) => (data.get_value(), None),
+            RecordData::CNAME(target) | RecordData::NS(target) => (target.clone(), None),
+            RecordData::MX {
+                priority,
+                mail_server,
+            } => (mail_server.clone(), Some(*priority)),
+            RecordData::SRV {
+                priority,
+                weight,
+                port,
+                target,
+            } => (format!("{} {} {}", weight, port, target), Some(*priority)),
+            RecordData::TXT(text) => (quote_txt(text), None),
+            RecordData::Other { typ, value } if OTHER_RECORD_TYPES.contains(&typ.as_str()) => {
+                (value.clone(), None)
+            }
+            RecordData::Other { .. } => return Err(CreateRecordError::UnsupportedType),
+        };
+
+        Ok(api::RecordRequest {
+            record_type: data.get_type().to_string(),
+            name: self.relative_name(host),
+            data: value,
+            ttl,
+            priority,
+        })
+    }
+}
+
+/// Converts an API record into a generic record.
+fn record_from_api(record: api::DomainRecord) -> Record {
+    let priority = u16::try_from(record.priority).unwrap_or_default();
+    let target = record.data.trim_end_matches('.').to_string();
+    let data = match record.record_type.as_str() {
+        "CNAME" => RecordData::CNAME(target),
+        "NS" => RecordData::NS(target),
+        "MX" => RecordData::MX {
+            priority,
+            mail_server: target,
+        },
+        "SRV" => {
+            let parts: Vec<&str> = target.split_whitespace().collect();
+            match parts.as_slice() {
+                [weight, port, srv_target] => match (weight.parse(), port.parse()) {
+                    (Ok(weight), Ok(port)) => RecordData::SRV {
+                        priority,
+                        weight,
+                        port,
+                        target: srv_target.trim_end_matches('.').to_string(),
+                    },
+                    _ => RecordData::from_raw("SRV", &record.data),
+                },
+                _ => RecordData::from_raw("SRV", &record.data),
+            }
+        }
+        "TXT" => RecordData::TXT(unquote_txt(&record.data)),
+        typ => RecordData::from_raw(typ, &record.data),
+    };
+
+    Record {
+        id: record.id,
+        host: if record.name.is_empty() {
+            "@".to_string()
+        } else {
+            record.name
+        },
+        data,
+        ttl: record.ttl,
+    }
+}
+
+impl Zone for VultrZone {
+    type CustomRetrieveError = VultrError;
+
+    fn id(&self) -> &str {
+        &self.repr.domain
+    }
+
+    fn domain(&self) -> &str {
+        &self.repr.domain
+    }
+
+    async fn list_records(
+        &self,
+    ) -> Result<Vec<Record>, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let records = self
+            .api_client
+            .list_records(&self.repr.domain)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(records.into_iter().map(record_from_api).collect())
+    }
+
+    async fn get_record(
+        &self,
+        record_id: &str,
+    ) -> Result<Record, RetrieveRecordError<Self::CustomRetrieveError>> {
+        let record = self
+            .api_client
+            .get_record(&self.repr.domain, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => RetrieveRecordError::NotFound,
+                Some(401 | 403) => RetrieveRecordError::Unauthorized,
+                _ => RetrieveRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl CreateRecord for VultrZone {
+    type CustomCreateError = VultrError;
+
+    async fn create_record(
+        &self,
+        host: &str,
+        data: &RecordData,
+        ttl: u64,
+    ) -> Result<Record, CreateRecordError<Self::CustomCreateError>> {
+        let request = self.request_from_record_data(host, data, ttl)?;
+
+        let record = self
+            .api_client
+            .create_record(&self.repr.domain, &request)
+            .await
+            .map_err(|err| match err.status() {
+                Some(401 | 403) => CreateRecordError::Unauthorized,
+                Some(400 | 422) => CreateRecordError::InvalidRecord,
+                _ => CreateRecordError::Custom(err),
+            })?;
+
+        Ok(record_from_api(record))
+    }
+}
+
+impl DeleteRecord for VultrZone {
+    type CustomDeleteError = VultrError;
+
+    async fn delete_record(
+        &self,
+        record_id: &str,
+    ) -> Result<(), DeleteRecordError<Self::CustomDeleteError>> {
+        self.api_client
+            .delete_record(&self.repr.domain, record_id)
+            .await
+            .map_err(|err| match err.status() {
+                Some(404) => DeleteRecordError::NotFound,
+                Some(401 | 403) => DeleteRecordError::Unauthorized,
+                _ => DeleteRecordError::Custom(err),
+            })
+    }
+}
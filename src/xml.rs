@@ -0,0 +1,115 @@
+//! Minimal XML element tree shared by the XML based API clients.
+//!
+//! Responses are small, so they are parsed into a tree of [`Element`]s that
+//! callers walk by local name. Namespaces and attributes are ignored.
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// A minimal XML element tree used to read API responses.
+#[derive(Debug, Default)]
+pub(crate) struct Element {
+    pub(crate) name: String,
+    pub(crate) text: String,
+    pub(crate) children: Vec<Element>,
+}
+
+impl Element {
+    /// Parses an XML document into its root element.
+    ///
+    /// Returns a description of the problem if the document is malformed;
+    /// callers wrap it in their own error type.
+    pub(crate) fn parse(xml: &str) -> Result<Element, String> {
+        let mut reader = Reader::from_str(xml);
+        let mut stack: Vec<Element> = vec![Element::default()];
+
+        loop {
+            match reader.read_event() {
+                Ok(Event::Start(ref e)) => stack.push(Element {
+                    name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+                    ..Default::default()
+                }),
+                Ok(Event::Empty(ref e)) => {
+                    let element = Element {
+                        name: String::from_utf8_lossy(e.local_name().as_ref()).into_owned(),
+                        ..Default::default()
+                    };
+                    stack.last_mut().unwrap().children.push(element);
+                }
+                Ok(Event::End(_)) => {
+                    let element = stack.pop().unwrap();
+                    match stack.last_mut() {
+                        Some(parent) => parent.children.push(element),
+                        None => return Err("unbalanced XML".to_string()),
+                    }
+                }
+                Ok(Event::Text(ref e)) => {
+                    let text = e
+                        .xml_content()
+                        .map_err(|e| format!("XML parse error: {}", e))?;
+                    stack.last_mut().unwrap().text.push_str(&text);
+                }
+                Ok(Event::CData(ref e)) => {
+                    let text = String::from_utf8_lossy(e.as_ref()).into_owned();
+                    stack.last_mut().unwrap().text.push_str(&text);
+                }
+                Ok(Event::GeneralRef(ref e)) => {
+                    let resolved = match e.resolve_char_ref() {
+                        Ok(Some(c)) => c.to_string(),
+                        _ => match e.as_ref() {
+                            b"lt" => "<".to_string(),
+                            b"gt" => ">".to_string(),
+                            b"amp" => "&".to_string(),
+                            b"apos" => "'".to_string(),
+                            b"quot" => "\"".to_string(),
+                            other => format!("&{};", String::from_utf8_lossy(other)),
+                        },
+                    };
+                    stack.last_mut().unwrap().text.push_str(&resolved);
+                }
+                Ok(Event::Eof) => break,
+                Err(e) => return Err(format!("XML parse error: {}", e)),
+                _ => {}
+            }
+        }
+
+        if stack.len() != 1 {
+            return Err("unbalanced XML".to_string());
+        }
+
+        let document = stack.pop().unwrap();
+        document
+            .children
+            .into_iter()
+            .next()
+            .ok_or_else(|| "empty XML document".to_string())
+    }
+
+    /// Returns the first child with the given name.
+    pub(crate) fn child(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find(|c| c.name == name)
+    }
+
+    /// Returns all children with the given name.
+    pub(crate) fn children<'a>(&'a self, name: &'a str) -> impl Iterator<Item = &'a Element> + 'a {
+        self.children.iter().filter(move |c| c.name == name)
+    }
+
+    /// Returns the text of the first child with the given name.
+    #[cfg(feature = "route53")]
+    pub(crate) fn child_text(&self, name: &str) -> Option<&str> {
+        self.child(name).map(|c| c.text.as_str())
+    }
+
+    /// Finds the first descendant with the given name (depth-first).
+    #[cfg(feature = "route53")]
+    pub(crate) fn find(&self, name: &str) -> Option<&Element> {
+        self.children.iter().find_map(|c| {
+            if c.name == name {
+                Some(c)
+            } else {
+                c.find(name)
+            }
+        })
+    }
+}
@@ -0,0 +1,485 @@
+//! Minimal XML-RPC encoding and decoding.
+//!
+//! This module implements the subset of [XML-RPC](http://xmlrpc.com/spec.md)
+//! needed by providers whose APIs only speak XML-RPC (INWX and Loopia):
+//! encoding method calls and decoding method responses and faults. Transport
+//! is left to the caller, which posts the encoded call as `text/xml`.
+
+use std::collections::BTreeMap;
+
+use quick_xml::escape::escape;
+use thiserror::Error;
+
+use crate::xml::Element;
+
+/// Errors that may occur when decoding an XML-RPC response.
+#[derive(Debug, Error)]
+pub enum XmlRpcError {
+    /// The server returned an XML-RPC fault.
+    #[error("XML-RPC fault {code}: {message}")]
+    Fault {
+        /// Fault code.
+        code: i64,
+        /// Fault message.
+        message: String,
+    },
+
+    /// The response is not a valid XML-RPC document.
+    #[error("XML-RPC parse error: {0}")]
+    Parse(String),
+}
+
+/// An XML-RPC value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// `<int>` or `<i4>` (and the `<i8>` extension).
+    Int(i64),
+    /// `<boolean>`.
+    Bool(bool),
+    /// `<string>`, or an untyped value.
+    String(String),
+    /// `<double>`.
+    Double(f64),
+    /// `<dateTime.iso8601>`, kept as received.
+    DateTime(String),
+    /// `<base64>`, kept encoded.
+    Base64(String),
+    /// `<array>`.
+    Array(Vec<Value>),
+    /// `<struct>`.
+    Struct(BTreeMap<String, Value>),
+    /// `<nil/>` extension.
+    Nil,
+}
+
+impl Value {
+    /// Returns the string content of a string value.
+    #[cfg(any(feature = "loopia", test))]
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Value::String(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Returns an integer value, also accepting integers sent as strings.
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Value::Int(n) => Some(*n),
+            Value::String(s) => s.trim().parse().ok(),
+            _ => None,
+        }
+    }
+
+    /// Returns a boolean value.
+    #[cfg(any(feature = "loopia", test))]
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Value::Bool(b) => Some(*b),
+            _ => None,
+        }
+    }
+
+    /// Returns the elements of an array value.
+    pub fn as_array(&self) -> Option<&[Value]> {
+        match self {
+            Value::Array(values) => Some(values),
+            _ => None,
+        }
+    }
+
+    /// Returns the members of a struct value.
+    pub fn as_struct(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Struct(members) => Some(members),
+            _ => None,
+        }
+    }
+
+    /// Returns a struct member by name.
+    pub fn get(&self, name: &str) -> Option<&Value> {
+        self.as_struct().and_then(|members| members.get(name))
+    }
+
+    /// Returns a struct member as a string, rendering numbers as text.
+    pub fn get_string(&self, name: &str) -> Option<String> {
+        match self.get(name)? {
+            Value::String(s) => Some(s.clone()),
+            Value::Int(n) => Some(n.to_string()),
+            Value::Double(n) => Some(n.to_string()),
+            _ => None,
+        }
+    }
+
+    /// Appends the `<value>` encoding of this value to `out`.
+    fn encode(&self, out: &mut String) {
+        out.push_str("<value>");
+        match self {
+            Value::Int(n) => {
+                out.push_str(&format!("<int>{}</int>", n));
+            }
+            Value::Bool(b) => {
+                out.push_str(&format!("<boolean>{}</boolean>", u8::from(*b)));
+            }
+            Value::String(s) => {
+                out.push_str("<string>");
+                out.push_str(&escape(s.as_str()));
+                out.push_str("</string>");
+            }
+            Value::Double(n) => {
+                out.push_str(&format!("<double>{}</double>", n));
+            }
+            Value::DateTime(s) => {
+                out.push_str("<dateTime.iso8601>");
+                out.push_str(&escape(s.as_str()));
+                out.push_str("</dateTime.iso8601>");
+            }
+            Value::Base64(s) => {
+                out.push_str("<base64>");
+                out.push_str(&escape(s.as_str()));
+                out.push_str("</base64>");
+            }
+            Value::Array(values) => {
+                out.push_str("<array><data>");
+                for value in values {
+                    value.encode(out);
+                }
+                out.push_str("</data></array>");
+            }
+            Value::Struct(members) => {
+                out.push_str("<struct>");
+                for (name, value) in members {
+                    out.push_str("<member><name>");
+                    out.push_str(&escape(name.as_str()));
+                    out.push_str("</name>");
+                    value.encode(out);
+                    out.push_str("</member>");
+                }
+                out.push_str("</struct>");
+            }
+            Value::Nil => out.push_str("<nil/>"),
+        }
+        out.push_str("</value>");
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Int(value)
+    }
+}
+
+impl From<i32> for Value {
+    fn from(value: i32) -> Self {
+        Value::Int(value.into())
+    }
+}
+
+impl From<u16> for Value {
+    fn from(value: u16) -> Self {
+        Value::Int(value.into())
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<Vec<Value>> for Value {
+    fn from(value: Vec<Value>) -> Self {
+        Value::Array(value)
+    }
+}
+
+impl From<BTreeMap<String, Value>> for Value {
+    fn from(value: BTreeMap<String, Value>) -> Self {
+        Value::Struct(value)
+    }
+}
+
+impl<K: Into<String>, V: Into<Value>, const N: usize> From<[(K, V); N]> for Value {
+    fn from(members: [(K, V); N]) -> Self {
+        Value::Struct(
+            members
+                .into_iter()
+                .map(|(name, value)| (name.into(), value.into()))
+                .collect(),
+        )
+    }
+}
+
+/// Encodes a method call.
+pub fn encode_call(method: &str, params: &[Value]) -> String {
+    let mut out =
+        String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?><methodCall><methodName>");
+    out.push_str(&escape(method));
+    out.push_str("</methodName><params>");
+    for param in params {
+        out.push_str("<param>");
+        param.encode(&mut out);
+        out.push_str("</param>");
+    }
+    out.push_str("</params></methodCall>");
+    out
+}
+
+/// Decodes a method response into its single return value.
+///
+/// A `<fault>` response is returned as [`XmlRpcError::Fault`].
+pub fn decode_response(xml: &str) -> Result<Value, XmlRpcError> {
+    let root = Element::parse(xml).map_err(XmlRpcError::Parse)?;
+    if root.name != "methodResponse" {
+        return Err(XmlRpcError::Parse(format!(
+            "unexpected root element <{}>",
+            root.name
+        )));
+    }
+
+    if let Some(fault) = root.child("fault") {
+        let value = decode_value(
+            fault
+                .child("value")
+                .ok_or_else(|| XmlRpcError::Parse("fault without value".to_string()))?,
+        )?;
+        return Err(XmlRpcError::Fault {
+            code: value.get("faultCode").and_then(Value::as_i64).unwrap_or(0),
+            message: value.get_string("faultString").unwrap_or_default(),
+        });
+    }
+
+    let value = root
+        .child("params")
+        .and_then(|params| params.child("param"))
+        .and_then(|param| param.child("value"))
+        .ok_or_else(|| XmlRpcError::Parse("response without value".to_string()))?;
+    decode_value(value)
+}
+
+/// Decodes a `<value>` element.
+fn decode_value(element: &Element) -> Result<Value, XmlRpcError> {
+    let Some(typed) = element.children.first() else {
+        // A value without a type element is a string.
+        return Ok(Value::String(element.text.clone()));
+    };
+
+    let text = typed.text.as_str();
+    let invalid = || XmlRpcError::Parse(format!("invalid <{}> value: {}", typed.name, text));
+
+    match typed.name.as_str() {
+        "int" | "i4" | "i8" => text.trim().parse().map(Value::Int).map_err(|_| invalid()),
+        "boolean" => match text.trim() {
+            "1" => Ok(Value::Bool(true)),
+            "0" => Ok(Value::Bool(false)),
+            _ => Err(invalid()),
+        },
+        "string" => Ok(Value::String(text.to_string())),
+        "double" => text
+            .trim()
+            .parse()
+            .map(Value::Double)
+            .map_err(|_| invalid()),
+        "dateTime.iso8601" => Ok(Value::DateTime(text.trim().to_string())),
+        "base64" => Ok(Value::Base64(text.trim().to_string())),
+        "nil" => Ok(Value::Nil),
+        "array" => {
+            let values = match typed.child("data") {
+                Some(data) => data
+                    .children("value")
+                    .map(decode_value)
+                    .collect::<Result<_, _>>()?,
+                None => Vec::new(),
+            };
+            Ok(Value::Array(values))
+        }
+        "struct" => {
+            let mut members = BTreeMap::new();
+            for member in typed.children("member") {
+                let name = member
+                    .child("name")
+                    .ok_or_else(|| XmlRpcError::Parse("member without name".to_string()))?;
+                let value = member
+                    .child("value")
+                    .ok_or_else(|| XmlRpcError::Parse("member without value".to_string()))?;
+                members.insert(name.text.clone(), decode_value(value)?);
+            }
+            Ok(Value::Struct(members))
+        }
+        other => Err(XmlRpcError::Parse(format!(
+            "unknown value type <{}>",
+            other
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // =============================================================================
+    // Encoding Tests
+    // =============================================================================
+
+    #[test]
+    fn test_encode_call() {
+        let call = encode_call(
+            "nameserver.createRecord",
+            &[Value::from([
+                ("domain", Value::from("example.com")),
+                ("ttl", Value::from(300)),
+                ("content", Value::from("a<b&c")),
+            ])],
+        );
+
+        assert_eq!(
+            call,
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?><methodCall>\
+             <methodName>nameserver.createRecord</methodName><params><param><value><struct>\
+             <member><name>content</name><value><string>a&lt;b&amp;c</string></value></member>\
+             <member><name>domain</name><value><string>example.com</string></value></member>\
+             <member><name>ttl</name><value><int>300</int></value></member>\
+             </struct></value></param></params></methodCall>"
+        );
+    }
+
+    #[test]
+    fn test_encode_scalars_and_arrays() {
+        let call = encode_call(
+            "test",
+            &[
+                Value::from(true),
+                Value::Double(1.5),
+                Value::from(vec![Value::from("a"), Value::Nil]),
+            ],
+        );
+
+        assert!(call.contains("<param><value><boolean>1</boolean></value></param>"));
+        assert!(call.contains("<param><value><double>1.5</double></value></param>"));
+        assert!(call.contains(
+            "<array><data><value><string>a</string></value><value><nil/></value></data></array>"
+        ));
+    }
+
+    // =============================================================================
+    // Decoding Tests
+    // =============================================================================
+
+    #[test]
+    fn test_decode_struct_response() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+    <methodResponse>
+      <params>
+        <param>
+          <value>
+            <struct>
+              <member><name>code</name><value><int>1000</int></value></member>
+              <member><name>msg</name><value><string>Command completed successfully</string></value></member>
+              <member>
+                <name>resData</name>
+                <value><struct>
+                  <member><name>record</name><value><array><data>
+                    <value><struct>
+                      <member><name>id</name><value><i4>42</i4></value></member>
+                      <member><name>name</name><value>www.example.com</value></member>
+                      <member><name>active</name><value><boolean>1</boolean></value></member>
+                    </struct></value>
+                  </data></array></value></member>
+                </struct></value>
+              </member>
+            </struct>
+          </value>
+        </param>
+      </params>
+    </methodResponse>"#;
+
+        let value = decode_response(xml).unwrap();
+        assert_eq!(value.get("code").and_then(Value::as_i64), Some(1000));
+        assert_eq!(
+            value.get_string("msg").as_deref(),
+            Some("Command completed successfully")
+        );
+
+        let records = value
+            .get("resData")
+            .and_then(|data| data.get("record"))
+            .and_then(Value::as_array)
+            .unwrap();
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].get("id").and_then(Value::as_i64), Some(42));
+        assert_eq!(
+            records[0].get_string("name").as_deref(),
+            Some("www.example.com")
+        );
+        assert_eq!(
+            records[0].get("active").and_then(Value::as_bool),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn test_decode_string_response_with_entities() {
+        let xml = "<methodResponse><params><param><value><string>&quot;v=spf1 -all&quot; &amp; more</string></value></param></params></methodResponse>";
+        let value = decode_response(xml).unwrap();
+        assert_eq!(value.as_str(), Some("\"v=spf1 -all\" & more"));
+    }
+
+    #[test]
+    fn test_decode_fault() {
+        let xml = r#"<methodResponse><fault><value><struct>
+            <member><name>faultCode</name><value><int>623</int></value></member>
+            <member><name>faultString</name><value><string>Method not found</string></value></member>
+        </struct></value></fault></methodResponse>"#;
+
+        match decode_response(xml) {
+            Err(XmlRpcError::Fault { code, message }) => {
+                assert_eq!(code, 623);
+                assert_eq!(message, "Method not found");
+            }
+            other => panic!("expected fault, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_decode_invalid_response() {
+        assert!(matches!(
+            decode_response("<html><body>Bad Gateway</body></html>"),
+            Err(XmlRpcError::Parse(_))
+        ));
+        assert!(matches!(
+            decode_response("<methodResponse><params><param><value><int>abc</int></value></param></params></methodResponse>"),
+            Err(XmlRpcError::Parse(_))
+        ));
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let value = Value::from([
+            (
+                "list",
+                Value::from(vec![Value::from(1), Value::from("two")]),
+            ),
+            ("flag", Value::from(false)),
+        ]);
+        let call = encode_call("echo", std::slice::from_ref(&value));
+
+        // Turn the call into a response carrying the same parameter.
+        let start = call.find("<params>").unwrap();
+        let end = call.find("</methodCall>").unwrap();
+        let response = format!("<methodResponse>{}</methodResponse>", &call[start..end]);
+
+        assert_eq!(decode_response(&response).unwrap(), value);
+    }
+}
@@ -0,0 +1,159 @@
+//! Mock-based tests for the acme-dns provider.
+//!
+//! These tests use `wiremock` to simulate an acme-dns server.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Registration and credential persistence
+//! - Zone lookup by subdomain or full domain
+//! - TXT updates with `X-Api-User`/`X-Api-Key` headers
+//! - Unsupported record types
+//! - Error handling (400, 401)
+//!
+//! # API Structure
+//!
+//! - Register: POST `/register`
+//! - Update: POST `/update` with `{"subdomain", "txt"}`
+//! - Auth: `X-Api-User` and `X-Api-Key` headers on updates
+
+use crate::common::acmedns::*;
+use crate::common::setup_mock_server;
+
+use manydns::acmedns::{AcmeDnsProvider, Credentials};
+use manydns::{CreateRecord, CreateRecordError, Provider, RecordData, RetrieveZoneError, Zone};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SUBDOMAIN: &str = "d420c923-bbd7-4056-ab64-c3ca54c9b3cf";
+const TOKEN: &str = "___validation_token_received_from_the_ca___";
+
+fn provider(server: &MockServer) -> AcmeDnsProvider {
+    AcmeDnsProvider::new(&server.uri()).expect("Failed to create provider")
+}
+
+fn credentials() -> Credentials {
+    serde_json::from_value(mock_registration(SUBDOMAIN)).unwrap()
+}
+
+#[tokio::test]
+async fn test_register() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/register"))
+        .and(body_json(json!({ "allowfrom": ["192.0.2.0/24"] })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_registration(SUBDOMAIN)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let credentials = provider(&server).register(&["192.0.2.0/24"]).await.unwrap();
+    assert_eq!(credentials.subdomain, SUBDOMAIN);
+    assert_eq!(
+        credentials.fulldomain,
+        format!("{}.auth.example.org", SUBDOMAIN)
+    );
+    assert!(!format!("{:?}", credentials).contains(&credentials.password));
+}
+
+#[tokio::test]
+async fn test_credentials_persistence() {
+    let path = std::env::temp_dir().join(format!("manydns-acmedns-{}.json", std::process::id()));
+
+    credentials().save(&path).unwrap();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+    let loaded = Credentials::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, credentials());
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    let provider = provider(&server).with_credentials([credentials()]);
+
+    let zone = provider.get_zone(SUBDOMAIN).await.unwrap();
+    assert_eq!(zone.id(), SUBDOMAIN);
+
+    let zone = provider
+        .get_zone(&format!("{}.auth.example.org.", SUBDOMAIN))
+        .await
+        .unwrap();
+    assert_eq!(zone.domain(), format!("{}.auth.example.org", SUBDOMAIN));
+    assert!(zone.list_records().await.unwrap().is_empty());
+
+    let result = provider.get_zone("unknown").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+
+    assert_eq!(provider.list_zones().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_create_txt_record() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/update"))
+        .and(header("X-Api-User", "eabcdb41-d89f-4580-826f-3e62e9755ef2"))
+        .and(header(
+            "X-Api-Key",
+            "pbAXVjlIOE01xbut7YnAbkhMQIkcwoHO0ek2j4Q0",
+        ))
+        .and(body_json(json!({ "subdomain": SUBDOMAIN, "txt": TOKEN })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "txt": TOKEN })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).zone(credentials());
+    let record = zone
+        .create_record("_acme-challenge", &RecordData::TXT(TOKEN.to_string()), 120)
+        .await
+        .unwrap();
+    assert_eq!(record.host, "@");
+    assert_eq!(record.data, RecordData::TXT(TOKEN.to_string()));
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/update"))
+        .and(body_json(json!({ "subdomain": SUBDOMAIN, "txt": "short" })))
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error("bad_txt")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/update"))
+        .and(body_json(json!({ "subdomain": SUBDOMAIN, "txt": TOKEN })))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error("forbidden")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).zone(credentials());
+
+    let result = zone
+        .create_record("@", &RecordData::TXT("short".to_string()), 120)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record("@", &RecordData::TXT(TOKEN.to_string()), 120)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::Unauthorized)));
+
+    let result = zone
+        .create_record("@", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 120)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
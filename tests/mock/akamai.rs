@@ -0,0 +1,534 @@
+//! Mock-based tests for the Akamai Edge DNS provider.
+//!
+//! These tests use `wiremock` to simulate the Edge DNS Zone Management API
+//! without requiring an Akamai account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - EdgeGrid request signing (the `Authorization` header)
+//! - Zone operations (list with pagination, get)
+//! - Record conversion (FQDN targets, quoted TXT values)
+//! - Record set updates on record creation and deletion
+//! - Changelist-based bulk updates
+//! - Error handling (401, 403, 404)
+//!
+//! # API Structure
+//!
+//! - Zones: `/config-dns/v2/zones`, `/config-dns/v2/zones/{zone}`
+//! - Record sets: `/config-dns/v2/zones/{zone}/recordsets`,
+//!   `/config-dns/v2/zones/{zone}/names/{name}/types/{type}`
+//! - Changelists: `/config-dns/v2/changelists`, `/config-dns/v2/changelists/{zone}/...`
+//! - Pagination: `page`/`pageSize` with `metadata.totalElements`
+//! - Errors: problem details with `title`, `status` and `detail`
+
+use crate::common::akamai::*;
+use crate::common::setup_mock_server;
+
+use manydns::akamai::{AkamaiProvider, AkamaiZone, Credentials, RecordSet, RecordSetChange};
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header_regex, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const RECORDSET_PATH: &str = "/config-dns/v2/zones/example.com/names/www.example.com/types/A";
+
+fn provider(server: &MockServer) -> AkamaiProvider {
+    AkamaiProvider::with_base_url(
+        Credentials::new(
+            "akab-example.luna.akamaiapis.net",
+            "akab-client-token",
+            "client-secret",
+            "akab-access-token",
+        ),
+        &server.uri(),
+    )
+    .expect("Failed to create provider")
+}
+
+async fn zone(server: &MockServer) -> AkamaiZone {
+    Mock::given(method("GET"))
+        .and(path("/config-dns/v2/zones/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(server)
+        .await;
+
+    provider(server).get_zone("example.com").await.unwrap()
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_signed_and_paginated() {
+    let server = setup_mock_server().await;
+
+    let first_page: Vec<_> = (0..1000)
+        .map(|i| mock_zone(&format!("example{}.com", i)))
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path("/config-dns/v2/zones"))
+        .and(header_regex(
+            "Authorization",
+            r"^EG1-HMAC-SHA256 client_token=akab-client-token;access_token=akab-access-token;timestamp=\d{8}T\d{2}:\d{2}:\d{2}\+0000;nonce=[0-9a-f]{8}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{4}-[0-9a-f]{12};signature=[A-Za-z0-9+/]{43}=$",
+        ))
+        .and(query_param("page", "1"))
+        .and(query_param("pageSize", "1000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "page": 1, "pageSize": 1000, "totalElements": 1001 },
+            "zones": first_page
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/config-dns/v2/zones"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "page": 2, "pageSize": 1000, "totalElements": 1001 },
+            "zones": [mock_zone("example.com")]
+        })))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 1001);
+    assert_eq!(zones[1000].id(), "example.com");
+    assert_eq!(zones[1000].domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.zone_type(), "PRIMARY");
+    assert_eq!(zone.activation_state(), Some("ACTIVE"));
+    assert!(!zone.sign_and_serve());
+}
+
+#[tokio::test]
+async fn test_get_zone_errors() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/config-dns/v2/zones/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            404,
+            "Not Found",
+            "Zone missing.com does not exist",
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/config-dns/v2/zones"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(
+            401,
+            "Not authorized",
+            "The signature does not match",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com.").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/config-dns/v2/zones/example.com/recordsets"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "metadata": { "page": 1, "pageSize": 1000, "totalElements": 3 },
+            "recordsets": [
+                mock_record_set("example.com", "MX", 300, &["10 mail.example.com."]),
+                mock_record_set("example.com", "TXT", 300, &["\"v=spf1 \" \"-all\""]),
+                mock_record_set("www.example.com", "A", 600, &["192.0.2.1", "192.0.2.2"])
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].host, "@");
+    assert_eq!(
+        records[0].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[1].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(records[3].id, "www/A/192.0.2.2");
+    assert_eq!(records[3].ttl, 600);
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(RECORDSET_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            300,
+            &["192.0.2.1"],
+        )))
+        .mount(&server)
+        .await;
+
+    let record = zone.get_record("www/A/192.0.2.1").await.unwrap();
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    let result = zone.get_record("www/A/192.0.2.9").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_record_new_set() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(
+            "/config-dns/v2/zones/example.com/names/example.com/types/CNAME",
+        ))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            404,
+            "Not Found",
+            "Record set not found",
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/config-dns/v2/zones/example.com/names/example.com/types/CNAME",
+        ))
+        .and(body_json(mock_record_set(
+            "example.com",
+            "CNAME",
+            300,
+            &["target.example.net."],
+        )))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_record_set(
+            "example.com",
+            "CNAME",
+            300,
+            &["target.example.net."],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::CNAME("target.example.net".to_string()),
+            300,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "@/CNAME/target.example.net.");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record_appends_to_set() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(RECORDSET_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            300,
+            &["192.0.2.1"],
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(RECORDSET_PATH))
+        .and(body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            600,
+            &["192.0.2.1", "192.0.2.2"],
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            600,
+            &["192.0.2.1", "192.0.2.2"],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "www/A/192.0.2.2");
+
+    // The same value cannot be added twice.
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 600)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(RECORDSET_PATH))
+        .respond_with(ResponseTemplate::new(403).set_body_json(mock_error(
+            403,
+            "Forbidden",
+            "The client does not have access to the zone",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::Unauthorized)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "SSHFP".to_string(),
+                value: "1 1 abc".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_other_values() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(RECORDSET_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            300,
+            &["192.0.2.1", "192.0.2.2"],
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path(RECORDSET_PATH))
+        .and(body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            300,
+            &["192.0.2.2"],
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            300,
+            &["192.0.2.2"],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+
+    let result = zone.delete_record("www/A/192.0.2.9").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_delete_record_removes_empty_set() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(RECORDSET_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "www.example.com",
+            "A",
+            300,
+            &["192.0.2.1"],
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(RECORDSET_PATH))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+// =============================================================================
+// Changelist Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_apply_changes() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/config-dns/v2/changelists"))
+        .and(query_param("zone", "example.com"))
+        .and(query_param("overwrite", "stale"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "zone": "example.com",
+            "changeTag": "tag-1",
+            "zoneVersionId": "a1b2c3d4-0000-4000-8000-000000000000",
+            "stale": false
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/config-dns/v2/changelists/example.com/recordsets/add-change",
+        ))
+        .and(body_json(json!({
+            "name": "www.example.com",
+            "type": "A",
+            "op": "EDIT",
+            "ttl": 300,
+            "rdata": ["192.0.2.1", "192.0.2.2"]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/config-dns/v2/changelists/example.com/recordsets/add-change",
+        ))
+        .and(body_json(json!({
+            "name": "old.example.com",
+            "type": "CNAME",
+            "op": "DELETE"
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/config-dns/v2/changelists/example.com/submit"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.apply_changes(&[
+        RecordSetChange::edit(RecordSet {
+            name: "www.example.com".to_string(),
+            record_type: "A".to_string(),
+            ttl: 300,
+            rdata: vec!["192.0.2.1".to_string(), "192.0.2.2".to_string()],
+        }),
+        RecordSetChange::delete("old.example.com", "CNAME"),
+    ])
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_apply_changes_discards_changelist_on_error() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/config-dns/v2/changelists"))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "zone": "example.com",
+            "stale": false
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path(
+            "/config-dns/v2/changelists/example.com/recordsets/add-change",
+        ))
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error(
+            400,
+            "Invalid Record Data",
+            "Invalid IPv4 address",
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/config-dns/v2/changelists/example.com/submit"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/config-dns/v2/changelists/example.com"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let err = zone
+        .apply_changes(&[RecordSetChange::add(RecordSet {
+            name: "www.example.com".to_string(),
+            record_type: "A".to_string(),
+            ttl: 300,
+            rdata: vec!["not-an-ip".to_string()],
+        })])
+        .await
+        .unwrap_err();
+
+    assert_eq!(err.status(), Some(400));
+}
@@ -0,0 +1,411 @@
+//! Mock-based tests for the Alibaba Cloud DNS provider.
+//!
+//! These tests use `wiremock` to simulate the Alidns RPC API without
+//! requiring an Alibaba Cloud account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Signed common parameters (`AccessKeyId`, `SignatureNonce`, `Signature`, ...)
+//! - Zone operations (list with pagination, get by exact name)
+//! - Record conversion (MX priority, SRV values, resolution lines)
+//! - Record creation and deletion (with zone ownership check)
+//! - Error handling (error codes mapped to generic errors)
+//!
+//! # API Structure
+//!
+//! - All actions: GET `/?Action={action}&...` with RPC common parameters
+//! - Zones: `DescribeDomains`
+//! - Records: `DescribeDomainRecords`, `DescribeDomainRecordInfo`,
+//!   `AddDomainRecord`, `DeleteDomainRecord`
+
+use crate::common::alidns::*;
+use crate::common::setup_mock_server;
+
+use manydns::alidns::api::rpc_signature;
+use manydns::alidns::AlidnsProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> AlidnsProvider {
+    AlidnsProvider::with_base_url("test-key-id", "test-key-secret", &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomains"))
+        .and(query_param("KeyWord", "example.com"))
+        .and(query_param("SearchMode", "EXACT"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_domains_response(
+                vec![mock_domain("d-1", "example.com")],
+                1,
+            )),
+        )
+        .mount(server)
+        .await;
+}
+
+async fn mount_record_info(server: &MockServer, record: serde_json::Value) {
+    let id = record["RecordId"].as_str().unwrap().to_string();
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomainRecordInfo"))
+        .and(query_param("RecordId", id.as_str()))
+        .respond_with(ResponseTemplate::new(200).set_body_json(record))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Signing Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_signed_common_parameters() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomains"))
+        .and(query_param("Format", "JSON"))
+        .and(query_param("Version", "2015-01-09"))
+        .and(query_param("AccessKeyId", "test-key-id"))
+        .and(query_param("SignatureMethod", "HMAC-SHA1"))
+        .and(query_param("SignatureVersion", "1.0"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domains_response(vec![], 0)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).list_zones().await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let pairs: Vec<(String, String)> = requests[0]
+        .url
+        .query_pairs()
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    let signature = pairs
+        .iter()
+        .find(|(k, _)| k == "Signature")
+        .map(|(_, v)| v.clone())
+        .expect("missing Signature");
+    let params: Vec<(&str, &str)> = pairs
+        .iter()
+        .filter(|(k, _)| k != "Signature")
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    assert!(params.iter().any(|(k, _)| *k == "SignatureNonce"));
+    assert!(params.iter().any(|(k, _)| *k == "Timestamp"));
+    assert_eq!(rpc_signature("test-key-secret", "GET", &params), signature);
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_paginates() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomains"))
+        .and(query_param("PageNumber", "1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_domains_response(
+                vec![mock_domain("d-1", "example.com")],
+                2,
+            )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomains"))
+        .and(query_param("PageNumber", "2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_domains_response(
+                vec![mock_domain("d-2", "example.org")],
+                2,
+            )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[0].domain_id(), "d-1");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.domain_id(), "d-1");
+    assert_eq!(
+        zone.name_servers(),
+        &[
+            "dns1.hichina.com".to_string(),
+            "dns2.hichina.com".to_string()
+        ]
+    );
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomains"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domains_response(vec![], 0)))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_access_key_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "InvalidAccessKeyId.NotFound",
+            "Specified access key is not found.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_conversion() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let mut mx = mock_record("r-2", "example.com", "@", "MX", "mail.example.com");
+    mx["Priority"] = json!(10);
+    let mut telecom = mock_record("r-5", "example.com", "www", "A", "192.0.2.2");
+    telecom["Line"] = json!("telecom");
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomainRecords"))
+        .and(query_param("DomainName", "example.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_records_response(
+                vec![
+                    mock_record("r-1", "example.com", "www", "A", "192.0.2.1"),
+                    mx,
+                    mock_record(
+                        "r-3",
+                        "example.com",
+                        "_sip._tcp",
+                        "SRV",
+                        "10 20 5060 sip.example.com",
+                    ),
+                    mock_record("r-4", "example.com", "@", "TXT", "v=spf1 -all"),
+                    telecom,
+                ],
+                5,
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].ttl, 600);
+    assert!(matches!(records[0].data, RecordData::A(ip) if ip == Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(records[4].id, "r-5");
+}
+
+#[tokio::test]
+async fn test_get_record_from_other_zone_is_not_found() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_info(
+        &server,
+        mock_record("r-9", "example.org", "www", "A", "192.0.2.1"),
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone.get_record("r-9").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_record_on_default_line() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "AddDomainRecord"))
+        .and(query_param("DomainName", "example.com"))
+        .and(query_param("RR", "mail"))
+        .and(query_param("Type", "MX"))
+        .and(query_param("Value", "mx.example.net"))
+        .and(query_param("Priority", "5"))
+        .and(query_param("TTL", "600"))
+        .and(query_param("Line", "default"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "RequestId": "req", "RecordId": "r-10" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let data = RecordData::MX {
+        priority: 5,
+        mail_server: "mx.example.net.".to_string(),
+    };
+    let record = zone
+        .create_record("mail.example.com", &data, 600)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "r-10");
+    assert_eq!(record.host, "mail");
+}
+
+#[tokio::test]
+async fn test_create_duplicate_record_is_invalid() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "AddDomainRecord"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error(
+            "DomainRecordDuplicate",
+            "The DNS record already exists.",
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 600)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let data = RecordData::Other {
+        typ: "TLSA".to_string(),
+        value: "3 1 1 abcdef".to_string(),
+    };
+    let result = zone.create_record("_443._tcp", &data, 600).await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_info(
+        &server,
+        mock_record("r-1", "example.com", "www", "A", "192.0.2.1"),
+    )
+    .await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DeleteDomainRecord"))
+        .and(query_param("RecordId", "r-1"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "RequestId": "req", "RecordId": "r-1" })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("r-1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DescribeDomainRecordInfo"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error(
+            "DomainRecordNotBelongToUser",
+            "The DNS record does not belong to the user.",
+        )))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/"))
+        .and(query_param("Action", "DeleteDomainRecord"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone.delete_record("r-404").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
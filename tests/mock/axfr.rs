@@ -0,0 +1,344 @@
+//! Mock-based tests for the zone transfer (AXFR/IXFR) provider.
+//!
+//! These tests run a local TCP server that answers queries with handcrafted
+//! DNS wire-format messages, so no real name server is required.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Zone retrieval via SOA query (success, NXDOMAIN, REFUSED)
+//! - Full transfers, including transfers split across messages
+//! - Record conversion (A, MX, TXT, NS, SOA) and record IDs
+//! - Incremental transfers (differences, up to date, full fallback, SOA-only zone)
+//! - Malformed responses
+
+use crate::common::axfr::*;
+
+use manydns::axfr::{AxfrError, AxfrProvider, Rcode, ZoneChanges};
+use manydns::{Provider, RecordData, RetrieveRecordError, RetrieveZoneError, Zone};
+use std::net::Ipv4Addr;
+
+const ZONE: &str = "example.com";
+
+/// Answers SOA queries with serial 2024010102 and AXFR with a small zone.
+fn zone_handler(query: &[u8]) -> Vec<Vec<u8>> {
+    match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 2024010102)])],
+        TYPE_AXFR => vec![response(
+            query,
+            0,
+            &[
+                soa(ZONE, 2024010102),
+                rr(ZONE, TYPE_NS, 3600, &encode_name("ns1.example.com")),
+                a("www.example.com", 300, [192, 0, 2, 1]),
+                mx(ZONE, 3600, 10, "mail.example.com"),
+                txt(ZONE, 600, &["v=spf1 ", "-all"]),
+                soa(ZONE, 2024010102),
+            ],
+        )],
+        _ => vec![response(query, 4, &[])],
+    }
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_get_zone_success() {
+    let addr = start_server(zone_handler).await;
+    let provider = AxfrProvider::new(addr);
+
+    let zone = provider
+        .get_zone("example.com.")
+        .await
+        .expect("Failed to get zone");
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.serial(), 2024010102);
+    assert_eq!(zone.soa().mname.to_dotted(), "ns1.example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone_nxdomain() {
+    let addr = start_server(|query| vec![response(query, RCODE_NXDOMAIN, &[])]).await;
+    let provider = AxfrProvider::new(addr);
+
+    let result = provider.get_zone("missing.example").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_get_zone_parent_soa_is_not_found() {
+    let addr = start_server(|query| vec![response(query, 0, &[soa(ZONE, 1)])]).await;
+    let provider = AxfrProvider::new(addr);
+
+    let result = provider.get_zone("sub.example.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_list_zones_configured() {
+    let addr = start_server(zone_handler).await;
+
+    let provider = AxfrProvider::new(addr);
+    assert!(provider.list_zones().await.unwrap().is_empty());
+
+    let provider = provider.with_zones(["example.com"]);
+    let zones = provider.list_zones().await.expect("Failed to list zones");
+    assert_eq!(zones.len(), 1);
+    assert_eq!(zones[0].domain(), "example.com");
+}
+
+// =============================================================================
+// AXFR Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_converts_records() {
+    let addr = start_server(zone_handler).await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let records = zone.list_records().await.expect("Failed to list records");
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].host, "@");
+    assert_eq!(records[0].data.get_type(), "SOA");
+    assert_eq!(
+        records[0].data.get_value(),
+        "ns1.example.com hostmaster.example.com 2024010102 7200 3600 1209600 300"
+    );
+
+    assert_eq!(
+        records[1].data,
+        RecordData::NS("ns1.example.com".to_string())
+    );
+
+    assert_eq!(records[2].id, "www/A/192.0.2.1");
+    assert_eq!(records[2].host, "www");
+    assert_eq!(records[2].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(records[2].ttl, 300);
+
+    assert_eq!(
+        records[3].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[4].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_list_records_multiple_messages() {
+    let addr = start_server(|query| match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 5)])],
+        _ => vec![
+            response(
+                query,
+                0,
+                &[soa(ZONE, 5), a("a.example.com", 60, [192, 0, 2, 1])],
+            ),
+            response(query, 0, &[a("b.example.com", 60, [192, 0, 2, 2])]),
+            response(query, 0, &[soa(ZONE, 5)]),
+        ],
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let records = zone.list_records().await.expect("Failed to list records");
+    let hosts: Vec<_> = records.iter().map(|r| r.host.as_str()).collect();
+    assert_eq!(hosts, ["@", "a", "b"]);
+}
+
+#[tokio::test]
+async fn test_list_records_compressed_names() {
+    let addr = start_server(|query| match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 5)])],
+        _ => {
+            // Owner "www" + pointer to the question name at offset 12.
+            let mut compressed = vec![3, b'w', b'w', b'w', 0xc0, 12];
+            compressed.extend_from_slice(&[0, 1, 0, 1, 0, 0, 0, 60, 0, 4, 198, 51, 100, 7]);
+            vec![response(
+                query,
+                0,
+                &[soa(ZONE, 5), compressed, soa(ZONE, 5)],
+            )]
+        }
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let records = zone.list_records().await.expect("Failed to list records");
+    assert_eq!(records[1].host, "www");
+    assert_eq!(
+        records[1].data,
+        RecordData::A(Ipv4Addr::new(198, 51, 100, 7))
+    );
+}
+
+#[tokio::test]
+async fn test_list_records_refused() {
+    let addr = start_server(|query| match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 5)])],
+        _ => vec![response(query, RCODE_REFUSED, &[])],
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let result = zone.list_records().await;
+    assert!(matches!(result, Err(RetrieveRecordError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_record_by_id() {
+    let addr = start_server(zone_handler).await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let record = zone
+        .get_record("www/A/192.0.2.1")
+        .await
+        .expect("Failed to get record");
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    let result = zone.get_record("www/A/192.0.2.99").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+// =============================================================================
+// IXFR Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_changes_since_incremental() {
+    let addr = start_server(|query| match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 3)])],
+        TYPE_IXFR => {
+            assert_eq!(ixfr_serial(query), 1);
+            vec![response(
+                query,
+                0,
+                &[
+                    soa(ZONE, 3),
+                    soa(ZONE, 1),
+                    a("old.example.com", 60, [192, 0, 2, 1]),
+                    soa(ZONE, 2),
+                    a("new.example.com", 60, [192, 0, 2, 2]),
+                    soa(ZONE, 2),
+                    soa(ZONE, 3),
+                    txt("new.example.com", 60, &["hello"]),
+                    soa(ZONE, 3),
+                ],
+            )]
+        }
+        _ => vec![response(query, 4, &[])],
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let ZoneChanges::Incremental(diffs) = zone.changes_since(1).await.unwrap() else {
+        panic!("expected incremental changes");
+    };
+    assert_eq!(diffs.len(), 2);
+
+    assert_eq!((diffs[0].from_serial, diffs[0].to_serial), (1, 2));
+    assert_eq!(diffs[0].deleted.len(), 1);
+    assert_eq!(diffs[0].deleted[0].host, "old");
+    assert_eq!(diffs[0].added[0].host, "new");
+
+    assert_eq!((diffs[1].from_serial, diffs[1].to_serial), (2, 3));
+    assert!(diffs[1].deleted.is_empty());
+    assert_eq!(diffs[1].added[0].data, RecordData::TXT("hello".to_string()));
+}
+
+#[tokio::test]
+async fn test_changes_since_up_to_date() {
+    let addr = start_server(|query| vec![response(query, 0, &[soa(ZONE, 7)])]).await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let changes = zone.changes_since(7).await.unwrap();
+    assert_eq!(changes, ZoneChanges::Incremental(vec![]));
+}
+
+#[tokio::test]
+async fn test_changes_since_full_fallback() {
+    let addr = start_server(|query| match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 9)])],
+        _ => vec![response(
+            query,
+            0,
+            &[
+                soa(ZONE, 9),
+                a("www.example.com", 60, [192, 0, 2, 1]),
+                soa(ZONE, 9),
+            ],
+        )],
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let ZoneChanges::Full(records) = zone.changes_since(1).await.unwrap() else {
+        panic!("expected full zone");
+    };
+    assert_eq!(records.len(), 2);
+    assert_eq!(records[1].host, "www");
+}
+
+#[tokio::test]
+async fn test_changes_since_full_soa_only_zone() {
+    let addr = start_server(|query| match query_type(query) {
+        TYPE_SOA => vec![response(query, 0, &[soa(ZONE, 9)])],
+        _ => vec![response(query, 0, &[soa(ZONE, 9), soa(ZONE, 9)])],
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+    let zone = provider.get_zone(ZONE).await.unwrap();
+
+    let ZoneChanges::Full(records) = zone.changes_since(1).await.unwrap() else {
+        panic!("expected full zone");
+    };
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].data.get_type(), "SOA");
+}
+
+// =============================================================================
+// Error Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_mismatched_id_is_rejected() {
+    let addr = start_server(|query| {
+        let mut message = response(query, 0, &[soa(ZONE, 1)]);
+        message[0] ^= 0xff;
+        vec![message]
+    })
+    .await;
+    let provider = AxfrProvider::new(addr);
+
+    let result = provider.get_zone(ZONE).await;
+    assert!(matches!(
+        result,
+        Err(RetrieveZoneError::Custom(AxfrError::Malformed(_)))
+    ));
+}
+
+#[tokio::test]
+async fn test_rcode_error_is_reported() {
+    let addr = start_server(|query| vec![response(query, 2, &[])]).await;
+    let provider = AxfrProvider::new(addr);
+
+    let result = provider.get_zone(ZONE).await;
+    assert!(matches!(
+        result,
+        Err(RetrieveZoneError::Custom(AxfrError::Rcode(Rcode::ServFail)))
+    ));
+}
@@ -0,0 +1,659 @@
+//! Mock-based tests for the Azure DNS provider.
+//!
+//! These tests use `wiremock` to simulate both the Microsoft Entra ID token
+//! endpoint and the Azure Resource Manager DNS API without requiring an
+//! Azure subscription.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Client-credentials token exchange, caching and 401 retry
+//! - Zone operations (list with `nextLink` pagination, get, create, delete)
+//! - Pagination staying on the configured base URL
+//! - Typed record set payloads to record conversion (A, MX, TXT, SRV, CAA, SOA)
+//! - Record creation and deletion with ETag preconditions
+//! - Error handling (OAuth `invalid_client`, 403, 404)
+//!
+//! # API Structure
+//!
+//! - Token: POST `{authority}/{tenant}/oauth2/v2.0/token` with client credentials
+//! - Zones: GET `/subscriptions/{sub}/resourceGroups/{rg}/providers/Microsoft.Network/dnsZones[/{zone}]`, PUT/DELETE `.../dnsZones/{zone}`
+//! - Record sets: GET `.../dnsZones/{zone}/recordsets`, GET/PUT/DELETE `.../dnsZones/{zone}/{type}/{name}`
+//! - Auth: `Authorization: Bearer {access_token}` header, `api-version` query parameter
+
+use crate::common::azure::*;
+use crate::common::setup_mock_server;
+
+use manydns::azure::{AzureProvider, Credentials};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    DeleteZoneError, Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, body_string_contains, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ZONE_PATH: &str = "/subscriptions/00000000-0000-0000-0000-000000000000/resourceGroups/dns-rg/providers/Microsoft.Network/dnsZones/example.com";
+
+fn provider(server: &MockServer) -> AzureProvider {
+    let credentials = Credentials::new("test-tenant", "test-client", "test-secret")
+        .with_authority_url(&server.uri());
+    AzureProvider::with_base_url(credentials, SUBSCRIPTION, RESOURCE_GROUP, &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn mount_token(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("test-token", 3599)))
+        .mount(server)
+        .await;
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(server)
+        .await;
+}
+
+async fn mount_record_set(
+    server: &MockServer,
+    typ: &str,
+    name: &str,
+    properties: serde_json::Value,
+) {
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}/{}", ZONE_PATH, typ, name)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "example.com",
+            name,
+            typ,
+            "etag-1",
+            properties,
+        )))
+        .mount(server)
+        .await;
+}
+
+async fn mount_missing_record_set(server: &MockServer, typ: &str, name: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("{}/{}/{}", ZONE_PATH, typ, name)))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "NotFound",
+            "The resource record 'www' does not exist in resource group 'dns-rg'.",
+        )))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Authentication Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_token_exchange_is_cached() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .and(header("Content-Type", "application/x-www-form-urlencoded"))
+        .and(body_string_contains("grant_type=client_credentials"))
+        .and(body_string_contains("client_id=test-client"))
+        .and(body_string_contains("client_secret=test-secret"))
+        .and(body_string_contains(
+            "scope=https%3A%2F%2Fmanagement.azure.com%2F.default",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("cached-token", 3599)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(query_param("api-version", "2018-05-01"))
+        .and(header("Authorization", "Bearer cached-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "value": [] })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider.list_zones().await.unwrap();
+    provider.list_zones().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rejected_token_is_refreshed_and_retried() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("revoked-token", 3599)))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("fresh-token", 3599)))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(header("Authorization", "Bearer revoked-token"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(
+            "ExpiredAuthenticationToken",
+            "The access token expiry UTC time is earlier than current UTC time.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(header("Authorization", "Bearer fresh-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "value": [mock_zone("example.com")] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 1);
+}
+
+#[tokio::test]
+async fn test_token_error_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(TOKEN_PATH))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_oauth_error(
+            "invalid_client",
+            "AADSTS7000215: Invalid client secret provided.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(query_param("$skipToken", "page2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "value": [mock_zone("example.org")] })),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "value": [mock_zone("example.com")],
+            "nextLink": format!("{}{}?api-version=2018-05-01&$skipToken=page2", server.uri(), ZONES_PATH)
+        })))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    let domains: Vec<&str> = zones.iter().map(|z| z.domain()).collect();
+    assert_eq!(domains, vec!["example.com", "example.org"]);
+}
+
+#[tokio::test]
+async fn test_list_zones_ignores_next_link_host() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(query_param("api-version", "2018-05-01"))
+        .and(query_param("$skipToken", "page2"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "value": [mock_zone("example.org")] })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "value": [mock_zone("example.com")],
+            "nextLink": format!("https://attacker.invalid{}?api-version=2018-05-01&$skipToken=page2", ZONES_PATH)
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.name_servers().len(), 4);
+    assert_eq!(zone.number_of_record_sets(), Some(2));
+    assert!(zone.resource_id().ends_with("/dnsZones/example.com"));
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "ResourceNotFound",
+            "The Resource 'Microsoft.Network/dnszones/example.com' under resource group 'dns-rg' was not found.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("example.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_get_zone_forbidden() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(403).set_body_json(mock_error(
+            "AuthorizationFailed",
+            "The client does not have authorization to perform action 'Microsoft.Network/dnszones/read'.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("example.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("PUT"))
+        .and(path(ZONE_PATH))
+        .and(query_param("api-version", "2018-05-01"))
+        .and(header("If-None-Match", "*"))
+        .and(body_json(json!({
+            "location": "global",
+            "properties": { "zoneType": "Public" }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_zone("example.com")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(202))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_zone_not_found() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(mock_error("ResourceNotFound", "The zone was not found.")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).delete_zone("example.com").await;
+    assert!(matches!(result, Err(DeleteZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/recordsets", ZONE_PATH)))
+        .and(query_param("api-version", "2018-05-01"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "value": [
+                mock_record_set("example.com", "@", "SOA", "e0", json!({
+                    "SOARecord": {
+                        "host": "ns1-01.azure-dns.com.",
+                        "email": "azuredns-hostmaster.microsoft.com",
+                        "serialNumber": 1,
+                        "refreshTime": 3600,
+                        "retryTime": 300,
+                        "expireTime": 2419200,
+                        "minimumTTL": 300
+                    }
+                })),
+                mock_record_set("example.com", "www", "A", "e1", json!({
+                    "ARecords": [{ "ipv4Address": "192.0.2.1" }, { "ipv4Address": "192.0.2.2" }]
+                })),
+                mock_record_set("example.com", "@", "MX", "e2", json!({
+                    "MXRecords": [{ "preference": 10, "exchange": "mail.example.com." }]
+                })),
+                mock_record_set("example.com", "@", "TXT", "e3", json!({
+                    "TXTRecords": [{ "value": ["v=spf1 ", "-all"] }]
+                })),
+                mock_record_set("example.com", "_sip._tcp", "SRV", "e4", json!({
+                    "SRVRecords": [{ "priority": 10, "weight": 60, "port": 5060, "target": "sip.example.com" }]
+                })),
+                mock_record_set("example.com", "@", "CAA", "e5", json!({
+                    "caaRecords": [{ "flags": 0, "tag": "issue", "value": "letsencrypt.org" }]
+                })),
+                mock_record_set("example.com", "alias", "A", "e6", json!({
+                    "targetResource": { "id": "/subscriptions/x/resourceGroups/y/providers/Microsoft.Network/publicIPAddresses/ip" }
+                }))
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 7);
+
+    assert_eq!(records[0].data.get_type(), "SOA");
+    assert_eq!(records[1].id, "www/A/192.0.2.1");
+    assert_eq!(records[1].host, "www");
+    assert_eq!(records[1].ttl, 300);
+    assert_eq!(records[2].id, "www/A/192.0.2.2");
+    assert_eq!(
+        records[3].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].host, "@");
+    assert_eq!(records[4].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(
+        records[5].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 60,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[6].data,
+        RecordData::Other {
+            typ: "CAA".to_string(),
+            value: "0 issue \"letsencrypt.org\"".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "A",
+        "www",
+        json!({ "ARecords": [{ "ipv4Address": "192.0.2.1" }, { "ipv4Address": "192.0.2.2" }] }),
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone.get_record("www/A/192.0.2.2").await.unwrap();
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+
+    let missing = zone.get_record("www/A/192.0.2.3").await;
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn test_create_record_merges_record_set() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "MX",
+        "@",
+        json!({ "MXRecords": [{ "preference": 10, "exchange": "mx1.example.com" }] }),
+    )
+    .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/MX/@", ZONE_PATH)))
+        .and(header("If-Match", "etag-1"))
+        .and(body_json(json!({
+            "properties": {
+                "TTL": 600,
+                "MXRecords": [
+                    { "preference": 10, "exchange": "mx1.example.com" },
+                    { "preference": 20, "exchange": "mx2.example.com" }
+                ]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "example.com",
+            "@",
+            "MX",
+            "etag-2",
+            json!({}),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "example.com",
+            &RecordData::MX {
+                priority: 20,
+                mail_server: "mx2.example.com".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "@/MX/20 mx2.example.com");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record_new_record_set() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_missing_record_set(&server, "TXT", "_acme-challenge").await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/TXT/_acme-challenge", ZONE_PATH)))
+        .and(header("If-None-Match", "*"))
+        .and(body_json(json!({
+            "properties": {
+                "TTL": 60,
+                "TXTRecords": [{ "value": ["token-value"] }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_record_set(
+            "example.com",
+            "_acme-challenge",
+            "TXT",
+            "etag-1",
+            json!({}),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "_acme-challenge",
+            &RecordData::TXT("token-value".to_string()),
+            60,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "_acme-challenge/TXT/token-value");
+}
+
+#[tokio::test]
+async fn test_create_record_duplicate_and_unsupported() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "A",
+        "www",
+        json!({ "ARecords": [{ "ipv4Address": "192.0.2.1" }] }),
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+
+    let duplicate = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(duplicate, Err(CreateRecordError::InvalidRecord)));
+
+    let unsupported = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "SSHFP".to_string(),
+                value: "1 1 abcdef".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(
+        unsupported,
+        Err(CreateRecordError::UnsupportedType)
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_remaining_values() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "A",
+        "www",
+        json!({ "ARecords": [{ "ipv4Address": "192.0.2.1" }, { "ipv4Address": "192.0.2.2" }] }),
+    )
+    .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("{}/A/www", ZONE_PATH)))
+        .and(header("If-Match", "etag-1"))
+        .and(body_json(json!({
+            "properties": {
+                "TTL": 300,
+                "ARecords": [{ "ipv4Address": "192.0.2.2" }]
+            }
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record_set(
+            "example.com",
+            "www",
+            "A",
+            "etag-2",
+            json!({}),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_last_record_deletes_record_set() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "TXT",
+        "_acme-challenge",
+        json!({ "TXTRecords": [{ "value": ["token-value"] }] }),
+    )
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("{}/TXT/_acme-challenge", ZONE_PATH)))
+        .and(header("If-Match", "etag-1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("_acme-challenge/TXT/token-value")
+        .await
+        .unwrap();
+
+    let missing = zone.delete_record("_acme-challenge/TXT/other").await;
+    assert!(matches!(missing, Err(DeleteRecordError::NotFound)));
+}
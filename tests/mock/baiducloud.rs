@@ -0,0 +1,337 @@
+//! Mock-based tests for the Baidu AI Cloud DNS provider.
+//!
+//! These tests use `wiremock` to simulate the BCE DNS v1 API without
+//! requiring a Baidu AI Cloud account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - `bce-auth-v1` request signing (the `Authorization` and `x-bce-date` headers)
+//! - Zone operations (list with marker pagination, get, create, delete)
+//! - Record conversion (MX/SRV priorities)
+//! - Record creation (with ID lookup), update and deletion
+//! - Error handling (403, 404)
+//!
+//! # API Structure
+//!
+//! - Zones: `/v1/dns/zone`, `/v1/dns/zone/{name}`
+//! - Records: `/v1/dns/zone/{name}/record`, `/v1/dns/zone/{name}/record/{id}`
+//! - Pagination: `marker`/`maxKeys` with `isTruncated`/`nextMarker`
+//! - Errors: `{"requestId": ..., "code": ..., "message": ...}`
+
+use crate::common::baiducloud::*;
+use crate::common::setup_mock_server;
+
+use manydns::baiducloud::{BaiduCloudProvider, BaiduCloudZone, Credentials};
+use manydns::{
+    CreateRecord, CreateZone, DeleteRecord, DeleteZone, Provider, RecordData, RetrieveRecordError,
+    RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header_regex, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> BaiduCloudProvider {
+    BaiduCloudProvider::with_base_url(
+        Credentials::new("exampleaccesskeyid", "examplesecretaccesskey"),
+        &server.uri(),
+    )
+    .expect("Failed to create provider")
+}
+
+async fn zone(server: &MockServer) -> BaiduCloudZone {
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone"))
+        .and(query_param("name", "example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "zones": [mock_zone("z1", "example.com")],
+            "isTruncated": false,
+            "maxKeys": 1000
+        })))
+        .mount(server)
+        .await;
+
+    provider(server).get_zone("example.com").await.unwrap()
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_signed_and_paginated() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone"))
+        .and(header_regex(
+            "Authorization",
+            r"^bce-auth-v1/exampleaccesskeyid/\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z/1800/host;x-bce-date/[0-9a-f]{64}$",
+        ))
+        .and(header_regex("x-bce-date", r"^\d{4}-\d{2}-\d{2}T\d{2}:\d{2}:\d{2}Z$"))
+        .and(query_param("marker", "page2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "zones": [mock_zone("z2", "example.org")],
+            "isTruncated": false,
+            "maxKeys": 1000
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone"))
+        .and(query_param("maxKeys", "1000"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "zones": [mock_zone("z1", "example.com")],
+            "isTruncated": true,
+            "nextMarker": "page2",
+            "maxKeys": 1000
+        })))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.status(), Some("running"));
+    assert_eq!(zone.product_version(), Some("free"));
+}
+
+#[tokio::test]
+async fn test_get_zone_errors() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone"))
+        .and(query_param("name", "missing.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "zones": [],
+            "isTruncated": false
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone"))
+        .respond_with(
+            ResponseTemplate::new(403).set_body_json(mock_error("AccessDenied", "Access denied.")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/dns/zone"))
+        .and(body_json(json!({ "name": "example.com" })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The new zone is looked up after creation.
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone"))
+        .and(query_param("name", "example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "zones": [mock_zone("z1", "example.com")],
+            "isTruncated": false
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/dns/zone/example.com"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone/example.com/record"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [
+                mock_record("1", "@", "A", "192.0.2.1", 0),
+                mock_record("2", "@", "MX", "mail.example.com.", 10),
+                mock_record("3", "_sip._tcp", "SRV", "5 5060 sip.example.com", 20)
+            ],
+            "isTruncated": false
+        })))
+        .mount(&server)
+        .await;
+
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 20,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_record_not_found() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone/example.com/record"))
+        .and(query_param("id", "404"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [],
+            "isTruncated": false
+        })))
+        .mount(&server)
+        .await;
+
+    let result = zone.get_record("404").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_record_looks_up_id() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v1/dns/zone/example.com/record"))
+        .and(body_json(json!({
+            "rr": "www",
+            "type": "A",
+            "value": "192.0.2.1",
+            "ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/dns/zone/example.com/record"))
+        .and(query_param("rr", "www"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [
+                mock_record("7", "www", "A", "192.0.2.2", 0),
+                mock_record("8", "www", "A", "192.0.2.1", 0)
+            ],
+            "isTruncated": false
+        })))
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)),
+            300,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "8");
+    assert_eq!(record.host, "www");
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/dns/zone/example.com/record/2"))
+        .and(body_json(json!({
+            "rr": "@",
+            "type": "MX",
+            "value": "mail.example.com",
+            "ttl": 600,
+            "priority": 5
+        })))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .update_record(
+            "2",
+            "@",
+            &RecordData::MX {
+                priority: 5,
+                mail_server: "mail.example.com".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "2");
+    assert_eq!(record.ttl, 600);
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/dns/zone/example.com/record/1"))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.delete_record("1").await.unwrap();
+}
@@ -0,0 +1,520 @@
+//! Mock-based tests for the bunny.net provider.
+//!
+//! These tests use `wiremock` to simulate the bunny.net DNS zone API without
+//! requiring a bunny.net account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - AccessKey authentication header
+//! - Zone operations (list with page pagination, get by ID or name, create, delete)
+//! - Record conversion (numeric types, apex name, MX/SRV fields, CAA flags and tag)
+//! - Bunny-specific records (redirect, pull zone) as `RecordData::Other`
+//! - Record creation and deletion
+//! - Error handling (400, 401, 404)
+//!
+//! # API Structure
+//!
+//! - Zones: GET/POST `/dnszone`, GET/DELETE `/dnszone/{id}`
+//! - Records: PUT `/dnszone/{id}/records`, DELETE `/dnszone/{id}/records/{record_id}`
+//! - Pagination: `?page=N&perPage=1000`, continued while `HasMoreItems` is set
+//! - Auth: `AccessKey: {key}` header
+
+use crate::common::bunny::*;
+use crate::common::setup_mock_server;
+
+use manydns::bunny::BunnyProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, Provider, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> BunnyProvider {
+    BunnyProvider::with_base_url("test-key", &server.uri()).expect("Failed to create provider")
+}
+
+async fn mount_zone(server: &MockServer, records: Vec<serde_json::Value>) {
+    Mock::given(method("GET"))
+        .and(path("/dnszone/1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone(
+            1234,
+            "example.com",
+            records,
+        )))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/dnszone"))
+        .and(query_param("page", "1"))
+        .and(query_param("perPage", "1000"))
+        .and(header("AccessKey", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_zone(1, "example.com", vec![])],
+            1,
+            true,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/dnszone"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_zone(2, "example.org", vec![])],
+            2,
+            false,
+        )))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "1");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone_by_id() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    assert_eq!(zone.id(), "1234");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(
+        zone.name_servers(),
+        vec!["kiki.bunny.net", "coco.bunny.net"]
+    );
+    assert!(zone.nameservers_detected());
+}
+
+#[tokio::test]
+async fn test_get_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/dnszone"))
+        .and(query_param("search", "example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![
+                mock_zone(1, "sub.example.com", vec![]),
+                mock_zone(1234, "example.com", vec![]),
+            ],
+            1,
+            false,
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "1234");
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/dnszone/999"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(mock_error("dnszone.not_found", "The zone was not found")),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/dnszone"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(vec![], 1, false)))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("999").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_key_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/dnszone"))
+        .respond_with(ResponseTemplate::new(401))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/dnszone"))
+        .and(body_json(json!({ "Domain": "example.com" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_zone(
+            1234,
+            "example.com",
+            vec![],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "1234");
+}
+
+#[tokio::test]
+async fn test_create_zone_invalid() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/dnszone"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(mock_error("dnszone.validation", "The domain is invalid")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).create_zone("bad_domain").await;
+    assert!(matches!(result, Err(CreateZoneError::InvalidDomainName)));
+}
+
+#[tokio::test]
+async fn test_delete_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/dnszone"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_zone(1234, "example.com", vec![])],
+            1,
+            false,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/dnszone/1234"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+
+    let mut mx = mock_record(2, 4, "", "mail.example.com");
+    mx["Priority"] = json!(10);
+    let mut srv = mock_record(3, 8, "_sip._tcp", "sip.example.com");
+    srv["Priority"] = json!(10);
+    srv["Weight"] = json!(20);
+    srv["Port"] = json!(5060);
+    let mut caa = mock_record(4, 9, "", "letsencrypt.org");
+    caa["Tag"] = json!("issue");
+
+    mount_zone(
+        &server,
+        vec![
+            mock_record(1, 0, "www", "192.0.2.1"),
+            mx,
+            srv,
+            caa,
+            mock_record(5, 3, "", "v=spf1 -all"),
+        ],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].ttl, 300);
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].data.get_type(), "CAA");
+    assert_eq!(records[3].data.get_value(), "0 issue \"letsencrypt.org\"");
+    assert_eq!(records[4].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_list_bunny_specific_records() {
+    let server = setup_mock_server().await;
+
+    let mut pull_zone = mock_record(2, 7, "cdn", "");
+    pull_zone["LinkName"] = json!("my-pull-zone");
+
+    mount_zone(
+        &server,
+        vec![
+            mock_record(1, 5, "old", "https://example.org/"),
+            pull_zone,
+            mock_record(3, 42, "future", "value"),
+        ],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 3);
+
+    assert_eq!(
+        records[0].data,
+        RecordData::Other {
+            typ: "REDIRECT".to_string(),
+            value: "https://example.org/".to_string()
+        }
+    );
+    assert_eq!(
+        records[1].data,
+        RecordData::Other {
+            typ: "PULLZONE".to_string(),
+            value: "my-pull-zone".to_string()
+        }
+    );
+    assert_eq!(records[2].data.get_type(), "TYPE42");
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![
+            mock_record(1, 0, "www", "192.0.2.1"),
+            mock_record(2, 2, "blog", "example.org"),
+        ],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let record = zone.get_record("2").await.unwrap();
+    assert_eq!(record.data, RecordData::CNAME("example.org".to_string()));
+
+    let result = zone.get_record("3").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+    let result = zone.get_record("not-a-number").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_mx_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let mut created = mock_record(10, 4, "", "mail.example.com");
+    created["Priority"] = json!(10);
+    created["Ttl"] = json!(3600);
+
+    Mock::given(method("PUT"))
+        .and(path("/dnszone/1234/records"))
+        .and(body_json(json!({
+            "Type": 4,
+            "Name": "",
+            "Value": "mail.example.com",
+            "Ttl": 3600,
+            "Priority": 10
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(created))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let record = zone
+        .create_record(
+            "example.com",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "10");
+    assert_eq!(record.host, "@");
+    assert_eq!(record.ttl, 3600);
+}
+
+#[tokio::test]
+async fn test_create_caa_and_redirect_records() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let mut caa = mock_record(11, 9, "", "letsencrypt.org");
+    caa["Tag"] = json!("issue");
+
+    Mock::given(method("PUT"))
+        .and(path("/dnszone/1234/records"))
+        .and(body_json(json!({
+            "Type": 9,
+            "Name": "",
+            "Value": "letsencrypt.org",
+            "Ttl": 300,
+            "Flags": 0,
+            "Tag": "issue"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(caa))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/dnszone/1234/records"))
+        .and(body_json(json!({
+            "Type": 5,
+            "Name": "old",
+            "Value": "https://example.org/",
+            "Ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_record(
+            12,
+            5,
+            "old",
+            "https://example.org/",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "CAA".to_string(),
+                value: "0 issue \"letsencrypt.org\"".to_string(),
+            },
+            300,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.data.get_value(), "0 issue \"letsencrypt.org\"");
+
+    let record = zone
+        .create_record(
+            "old",
+            &RecordData::Other {
+                typ: "REDIRECT".to_string(),
+                value: "https://example.org/".to_string(),
+            },
+            300,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.data.get_type(), "REDIRECT");
+}
+
+#[tokio::test]
+async fn test_create_unsupported_record_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let result = zone
+        .create_record(
+            "cdn",
+            &RecordData::Other {
+                typ: "PULLZONE".to_string(),
+                value: "my-pull-zone".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_create_record_invalid() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/dnszone/1234/records"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error(
+            "dnszone.record.invalid",
+            "The record is invalid",
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::CNAME("bad..name".to_string()), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/dnszone/1234/records/10"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/dnszone/1234/records/11"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(mock_error("dnszone.record.not_found", "Record not found")),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    zone.delete_record("10").await.unwrap();
+
+    let result = zone.delete_record("11").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
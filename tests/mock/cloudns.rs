@@ -0,0 +1,464 @@
+//! Mock-based tests for the ClouDNS provider.
+//!
+//! These tests use `wiremock` to simulate the ClouDNS HTTP API without
+//! requiring a ClouDNS account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Form-encoded credentials (`auth-id`, `sub-auth-id`, `auth-password`)
+//! - Zone operations (list with pagination, get, create, delete)
+//! - Record conversion (apex host, MX/SRV fields, CAA, empty zones)
+//! - Record creation with TTL rounding, update and deletion
+//! - Dynamic URLs
+//! - Error handling (`Failed` status responses)
+//!
+//! # API Structure
+//!
+//! - Zones: `/dns/list-zones.json`, `/dns/get-zone-info.json`, `/dns/register.json`, `/dns/delete.json`
+//! - Records: `/dns/records.json`, `/dns/add-record.json`, `/dns/mod-record.json`, `/dns/delete-record.json`
+//! - Dynamic URLs: `/dns/get-dynamic-url.json`, `/dns/change-dynamic-url.json`, `/dns/disable-dynamic-url.json`
+//! - Auth: `auth-id` (or `sub-auth-id`/`sub-auth-user`) and `auth-password` form parameters
+//! - Errors: HTTP 200 with `{"status": "Failed", "statusDescription": ...}`
+
+use crate::common::cloudns::*;
+use crate::common::setup_mock_server;
+
+use manydns::cloudns::{AuthId, CloudnsProvider};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_string_contains, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> CloudnsProvider {
+    CloudnsProvider::with_base_url(AuthId::User("1234".to_string()), "p@ss", &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn zone(server: &MockServer) -> manydns::cloudns::CloudnsZone {
+    Mock::given(method("POST"))
+        .and(path("/dns/get-zone-info.json"))
+        .and(body_string_contains("domain-name=example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(server)
+        .await;
+
+    provider(server).get_zone("example.com").await.unwrap()
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    let first_page: Vec<_> = (0..100)
+        .map(|i| mock_zone(&format!("example{}.com", i)))
+        .collect();
+    Mock::given(method("POST"))
+        .and(path("/dns/list-zones.json"))
+        .and(body_string_contains("auth-id=1234&auth-password=p%40ss"))
+        .and(body_string_contains("page=1&rows-per-page=100"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/dns/list-zones.json"))
+        .and(body_string_contains("page=2&"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([mock_zone("example.org")])))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 101);
+    assert_eq!(zones[0].id(), "example0.com");
+    assert_eq!(zones[100].domain(), "example.org");
+    assert_eq!(zones[100].zone_type(), "master");
+}
+
+#[tokio::test]
+async fn test_sub_user_credentials() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/get-zone-info.json"))
+        .and(body_string_contains("sub-auth-user=deploy&auth-password="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = CloudnsProvider::with_base_url(
+        AuthId::SubUserName("deploy".to_string()),
+        "secret",
+        &server.uri(),
+    )
+    .unwrap();
+    let zone = provider.get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/get-zone-info.json"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_status("Failed", "Zone not found")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_credentials_are_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/list-zones.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_status(
+            "Failed",
+            "Invalid authentication, incorrect auth-id or auth-password.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_and_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/register.json"))
+        .and(body_string_contains(
+            "domain-name=example.com&zone-type=master",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_status(
+            "Success",
+            "Domain zone example.com was created successfully.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/dns/get-zone-info.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/dns/delete.json"))
+        .and(body_string_contains("domain-name=example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_status(
+            "Success",
+            "Domain zone example.com was deleted successfully.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let zone = provider.create_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    provider.delete_zone("example.com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    let mut mx = mock_record("2", "MX", "", "mail.example.com");
+    mx["priority"] = json!("10");
+    let mut srv = mock_record("3", "SRV", "_sip._tcp", "sip.example.com");
+    srv["priority"] = json!("10");
+    srv["weight"] = json!("20");
+    srv["port"] = json!("5060");
+    let mut caa = mock_record("4", "CAA", "", "");
+    caa["caa_flag"] = json!("0");
+    caa["caa_type"] = json!("issue");
+    caa["caa_value"] = json!("letsencrypt.org");
+
+    Mock::given(method("POST"))
+        .and(path("/dns/records.json"))
+        .and(body_string_contains("domain-name=example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "1": mock_record("1", "A", "", "192.0.2.1"),
+            "2": mx,
+            "3": srv,
+            "4": caa
+        })))
+        .mount(&server)
+        .await;
+
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].host, "@");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(records[0].ttl, 3600);
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[3].data,
+        RecordData::Other {
+            typ: "CAA".to_string(),
+            value: "0 issue \"letsencrypt.org\"".to_string()
+        }
+    );
+
+    let record = zone.get_record("3").await.unwrap();
+    assert_eq!(record.host, "_sip._tcp");
+}
+
+#[tokio::test]
+async fn test_list_records_empty_zone() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/records.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .mount(&server)
+        .await;
+
+    assert!(zone.list_records().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_create_record_rounds_ttl() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/add-record.json"))
+        .and(body_string_contains(
+            "domain-name=example.com&record-type=MX&host=&record=mail.example.com&ttl=300&priority=10",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "Success",
+            "statusDescription": "The record was added successfully.",
+            "data": { "id": 42 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com.".to_string(),
+            },
+            120,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "42");
+    assert_eq!(record.host, "@");
+    assert_eq!(record.ttl, 300);
+}
+
+#[tokio::test]
+async fn test_create_caa_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/add-record.json"))
+        .and(body_string_contains("record-type=CAA"))
+        .and(body_string_contains(
+            "caa_flag=0&caa_type=issue&caa_value=letsencrypt.org",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "status": "Success",
+            "statusDescription": "The record was added successfully.",
+            "data": { "id": "43" }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "CAA".to_string(),
+                value: "0 issue \"letsencrypt.org\"".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "43");
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/add-record.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_status(
+            "Failed",
+            "Invalid record. The record must be a valid IPv4 address.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "NAPTR".to_string(),
+                value: "x".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/mod-record.json"))
+        .and(body_string_contains(
+            "domain-name=example.com&record-id=42&host=www&record=192.0.2.2&ttl=3600",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_status(
+            "Success",
+            "The record was modified successfully.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .update_record(
+            "42",
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "42");
+    assert_eq!(record.host, "www");
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/delete-record.json"))
+        .and(body_string_contains("record-id=42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_status(
+            "Success",
+            "The record was deleted successfully.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/dns/delete-record.json"))
+        .and(body_string_contains("record-id=99"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_status("Failed", "Invalid record-id param.")),
+        )
+        .mount(&server)
+        .await;
+
+    zone.delete_record("42").await.unwrap();
+    let result = zone.delete_record("99").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
+
+// =============================================================================
+// Dynamic URL Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_dynamic_url() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/get-dynamic-url.json"))
+        .and(body_string_contains("domain-name=example.com&record-id=42"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "host": "home",
+            "url": "https://ipv4.cloudns.net/api/dynamicURL/?q=abc"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/dns/change-dynamic-url.json"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "host": "home",
+            "url": "https://ipv4.cloudns.net/api/dynamicURL/?q=def"
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/dns/disable-dynamic-url.json"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_status("Success", "Dynamic URL was disabled.")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    assert_eq!(
+        zone.dynamic_url("42").await.unwrap(),
+        "https://ipv4.cloudns.net/api/dynamicURL/?q=abc"
+    );
+    assert_eq!(
+        zone.change_dynamic_url("42").await.unwrap(),
+        "https://ipv4.cloudns.net/api/dynamicURL/?q=def"
+    );
+    zone.disable_dynamic_url("42").await.unwrap();
+}
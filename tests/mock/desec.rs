@@ -0,0 +1,513 @@
+//! Mock-based tests for the deSEC provider.
+//!
+//! These tests use `wiremock` to simulate the deSEC REST API without
+//! requiring a deSEC account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Token authentication header
+//! - Domain operations (list with cursor pagination, get with DNSSEC keys, create, delete)
+//! - Pagination staying on the configured base URL
+//! - RRset to record conversion with synthetic IDs (A, MX, TXT)
+//! - Record creation and deletion via bulk RRset PATCH
+//! - Minimum TTL handling
+//! - Throttling (429 with `Retry-After`) and error handling (401, 404)
+//!
+//! # API Structure
+//!
+//! - Domains: GET/POST `/domains/`, GET/DELETE `/domains/{name}/`
+//! - RRsets: GET/PATCH `/domains/{name}/rrsets/`, GET `/domains/{name}/rrsets/{subname}/{type}/`
+//! - Pagination: `?cursor=` with `Link: <...>; rel="next"` headers
+//! - Auth: `Authorization: Token {token}` header
+
+use crate::common::desec::*;
+use crate::common::setup_mock_server;
+
+use manydns::desec::{DesecError, DesecProvider};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    DeleteZoneError, Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> DesecProvider {
+    DesecProvider::with_base_url("test-token", &server.uri()).expect("Failed to create provider")
+}
+
+async fn mount_domain(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com/"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_domain_with_keys("example.com", 3600)),
+        )
+        .mount(server)
+        .await;
+}
+
+async fn mount_rrset(server: &MockServer, subname: &str, typ: &str, records: &[&str]) {
+    let segment = if subname.is_empty() { "@" } else { subname };
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/domains/example.com/rrsets/{}/{}/",
+            segment, typ
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_rrset(
+            "example.com",
+            subname,
+            typ,
+            3600,
+            records,
+        )))
+        .mount(server)
+        .await;
+}
+
+async fn mount_missing_rrset(server: &MockServer, subname: &str, typ: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/domains/example.com/rrsets/{}/{}/",
+            subname, typ
+        )))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Not found.")))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/"))
+        .and(query_param("cursor", ""))
+        .and(header("Authorization", "Token test-token"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!(
+                        "<{0}/domains/?cursor=>; rel=\"first\", <{0}/domains/?cursor=page2>; rel=\"next\"",
+                        server.uri()
+                    ),
+                )
+                .set_body_json(json!([mock_domain("example.com", 3600)])),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/domains/"))
+        .and(query_param("cursor", "page2"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    format!("<{}/domains/?cursor=>; rel=\"first\"", server.uri()),
+                )
+                .set_body_json(json!([mock_domain("example.org", 60)])),
+        )
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    let domains: Vec<&str> = zones.iter().map(|z| z.domain()).collect();
+    assert_eq!(domains, vec!["example.com", "example.org"]);
+    assert_eq!(zones[1].minimum_ttl(), 60);
+    assert!(zones[0].keys().is_empty());
+}
+
+#[tokio::test]
+async fn test_list_zones_ignores_next_link_host() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/"))
+        .and(query_param("cursor", ""))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .insert_header(
+                    "Link",
+                    "<https://attacker.invalid/domains/?cursor=page%3D2>; rel=\"next\"",
+                )
+                .set_body_json(json!([mock_domain("example.com", 3600)])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/domains/"))
+        .and(query_param("cursor", "page=2"))
+        .and(header("Authorization", "Token test-token"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([mock_domain("example.org", 60)])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone_with_keys() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.minimum_ttl(), 3600);
+    assert_eq!(zone.keys().len(), 1);
+    assert_eq!(zone.keys()[0].flags, 257);
+    assert_eq!(zone.keys()[0].ds.len(), 2);
+    assert!(zone.published().is_some());
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/missing.com/"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Not found.")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_token_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error("Invalid token.")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/domains/"))
+        .and(body_json(json!({ "name": "example.com" })))
+        .respond_with(
+            ResponseTemplate::new(201).set_body_json(mock_domain_with_keys("example.com", 3600)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.keys().len(), 1);
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/domains/example.com/"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/missing.com/"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Not found.")))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).delete_zone("missing.com").await;
+    assert!(matches!(result, Err(DeleteZoneError::NotFound)));
+}
+
+// =============================================================================
+// Throttling Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_throttled_request_is_retried() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com/"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "0")
+                .set_body_json(mock_error(
+                    "Request was throttled. Expected available in 0 seconds.",
+                )),
+        )
+        .up_to_n_times(2)
+        .expect(2)
+        .mount(&server)
+        .await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_long_retry_after_is_not_waited_out() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com/"))
+        .respond_with(
+            ResponseTemplate::new(429)
+                .insert_header("Retry-After", "86400")
+                .set_body_json(mock_error(
+                    "Request was throttled. Expected available in 86400 seconds.",
+                )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("example.com").await;
+    assert!(matches!(
+        result,
+        Err(RetrieveZoneError::Custom(DesecError::Throttled {
+            retry_after: Some(86400)
+        }))
+    ));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com/rrsets/"))
+        .and(query_param("cursor", ""))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            mock_rrset("example.com", "", "MX", 3600, &["10 mail.example.com."]),
+            mock_rrset("example.com", "", "TXT", 3600, &["\"v=spf1 \" \"-all\""]),
+            mock_rrset("example.com", "www", "A", 3600, &["192.0.2.1", "192.0.2.2"])
+        ])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+
+    assert_eq!(records[0].id, "@/MX/10 mail.example.com.");
+    assert_eq!(records[0].host, "@");
+    assert_eq!(
+        records[0].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[1].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(records[2].id, "www/A/192.0.2.1");
+    assert_eq!(records[3].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+    assert_eq!(records[3].ttl, 3600);
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "", "MX", &["10 mail.example.com."]).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone.get_record("@/MX/10 mail.example.com.").await.unwrap();
+    assert_eq!(record.host, "@");
+
+    let missing = zone.get_record("@/MX/20 other.example.com.").await;
+    assert!(missing.is_err());
+}
+
+#[tokio::test]
+async fn test_create_record_merges_rrset_and_raises_ttl() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1"]).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domains/example.com/rrsets/"))
+        .and(body_json(json!([{
+            "subname": "www",
+            "type": "A",
+            "ttl": 3600,
+            "records": ["192.0.2.1", "192.0.2.2"]
+        }])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([mock_rrset(
+            "example.com",
+            "www",
+            "A",
+            3600,
+            &["192.0.2.1", "192.0.2.2"]
+        )])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 60)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "www/A/192.0.2.2");
+    assert_eq!(record.ttl, 3600);
+}
+
+#[tokio::test]
+async fn test_create_record_new_rrset() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_missing_rrset(&server, "_acme-challenge", "TXT").await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domains/example.com/rrsets/"))
+        .and(body_json(json!([{
+            "subname": "_acme-challenge",
+            "type": "TXT",
+            "ttl": 7200,
+            "records": ["\"token-value\""]
+        }])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([mock_rrset(
+            "example.com",
+            "_acme-challenge",
+            "TXT",
+            7200,
+            &["\"token-value\""]
+        )])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "_acme-challenge.example.com",
+            &RecordData::TXT("token-value".to_string()),
+            7200,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.host, "_acme-challenge");
+    assert_eq!(record.ttl, 7200);
+}
+
+#[tokio::test]
+async fn test_create_record_duplicate_and_invalid() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1"]).await;
+    mount_missing_rrset(&server, "www", "CNAME").await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domains/example.com/rrsets/"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!([{
+            "non_field_errors": ["RRset with conflicting type present: database (A). (No other RRsets are allowed alongside CNAME.)"]
+        }])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+
+    let duplicate = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 3600)
+        .await;
+    assert!(matches!(duplicate, Err(CreateRecordError::InvalidRecord)));
+
+    let conflicting = zone
+        .create_record("www", &RecordData::CNAME("example.net".to_string()), 3600)
+        .await;
+    assert!(matches!(conflicting, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_remaining_values() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1", "192.0.2.2"]).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domains/example.com/rrsets/"))
+        .and(body_json(json!([{
+            "subname": "www",
+            "type": "A",
+            "ttl": 3600,
+            "records": ["192.0.2.2"]
+        }])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([mock_rrset(
+            "example.com",
+            "www",
+            "A",
+            3600,
+            &["192.0.2.2"]
+        )])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_last_record_deletes_rrset() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "", "TXT", &["\"token-value\""]).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domains/example.com/rrsets/"))
+        .and(body_json(json!([{
+            "subname": "",
+            "type": "TXT",
+            "records": []
+        }])))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("@/TXT/\"token-value\"").await.unwrap();
+
+    let missing = zone.delete_record("@/TXT/\"other\"").await;
+    assert!(matches!(missing, Err(DeleteRecordError::NotFound)));
+}
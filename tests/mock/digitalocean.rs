@@ -0,0 +1,519 @@
+//! Mock-based tests for the DigitalOcean provider.
+//!
+//! These tests use `wiremock` to simulate the DigitalOcean Domains API
+//! without requiring an account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Domain operations (list with link pagination, get, create, delete)
+//! - Pagination staying on the configured base URL
+//! - Record conversion (A, MX, SRV, CAA, CNAME)
+//! - Record creation with type-specific fields (priority, weight, port, flags, tag)
+//! - Record deletion
+//! - Error handling (401, 404, 422)
+//!
+//! # API Structure
+//!
+//! - Domains: GET/POST `/v2/domains`, GET/DELETE `/v2/domains/{name}`
+//! - Records: GET/POST `/v2/domains/{name}/records`, GET/DELETE `/v2/domains/{name}/records/{id}`
+//! - Auth: `Authorization: Bearer {token}` header
+
+use crate::common::digitalocean::*;
+use crate::common::setup_mock_server;
+
+use manydns::digitalocean::DigitalOceanProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, Provider, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(uri: &str) -> DigitalOceanProvider {
+    DigitalOceanProvider::with_base_url("test-token", uri).expect("Failed to create provider")
+}
+
+async fn mount_domain(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "domain": mock_domain("example.com") })),
+        )
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_follows_links() {
+    let server = setup_mock_server().await;
+    let next = format!("{}/v2/domains?page=2&per_page=200", server.uri());
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .and(query_param("per_page", "200"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.com")],
+            "links": mock_links(Some(&next)),
+            "meta": { "total": 2 }
+        })))
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.org")],
+            "links": mock_links(None),
+            "meta": { "total": 2 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri()).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[0].ttl(), Some(1800));
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_list_zones_ignores_next_link_host() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.com")],
+            "links": mock_links(Some("https://attacker.invalid/v2/domains?page=2")),
+            "meta": { "total": 2 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .and(query_param("page", "2"))
+        .and(query_param("per_page", "200"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.org")],
+            "links": mock_links(None),
+            "meta": { "total": 2 }
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri()).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_list_zones_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .respond_with(
+            ResponseTemplate::new(401)
+                .set_body_json(mock_error("unauthorized", "Unable to authenticate you.")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).list_zones().await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "not_found",
+            "The resource you requested could not be found.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).get_zone("missing.com").await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains"))
+        .and(body_json(json!({ "name": "example.com" })))
+        .respond_with(
+            ResponseTemplate::new(201)
+                .set_body_json(json!({ "domain": mock_domain("example.com") })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .create_zone("example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_create_zone_invalid() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains"))
+        .respond_with(
+            ResponseTemplate::new(422)
+                .set_body_json(mock_error("unprocessable_entity", "Name is invalid")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).create_zone("not a domain").await;
+
+    assert!(matches!(result, Err(CreateZoneError::InvalidDomainName)));
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/domains/example.com"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server.uri())
+        .delete_zone("example.com")
+        .await
+        .unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_converts_types() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    let next = format!(
+        "{}/v2/domains/example.com/records?page=2&per_page=200",
+        server.uri()
+    );
+
+    let mut mx = mock_record(2, "MX", "@", "mail.example.com", 3600);
+    mx["priority"] = json!(10);
+    let mut srv = mock_record(3, "SRV", "_sip._tcp", "sip.example.com", 3600);
+    srv["priority"] = json!(10);
+    srv["weight"] = json!(20);
+    srv["port"] = json!(5060);
+    let mut caa = mock_record(4, "CAA", "@", "letsencrypt.org", 3600);
+    caa["flags"] = json!(0);
+    caa["tag"] = json!("issue");
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com/records"))
+        .and(query_param("per_page", "200"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domain_records": [mock_record(1, "A", "www", "192.0.2.1", 300), mx, srv],
+            "links": mock_links(Some(&next)),
+            "meta": { "total": 5 }
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com/records"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domain_records": [caa, mock_record(5, "CNAME", "blog", "@", 300)],
+            "links": mock_links(None),
+            "meta": { "total": 5 }
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 5);
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[3].data,
+        RecordData::Other {
+            typ: "CAA".to_string(),
+            value: "0 issue \"letsencrypt.org\"".to_string()
+        }
+    );
+    assert_eq!(
+        records[4].data,
+        RecordData::CNAME("example.com".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_get_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com/records/99"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(mock_error("not_found", "not found")),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone.get_record("99").await;
+
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_srv_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let mut srv = mock_record(10, "SRV", "_sip._tcp", "sip.example.com", 600);
+    srv["priority"] = json!(10);
+    srv["weight"] = json!(20);
+    srv["port"] = json!(5060);
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains/example.com/records"))
+        .and(body_json(json!({
+            "type": "SRV",
+            "name": "_sip._tcp",
+            "data": "sip.example.com.",
+            "priority": 10,
+            "port": 5060,
+            "ttl": 600,
+            "weight": 20,
+            "flags": null,
+            "tag": null
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "domain_record": srv })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record(
+            "_sip._tcp",
+            &RecordData::SRV {
+                priority: 10,
+                weight: 20,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "10");
+    assert_eq!(record.host, "_sip._tcp");
+}
+
+#[tokio::test]
+async fn test_create_caa_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let mut caa = mock_record(11, "CAA", "@", "letsencrypt.org", 3600);
+    caa["flags"] = json!(128);
+    caa["tag"] = json!("issue");
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains/example.com/records"))
+        .and(body_json(json!({
+            "type": "CAA",
+            "name": "@",
+            "data": "letsencrypt.org",
+            "priority": null,
+            "port": null,
+            "ttl": 3600,
+            "weight": null,
+            "flags": 128,
+            "tag": "issue"
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({ "domain_record": caa })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "CAA".to_string(),
+                value: "128 issue \"letsencrypt.org\"".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        record.data,
+        RecordData::Other {
+            typ: "CAA".to_string(),
+            value: "128 issue \"letsencrypt.org\"".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_create_record_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "TLSA".to_string(),
+                value: "3 1 1 abcd".to_string(),
+            },
+            300,
+        )
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_create_record_invalid() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains/example.com/records"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(mock_error(
+            "unprocessable_entity",
+            "Data needs to end with a dot (.)",
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/domains/example.com/records/42"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.delete_record("42").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/domains/example.com/records/42"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(mock_error("not_found", "not found")),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone.delete_record("42").await;
+
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
@@ -0,0 +1,425 @@
+//! Mock-based tests for the DNSimple provider.
+//!
+//! These tests use `wiremock` to simulate the DNSimple API v2 without
+//! requiring a DNSimple account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Bearer token authentication header and account-scoped paths
+//! - Sandbox and production environment selection
+//! - Zone operations (list with page pagination, get, create and delete via domains)
+//! - Record conversion (apex name, MX priority, SRV content)
+//! - Record creation, update and deletion
+//! - Error handling (400, 401, 404)
+//!
+//! # API Structure
+//!
+//! - Zones: GET `/v2/{account}/zones`, GET `/v2/{account}/zones/{zone}`
+//! - Domains: POST `/v2/{account}/domains`, DELETE `/v2/{account}/domains/{domain}`
+//! - Records: GET/POST `/v2/{account}/zones/{zone}/records`, GET/PATCH/DELETE `.../records/{id}`
+//! - Pagination: `?page=N&per_page=100`
+//! - Auth: `Authorization: Bearer {token}` header
+
+use crate::common::dnsimple::*;
+use crate::common::setup_mock_server;
+
+use manydns::dnsimple::{ClientConfig, DnsimpleProvider};
+use manydns::types::Environment;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, DeleteZoneError, Provider, RecordData, RetrieveRecordError, RetrieveZoneError,
+    Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> DnsimpleProvider {
+    DnsimpleProvider::with_base_url(ClientConfig::sandbox("test-token", "1385"), &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones/example.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_data(mock_zone(1, "example.com"))),
+        )
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Configuration Tests
+// =============================================================================
+
+#[test]
+fn test_client_config_environments() {
+    let config = ClientConfig::sandbox("token", "1385");
+    assert!(config.environment.is_sandbox());
+    assert_eq!(config.api_url(), "https://api.sandbox.dnsimple.com");
+
+    let config = ClientConfig::new("token", "1385", Environment::Production);
+    assert_eq!(config.api_url(), "https://api.dnsimple.com");
+
+    let debug = format!("{:?}", config);
+    assert!(debug.contains("1385"));
+    assert!(!debug.contains("token\""));
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones"))
+        .and(query_param("page", "1"))
+        .and(query_param("per_page", "100"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_zone(1, "example.com")],
+            1,
+            2,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_zone(2, "example.org")],
+            2,
+            2,
+        )))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert!(zone.is_active());
+    assert!(!zone.is_secondary());
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones/missing.com"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(mock_error("Zone `missing.com` not found")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_token_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error("Authentication failed")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/1385/domains"))
+        .and(body_json(json!({ "name": "example.com" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_data(json!({
+            "id": 181984,
+            "account_id": 1385,
+            "name": "example.com",
+            "state": "hosted"
+        }))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+}
+
+#[tokio::test]
+async fn test_create_zone_invalid() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/1385/domains"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "message": "Validation failed",
+            "errors": { "name": ["is an invalid domain"] }
+        })))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).create_zone("bad_domain").await;
+    assert!(matches!(result, Err(CreateZoneError::InvalidDomainName)));
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/1385/domains/example.com"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v2/1385/domains/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Domain not found")))
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+    let result = provider(&server).delete_zone("missing.com").await;
+    assert!(matches!(result, Err(DeleteZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let mut mx = mock_record(2, "MX", "", "mail.example.com");
+    mx["priority"] = json!(10);
+    let mut srv = mock_record(3, "SRV", "_sip._tcp", "20 5060 sip.example.com");
+    srv["priority"] = json!(10);
+
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones/example.com/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![
+                mock_record(1, "A", "www", "192.0.2.1"),
+                mx,
+                srv,
+                mock_record(4, "TXT", "", "v=spf1 -all"),
+                mock_record(5, "ALIAS", "", "lb.example.net"),
+            ],
+            1,
+            1,
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].ttl, 3600);
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(records[4].data.get_type(), "ALIAS");
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones/example.com/records/1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_data(mock_record(
+                1,
+                "CNAME",
+                "blog",
+                "example.org",
+            ))),
+        )
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/1385/zones/example.com/records/2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Record not found")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone.get_record("1").await.unwrap();
+    assert_eq!(record.data, RecordData::CNAME("example.org".to_string()));
+
+    let result = zone.get_record("2").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_mx_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let mut created = mock_record(10, "MX", "", "mail.example.com");
+    created["priority"] = json!(10);
+
+    Mock::given(method("POST"))
+        .and(path("/v2/1385/zones/example.com/records"))
+        .and(body_json(json!({
+            "name": "",
+            "type": "MX",
+            "content": "mail.example.com",
+            "ttl": 3600,
+            "priority": 10
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_data(created)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "example.com",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com.".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "10");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/1385/zones/example.com/records"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "message": "Validation failed",
+            "errors": { "content": ["is invalid"] }
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::CNAME("bad..name".to_string()), 3600)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "TLSA".to_string(),
+                value: "3 1 1 abc".to_string(),
+            },
+            3600,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v2/1385/zones/example.com/records/1"))
+        .and(body_json(json!({
+            "name": "www",
+            "content": "192.0.2.2",
+            "ttl": 600
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_data({
+            let mut record = mock_record(1, "A", "www", "192.0.2.2");
+            record["ttl"] = json!(600);
+            record
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .update_record("1", "www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 600)
+        .await
+        .unwrap();
+    assert_eq!(record.ttl, 600);
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/1385/zones/example.com/records/10"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v2/1385/zones/example.com/records/11"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Record not found")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("10").await.unwrap();
+
+    let result = zone.delete_record("11").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
@@ -0,0 +1,327 @@
+//! Mock-based tests for the Exoscale provider.
+//!
+//! These tests use `wiremock` to simulate the Exoscale v2 API without
+//! requiring an Exoscale account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - `EXO2-HMAC-SHA256` request signing (the `Authorization` header)
+//! - Zone operations (list, get by ID or name, create, delete)
+//! - Record conversion (apex name, MX/SRV priorities)
+//! - Record creation, update and deletion through operations
+//! - Error handling (403, 404, failed operations)
+//!
+//! # API Structure
+//!
+//! - Domains: `/v2/dns-domain`, `/v2/dns-domain/{id}`
+//! - Records: `/v2/dns-domain/{id}/record`, `/v2/dns-domain/{id}/record/{record-id}`
+//! - Auth: `Authorization: EXO2-HMAC-SHA256 credential=...,expires=...,signature=...`
+//! - Modifications return an operation referencing the affected resource
+
+use crate::common::exoscale::*;
+use crate::common::setup_mock_server;
+
+use manydns::exoscale::{Credentials, ExoscaleError, ExoscaleProvider, ExoscaleZone};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteZone, Provider, RecordData,
+    RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header_regex, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const DOMAIN_ID: &str = "3b1e6c4e-0000-4000-8000-000000000001";
+
+fn provider(server: &MockServer) -> ExoscaleProvider {
+    ExoscaleProvider::with_base_url(
+        Credentials::new("EXOtest", "dHVzaWx2ZXJ0ZXN0c2VjcmV0"),
+        &format!("{}/v2", server.uri()),
+    )
+    .expect("Failed to create provider")
+}
+
+async fn zone(server: &MockServer) -> ExoscaleZone {
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/dns-domain/{}", DOMAIN_ID)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_domain(DOMAIN_ID, "example.com")),
+        )
+        .mount(server)
+        .await;
+
+    provider(server).get_zone(DOMAIN_ID).await.unwrap()
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_signed() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/dns-domain"))
+        .and(header_regex(
+            "Authorization",
+            r"^EXO2-HMAC-SHA256 credential=EXOtest,expires=\d+,signature=[A-Za-z0-9+/]+=*$",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns-domains": [
+                mock_domain(DOMAIN_ID, "example.com"),
+                mock_domain("3b1e6c4e-0000-4000-8000-000000000002", "example.org")
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), DOMAIN_ID);
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone_by_id() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    assert_eq!(zone.id(), DOMAIN_ID);
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/dns-domain"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns-domains": [mock_domain(DOMAIN_ID, "example.com")]
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), DOMAIN_ID);
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_get_zone_errors() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/dns-domain/missing"))
+        .respond_with(
+            ResponseTemplate::new(404).set_body_json(json!({ "message": "Domain not found" })),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/dns-domain"))
+        .respond_with(
+            ResponseTemplate::new(403).set_body_json(json!({ "message": "Invalid signature" })),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/dns-domain"))
+        .and(body_json(json!({ "unicode-name": "example.com" })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_domain(DOMAIN_ID, "example.com")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+    assert_eq!(zone.id(), DOMAIN_ID);
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/v2/dns-domain/{}", DOMAIN_ID)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_operation("success", DOMAIN_ID)),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone(DOMAIN_ID).await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    let mut mx = mock_record("r2", "", "MX", "mail.example.com");
+    mx["priority"] = json!(10);
+    let mut srv = mock_record("r3", "_sip._tcp", "SRV", "5 5060 sip.example.com");
+    srv["priority"] = json!(20);
+
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/dns-domain/{}/record", DOMAIN_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns-domain-records": [mock_record("r1", "", "A", "192.0.2.1"), mx, srv]
+        })))
+        .mount(&server)
+        .await;
+
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].host, "@");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 20,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_create_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/v2/dns-domain/{}/record", DOMAIN_ID)))
+        .and(body_json(json!({
+            "name": "www",
+            "type": "A",
+            "content": "192.0.2.1",
+            "ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_operation("pending", "new-id")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)),
+            300,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "new-id");
+    assert_eq!(record.host, "www");
+    assert_eq!(record.ttl, 300);
+}
+
+#[tokio::test]
+async fn test_create_record_failed_operation() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/v2/dns-domain/{}/record", DOMAIN_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": "e2047130-b86e-11ef-83b3-0242ac120002",
+            "state": "failure",
+            "message": "record conflict"
+        })))
+        .mount(&server)
+        .await;
+
+    let result = zone
+        .create_record("www", &RecordData::TXT("hello".to_string()), 300)
+        .await;
+    assert!(matches!(
+        result,
+        Err(CreateRecordError::Custom(ExoscaleError::Operation { .. }))
+    ));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("/v2/dns-domain/{}/record/r2", DOMAIN_ID)))
+        .and(body_json(json!({
+            "name": "",
+            "content": "mail.example.com",
+            "ttl": 600,
+            "priority": 5
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_operation("success", "r2")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .update_record(
+            "r2",
+            "@",
+            &RecordData::MX {
+                priority: 5,
+                mail_server: "mail.example.com.".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "r2");
+    assert_eq!(record.host, "@");
+    assert_eq!(record.ttl, 600);
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/v2/dns-domain/{}/record/r1", DOMAIN_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_operation("success", "r1")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.delete_record("r1").await.unwrap();
+}
@@ -0,0 +1,461 @@
+//! Mock-based tests for the Gandi LiveDNS provider.
+//!
+//! These tests use `wiremock` to simulate the Gandi LiveDNS v5 API without
+//! requiring a Gandi account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Domain operations (list with pagination, get)
+//! - RRset to record conversion with synthetic IDs (A, MX, TXT)
+//! - Record creation and deletion via RRset PUT/DELETE
+//! - Snapshot endpoints (list, create, get, delete, restore)
+//! - Error handling (401, 404)
+//!
+//! # API Structure
+//!
+//! - Domains: GET `/domains`, GET `/domains/{fqdn}`
+//! - RRsets: GET/PUT `/domains/{fqdn}/records`, GET/PUT/DELETE `/domains/{fqdn}/records/{name}/{type}`
+//! - Snapshots: GET/POST `/domains/{fqdn}/snapshots`, GET/DELETE `/domains/{fqdn}/snapshots/{id}`
+//! - Auth: `Authorization: Bearer {token}` header
+
+use crate::common::gandi::*;
+use crate::common::setup_mock_server;
+
+use manydns::gandi::GandiProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SNAPSHOT_ID: &str = "7d6a8f0c-0d7e-11ee-9f5d-00163e8fd4b8";
+
+fn provider(uri: &str) -> GandiProvider {
+    GandiProvider::with_base_url("test-token", uri).expect("Failed to create provider")
+}
+
+async fn mount_domain(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domain("example.com")))
+        .mount(server)
+        .await;
+}
+
+async fn mount_rrset(server: &MockServer, name: &str, typ: &str, values: &[&str]) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/domains/example.com/records/{}/{}",
+            name, typ
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_rrset(name, typ, 300, values)))
+        .mount(server)
+        .await;
+}
+
+async fn mount_missing_rrset(server: &MockServer, name: &str, typ: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/domains/example.com/records/{}/{}",
+            name, typ
+        )))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(404, "Not Found")))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains"))
+        .and(query_param("page", "1"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            mock_domain("example.com"),
+            mock_domain("example.org")
+        ])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri()).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[0].automatic_snapshots(), Some(true));
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_list_zones_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(401, "Unauthorized")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).list_zones().await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(404, "Not Found")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).get_zone("missing.com").await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            mock_rrset("www", "A", 300, &["192.0.2.1", "192.0.2.2"]),
+            mock_rrset("@", "MX", 10800, &["10 mail.example.com."]),
+            mock_rrset(
+                "@",
+                "TXT",
+                10800,
+                &["\"v=spf1 include:_mailcust.gandi.net ?all\""]
+            )
+        ])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].id, "www/A/192.0.2.1");
+    assert_eq!(records[1].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+    assert_eq!(records[2].host, "@");
+    assert_eq!(
+        records[2].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[2].ttl, 10800);
+    assert_eq!(
+        records[3].data,
+        RecordData::TXT("v=spf1 include:_mailcust.gandi.net ?all".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1", "192.0.2.2"]).await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone.get_record("www/A/192.0.2.2").await.unwrap();
+
+    assert_eq!(record.host, "www");
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+
+    let missing = zone.get_record("www/A/192.0.2.9").await;
+    assert!(matches!(missing, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_record_new_rrset() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_missing_rrset(&server, "_acme-challenge", "TXT").await;
+
+    Mock::given(method("PUT"))
+        .and(path("/domains/example.com/records/_acme-challenge/TXT"))
+        .and(body_json(json!({
+            "rrset_values": ["\"token\""],
+            "rrset_ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_message("DNS Record Created")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record(
+            "_acme-challenge.example.com",
+            &RecordData::TXT("token".to_string()),
+            300,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "_acme-challenge/TXT/\"token\"");
+    assert_eq!(record.host, "_acme-challenge");
+}
+
+#[tokio::test]
+async fn test_create_record_merges_rrset() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "@", "MX", &["10 mail.example.com."]).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/domains/example.com/records/@/MX"))
+        .and(body_json(json!({
+            "rrset_values": ["10 mail.example.com.", "20 backup.example.com."],
+            "rrset_ttl": 3600
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_message("DNS Record Created")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::MX {
+                priority: 20,
+                mail_server: "backup.example.com".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "@/MX/20 backup.example.com.");
+}
+
+#[tokio::test]
+async fn test_create_record_duplicate() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1"]).await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_siblings() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1", "192.0.2.2"]).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/domains/example.com/records/www/A"))
+        .and(body_json(json!({
+            "rrset_values": ["192.0.2.2"],
+            "rrset_ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_message("DNS Record Created")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_last_record_deletes_rrset() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_rrset(&server, "www", "A", &["192.0.2.1"]).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/domains/example.com/records/www/A"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+    mount_missing_rrset(&server, "www", "A").await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone.delete_record("www/A/192.0.2.1").await;
+
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
+
+// =============================================================================
+// Snapshot Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_snapshots() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/domains/example.com/snapshots"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([{
+            "id": SNAPSHOT_ID,
+            "name": "before-migration",
+            "created_at": "2024-01-01T00:00:00Z",
+            "automatic": false,
+            "snapshot_href": "https://api.gandi.net/v5/livedns/domains/example.com/snapshots/x"
+        }])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let snapshots = zone.list_snapshots().await.unwrap();
+
+    assert_eq!(snapshots.len(), 1);
+    assert_eq!(snapshots[0].id, SNAPSHOT_ID);
+    assert_eq!(snapshots[0].name.as_deref(), Some("before-migration"));
+    assert!(!snapshots[0].automatic);
+    assert!(snapshots[0].zone_data.is_empty());
+}
+
+#[tokio::test]
+async fn test_create_snapshot() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/domains/example.com/snapshots"))
+        .and(body_json(json!({ "name": "before-migration" })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "id": SNAPSHOT_ID,
+            "message": "Snapshot Created"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let created = zone
+        .create_snapshot(Some("before-migration"))
+        .await
+        .unwrap();
+
+    assert_eq!(created.id, SNAPSHOT_ID);
+}
+
+#[tokio::test]
+async fn test_restore_snapshot() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/domains/example.com/snapshots/{}",
+            SNAPSHOT_ID
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "id": SNAPSHOT_ID,
+            "name": "before-migration",
+            "created_at": "2024-01-01T00:00:00Z",
+            "automatic": false,
+            "zone_data": [mock_rrset("www", "A", 300, &["192.0.2.1"])]
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/domains/example.com/records"))
+        .and(body_json(json!({
+            "items": [mock_rrset("www", "A", 300, &["192.0.2.1"])]
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_message("DNS Record Created")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.restore_snapshot(SNAPSHOT_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_snapshot() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!(
+            "/domains/example.com/snapshots/{}",
+            SNAPSHOT_ID
+        )))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.delete_snapshot(SNAPSHOT_ID).await.unwrap();
+}
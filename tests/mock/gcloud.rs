@@ -0,0 +1,594 @@
+//! Mock-based tests for the Google Cloud DNS provider.
+//!
+//! These tests use `wiremock` to simulate both the OAuth 2.0 token endpoint
+//! and the Cloud DNS v1 API without requiring a Google Cloud project.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Service-account token exchange, caching, expiry refresh and 401 retry
+//! - Managed zone operations (list with pagination, get by name or DNS name, create, delete)
+//! - Record set to record conversion with synthetic IDs (A, MX, TXT)
+//! - Record creation and deletion via atomic changes
+//! - Error handling (OAuth `invalid_grant`, 403, 404)
+//!
+//! # API Structure
+//!
+//! - Token: POST `{token_uri}` with a JWT bearer assertion
+//! - Zones: GET/POST `/projects/{project}/managedZones`, GET/DELETE `/projects/{project}/managedZones/{zone}`
+//! - RRsets: GET `/projects/{project}/managedZones/{zone}/rrsets[/{name}/{type}]`
+//! - Changes: POST `/projects/{project}/managedZones/{zone}/changes`
+//! - Auth: `Authorization: Bearer {access_token}` header
+
+use crate::common::gcloud::*;
+use crate::common::setup_mock_server;
+
+use manydns::gcloud::{GoogleCloudProvider, ServiceAccountKey};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{
+    body_json, body_string_contains, header, method, path, query_param, query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ZONE_PATH: &str = "/projects/test-project/managedZones/example-com";
+
+fn provider(server: &MockServer) -> GoogleCloudProvider {
+    let key = ServiceAccountKey::from_json(&service_account_json("https://unused.invalid/token"))
+        .unwrap()
+        .with_token_uri(&format!("{}/token", server.uri()));
+    GoogleCloudProvider::with_base_url(key, &server.uri()).expect("Failed to create provider")
+}
+
+async fn mount_token(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("test-token", 3599)))
+        .mount(server)
+        .await;
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone(
+            "example-com",
+            "example.com.",
+            "public",
+        )))
+        .mount(server)
+        .await;
+}
+
+async fn mount_rrset(server: &MockServer, name: &str, typ: &str, rrdatas: &[&str]) {
+    Mock::given(method("GET"))
+        .and(path(format!("{}/rrsets/{}/{}", ZONE_PATH, name, typ)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_rrset(name, typ, 300, rrdatas)))
+        .mount(server)
+        .await;
+}
+
+async fn mount_missing_rrset(server: &MockServer, name: &str, typ: &str) {
+    Mock::given(method("GET"))
+        .and(path(format!("{}/rrsets/{}/{}", ZONE_PATH, name, typ)))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            404,
+            "notFound",
+            "The 'parameters.name' resource named 'www.example.com.' does not exist.",
+        )))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Authentication Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_token_exchange_is_cached() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .and(header("Content-Type", "application/x-www-form-urlencoded"))
+        .and(body_string_contains(
+            "grant_type=urn%3Aietf%3Aparams%3Aoauth%3Agrant-type%3Ajwt-bearer",
+        ))
+        .and(body_string_contains("&assertion=eyJ"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("cached-token", 3599)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(header("Authorization", "Bearer cached-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "managedZones": [] })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider.list_zones().await.unwrap();
+    provider.list_zones().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_token_refreshed_before_expiry() {
+    let server = setup_mock_server().await;
+
+    // Tokens expiring within the refresh margin are never reused.
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("short-lived", 30)))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "managedZones": [] })))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    provider.list_zones().await.unwrap();
+    provider.list_zones().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_rejected_token_is_refreshed_and_retried() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_token("test-token", 3599)))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(
+            401,
+            "authError",
+            "Invalid Credentials",
+        )))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "managedZones": [mock_zone("example-com", "example.com.", "public")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 1);
+}
+
+#[tokio::test]
+async fn test_token_error_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/token"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "error": "invalid_grant",
+            "error_description": "Invalid JWT Signature."
+        })))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(query_param_is_missing("pageToken"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "managedZones": [mock_zone("example-com", "example.com.", "public")],
+            "nextPageToken": "page/2"
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(query_param("pageToken", "page/2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "managedZones": [mock_zone("internal", "example.org.", "private")]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example-com");
+    assert_eq!(zones[0].domain(), "example.com");
+    assert!(!zones[0].is_private());
+    assert_eq!(zones[0].name_servers().len(), 2);
+    assert!(zones[1].is_private());
+}
+
+#[tokio::test]
+async fn test_get_zone_by_dns_name_prefers_public() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(query_param("dnsName", "example.com."))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "managedZones": [
+                mock_zone("example-com-internal", "example.com.", "private"),
+                mock_zone("example-com", "example.com.", "public")
+            ]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+
+    assert_eq!(zone.id(), "example-com");
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/missing", ZONES_PATH)))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            404,
+            "notFound",
+            "The 'parameters.managedZone' resource named 'missing' does not exist.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing").await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_get_zone_forbidden() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(403).set_body_json(mock_error(
+            403,
+            "forbidden",
+            "Forbidden",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("example-com").await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path(ZONES_PATH))
+        .and(body_json(json!({
+            "name": "example-com",
+            "dnsName": "example.com.",
+            "description": ""
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone(
+            "example-com",
+            "example.com.",
+            "public",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+
+    assert_eq!(zone.id(), "example-com");
+    assert_eq!(zone.numeric_id(), "1234567890123456789");
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example-com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/rrsets", ZONE_PATH)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "rrsets": [
+                mock_rrset("www.example.com.", "A", 300, &["192.0.2.1", "192.0.2.2"]),
+                mock_rrset("example.com.", "MX", 3600, &["10 mail.example.com."]),
+                mock_rrset("example.com.", "TXT", 300, &["\"v=spf1 \" \"-all\""]),
+                {
+                    "name": "geo.example.com.",
+                    "type": "A",
+                    "ttl": 300,
+                    "routingPolicy": { "geo": { "items": [] } }
+                }
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].id, "www/A/192.0.2.1");
+    assert_eq!(records[1].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+    assert_eq!(records[2].host, "@");
+    assert_eq!(
+        records[2].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[2].ttl, 3600);
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_rrset(
+        &server,
+        "www.example.com.",
+        "A",
+        &["192.0.2.1", "192.0.2.2"],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+    let record = zone.get_record("www/A/192.0.2.2").await.unwrap();
+
+    assert_eq!(record.host, "www");
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+}
+
+#[tokio::test]
+async fn test_create_record_merges_rrset() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_rrset(&server, "www.example.com.", "A", &["192.0.2.1"]).await;
+
+    let deletions = json!([mock_rrset("www.example.com.", "A", 300, &["192.0.2.1"])]);
+    let additions = json!([mock_rrset(
+        "www.example.com.",
+        "A",
+        600,
+        &["192.0.2.1", "192.0.2.2"]
+    )]);
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/changes", ZONE_PATH)))
+        .and(body_json(json!({
+            "additions": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "ttl": 600,
+                "rrdatas": ["192.0.2.1", "192.0.2.2"]
+            }],
+            "deletions": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "ttl": 300,
+                "rrdatas": ["192.0.2.1"]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_change(additions, deletions)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 600)
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "www/A/192.0.2.2");
+    assert_eq!(record.ttl, 600);
+}
+
+#[tokio::test]
+async fn test_create_record_new_rrset() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_missing_rrset(&server, "alias.example.com.", "CNAME").await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/changes", ZONE_PATH)))
+        .and(body_json(json!({
+            "additions": [{
+                "name": "alias.example.com.",
+                "type": "CNAME",
+                "ttl": 300,
+                "rrdatas": ["www.example.com."]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_change(json!([]), json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+    let record = zone
+        .create_record(
+            "alias.example.com",
+            &RecordData::CNAME("www.example.com".to_string()),
+            300,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "alias/CNAME/www.example.com.");
+    assert_eq!(record.host, "alias");
+}
+
+#[tokio::test]
+async fn test_create_record_duplicate_and_unsupported() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_rrset(&server, "www.example.com.", "A", &["192.0.2.1"]).await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+
+    let duplicate = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(duplicate, Err(CreateRecordError::InvalidRecord)));
+
+    let unsupported = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "LOC".to_string(),
+                value: "52 22 23.000 N 4 53 32.000 E -2.00m 0.00m 10000m 10m".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(
+        unsupported,
+        Err(CreateRecordError::UnsupportedType)
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_remaining_values() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_rrset(
+        &server,
+        "www.example.com.",
+        "A",
+        &["192.0.2.1", "192.0.2.2"],
+    )
+    .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/changes", ZONE_PATH)))
+        .and(body_json(json!({
+            "additions": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "ttl": 300,
+                "rrdatas": ["192.0.2.2"]
+            }],
+            "deletions": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "ttl": 300,
+                "rrdatas": ["192.0.2.1", "192.0.2.2"]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_change(json!([]), json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_last_record_deletes_rrset() {
+    let server = setup_mock_server().await;
+    mount_token(&server).await;
+    mount_zone(&server).await;
+    mount_rrset(
+        &server,
+        "_acme-challenge.example.com.",
+        "TXT",
+        &["\"token\""],
+    )
+    .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/changes", ZONE_PATH)))
+        .and(body_json(json!({
+            "deletions": [{
+                "name": "_acme-challenge.example.com.",
+                "type": "TXT",
+                "ttl": 300,
+                "rrdatas": ["\"token\""]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_change(json!([]), json!([]))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example-com").await.unwrap();
+    zone.delete_record("_acme-challenge/TXT/\"token\"")
+        .await
+        .unwrap();
+
+    let missing = zone.delete_record("_acme-challenge/TXT/\"other\"").await;
+    assert!(matches!(missing, Err(DeleteRecordError::NotFound)));
+}
@@ -0,0 +1,407 @@
+//! Mock-based tests for the GoDaddy provider.
+//!
+//! These tests use `wiremock` to simulate the GoDaddy Domains API v1 without
+//! requiring a GoDaddy account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - `sso-key` authentication and OTE/production endpoint selection
+//! - Zone operations (list with marker pagination, get)
+//! - Record conversion (MX priority, SRV service/protocol labels)
+//! - Per-value record creation and deletion on top of type/name replacement
+//! - Error handling (401, 404, 422)
+//!
+//! # API Structure
+//!
+//! - Domains: GET `/v1/domains`, GET `/v1/domains/{domain}`
+//! - Records: GET `/v1/domains/{domain}/records`,
+//!   GET/PUT/DELETE `/v1/domains/{domain}/records/{type}/{name}`
+//! - Auth: `Authorization: sso-key KEY:SECRET`
+
+use crate::common::godaddy::*;
+use crate::common::setup_mock_server;
+
+use manydns::godaddy::{ClientConfig, GoDaddyProvider};
+use manydns::types::Environment;
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> GoDaddyProvider {
+    GoDaddyProvider::with_base_url(ClientConfig::ote("test-key", "test-secret"), &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v1/domains/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domain(1, "example.com")))
+        .mount(server)
+        .await;
+}
+
+async fn mount_set(server: &MockServer, typ: &str, name: &str, records: serde_json::Value) {
+    Mock::given(method("GET"))
+        .and(path(format!(
+            "/v1/domains/example.com/records/{}/{}",
+            typ, name
+        )))
+        .respond_with(ResponseTemplate::new(200).set_body_json(records))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Configuration Tests
+// =============================================================================
+
+#[test]
+fn test_environment_urls() {
+    assert_eq!(
+        ClientConfig::ote("k", "s").api_url(),
+        "https://api.ote-godaddy.com"
+    );
+    assert_eq!(
+        ClientConfig::production("k", "s").api_url(),
+        "https://api.godaddy.com"
+    );
+    assert_eq!(
+        ClientConfig::new("k", "s", Environment::Sandbox).environment,
+        Environment::Sandbox
+    );
+    assert!(!format!("{:?}", ClientConfig::ote("k", "secret-value")).contains("secret-value"));
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_sso_key_auth_header() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/domains/example.com"))
+        .and(header("Authorization", "sso-key test-key:test-secret"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domain(1, "example.com")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.status(), Some("ACTIVE"));
+    assert_eq!(zone.name_servers().len(), 2);
+}
+
+#[tokio::test]
+async fn test_list_zones_paginates_with_marker() {
+    let server = setup_mock_server().await;
+
+    let first_page: Vec<_> = (0..1000)
+        .map(|i| mock_domain(i, &format!("domain{:04}.com", i)))
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path("/v1/domains"))
+        .and(query_param("statuses", "ACTIVE"))
+        .and(query_param("marker", "domain0999.com"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(json!([mock_domain(1000, "example.com")])),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/domains"))
+        .and(query_param("statuses", "ACTIVE"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(first_page))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 1001);
+    assert_eq!(zones[1000].domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/domains/missing.com"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(mock_error("NOT_FOUND", "The given domain is not found")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/domains"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(
+            "UNABLE_TO_AUTHENTICATE",
+            "Unable to authenticate request",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_conversion() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let mut mx = mock_record("MX", "@", "mail.example.com");
+    mx["priority"] = json!(10);
+    let srv = json!({
+        "type": "SRV",
+        "name": "@",
+        "data": "sip.example.com",
+        "ttl": 3600,
+        "priority": 10,
+        "weight": 20,
+        "port": 5060,
+        "service": "_sip",
+        "protocol": "_tcp"
+    });
+
+    Mock::given(method("GET"))
+        .and(path("/v1/domains/example.com/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            mock_record("A", "www", "192.0.2.1"),
+            mx,
+            srv,
+            mock_record("TXT", "@", "v=spf1 -all"),
+        ])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+
+    assert_eq!(records[0].id, "www/A/192.0.2.1");
+    assert_eq!(records[1].id, "@/MX/10 mail.example.com");
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_set(
+        &server,
+        "A",
+        "www",
+        json!([
+            mock_record("A", "www", "192.0.2.1"),
+            mock_record("A", "www", "192.0.2.2")
+        ]),
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone.get_record("www/A/192.0.2.2").await.unwrap();
+    assert!(matches!(record.data, RecordData::A(ip) if ip == Ipv4Addr::new(192, 0, 2, 2)));
+
+    let result = zone.get_record("www/A/192.0.2.9").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_record_appends_value() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_set(
+        &server,
+        "A",
+        "www",
+        json!([mock_record("A", "www", "192.0.2.1")]),
+    )
+    .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/domains/example.com/records/A/www"))
+        .and(body_json(json!([
+            { "data": "192.0.2.1", "ttl": 600 },
+            { "data": "192.0.2.2", "ttl": 3600 }
+        ])))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "www/A/192.0.2.2");
+
+    let duplicate = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 600)
+        .await;
+    assert!(matches!(duplicate, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_srv_record_at_apex() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_set(&server, "SRV", "@", json!([])).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/domains/example.com/records/SRV/@"))
+        .and(body_json(json!([{
+            "data": "sip.example.com",
+            "ttl": 600,
+            "priority": 10,
+            "weight": 20,
+            "port": 5060,
+            "service": "_sip",
+            "protocol": "_tcp"
+        }])))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let data = RecordData::SRV {
+        priority: 10,
+        weight: 20,
+        port: 5060,
+        target: "sip.example.com".to_string(),
+    };
+    let record = zone.create_record("_sip._tcp", &data, 600).await.unwrap();
+    assert_eq!(record.id, "_sip._tcp/SRV/10 20 5060 sip.example.com");
+}
+
+#[tokio::test]
+async fn test_create_record_invalid() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_set(&server, "CNAME", "www", json!([])).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/domains/example.com/records/CNAME/www"))
+        .respond_with(ResponseTemplate::new(422).set_body_json(mock_error(
+            "INVALID_BODY",
+            "Request body doesn't fulfill schema",
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::CNAME("example.net".to_string()), 600)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let data = RecordData::Other {
+        typ: "TLSA".to_string(),
+        value: "3 1 1 abcdef".to_string(),
+    };
+    let result = zone.create_record("_443._tcp", &data, 600).await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_remaining_values() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_set(
+        &server,
+        "TXT",
+        "_acme-challenge",
+        json!([
+            mock_record("TXT", "_acme-challenge", "token-1"),
+            mock_record("TXT", "_acme-challenge", "token-2")
+        ]),
+    )
+    .await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/domains/example.com/records/TXT/_acme-challenge"))
+        .and(body_json(json!([{ "data": "token-2", "ttl": 600 }])))
+        .respond_with(ResponseTemplate::new(200))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("_acme-challenge/TXT/token-1")
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_last_value_deletes_records() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_set(
+        &server,
+        "A",
+        "www",
+        json!([mock_record("A", "www", "192.0.2.1")]),
+    )
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/domains/example.com/records/A/www"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+
+    let missing = zone.delete_record("www/A/192.0.2.9").await;
+    assert!(matches!(missing, Err(DeleteRecordError::NotFound)));
+}
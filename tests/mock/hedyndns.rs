@@ -0,0 +1,192 @@
+//! Mock-based tests for the Hurricane Electric dynamic DNS provider.
+//!
+//! These tests use `wiremock` to simulate the dyn.dns.he.net update endpoint.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Zones derived from configured keys
+//! - A, AAAA and TXT updates with the per-record key
+//! - Missing keys and unsupported record types
+//! - Response codes (`good`, `nochg`, `badauth`, `abuse`)
+//!
+//! # API Structure
+//!
+//! - Update: POST `/nic/update` with `hostname`, `password` and `myip` or `txt`
+//! - Responses: plain text status code, followed by the value on success
+
+use crate::common::setup_mock_server;
+
+use manydns::hedyndns::{HeDynDnsError, HeDynDnsProvider};
+use manydns::types::RecordType;
+use manydns::{CreateRecord, CreateRecordError, Provider, RecordData, RetrieveZoneError, Zone};
+use std::net::{Ipv4Addr, Ipv6Addr};
+use wiremock::matchers::{body_string, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> HeDynDnsProvider {
+    HeDynDnsProvider::with_base_url(&server.uri())
+        .expect("Failed to create provider")
+        .with_key("home.example.com", RecordType::A, "a-key")
+        .with_key("home.example.com.", RecordType::AAAA, "aaaa-key")
+        .with_key("_acme-challenge.example.com", RecordType::TXT, "txt-key")
+}
+
+#[tokio::test]
+async fn test_zones_from_keys() {
+    let server = setup_mock_server().await;
+    let provider = provider(&server);
+
+    let zones = provider.list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "home.example.com");
+    assert_eq!(zones[0].record_types(), [RecordType::A, RecordType::AAAA]);
+    assert_eq!(zones[1].domain(), "_acme-challenge.example.com");
+
+    let zone = provider.get_zone("HOME.example.com.").await.unwrap();
+    assert_eq!(zone.id(), "home.example.com");
+    assert!(zone.list_records().await.unwrap().is_empty());
+
+    let result = provider.get_zone("other.example.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_update_addresses() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/nic/update"))
+        .and(body_string(
+            "hostname=home.example.com&password=a-key&myip=192.0.2.1",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("good 192.0.2.1"))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/nic/update"))
+        .and(body_string(
+            "hostname=home.example.com&password=aaaa-key&myip=2001%3Adb8%3A%3A1",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("nochg 2001:db8::1"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .get_zone("home.example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record("@", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "@/A/192.0.2.1");
+    zone.create_record(
+        "home.example.com.",
+        &RecordData::AAAA("2001:db8::1".parse::<Ipv6Addr>().unwrap()),
+        300,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_update_txt() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/nic/update"))
+        .and(body_string(
+            "hostname=_acme-challenge.example.com&password=txt-key&txt=token%20value",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("good"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .get_zone("_acme-challenge.example.com")
+        .await
+        .unwrap();
+    zone.create_record("@", &RecordData::TXT("token value".to_string()), 300)
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_missing_key_and_unsupported_type() {
+    let server = setup_mock_server().await;
+    let zone = provider(&server)
+        .get_zone("home.example.com")
+        .await
+        .unwrap();
+
+    let result = zone
+        .create_record("@", &RecordData::TXT("value".to_string()), 300)
+        .await;
+    match result {
+        Err(CreateRecordError::Custom(HeDynDnsError::MissingKey {
+            hostname,
+            record_type,
+        })) => {
+            assert_eq!(hostname, "home.example.com");
+            assert_eq!(record_type, RecordType::TXT);
+        }
+        other => panic!("expected missing key, got {:?}", other.map(|r| r.id)),
+    }
+
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(
+        result,
+        Err(CreateRecordError::Custom(HeDynDnsError::MissingKey { .. }))
+    ));
+
+    let result = zone
+        .create_record("@", &RecordData::CNAME("example.com".to_string()), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_error_responses() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/nic/update"))
+        .and(body_string(
+            "hostname=home.example.com&password=a-key&myip=192.0.2.1",
+        ))
+        .respond_with(ResponseTemplate::new(401).set_body_string("badauth"))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/nic/update"))
+        .and(body_string(
+            "hostname=home.example.com&password=a-key&myip=192.0.2.2",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_string("abuse"))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .get_zone("home.example.com")
+        .await
+        .unwrap();
+
+    let result = zone
+        .create_record("@", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::Unauthorized)));
+
+    let result = zone
+        .create_record("@", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 300)
+        .await;
+    assert!(matches!(
+        result,
+        Err(CreateRecordError::Custom(HeDynDnsError::Abuse))
+    ));
+}
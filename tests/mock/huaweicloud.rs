@@ -0,0 +1,495 @@
+//! Mock-based tests for the Huawei Cloud DNS provider.
+//!
+//! These tests use `wiremock` to simulate the Huawei Cloud DNS v2 API without
+//! requiring a Huawei Cloud account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Signed request headers (`Authorization`, `X-Sdk-Date`, `X-Project-Id`)
+//! - Zone operations (list with pagination, get by ID or name, create, delete)
+//! - Record set expansion into records with synthesized IDs
+//! - Record creation and deletion by merging values into record sets
+//! - Error handling (401, 404, gateway error bodies)
+//!
+//! # API Structure
+//!
+//! - Zones: GET/POST `/v2/zones`, GET/DELETE `/v2/zones/{zone_id}`
+//! - Record sets: GET/POST `/v2/zones/{zone_id}/recordsets`,
+//!   PUT/DELETE `/v2/zones/{zone_id}/recordsets/{recordset_id}`
+//! - Auth: `Authorization: SDK-HMAC-SHA256 Access=..., SignedHeaders=..., Signature=...`
+
+use crate::common::huaweicloud::*;
+use crate::common::setup_mock_server;
+
+use manydns::huaweicloud::{Credentials, HuaweiCloudError, HuaweiCloudProvider};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    DeleteZoneError, Provider, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ZONE_ID: &str = "2c9eb155587194ec01587224c9f90149";
+
+fn provider(server: &MockServer) -> HuaweiCloudProvider {
+    let credentials = Credentials::new("test-ak", "test-sk");
+    HuaweiCloudProvider::with_base_url(credentials, &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/zones/{}", ZONE_ID)))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone(ZONE_ID, "example.com.")))
+        .mount(server)
+        .await;
+}
+
+async fn mount_record_set_lookup(
+    server: &MockServer,
+    name: &str,
+    typ: &str,
+    body: Vec<serde_json::Value>,
+) {
+    let total = body.len() as u64;
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/zones/{}/recordsets", ZONE_ID)))
+        .and(query_param("name", name))
+        .and(query_param("type", typ))
+        .and(query_param("search_mode", "equal"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_record_sets_response(body, total)),
+        )
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Signing Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_signed_headers_with_project_id() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/zones/{}", ZONE_ID)))
+        .and(header_exists("x-sdk-date"))
+        .and(header("x-project-id", "project-1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone(ZONE_ID, "example.com.")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let credentials = Credentials::new("test-ak", "test-sk").with_project_id("project-1");
+    let provider = HuaweiCloudProvider::with_base_url(credentials, &server.uri()).unwrap();
+    provider.get_zone(ZONE_ID).await.unwrap();
+
+    let requests = server.received_requests().await.unwrap();
+    let authorization = requests[0]
+        .headers
+        .get("authorization")
+        .unwrap()
+        .to_str()
+        .unwrap();
+    assert!(authorization.starts_with(
+        "SDK-HMAC-SHA256 Access=test-ak, SignedHeaders=host;x-project-id;x-sdk-date, Signature="
+    ));
+}
+
+#[tokio::test]
+async fn test_gateway_auth_error_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/zones"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "error_code": "APIGW.0301",
+            "error_msg": "Incorrect IAM authentication information: verify aksk signature fail",
+            "request_id": "req"
+        })))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_paginates() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/zones"))
+        .and(query_param("type", "public"))
+        .and(query_param("offset", "0"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_zones_response(
+                vec![mock_zone(ZONE_ID, "example.com.")],
+                2,
+            )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/zones"))
+        .and(query_param("offset", "1"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_zones_response(
+                vec![mock_zone(
+                    "ff8080825b8fc86c015b94bc6f8712c3",
+                    "example.org.",
+                )],
+                2,
+            )),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), ZONE_ID);
+    assert_eq!(zones[0].domain(), "example.com");
+    assert_eq!(zones[0].status(), Some("ACTIVE"));
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/zones"))
+        .and(query_param("name", "example.com."))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_zones_response(
+                vec![
+                    mock_zone("ff8080825b8fc86c015b94bc6f8712c3", "sub.example.com."),
+                    mock_zone(ZONE_ID, "example.com."),
+                ],
+                2,
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    assert_eq!(zone.id(), ZONE_ID);
+    assert_eq!(zone.name_servers().len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/zones/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(mock_error("DNS.0101", "The zone does not exist.")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/zones"))
+        .and(header("content-type", "application/json"))
+        .and(body_json(
+            json!({ "name": "example.com.", "zone_type": "public" }),
+        ))
+        .respond_with(ResponseTemplate::new(202).set_body_json(mock_zone(ZONE_ID, "example.com.")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+    assert_eq!(zone.id(), ZONE_ID);
+}
+
+#[tokio::test]
+async fn test_delete_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/zones/missing"))
+        .respond_with(
+            ResponseTemplate::new(404)
+                .set_body_json(mock_error("DNS.0101", "The zone does not exist.")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).delete_zone("missing").await;
+    assert!(matches!(result, Err(DeleteZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_expands_record_sets() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/zones/{}/recordsets", ZONE_ID)))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_record_sets_response(
+                vec![
+                    mock_record_set("rs-1", "www.example.com.", "A", &["192.0.2.1", "192.0.2.2"]),
+                    mock_record_set("rs-2", "example.com.", "MX", &["10 mail.example.com."]),
+                    mock_record_set("rs-3", "example.com.", "TXT", &["\"v=spf1 -all\""]),
+                ],
+                3,
+            )),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+
+    assert_eq!(records[0].id, "www/A/192.0.2.1");
+    assert_eq!(records[1].id, "www/A/192.0.2.2");
+    assert_eq!(records[2].host, "@");
+    assert_eq!(
+        records[2].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set_lookup(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![mock_record_set(
+            "rs-1",
+            "www.example.com.",
+            "A",
+            &["192.0.2.1"],
+        )],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    let record = zone.get_record("www/A/192.0.2.1").await.unwrap();
+    assert!(matches!(record.data, RecordData::A(ip) if ip == Ipv4Addr::new(192, 0, 2, 1)));
+
+    let result = zone.get_record("www/A/192.0.2.9").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_record_creates_record_set() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set_lookup(&server, "_acme-challenge.example.com.", "TXT", vec![]).await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("/v2/zones/{}/recordsets", ZONE_ID)))
+        .and(body_json(json!({
+            "name": "_acme-challenge.example.com.",
+            "type": "TXT",
+            "ttl": 60,
+            "records": ["\"token\""]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(mock_record_set(
+            "rs-9",
+            "_acme-challenge.example.com.",
+            "TXT",
+            &["\"token\""],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    let record = zone
+        .create_record("_acme-challenge", &RecordData::TXT("token".to_string()), 60)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "_acme-challenge/TXT/\"token\"");
+}
+
+#[tokio::test]
+async fn test_create_record_merges_into_record_set() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set_lookup(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![mock_record_set(
+            "rs-1",
+            "www.example.com.",
+            "A",
+            &["192.0.2.1"],
+        )],
+    )
+    .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("/v2/zones/{}/recordsets/rs-1", ZONE_ID)))
+        .and(body_json(json!({
+            "name": "www.example.com.",
+            "type": "A",
+            "ttl": 600,
+            "records": ["192.0.2.1", "192.0.2.2"]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(mock_record_set(
+            "rs-1",
+            "www.example.com.",
+            "A",
+            &["192.0.2.1", "192.0.2.2"],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 600)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "www/A/192.0.2.2");
+
+    let duplicate = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 600)
+        .await;
+    assert!(matches!(duplicate, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_record_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    let data = RecordData::Other {
+        typ: "TLSA".to_string(),
+        value: "3 1 1 abcdef".to_string(),
+    };
+    let result = zone.create_record("_443._tcp", &data, 300).await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_last_value_deletes_record_set() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set_lookup(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![mock_record_set(
+            "rs-1",
+            "www.example.com.",
+            "A",
+            &["192.0.2.1"],
+        )],
+    )
+    .await;
+
+    Mock::given(method("DELETE"))
+        .and(path(format!("/v2/zones/{}/recordsets/rs-1", ZONE_ID)))
+        .respond_with(ResponseTemplate::new(202).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_remaining_values() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set_lookup(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![mock_record_set(
+            "rs-1",
+            "www.example.com.",
+            "A",
+            &["192.0.2.1", "192.0.2.2"],
+        )],
+    )
+    .await;
+
+    Mock::given(method("PUT"))
+        .and(path(format!("/v2/zones/{}/recordsets/rs-1", ZONE_ID)))
+        .and(body_json(json!({
+            "name": "www.example.com.",
+            "type": "A",
+            "ttl": 300,
+            "records": ["192.0.2.2"]
+        })))
+        .respond_with(ResponseTemplate::new(202).set_body_json(mock_record_set(
+            "rs-1",
+            "www.example.com.",
+            "A",
+            &["192.0.2.2"],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+
+    let missing = zone.delete_record("www/A/192.0.2.9").await;
+    assert!(matches!(missing, Err(DeleteRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_api_error_code_is_exposed() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("/v2/zones/{}/recordsets", ZONE_ID)))
+        .respond_with(
+            ResponseTemplate::new(500).set_body_json(mock_error("DNS.0001", "Internal error.")),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone(ZONE_ID).await.unwrap();
+    match zone.list_records().await {
+        Err(RetrieveRecordError::Custom(err @ HuaweiCloudError::Api { .. })) => {
+            assert_eq!(err.status(), Some(500));
+            assert_eq!(err.code(), Some("DNS.0001"));
+        }
+        other => panic!("unexpected result: {:?}", other.map(|r| r.len())),
+    }
+}
@@ -0,0 +1,377 @@
+//! Mock-based tests for the INWX provider.
+//!
+//! These tests use `wiremock` to simulate the INWX DomRobot XML-RPC API
+//! without requiring an INWX account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Session login with cookie reuse and TOTP unlock
+//! - Re-login and retry after an expired session (result code 2002)
+//! - Domain operations (paginated list, info)
+//! - Record conversion (apex host, MX and SRV priority)
+//! - Record creation and deletion
+//! - Error handling (result codes 2200, 2005, 2303)
+//!
+//! # API Structure
+//!
+//! - Single XML-RPC endpoint, dispatched by `<methodName>`
+//! - Session: `account.login`, then `account.unlock` with a TOTP code for 2FA accounts
+//! - Records: `nameserver.info`, `nameserver.createRecord`, `nameserver.deleteRecord`
+//! - Auth: `domrobot` session cookie
+
+use crate::common::inwx::*;
+use crate::common::setup_mock_server;
+
+use manydns::inwx::{ClientConfig, InwxError, InwxProvider};
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveZoneError, Zone,
+};
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_string_contains, header, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> InwxProvider {
+    InwxProvider::with_base_url(ClientConfig::ote("user", "secret"), &server.uri())
+        .expect("Failed to create provider")
+}
+
+fn xml(body: String) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Content-Type", "text/xml")
+        .set_body_string(body)
+}
+
+fn call(name: &str) -> wiremock::matchers::BodyContainsMatcher {
+    body_string_contains(format!("<methodName>{}</methodName>", name))
+}
+
+async fn mount_login(server: &MockServer, tfa: &str) {
+    Mock::given(method("POST"))
+        .and(call("account.login"))
+        .and(body_string_contains("<string>user</string>"))
+        .respond_with(
+            xml(mock_response(
+                1000,
+                &member("tfa", &format!("<string>{}</string>", tfa)),
+            ))
+            .insert_header("Set-Cookie", "domrobot=session123; path=/; HttpOnly"),
+        )
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+async fn mount_info(server: &MockServer, records: &[String]) {
+    Mock::given(method("POST"))
+        .and(call("nameserver.info"))
+        .and(body_string_contains("<string>example.com</string>"))
+        .and(header("Cookie", "domrobot=session123"))
+        .respond_with(xml(mock_response(1000, &mock_info("example.com", records))))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Session Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_login_once_and_list_zones() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "0").await;
+
+    let domain = |name: &str| {
+        format!(
+            "<value><struct>{}{}</struct></value>",
+            member("domain", &format!("<string>{}</string>", name)),
+            member("type", "<string>MASTER</string>")
+        )
+    };
+    Mock::given(method("POST"))
+        .and(call("nameserver.list"))
+        .and(body_string_contains("<int>1</int>"))
+        .and(header("Cookie", "domrobot=session123"))
+        .respond_with(xml(mock_response(
+            1000,
+            &format!(
+                "{}{}",
+                member("count", "<int>2</int>"),
+                member(
+                    "domains",
+                    &format!(
+                        "<array><data>{}{}</data></array>",
+                        domain("example.com"),
+                        domain("example.org")
+                    )
+                )
+            ),
+        )))
+        .expect(2)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let zones = provider.list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[1].domain(), "example.org");
+
+    // The session is reused.
+    provider.list_zones().await.unwrap();
+}
+
+#[tokio::test]
+async fn test_relogin_after_session_expired() {
+    let server = setup_mock_server().await;
+
+    // The first login returns a session that has expired by the next call.
+    Mock::given(method("POST"))
+        .and(call("account.login"))
+        .respond_with(
+            xml(mock_response(1000, &member("tfa", "<string>0</string>")))
+                .insert_header("Set-Cookie", "domrobot=expired; path=/; HttpOnly"),
+        )
+        .up_to_n_times(1)
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_login(&server, "0").await;
+
+    Mock::given(method("POST"))
+        .and(call("nameserver.info"))
+        .and(header("Cookie", "domrobot=expired"))
+        .respond_with(xml(mock_response(2002, "")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    mount_info(&server, &[]).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    assert_eq!(zone.ro_id(), Some(123456));
+}
+
+#[tokio::test]
+async fn test_login_with_totp() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "GOOGLE-AUTH").await;
+    mount_info(&server, &[]).await;
+
+    Mock::given(method("POST"))
+        .and(call("account.unlock"))
+        .and(body_string_contains("<name>tan</name>"))
+        .and(header("Cookie", "domrobot=session123"))
+        .respond_with(xml(mock_response(1000, "")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let config = ClientConfig::ote("user", "secret").with_totp_secret("JBSWY3DPEHPK3PXP");
+    let provider = InwxProvider::with_base_url(config, &server.uri()).unwrap();
+    let zone = provider.get_zone("example.com").await.unwrap();
+    assert_eq!(zone.ro_id(), Some(123456));
+}
+
+#[tokio::test]
+async fn test_login_totp_required() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "GOOGLE-AUTH").await;
+
+    let result = provider(&server).get_zone("example.com").await;
+    assert!(matches!(
+        result,
+        Err(RetrieveZoneError::Custom(InwxError::TotpRequired))
+    ));
+}
+
+#[tokio::test]
+async fn test_invalid_credentials_are_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(call("account.login"))
+        .respond_with(xml(mock_response(2200, "")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Zone and Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "0").await;
+
+    Mock::given(method("POST"))
+        .and(call("nameserver.info"))
+        .respond_with(xml(mock_response(2303, "")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "0").await;
+    mount_info(
+        &server,
+        &[
+            mock_record(1, "www.example.com", "A", "192.0.2.1", 0),
+            mock_record(2, "example.com", "MX", "mail.example.com", 10),
+            mock_record(
+                3,
+                "_sip._tcp.example.com",
+                "SRV",
+                "20 5060 sip.example.com",
+                10,
+            ),
+            mock_record(4, "example.com", "TXT", "v=spf1 -all", 0),
+        ],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].ttl, 3600);
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+
+    let record = zone.get_record("2").await.unwrap();
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "0").await;
+    mount_info(&server, &[]).await;
+
+    Mock::given(method("POST"))
+        .and(call("nameserver.createRecord"))
+        .and(body_string_contains(
+            "<member><name>name</name><value><string></string></value></member>",
+        ))
+        .and(body_string_contains(
+            "<member><name>content</name><value><string>mail.example.com</string></value></member>",
+        ))
+        .and(body_string_contains(
+            "<member><name>prio</name><value><int>10</int></value></member>",
+        ))
+        .and(body_string_contains(
+            "<member><name>type</name><value><string>MX</string></value></member>",
+        ))
+        .respond_with(xml(mock_response(1000, &member("id", "<int>99</int>"))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com.".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "99");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "0").await;
+    mount_info(&server, &[]).await;
+
+    Mock::given(method("POST"))
+        .and(call("nameserver.createRecord"))
+        .respond_with(xml(mock_response(2005, "")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 60)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "HINFO".to_string(),
+                value: "x86 linux".to_string(),
+            },
+            3600,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_login(&server, "0").await;
+    mount_info(&server, &[]).await;
+
+    Mock::given(method("POST"))
+        .and(call("nameserver.deleteRecord"))
+        .and(body_string_contains("<int>1</int>"))
+        .respond_with(xml(mock_response(1000, "")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("nameserver.deleteRecord"))
+        .and(body_string_contains("<int>2</int>"))
+        .respond_with(xml(mock_response(2303, "")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("1").await.unwrap();
+
+    let result = zone.delete_record("2").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+
+    let result = zone.delete_record("invalid").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
@@ -0,0 +1,441 @@
+//! Mock-based tests for the Linode provider.
+//!
+//! These tests use `wiremock` to simulate the Linode API v4 without
+//! requiring a Linode account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Bearer token authentication header
+//! - Domain operations (list with page pagination, get by ID or name, create, delete)
+//! - Record conversion (apex name, MX priority, SRV service/protocol split, CAA tag)
+//! - Record creation, update and deletion
+//! - Error handling (400, 401, 404)
+//!
+//! # API Structure
+//!
+//! - Domains: GET/POST `/v4/domains`, GET/DELETE `/v4/domains/{id}`
+//! - Records: GET/POST `/v4/domains/{id}/records`, GET/PUT/DELETE `/v4/domains/{id}/records/{record_id}`
+//! - Pagination: `?page=N&page_size=500`, filters via the `X-Filter` header
+//! - Auth: `Authorization: Bearer {token}` header
+
+use crate::common::linode::*;
+use crate::common::setup_mock_server;
+
+use manydns::linode::LinodeProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, Provider, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> LinodeProvider {
+    LinodeProvider::with_base_url("test-token", &server.uri()).expect("Failed to create provider")
+}
+
+async fn mount_domain(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domain(1234, "example.com")))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .and(query_param("page", "1"))
+        .and(query_param("page_size", "500"))
+        .and(header("Authorization", "Bearer test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_domain(1, "example.com")],
+            1,
+            2,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_domain(2, "example.org")],
+            2,
+            2,
+        )))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    let ids: Vec<&str> = zones.iter().map(|z| z.id()).collect();
+    let domains: Vec<&str> = zones.iter().map(|z| z.domain()).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(domains, vec!["example.com", "example.org"]);
+}
+
+#[tokio::test]
+async fn test_get_zone_by_id() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    assert_eq!(zone.id(), "1234");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.zone_type(), "master");
+    assert_eq!(zone.soa_email(), Some("hostmaster@example.com"));
+}
+
+#[tokio::test]
+async fn test_get_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .and(header("X-Filter", r#"{"domain":"example.com"}"#))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_domain(1234, "example.com")],
+            1,
+            1,
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "1234");
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/999"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(None, "Not found")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(vec![], 1, 0)))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("999").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_token_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(None, "Invalid Token")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v4/domains"))
+        .and(body_json(json!({
+            "domain": "example.com",
+            "type": "master",
+            "soa_email": "dns@example.net"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domain(1234, "example.com")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .with_soa_email("dns@example.net")
+        .create_zone("example.com")
+        .await
+        .unwrap();
+    assert_eq!(zone.id(), "1234");
+}
+
+#[tokio::test]
+async fn test_create_zone_invalid() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v4/domains"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(mock_error(Some("domain"), "Domain is invalid.")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).create_zone("bad_domain").await;
+    assert!(matches!(result, Err(CreateZoneError::InvalidDomainName)));
+}
+
+#[tokio::test]
+async fn test_delete_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![mock_domain(1234, "example.com")],
+            1,
+            1,
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v4/domains/1234"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let mut mx = mock_record(2, "MX", "", "mail.example.com", 0);
+    mx["priority"] = json!(10);
+    let mut srv = mock_record(3, "SRV", "", "sip.example.com", 300);
+    srv["priority"] = json!(10);
+    srv["weight"] = json!(20);
+    srv["port"] = json!(5060);
+    srv["service"] = json!("sip");
+    srv["protocol"] = json!("tcp");
+    let mut caa = mock_record(4, "CAA", "", "letsencrypt.org", 300);
+    caa["tag"] = json!("issue");
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/1234/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_page(
+            vec![
+                mock_record(1, "A", "www", "192.0.2.1", 300),
+                mx,
+                srv,
+                caa,
+                mock_record(5, "TXT", "", "v=spf1 -all", 300),
+            ],
+            1,
+            1,
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(records[1].ttl, 3600);
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].data.get_value(), "0 issue \"letsencrypt.org\"");
+    assert_eq!(records[4].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_get_record_invalid_id() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let result = zone.get_record("not-a-number").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_srv_record_splits_service_and_protocol() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let mut created = mock_record(10, "SRV", "eu", "sip.example.com", 300);
+    created["priority"] = json!(10);
+    created["weight"] = json!(20);
+    created["port"] = json!(5060);
+    created["service"] = json!("sip");
+    created["protocol"] = json!("udp");
+
+    Mock::given(method("POST"))
+        .and(path("/v4/domains/1234/records"))
+        .and(body_json(json!({
+            "type": "SRV",
+            "name": "eu",
+            "target": "sip.example.com",
+            "priority": 10,
+            "weight": 20,
+            "port": 5060,
+            "service": "sip",
+            "protocol": "udp",
+            "ttl_sec": 300
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(created))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let record = zone
+        .create_record(
+            "_sip._udp.eu",
+            &RecordData::SRV {
+                priority: 10,
+                weight: 20,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            },
+            300,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "10");
+    assert_eq!(record.host, "_sip._udp.eu");
+}
+
+#[tokio::test]
+async fn test_create_srv_record_without_service_is_invalid() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let result = zone
+        .create_record(
+            "sip",
+            &RecordData::SRV {
+                priority: 10,
+                weight: 20,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_unsupported_record_type() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let result = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "SSHFP".to_string(),
+                value: "1 1 abcdef".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v4/domains/1234/records/1"))
+        .and(body_json(json!({
+            "type": "A",
+            "name": "www",
+            "target": "192.0.2.2",
+            "ttl_sec": 600
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            1,
+            "A",
+            "www",
+            "192.0.2.2",
+            600,
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    let record = zone
+        .update_record(
+            "1",
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.ttl, 600);
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v4/domains/1234/records/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v4/domains/1234/records/2"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(None, "Not found")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("1234").await.unwrap();
+    zone.delete_record("1").await.unwrap();
+    let result = zone.delete_record("2").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
@@ -0,0 +1,327 @@
+//! Mock-based tests for the Loopia provider.
+//!
+//! These tests use `wiremock` to simulate the Loopia XML-RPC API without
+//! requiring a Loopia account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Credentials and customer number parameters
+//! - Domain operations (list, get)
+//! - Record conversion across subdomains (apex, MX and SRV priority)
+//! - Record creation (adding the subdomain and looking up the new ID)
+//! - Record deletion (removing emptied subdomains)
+//! - Error handling (`AUTH_ERROR`, `BAD_INDATA`)
+//!
+//! # API Structure
+//!
+//! - Single XML-RPC endpoint, dispatched by `<methodName>`
+//! - Every call starts with the username, password and optional customer number
+//! - Write calls return the status string `OK`
+
+use crate::common::loopia::*;
+use crate::common::setup_mock_server;
+
+use manydns::loopia::LoopiaProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveZoneError, Zone,
+};
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_string_contains, method};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> LoopiaProvider {
+    LoopiaProvider::with_base_url("user@loopiaapi", "secret", &server.uri())
+        .expect("Failed to create provider")
+}
+
+fn xml(value: String) -> ResponseTemplate {
+    ResponseTemplate::new(200)
+        .insert_header("Content-Type", "text/xml")
+        .set_body_string(mock_response(&value))
+}
+
+fn call(name: &str) -> wiremock::matchers::BodyContainsMatcher {
+    body_string_contains(format!("<methodName>{}</methodName>", name))
+}
+
+fn subdomain(name: &str) -> wiremock::matchers::BodyContainsMatcher {
+    body_string_contains(format!(
+        "<value><string>example.com</string></value></param><param><value><string>{}</string></value>",
+        name
+    ))
+}
+
+async fn mount_domains(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(call("getDomains"))
+        .and(body_string_contains(
+            "<param><value><string>user@loopiaapi</string></value></param>\
+             <param><value><string>secret</string></value></param></params>",
+        ))
+        .respond_with(xml(mock_array(&[
+            mock_domain("example.com"),
+            mock_domain("example.org"),
+        ])))
+        .mount(server)
+        .await;
+}
+
+async fn mount_subdomains(server: &MockServer, names: &[&str]) {
+    Mock::given(method("POST"))
+        .and(call("getSubdomains"))
+        .respond_with(xml(mock_array(
+            &names.iter().map(|n| mock_string(n)).collect::<Vec<_>>(),
+        )))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[1].domain(), "example.org");
+    assert_eq!(zones[0].expiration_date(), Some("2027-01-01"));
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    let zone = provider(&server).get_zone("example.org.").await.unwrap();
+    assert_eq!(zone.domain(), "example.org");
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_customer_number() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(call("getDomains"))
+        .and(body_string_contains(
+            "<param><value><string>secret</string></value></param>\
+             <param><value><string>C123456</string></value></param></params>",
+        ))
+        .respond_with(xml(mock_array(&[mock_domain("example.com")])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server)
+        .with_customer_number("C123456")
+        .list_zones()
+        .await
+        .unwrap();
+    assert_eq!(zones.len(), 1);
+}
+
+#[tokio::test]
+async fn test_auth_error_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(call("getDomains"))
+        .respond_with(xml(mock_array(&[mock_string("AUTH_ERROR")])))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+    mount_subdomains(&server, &["@", "www", "_sip._tcp"]).await;
+
+    Mock::given(method("POST"))
+        .and(call("getZoneRecords"))
+        .and(subdomain("@"))
+        .respond_with(xml(mock_array(&[
+            mock_record(1, "MX", "mail.example.com.", 10),
+            mock_record(2, "TXT", "v=spf1 -all", 0),
+        ])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("getZoneRecords"))
+        .and(subdomain("www"))
+        .respond_with(xml(mock_array(&[mock_record(3, "A", "192.0.2.1", 0)])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("getZoneRecords"))
+        .and(subdomain("_sip._tcp"))
+        .respond_with(xml(mock_array(&[mock_record(
+            4,
+            "SRV",
+            "20 5060 sip.example.com.",
+            10,
+        )])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+
+    assert_eq!(records[0].id, "@/1");
+    assert_eq!(records[0].host, "@");
+    assert_eq!(
+        records[0].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[1].data, RecordData::TXT("v=spf1 -all".to_string()));
+
+    assert_eq!(records[2].id, "www/3");
+    assert_eq!(records[2].ttl, 3600);
+    assert_eq!(records[2].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(
+        records[3].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    let record = zone.get_record("www/3").await.unwrap();
+    assert_eq!(record.host, "www");
+}
+
+#[tokio::test]
+async fn test_create_record_on_new_subdomain() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+    mount_subdomains(&server, &["@"]).await;
+
+    Mock::given(method("POST"))
+        .and(call("addSubdomain"))
+        .and(subdomain("www"))
+        .respond_with(xml(mock_string("OK")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("addZoneRecord"))
+        .and(subdomain("www"))
+        .and(body_string_contains(
+            "<member><name>rdata</name><value><string>192.0.2.1</string></value></member>",
+        ))
+        .and(body_string_contains(
+            "<member><name>type</name><value><string>A</string></value></member>",
+        ))
+        .respond_with(xml(mock_string("OK")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("getZoneRecords"))
+        .and(subdomain("www"))
+        .respond_with(xml(mock_array(&[
+            mock_record(7, "A", "192.0.2.1", 0),
+            mock_record(8, "A", "192.0.2.1", 0),
+            mock_record(9, "A", "192.0.2.2", 0),
+        ])))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 3600)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "www/8");
+    assert_eq!(record.host, "www");
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+    mount_subdomains(&server, &["@"]).await;
+
+    Mock::given(method("POST"))
+        .and(call("addZoneRecord"))
+        .respond_with(xml(mock_string("BAD_INDATA")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("@", &RecordData::TXT("v=spf1 -all".to_string()), 3600)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "HINFO".to_string(),
+                value: "x86 linux".to_string(),
+            },
+            3600,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_last_record_removes_subdomain() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(call("getZoneRecords"))
+        .and(subdomain("www"))
+        .respond_with(xml(mock_array(&[mock_record(3, "A", "192.0.2.1", 0)])))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("removeZoneRecord"))
+        .and(body_string_contains("<value><int>3</int></value>"))
+        .respond_with(xml(mock_string("OK")))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(call("removeSubdomain"))
+        .and(subdomain("www"))
+        .respond_with(xml(mock_string("OK")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("www/3").await.unwrap();
+
+    let result = zone.delete_record("www/4").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+
+    let result = zone.delete_record("invalid").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
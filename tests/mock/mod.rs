@@ -11,3 +11,78 @@ pub mod hetzner;
 
 #[cfg(feature = "namecheap")]
 pub mod namecheap;
+
+#[cfg(feature = "axfr")]
+pub mod axfr;
+
+#[cfg(feature = "powerdns")]
+pub mod powerdns;
+
+#[cfg(feature = "route53")]
+pub mod route53;
+
+#[cfg(feature = "digitalocean")]
+pub mod digitalocean;
+
+#[cfg(feature = "gandi")]
+pub mod gandi;
+
+#[cfg(feature = "porkbun")]
+pub mod porkbun;
+
+#[cfg(feature = "gcloud-dns")]
+pub mod gcloud;
+
+#[cfg(feature = "azure-dns")]
+pub mod azure;
+
+#[cfg(feature = "desec")]
+pub mod desec;
+
+#[cfg(feature = "linode")]
+pub mod linode;
+
+#[cfg(feature = "vultr")]
+pub mod vultr;
+
+#[cfg(feature = "ovh")]
+pub mod ovh;
+
+#[cfg(feature = "alidns")]
+pub mod alidns;
+
+#[cfg(feature = "huaweicloud")]
+pub mod huaweicloud;
+
+#[cfg(feature = "godaddy")]
+pub mod godaddy;
+#[cfg(feature = "bunny")]
+pub mod bunny;
+#[cfg(feature = "namecom")]
+pub mod namecom;
+#[cfg(feature = "dnsimple")]
+pub mod dnsimple;
+#[cfg(feature = "ns1")]
+pub mod ns1;
+#[cfg(feature = "acmedns")]
+pub mod acmedns;
+#[cfg(feature = "inwx")]
+pub mod inwx;
+#[cfg(feature = "loopia")]
+pub mod loopia;
+#[cfg(feature = "cloudns")]
+pub mod cloudns;
+#[cfg(feature = "he-dyndns")]
+pub mod hedyndns;
+#[cfg(feature = "scaleway")]
+pub mod scaleway;
+#[cfg(feature = "exoscale")]
+pub mod exoscale;
+#[cfg(feature = "volcengine")]
+pub mod volcengine;
+#[cfg(feature = "baiducloud")]
+pub mod baiducloud;
+#[cfg(feature = "akamai")]
+pub mod akamai;
+#[cfg(feature = "technitium-dns")]
+pub mod technitium;
@@ -0,0 +1,372 @@
+//! Mock-based tests for the Name.com provider.
+//!
+//! These tests use `wiremock` to simulate the Name.com API v4 without
+//! requiring a Name.com account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Basic authentication header
+//! - Domain operations (list with page pagination, get)
+//! - Record conversion (apex host, MX priority, SRV answer)
+//! - Record creation, update and deletion
+//! - Error handling (400, 401, 404)
+//!
+//! # API Structure
+//!
+//! - Domains: GET `/v4/domains`, GET `/v4/domains/{domain}`
+//! - Records: GET/POST `/v4/domains/{domain}/records`, GET/PUT/DELETE `/v4/domains/{domain}/records/{id}`
+//! - Pagination: `?page=N&perPage=1000`, continued while `nextPage` is set
+//! - Auth: `Authorization: Basic base64(username:token)` header
+
+use crate::common::namecom::*;
+use crate::common::setup_mock_server;
+
+use manydns::namecom::NameComProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> NameComProvider {
+    NameComProvider::with_base_url("user", "token", &server.uri())
+        .expect("Failed to create provider")
+}
+
+async fn mount_domain(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_domain("example.com")))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .and(query_param("page", "1"))
+        .and(query_param("perPage", "1000"))
+        // base64("user:token")
+        .and(header("Authorization", "Basic dXNlcjp0b2tlbg=="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.com")],
+            "nextPage": 2,
+            "lastPage": 2
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.org")],
+            "lastPage": 2
+        })))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.name_servers(), ["ns1.name.com", "ns2.name.com"]);
+    assert_eq!(zone.expire_date(), Some("2027-01-01T00:00:00Z"));
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Not Found")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_credentials_are_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error("Unauthenticated")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let mut mx = mock_record(2, "MX", "", "mail.example.com");
+    mx["priority"] = json!(10);
+    let mut srv = mock_record(3, "SRV", "_sip._tcp", "20 5060 sip.example.com.");
+    srv["priority"] = json!(10);
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/example.com/records"))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [
+                mock_record(1, "A", "www", "192.0.2.1"),
+                mx,
+                srv,
+                mock_record(4, "TXT", "", "v=spf1 -all"),
+                mock_record(5, "ANAME", "", "lb.example.net")
+            ]
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].ttl, 300);
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(
+        records[4].data,
+        RecordData::Other {
+            typ: "ANAME".to_string(),
+            value: "lb.example.net".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v4/domains/example.com/records/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            1,
+            "CNAME",
+            "blog",
+            "example.org.",
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone.get_record("1").await.unwrap();
+    assert_eq!(record.data, RecordData::CNAME("example.org".to_string()));
+
+    let result = zone.get_record("not-a-number").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_srv_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let mut created = mock_record(10, "SRV", "_sip._tcp", "20 5060 sip.example.com");
+    created["priority"] = json!(10);
+
+    Mock::given(method("POST"))
+        .and(path("/v4/domains/example.com/records"))
+        .and(body_json(json!({
+            "host": "_sip._tcp",
+            "type": "SRV",
+            "answer": "20 5060 sip.example.com",
+            "ttl": 300,
+            "priority": 10
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(created))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "_sip._tcp.example.com",
+            &RecordData::SRV {
+                priority: 10,
+                weight: 20,
+                port: 5060,
+                target: "sip.example.com.".to_string(),
+            },
+            300,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "10");
+    assert_eq!(record.host, "_sip._tcp");
+}
+
+#[tokio::test]
+async fn test_create_apex_txt_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v4/domains/example.com/records"))
+        .and(body_json(json!({
+            "host": "",
+            "type": "TXT",
+            "answer": "v=spf1 -all",
+            "ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            11,
+            "TXT",
+            "",
+            "v=spf1 -all",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record("@", &RecordData::TXT("v=spf1 -all".to_string()), 300)
+        .await
+        .unwrap();
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v4/domains/example.com/records"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "message": "Invalid Argument",
+            "details": "TTL must be at least 300"
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 60)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "SSHFP".to_string(),
+                value: "1 1 abc".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v4/domains/example.com/records/1"))
+        .and(body_json(json!({
+            "host": "www",
+            "type": "A",
+            "answer": "192.0.2.2",
+            "ttl": 600
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json({
+            let mut record = mock_record(1, "A", "www", "192.0.2.2");
+            record["ttl"] = json!(600);
+            record
+        }))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .update_record("1", "www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 600)
+        .await
+        .unwrap();
+    assert_eq!(record.ttl, 600);
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v4/domains/example.com/records/10"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v4/domains/example.com/records/11"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Not Found")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("10").await.unwrap();
+
+    let result = zone.delete_record("11").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
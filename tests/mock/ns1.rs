@@ -0,0 +1,464 @@
+//! Mock-based tests for the NS1 provider.
+//!
+//! These tests use `wiremock` to simulate the NS1 REST API v1 without
+//! requiring an NS1 account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - API key header
+//! - Zone operations (list, get, create, delete)
+//! - Record conversion (short answers, apex host, MX, SRV, CAA)
+//! - Answer creation and deletion, preserving answer metadata and filters
+//! - Error handling (400, 401, 404)
+//!
+//! # API Structure
+//!
+//! - Zones: GET `/v1/zones`, GET/PUT/DELETE `/v1/zones/{zone}`
+//! - Records: GET/PUT/POST/DELETE `/v1/zones/{zone}/{domain}/{type}`
+//! - Auth: `X-NSONE-Key` header
+
+use crate::common::ns1::*;
+use crate::common::setup_mock_server;
+
+use manydns::ns1::Ns1Provider;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, Provider, RecordData, RetrieveRecordError, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> Ns1Provider {
+    Ns1Provider::with_base_url("test-key", &server.uri()).expect("Failed to create provider")
+}
+
+async fn mount_zone(server: &MockServer, records: Vec<serde_json::Value>) {
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com", records)))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones"))
+        .and(header("X-NSONE-Key", "test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            mock_zone("example.com", vec![]),
+            mock_zone("example.org", vec![])
+        ])))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.default_ttl(), 3600);
+    assert_eq!(
+        zone.name_servers(),
+        ["dns1.p01.nsone.net", "dns2.p01.nsone.net"]
+    );
+}
+
+#[tokio::test]
+async fn test_get_zone_errors() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("zone not found")))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/zones"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error("Unauthorized")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("PUT"))
+        .and(path("/v1/zones/example.com"))
+        .and(body_json(json!({ "zone": "example.com" })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com", vec![])))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/zones/invalid"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error("invalid zone")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com.").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+
+    let result = provider(&server).create_zone("invalid").await;
+    assert!(matches!(result, Err(CreateZoneError::InvalidDomainName)));
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v1/zones/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![
+            mock_zone_record("www.example.com", "A", &["192.0.2.1", "192.0.2.2"]),
+            mock_zone_record("example.com", "MX", &["10 mail.example.com"]),
+            mock_zone_record(
+                "_sip._tcp.example.com",
+                "SRV",
+                &["10 20 5060 sip.example.com"],
+            ),
+            mock_zone_record("example.com", "TXT", &["v=spf1 -all"]),
+            mock_zone_record("example.com", "CAA", &["0 issue letsencrypt.org"]),
+        ],
+    )
+    .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 6);
+
+    assert_eq!(records[0].id, "www/A/192.0.2.1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].ttl, 300);
+    assert_eq!(records[1].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+
+    assert_eq!(records[2].host, "@");
+    assert_eq!(
+        records[2].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].host, "_sip._tcp");
+    assert_eq!(
+        records[3].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[4].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(
+        records[5].data,
+        RecordData::Other {
+            typ: "CAA".to_string(),
+            value: "0 issue \"letsencrypt.org\"".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            "example.com",
+            "www.example.com",
+            "A",
+            vec![json!(["192.0.2.1"]), json!(["192.0.2.2"])],
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone.get_record("www/A/192.0.2.2").await.unwrap();
+    assert_eq!(record.host, "www");
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+
+    let result = zone.get_record("www/A/192.0.2.3").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+
+    let result = zone.get_record("invalid").await;
+    assert!(matches!(result, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_get_record_set_keeps_metadata() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let mut record = mock_record("example.com", "www.example.com", "A", vec![]);
+    record["answers"] = json!([
+        { "answer": ["192.0.2.1"], "meta": { "up": true, "weight": 10 }, "region": "us" }
+    ]);
+    record["filters"] = json!([{ "filter": "up", "config": {} }]);
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(record))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let set = zone.get_record_set("www", "A").await.unwrap();
+    assert_eq!(set.answers[0].rdata(), "192.0.2.1");
+    assert_eq!(set.answers[0].extra["meta"]["weight"], json!(10));
+    assert_eq!(set.answers[0].extra["region"], json!("us"));
+    assert_eq!(set.filters, vec![json!({ "filter": "up", "config": {} })]);
+    assert_eq!(set.extra["use_client_subnet"], json!(true));
+}
+
+#[tokio::test]
+async fn test_create_record_new() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/example.com/MX"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("record not found")))
+        .mount(&server)
+        .await;
+    Mock::given(method("PUT"))
+        .and(path("/v1/zones/example.com/example.com/MX"))
+        .and(body_json(json!({
+            "zone": "example.com",
+            "domain": "example.com",
+            "type": "MX",
+            "ttl": 600,
+            "answers": [{ "answer": [10, "mail.example.com"] }],
+            "filters": []
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            "example.com",
+            "example.com",
+            "MX",
+            vec![json!([10, "mail.example.com"])],
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "@",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com.".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "@/MX/10 mail.example.com");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_record_preserves_metadata_and_filters() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let mut existing = mock_record("example.com", "www.example.com", "A", vec![]);
+    existing["answers"] = json!([
+        { "answer": ["192.0.2.1"], "meta": { "up": true, "weight": 10 } }
+    ]);
+    existing["filters"] = json!([{ "filter": "up", "config": {} }]);
+    existing["meta"] = json!({ "note": "steered" });
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(existing.clone()))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .and(body_json(json!({
+            "zone": "example.com",
+            "domain": "www.example.com",
+            "type": "A",
+            "ttl": 300,
+            "use_client_subnet": true,
+            "answers": [
+                { "answer": ["192.0.2.1"], "meta": { "up": true, "weight": 10 } },
+                { "answer": ["192.0.2.2"] }
+            ],
+            "filters": [{ "filter": "up", "config": {} }],
+            "meta": { "note": "steered" },
+            "regions": {},
+            "tier": 1,
+            "link": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(existing))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 300)
+        .await
+        .unwrap();
+    assert_eq!(record.id, "www/A/192.0.2.2");
+}
+
+#[tokio::test]
+async fn test_create_record_errors() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            "example.com",
+            "www.example.com",
+            "A",
+            vec![json!(["192.0.2.1"])],
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "HINFO".to_string(),
+                value: "x86 linux".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record_removes_answer() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let mut existing = mock_record("example.com", "www.example.com", "A", vec![]);
+    existing["answers"] = json!([
+        { "answer": ["192.0.2.1"], "meta": { "weight": 10 } },
+        { "answer": ["192.0.2.2"], "meta": { "weight": 20 } }
+    ]);
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(existing.clone()))
+        .mount(&server)
+        .await;
+    Mock::given(method("POST"))
+        .and(path("/v1/zones/example.com/www.example.com/A"))
+        .and(body_json(json!({
+            "zone": "example.com",
+            "domain": "www.example.com",
+            "type": "A",
+            "ttl": 300,
+            "use_client_subnet": true,
+            "answers": [{ "answer": ["192.0.2.2"], "meta": { "weight": 20 } }],
+            "filters": [],
+            "meta": {},
+            "regions": {},
+            "tier": 1,
+            "link": null
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(existing))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+
+    let result = zone.delete_record("www/A/192.0.2.3").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_delete_record_last_answer() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/example.com/TXT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            "example.com",
+            "example.com",
+            "TXT",
+            vec![json!(["v=spf1 -all"])],
+        )))
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v1/zones/example.com/example.com/TXT"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v1/zones/example.com/old.example.com/A"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("record not found")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("@/TXT/v=spf1 -all").await.unwrap();
+
+    let result = zone.delete_record("old/A/192.0.2.1").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
@@ -0,0 +1,424 @@
+//! Mock-based tests for the OVHcloud provider.
+//!
+//! These tests use `wiremock` to simulate the OVHcloud API v1 without
+//! requiring an OVHcloud account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Signed request headers and server time synchronization (`/auth/time`)
+//! - Resynchronization after "Query out of time" rejections
+//! - Zone operations (list, get)
+//! - Record conversion (apex sub-domain, MX/SRV targets, quoted TXT)
+//! - Record creation, update and deletion followed by a zone refresh
+//! - Error handling (403, 404)
+//!
+//! # API Structure
+//!
+//! - Time: GET `/auth/time` (unauthenticated)
+//! - Zones: GET `/domain/zone`, GET `/domain/zone/{zone}`, POST `/domain/zone/{zone}/refresh`
+//! - Records: GET/POST `/domain/zone/{zone}/record`, GET/PUT/DELETE `/domain/zone/{zone}/record/{id}`
+//! - Auth: `X-Ovh-Application`, `X-Ovh-Consumer`, `X-Ovh-Timestamp` and `X-Ovh-Signature` headers
+
+use crate::common::ovh::*;
+use crate::common::setup_mock_server;
+
+use manydns::ovh::{Credentials, OvhProvider};
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, DeleteRecordError, Provider, RecordData,
+    RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, header_exists, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> OvhProvider {
+    let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    OvhProvider::with_base_url(credentials, &format!("{}/1.0", server.uri()))
+        .expect("Failed to create provider")
+}
+
+async fn mount_time(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/1.0/auth/time"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(SERVER_TIME))
+        .mount(server)
+        .await;
+}
+
+async fn mount_zone(server: &MockServer) {
+    mount_time(server).await;
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(server)
+        .await;
+}
+
+async fn mount_refresh(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/1.0/domain/zone/example.com/refresh"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(null)))
+        .expect(1)
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Signing Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_signed_headers_and_single_time_sync() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/1.0/auth/time"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(SERVER_TIME))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone"))
+        .and(header("X-Ovh-Application", "app-key"))
+        .and(header("X-Ovh-Consumer", "consumer-key"))
+        .and(header_exists("X-Ovh-Timestamp"))
+        .and(header_exists("X-Ovh-Signature"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(["example.com"])))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/example.com"))
+        .and(header_exists("X-Ovh-Signature"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    assert_eq!(zones.len(), 1);
+
+    let requests = server.received_requests().await.unwrap();
+    let signed = requests
+        .iter()
+        .find(|r| r.url.path() == "/1.0/domain/zone")
+        .unwrap();
+    let timestamp: i64 = signed.headers["X-Ovh-Timestamp"]
+        .to_str()
+        .unwrap()
+        .parse()
+        .unwrap();
+    assert!((timestamp - SERVER_TIME).abs() <= 1);
+
+    let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    let url = format!("{}/1.0/domain/zone", server.uri());
+    let expected = credentials.sign("GET", &url, "", timestamp);
+    assert_eq!(
+        signed.headers["X-Ovh-Signature"].to_str().unwrap(),
+        expected
+    );
+}
+
+#[tokio::test]
+async fn test_resync_after_query_out_of_time() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/1.0/auth/time"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(SERVER_TIME))
+        .expect(2)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/example.com"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(mock_error("Client::BadRequest", "Query out of time")),
+        )
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.name_servers().len(), 2);
+    assert!(zone.dnssec_supported());
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+    mount_time(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "Client::NotFound",
+            "This service does not exist",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_not_granted_is_unauthorized() {
+    let server = setup_mock_server().await;
+    mount_time(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone"))
+        .respond_with(ResponseTemplate::new(403).set_body_json(mock_error(
+            "Client::Forbidden",
+            "This call has not been granted",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/example.com/record"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([1, 2, 3, 4])))
+        .mount(&server)
+        .await;
+    for record in [
+        mock_record(1, "example.com", "www", "A", "192.0.2.1"),
+        mock_record(2, "example.com", "", "MX", "10 mail.example.com."),
+        mock_record(
+            3,
+            "example.com",
+            "_sip._tcp",
+            "SRV",
+            "10 20 5060 sip.example.com.",
+        ),
+        mock_record(4, "example.com", "", "TXT", "\"v=spf1 -all\""),
+    ] {
+        Mock::given(method("GET"))
+            .and(path(format!(
+                "/1.0/domain/zone/example.com/record/{}",
+                record["id"]
+            )))
+            .respond_with(ResponseTemplate::new(200).set_body_json(record))
+            .mount(&server)
+            .await;
+    }
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 4);
+
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_create_record_refreshes_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_refresh(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/1.0/domain/zone/example.com/record"))
+        .and(header("Content-Type", "application/json"))
+        .and(body_json(json!({
+            "fieldType": "MX",
+            "subDomain": "",
+            "target": "10 mail.example.com.",
+            "ttl": 3600
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_record(
+            5,
+            "example.com",
+            "",
+            "MX",
+            "10 mail.example.com.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "example.com",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "5");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_create_unsupported_record_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "HINFO".to_string(),
+                value: "\"x86\" \"Linux\"".to_string(),
+            },
+            3600,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record_refreshes_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_refresh(&server).await;
+
+    Mock::given(method("PUT"))
+        .and(path("/1.0/domain/zone/example.com/record/1"))
+        .and(body_json(json!({
+            "subDomain": "www",
+            "target": "\"hello world\"",
+            "ttl": 60
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(null)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .update_record(
+            "1",
+            "www.example.com",
+            &RecordData::TXT("hello world".to_string()),
+            60,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.host, "www");
+    assert_eq!(record.ttl, 60);
+}
+
+#[tokio::test]
+async fn test_delete_record_refreshes_zone() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_refresh(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/1.0/domain/zone/example.com/record/1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!(null)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/1.0/domain/zone/example.com/record/9"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "Client::NotFound",
+            "The requested object (id = 9) does not exist",
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone.delete_record("9").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+    let result = zone.delete_record("not-a-number").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_list_record_ids_with_filters() {
+    let server = setup_mock_server().await;
+    mount_time(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/1.0/domain/zone/example.com/record"))
+        .and(query_param("fieldType", "TXT"))
+        .and(query_param("subDomain", "_acme-challenge"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([7])))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    let client = manydns::ovh::Client::with_base_url(
+        credentials,
+        &format!("{}/1.0", server.uri()),
+        manydns::HttpClientConfig::default(),
+    )
+    .unwrap();
+    let ids = client
+        .list_record_ids("example.com", Some("TXT"), Some("_acme-challenge"))
+        .await
+        .unwrap();
+    assert_eq!(ids, vec![7]);
+}
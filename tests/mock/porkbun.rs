@@ -0,0 +1,516 @@
+//! Mock-based tests for the Porkbun provider.
+//!
+//! These tests use `wiremock` to simulate the Porkbun JSON API without
+//! requiring a Porkbun account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Domain listing with `start` pagination and zone lookup
+//! - Record conversion (A, MX, SRV, TXT) with `prio` and relative hosts
+//! - Record creation with `prio` for MX/SRV and apex names
+//! - Record deletion and `editByNameType`
+//! - Error handling (403, 400, `"status": "ERROR"` envelopes)
+//!
+//! # API Structure
+//!
+//! - Domains: POST `/domain/listAll`
+//! - Records: POST `/dns/retrieve/{domain}[/{id}]`, `/dns/create/{domain}`,
+//!   `/dns/delete/{domain}/{id}`, `/dns/editByNameType/{domain}/{type}/{subdomain}`
+//! - Auth: `apikey` and `secretapikey` in the JSON body
+
+use crate::common::porkbun::*;
+use crate::common::setup_mock_server;
+
+use manydns::porkbun::PorkbunProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, DeleteRecord, Provider, RecordData, RetrieveRecordError,
+    RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, method, path};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const API_KEY: &str = "pk1_test";
+const SECRET_API_KEY: &str = "sk1_test";
+
+fn provider(uri: &str) -> PorkbunProvider {
+    PorkbunProvider::with_base_url(API_KEY, SECRET_API_KEY, uri).expect("Failed to create provider")
+}
+
+async fn mount_domains(server: &MockServer) {
+    Mock::given(method("POST"))
+        .and(path("/domain/listAll"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({
+                "domains": [mock_domain("example.com"), mock_domain("example.org")]
+            }))),
+        )
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/domain/listAll"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "start": "0"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({
+                "domains": [mock_domain("example.com"), mock_domain("example.org")]
+            }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri()).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com");
+    assert_eq!(zones[0].status(), Some("ACTIVE"));
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    let first_page: Vec<_> = (0..1000)
+        .map(|i| mock_domain(&format!("domain{}.com", i)))
+        .collect();
+
+    Mock::given(method("POST"))
+        .and(path("/domain/listAll"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "start": "0"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_success(json!({ "domains": first_page }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/domain/listAll"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "start": "1000"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({
+                "domains": [mock_domain("last.com")]
+            }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri()).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 1001);
+    assert_eq!(zones[1000].domain(), "last.com");
+}
+
+#[tokio::test]
+async fn test_list_zones_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/domain/listAll"))
+        .respond_with(
+            ResponseTemplate::new(403).set_body_json(mock_error("Invalid API key. (002)")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).list_zones().await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    let provider = provider(&server.uri());
+
+    let zone = provider.get_zone("example.org.").await.unwrap();
+    assert_eq!(zone.id(), "example.org");
+
+    let missing = provider.get_zone("missing.com").await;
+    assert!(matches!(missing, Err(RetrieveZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/retrieve/example.com"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({
+                "records": [
+                    mock_record("1", "example.com", "A", "192.0.2.1", Some("0")),
+                    mock_record("2", "example.com", "MX", "mail.example.com", Some("10")),
+                    mock_record(
+                        "3",
+                        "_sip._tcp.example.com",
+                        "SRV",
+                        "5 5060 sip.example.com",
+                        Some("20")
+                    ),
+                    mock_record("4", "www.example.com", "TXT", "hello world", None)
+                ]
+            }))),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].id, "1");
+    assert_eq!(records[0].host, "@");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(records[0].ttl, 600);
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 20,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].host, "www");
+    assert_eq!(records[3].data, RecordData::TXT("hello world".to_string()));
+}
+
+#[tokio::test]
+async fn test_list_records_error_envelope() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/retrieve/example.com"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_error("Domain is not opted in to API access.")),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone.list_records().await;
+
+    match result {
+        Err(RetrieveRecordError::Custom(err)) => {
+            assert_eq!(err.status(), Some(200));
+            assert!(err.to_string().contains("opted in"));
+        }
+        other => panic!("unexpected result: {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn test_get_record() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/retrieve/example.com/2"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({
+                "records": [mock_record("2", "example.com", "MX", "mail.example.com", Some("10"))]
+            }))),
+        )
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/retrieve/example.com/99"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({ "records": [] }))),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+
+    let record = zone.get_record("2").await.unwrap();
+    assert_eq!(record.host, "@");
+    assert_eq!(
+        record.data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    let missing = zone.get_record("99").await;
+    assert!(matches!(missing, Err(RetrieveRecordError::NotFound)));
+
+    let invalid = zone.get_record("not-a-number").await;
+    assert!(matches!(invalid, Err(RetrieveRecordError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_create_mx_record() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/create/example.com"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "name": "",
+            "type": "MX",
+            "content": "mail.example.com",
+            "ttl": "3600",
+            "prio": "10"
+        })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_success(json!({ "id": 106926659 }))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record(
+            "example.com",
+            &RecordData::MX {
+                priority: 10,
+                mail_server: "mail.example.com".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "106926659");
+    assert_eq!(record.host, "@");
+    assert_eq!(record.ttl, 3600);
+}
+
+#[tokio::test]
+async fn test_create_srv_record() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/create/example.com"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "name": "_sip._tcp",
+            "type": "SRV",
+            "content": "5 5060 sip.example.com",
+            "ttl": "600",
+            "prio": "20"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_success(json!({ "id": 42 }))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record(
+            "_sip._tcp.example.com",
+            &RecordData::SRV {
+                priority: 20,
+                weight: 5,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "42");
+    assert_eq!(record.host, "_sip._tcp");
+}
+
+#[tokio::test]
+async fn test_create_record_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "NAPTR".to_string(),
+                value: "100 10 \"U\" \"E2U+sip\" \"!^.*$!sip:info@example.com!\" .".to_string(),
+            },
+            600,
+        )
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_create_record_invalid() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/create/example.com"))
+        .respond_with(
+            ResponseTemplate::new(400)
+                .set_body_json(mock_error("Could not add record: invalid content.")),
+        )
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record("www", &RecordData::CNAME("bad..target".to_string()), 600)
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/delete/example.com/106926659"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_success(json!({}))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+
+    zone.delete_record("106926659").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_edit_by_name_type() {
+    let server = setup_mock_server().await;
+    mount_domains(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/editByNameType/example.com/A/www"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "content": "192.0.2.10",
+            "ttl": "900"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_success(json!({}))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("POST"))
+        .and(path("/dns/editByNameType/example.com/MX"))
+        .and(body_json(json!({
+            "apikey": API_KEY,
+            "secretapikey": SECRET_API_KEY,
+            "content": "mx2.example.com",
+            "ttl": "600",
+            "prio": "20"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_success(json!({}))))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+
+    zone.edit_by_name_type(
+        "www.example.com",
+        &RecordData::A(Ipv4Addr::new(192, 0, 2, 10)),
+        900,
+    )
+    .await
+    .unwrap();
+
+    zone.edit_by_name_type(
+        "@",
+        &RecordData::MX {
+            priority: 20,
+            mail_server: "mx2.example.com".to_string(),
+        },
+        600,
+    )
+    .await
+    .unwrap();
+}
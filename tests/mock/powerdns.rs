@@ -0,0 +1,488 @@
+//! Mock-based tests for the PowerDNS Authoritative provider.
+//!
+//! These tests use `wiremock` to simulate the PowerDNS HTTP API
+//! (`/api/v1/servers/{server_id}`) without requiring a running server.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Zone operations (list, get, create, delete)
+//! - RRset to record conversion (A, MX, TXT, CNAME)
+//! - Record creation and deletion via RRset PATCH (REPLACE/DELETE)
+//! - Custom server IDs
+//! - Error handling (401, 404, 422)
+//!
+//! # API Structure
+//!
+//! - Zones: GET/POST `/zones`, GET/PATCH/DELETE `/zones/{zone_id}`
+//! - Auth: `X-API-Key` header
+
+use crate::common::powerdns::*;
+use crate::common::setup_mock_server;
+
+use manydns::powerdns::PowerDnsProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteRecordError,
+    DeleteZone, Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path};
+use wiremock::{Mock, ResponseTemplate};
+
+const ZONE_PATH: &str = "/api/v1/servers/localhost/zones/example.com.";
+
+fn provider(uri: &str) -> PowerDnsProvider {
+    PowerDnsProvider::new(uri, "test-token").expect("Failed to create provider")
+}
+
+/// Mounts a GET for the example.com zone with the given RRsets.
+async fn mount_zone(server: &wiremock::MockServer, rrsets: Vec<(&str, &str, u64, Vec<&str>)>) {
+    Mock::given(method("GET"))
+        .and(path(ZONE_PATH))
+        .and(header("X-API-Key", "test-token"))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_zone_with_rrsets("example.com.", rrsets)),
+        )
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_success() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .and(header("X-API-Key", "test-token"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([
+            mock_zone("example.com."),
+            mock_zone("example.org.")
+        ])))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri())
+        .list_zones()
+        .await
+        .expect("Failed to list zones");
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "example.com.");
+    assert_eq!(zones[0].domain(), "example.com");
+    assert_eq!(zones[0].kind(), "Native");
+    assert_eq!(zones[1].domain(), "example.org");
+}
+
+#[tokio::test]
+async fn test_list_zones_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(ZONES_PATH))
+        .respond_with(ResponseTemplate::new(401).set_body_string("Unauthorized"))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_get_zone_without_trailing_dot() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .expect("Failed to get zone");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.serial(), 2024010101);
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/servers/localhost/zones/missing.example."))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Not Found")))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).get_zone("missing.example").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_custom_server_id() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/v1/servers/ns1/zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!([mock_zone("example.com.")])))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri())
+        .with_server_id("ns1")
+        .list_zones()
+        .await
+        .expect("Failed to list zones");
+    assert_eq!(zones.len(), 1);
+}
+
+#[tokio::test]
+async fn test_create_zone_success() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(ZONES_PATH))
+        .and(body_json(json!({
+            "name": "new.example.",
+            "kind": "Native",
+            "nameservers": []
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(mock_zone("new.example.")))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .create_zone("new.example")
+        .await
+        .expect("Failed to create zone");
+    assert_eq!(zone.domain(), "new.example");
+}
+
+#[tokio::test]
+async fn test_create_zone_invalid() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(ZONES_PATH))
+        .respond_with(
+            ResponseTemplate::new(422).set_body_json(mock_error("Unable to parse DNS Name")),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).create_zone("bad..name").await;
+    assert!(matches!(result, Err(CreateZoneError::InvalidDomainName)));
+}
+
+#[tokio::test]
+async fn test_delete_zone_success() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(ZONE_PATH))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server.uri())
+        .delete_zone("example.com.")
+        .await
+        .expect("Failed to delete zone");
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_converts_rrsets() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![
+            ("www.example.com.", "A", 300, vec!["192.0.2.1", "192.0.2.2"]),
+            ("example.com.", "MX", 3600, vec!["10 mail.example.com."]),
+            ("example.com.", "TXT", 600, vec!["\"v=spf1 \" \"-all\""]),
+            ("alias.example.com.", "CNAME", 300, vec!["www.example.com."]),
+        ],
+    )
+    .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let records = zone.list_records().await.expect("Failed to list records");
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].id, "www/A/192.0.2.1");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(records[1].ttl, 300);
+
+    assert_eq!(records[2].host, "@");
+    assert_eq!(
+        records[2].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(
+        records[4].data,
+        RecordData::CNAME("www.example.com".to_string())
+    );
+}
+
+#[tokio::test]
+async fn test_get_record_by_id() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![("www.example.com.", "A", 300, vec!["192.0.2.1"])],
+    )
+    .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .get_record("www/A/192.0.2.1")
+        .await
+        .expect("Failed to get record");
+    assert_eq!(record.host, "www");
+
+    assert!(zone.get_record("www/A/192.0.2.9").await.is_err());
+}
+
+#[tokio::test]
+async fn test_create_record_merges_rrset() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![("www.example.com.", "A", 300, vec!["192.0.2.1"])],
+    )
+    .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(ZONE_PATH))
+        .and(body_json(json!({
+            "rrsets": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "changetype": "REPLACE",
+                "ttl": 600,
+                "records": [
+                    {"content": "192.0.2.1", "disabled": false},
+                    {"content": "192.0.2.2", "disabled": false}
+                ]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 600)
+        .await
+        .expect("Failed to create record");
+    assert_eq!(record.id, "www/A/192.0.2.2");
+    assert_eq!(record.ttl, 600);
+}
+
+#[tokio::test]
+async fn test_create_record_formats_content() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    Mock::given(method("PATCH"))
+        .and(path(ZONE_PATH))
+        .and(body_json(json!({
+            "rrsets": [{
+                "name": "example.com.",
+                "type": "MX",
+                "changetype": "REPLACE",
+                "ttl": 3600,
+                "records": [{"content": "10 mail.example.com.", "disabled": false}]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(ZONE_PATH))
+        .and(body_json(json!({
+            "rrsets": [{
+                "name": "_acme-challenge.example.com.",
+                "type": "TXT",
+                "changetype": "REPLACE",
+                "ttl": 60,
+                "records": [{"content": "\"token \\\"quoted\\\"\"", "disabled": false}]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.create_record(
+        "@",
+        &RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string(),
+        },
+        3600,
+    )
+    .await
+    .expect("Failed to create MX record");
+
+    zone.create_record(
+        "_acme-challenge",
+        &RecordData::TXT("token \"quoted\"".to_string()),
+        60,
+    )
+    .await
+    .expect("Failed to create TXT record");
+}
+
+#[tokio::test]
+async fn test_create_record_duplicate() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![("www.example.com.", "A", 300, vec!["192.0.2.1"])],
+    )
+    .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_record_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server, vec![]).await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "SOA".to_string(),
+                value: "ns1. hostmaster. 1 2 3 4 5".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_remaining() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![("www.example.com.", "A", 300, vec!["192.0.2.1", "192.0.2.2"])],
+    )
+    .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(ZONE_PATH))
+        .and(body_json(json!({
+            "rrsets": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "changetype": "REPLACE",
+                "ttl": 300,
+                "records": [{"content": "192.0.2.2", "disabled": false}]
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.delete_record("www/A/192.0.2.1")
+        .await
+        .expect("Failed to delete record");
+}
+
+#[tokio::test]
+async fn test_delete_last_record_deletes_rrset() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![("www.example.com.", "A", 300, vec!["192.0.2.1"])],
+    )
+    .await;
+
+    Mock::given(method("PATCH"))
+        .and(path(ZONE_PATH))
+        .and(body_json(json!({
+            "rrsets": [{
+                "name": "www.example.com.",
+                "type": "A",
+                "changetype": "DELETE"
+            }]
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    zone.delete_record("www/A/192.0.2.1")
+        .await
+        .expect("Failed to delete record");
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_zone(
+        &server,
+        vec![("www.example.com.", "A", 300, vec!["192.0.2.1"])],
+    )
+    .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+    let result = zone.delete_record("www/A/192.0.2.9").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+
+    let result = zone.delete_record("invalid-id").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
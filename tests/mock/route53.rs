@@ -0,0 +1,554 @@
+//! Mock-based tests for the Amazon Route 53 provider.
+//!
+//! These tests use `wiremock` to simulate the Route 53 REST/XML API without
+//! requiring AWS credentials.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Hosted zone operations (list with pagination, get by ID or name, create, delete)
+//! - Public and private hosted zones
+//! - Record set to record conversion (A, MX, TXT, wildcard names)
+//! - Record creation and deletion via `ChangeResourceRecordSets` (UPSERT/DELETE)
+//! - SigV4 headers, including the session token
+//! - Error handling (AccessDenied, NoSuchHostedZone, InvalidChangeBatch)
+//!
+//! # API Structure
+//!
+//! - Hosted zones: GET/POST `/2013-04-01/hostedzone`, GET/DELETE `/2013-04-01/hostedzone/{id}`
+//! - Record sets: GET `/2013-04-01/hostedzone/{id}/rrset`, POST `/2013-04-01/hostedzone/{id}/rrset/`
+//! - Auth: `Authorization: AWS4-HMAC-SHA256 ...` and `X-Amz-Date` headers
+
+use crate::common::route53::*;
+use crate::common::setup_mock_server;
+
+use manydns::route53::{Credentials, Route53Provider, Vpc};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    DeleteZoneError, HttpClientConfig, Provider, RecordData, RetrieveZoneError, Zone,
+};
+use std::net::Ipv4Addr;
+use wiremock::matchers::{
+    body_string_contains, header, header_exists, header_regex, method, path, query_param,
+    query_param_is_missing,
+};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const ZONE_ID: &str = "Z1D633PJN98FT9";
+
+fn credentials() -> Credentials {
+    Credentials::new("AKIDEXAMPLE", "secret")
+}
+
+fn provider(uri: &str) -> Route53Provider {
+    Route53Provider::with_base_url(credentials(), uri, HttpClientConfig::default())
+        .expect("Failed to create provider")
+}
+
+fn xml(body: String) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_raw(body, "text/xml")
+}
+
+fn xml_error(status: u16, code: &str, message: &str) -> ResponseTemplate {
+    ResponseTemplate::new(status).set_body_raw(error(code, message), "text/xml")
+}
+
+fn zone_path() -> String {
+    format!("{}/hostedzone/{}", API_PREFIX, ZONE_ID)
+}
+
+fn rrset_path() -> String {
+    format!("{}/hostedzone/{}/rrset", API_PREFIX, ZONE_ID)
+}
+
+async fn mount_zone(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path(zone_path()))
+        .respond_with(xml(get_hosted_zone(ZONE_ID, "example.com.", false)))
+        .mount(server)
+        .await;
+}
+
+/// Mounts a lookup of a single record set by name and type.
+async fn mount_record_set(server: &MockServer, name: &str, typ: &str, body: Vec<String>) {
+    Mock::given(method("GET"))
+        .and(path(rrset_path()))
+        .and(query_param("name", name))
+        .and(query_param("type", typ))
+        .and(query_param("maxitems", "1"))
+        .respond_with(xml(list_record_sets(&body, None)))
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Hosted Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_paginated() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .and(query_param_is_missing("marker"))
+        .and(header_regex(
+            "Authorization",
+            r"^AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/\d{8}/us-east-1/route53/aws4_request, SignedHeaders=host;x-amz-date, Signature=[0-9a-f]{64}$",
+        ))
+        .and(header_exists("x-amz-date"))
+        .respond_with(xml(list_hosted_zones(
+            &[hosted_zone("Z1", "example.com.", false)],
+            Some("Z2"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .and(query_param("marker", "Z2"))
+        .respond_with(xml(list_hosted_zones(
+            &[hosted_zone("Z2", "internal.example.com.", true)],
+            None,
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server.uri()).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 2);
+    assert_eq!(zones[0].id(), "Z1");
+    assert_eq!(zones[0].domain(), "example.com");
+    assert!(!zones[0].is_private());
+    assert_eq!(zones[0].comment(), Some("test"));
+    assert_eq!(zones[0].record_count(), Some(2));
+    assert_eq!(zones[1].domain(), "internal.example.com");
+    assert!(zones[1].is_private());
+}
+
+#[tokio::test]
+async fn test_session_token_header() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .and(header("x-amz-security-token", "session-token"))
+        .and(header_regex(
+            "Authorization",
+            "SignedHeaders=host;x-amz-date;x-amz-security-token,",
+        ))
+        .respond_with(xml(list_hosted_zones(&[], None)))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = Route53Provider::with_base_url(
+        credentials().with_session_token("session-token"),
+        &server.uri(),
+        HttpClientConfig::default(),
+    )
+    .unwrap();
+
+    assert!(provider.list_zones().await.unwrap().is_empty());
+}
+
+#[tokio::test]
+async fn test_get_zone_by_id() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+
+    assert_eq!(zone.id(), ZONE_ID);
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone_by_name_prefers_public() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .respond_with(xml(list_hosted_zones(
+            &[
+                hosted_zone("ZPRIVATE", "example.com.", true),
+                hosted_zone("ZPUBLIC", "example.com.", false),
+                hosted_zone("ZOTHER", "example.org.", false),
+            ],
+            None,
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .get_zone("example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(zone.id(), "ZPUBLIC");
+    assert!(!zone.is_private());
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/hostedzone/ZMISSING", API_PREFIX)))
+        .respond_with(xml_error(
+            404,
+            "NoSuchHostedZone",
+            "No hosted zone found with ID: ZMISSING",
+        ))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).get_zone("ZMISSING").await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_list_zones_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .respond_with(xml_error(
+            403,
+            "SignatureDoesNotMatch",
+            "The request signature we calculated does not match",
+        ))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).list_zones().await;
+
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_public_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .and(body_string_contains("<Name>example.com</Name>"))
+        .and(body_string_contains("<PrivateZone>false</PrivateZone>"))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(
+            create_hosted_zone(ZONE_ID, "example.com.", false),
+            "text/xml",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri())
+        .create_zone("example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(zone.id(), ZONE_ID);
+    assert!(!zone.is_private());
+}
+
+#[tokio::test]
+async fn test_create_private_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/hostedzone", API_PREFIX)))
+        .and(body_string_contains(
+            "<VPC><VPCRegion>eu-central-1</VPCRegion><VPCId>vpc-123</VPCId></VPC>",
+        ))
+        .and(body_string_contains("<PrivateZone>true</PrivateZone>"))
+        .respond_with(ResponseTemplate::new(201).set_body_raw(
+            create_hosted_zone("ZPRIVATE", "internal.example.com.", true),
+            "text/xml",
+        ))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let vpc = Vpc {
+        region: "eu-central-1".to_string(),
+        id: "vpc-123".to_string(),
+    };
+    let zone = provider(&server.uri())
+        .create_private_zone("internal.example.com", vpc)
+        .await
+        .unwrap();
+
+    assert_eq!(zone.domain(), "internal.example.com");
+    assert!(zone.is_private());
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(zone_path()))
+        .respond_with(xml(delete_hosted_zone()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server.uri()).delete_zone(ZONE_ID).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path(zone_path()))
+        .respond_with(xml_error(404, "NoSuchHostedZone", "not found"))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server.uri()).delete_zone(ZONE_ID).await;
+
+    assert!(matches!(result, Err(DeleteZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records_paginated() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path(rrset_path()))
+        .and(query_param_is_missing("name"))
+        .respond_with(xml(list_record_sets(
+            &[
+                record_set("example.com.", "MX", 3600, &["10 mail.example.com."]),
+                record_set("example.com.", "TXT", 300, &["\"v=spf1 -all\""]),
+            ],
+            Some(("\\052.example.com.", "A")),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path(rrset_path()))
+        .and(query_param("name", "\\052.example.com."))
+        .and(query_param("type", "A"))
+        .respond_with(xml(list_record_sets(
+            &[record_set(
+                "\\052.example.com.",
+                "A",
+                60,
+                &["192.0.2.1", "192.0.2.2"],
+            )],
+            None,
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].host, "@");
+    assert_eq!(
+        records[0].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(records[1].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(records[2].host, "*");
+    assert_eq!(records[2].id, "*/A/192.0.2.1");
+    assert_eq!(records[2].ttl, 60);
+    assert_eq!(records[3].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+}
+
+#[tokio::test]
+async fn test_create_record_merges_record_set() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![record_set("www.example.com.", "A", 300, &["192.0.2.1"])],
+    )
+    .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/", rrset_path())))
+        .and(body_string_contains(
+            "<Change><Action>UPSERT</Action><ResourceRecordSet><Name>www.example.com.</Name><Type>A</Type><TTL>600</TTL>\
+             <ResourceRecords><ResourceRecord><Value>192.0.2.1</Value></ResourceRecord>\
+             <ResourceRecord><Value>192.0.2.2</Value></ResourceRecord></ResourceRecords>",
+        ))
+        .respond_with(xml(change_info()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    let record = zone
+        .create_record("www", &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)), 600)
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "www/A/192.0.2.2");
+    assert_eq!(record.host, "www");
+    assert_eq!(record.ttl, 600);
+}
+
+#[tokio::test]
+async fn test_create_txt_record_is_quoted() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set(&server, "_acme-challenge.example.com.", "TXT", vec![]).await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/", rrset_path())))
+        .and(body_string_contains(
+            "<Value>&quot;token &amp; value&quot;</Value>",
+        ))
+        .respond_with(xml(change_info()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    zone.create_record(
+        "_acme-challenge",
+        &RecordData::TXT("token & value".to_string()),
+        60,
+    )
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_create_record_invalid_change_batch() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set(&server, "www.example.com.", "CNAME", vec![]).await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/", rrset_path())))
+        .respond_with(ResponseTemplate::new(400).set_body_raw(
+            r#"<?xml version="1.0"?><InvalidChangeBatch xmlns="https://route53.amazonaws.com/doc/2013-04-01/"><Messages><Message>RRSet of type CNAME with DNS name www.example.com. is not permitted</Message></Messages></InvalidChangeBatch>"#,
+            "text/xml",
+        ))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    let result = zone
+        .create_record("www", &RecordData::CNAME("example.org".to_string()), 300)
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_create_record_unsupported_type() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "HINFO".to_string(),
+                value: "x y".to_string(),
+            },
+            300,
+        )
+        .await;
+
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_delete_record_keeps_siblings() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![record_set(
+            "www.example.com.",
+            "A",
+            300,
+            &["192.0.2.1", "192.0.2.2"],
+        )],
+    )
+    .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/", rrset_path())))
+        .and(body_string_contains(
+            "<Action>UPSERT</Action><ResourceRecordSet><Name>www.example.com.</Name><Type>A</Type><TTL>300</TTL>\
+             <ResourceRecords><ResourceRecord><Value>192.0.2.2</Value></ResourceRecord></ResourceRecords>",
+        ))
+        .respond_with(xml(change_info()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_last_record_deletes_record_set() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![record_set("www.example.com.", "A", 300, &["192.0.2.1"])],
+    )
+    .await;
+
+    Mock::given(method("POST"))
+        .and(path(format!("{}/", rrset_path())))
+        .and(body_string_contains(
+            "<Action>DELETE</Action><ResourceRecordSet><Name>www.example.com.</Name><Type>A</Type><TTL>300</TTL>",
+        ))
+        .respond_with(xml(change_info()))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    zone.delete_record("www/A/192.0.2.1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    mount_zone(&server).await;
+    mount_record_set(
+        &server,
+        "www.example.com.",
+        "A",
+        vec![record_set("www.example.com.", "A", 300, &["192.0.2.1"])],
+    )
+    .await;
+
+    let zone = provider(&server.uri()).get_zone(ZONE_ID).await.unwrap();
+    let result = zone.delete_record("www/A/192.0.2.9").await;
+
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
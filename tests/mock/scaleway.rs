@@ -0,0 +1,376 @@
+//! Mock-based tests for the Scaleway provider.
+//!
+//! These tests use `wiremock` to simulate the Scaleway Domains and DNS API
+//! without requiring a Scaleway account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - `X-Auth-Token` authentication
+//! - Zone operations (list with pagination, get, create, delete)
+//! - Record conversion (apex name, MX/SRV priorities, quoted TXT)
+//! - Record changes (`add`, `set`, `delete`) through the PATCH endpoint
+//! - Error handling (401, 400, 404)
+//!
+//! # API Structure
+//!
+//! - Zones: `/domain/v2beta1/dns-zones`
+//! - Records: `/domain/v2beta1/dns-zones/{zone}/records` (GET, PATCH)
+//! - Auth: `X-Auth-Token` header
+//! - Errors: `{"type": ..., "message": ...}`
+
+use crate::common::scaleway::*;
+use crate::common::setup_mock_server;
+
+use manydns::scaleway::{ScalewayError, ScalewayProvider, ScalewayZone};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, CreateZoneError, DeleteRecord, DeleteZone,
+    Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const SECRET_KEY: &str = "22222222-2222-2222-2222-222222222222";
+
+fn provider(server: &MockServer) -> ScalewayProvider {
+    ScalewayProvider::with_base_url(SECRET_KEY, &server.uri()).expect("Failed to create provider")
+}
+
+async fn zone(server: &MockServer) -> ScalewayZone {
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .and(query_param("dns_zone", "example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns_zones": [mock_zone("example.com", "")],
+            "total_count": 1
+        })))
+        .mount(server)
+        .await;
+
+    provider(server).get_zone("example.com").await.unwrap()
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    let first_page: Vec<_> = (0..100)
+        .map(|i| mock_zone(&format!("example{}.com", i), ""))
+        .collect();
+
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .and(header("X-Auth-Token", SECRET_KEY))
+        .and(query_param("page", "1"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns_zones": first_page,
+            "total_count": 101
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .and(query_param("page", "2"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns_zones": [mock_zone("example.com", "sub")],
+            "total_count": 101
+        })))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 101);
+    assert_eq!(zones[0].domain(), "example0.com");
+    assert_eq!(zones[100].domain(), "sub.example.com");
+    assert_eq!(zones[100].id(), "sub.example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    assert_eq!(zone.id(), "example.com");
+    assert_eq!(zone.name_servers().len(), 2);
+    assert_eq!(zone.project_id(), "11111111-1111-1111-1111-111111111111");
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns_zones": [],
+            "total_count": 0
+        })))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .respond_with(ResponseTemplate::new(401).set_body_json(json!({
+            "type": "denied_authentication",
+            "message": "invalid authentication",
+            "method": "api_key",
+            "reason": "invalid_argument"
+        })))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .and(body_json(json!({
+            "domain": "example.com",
+            "subdomain": "",
+            "project_id": "11111111-1111-1111-1111-111111111111"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_zone("example.com", "")))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .with_project_id("11111111-1111-1111-1111-111111111111")
+        .create_zone("example.com")
+        .await
+        .unwrap();
+
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_create_zone_without_project_id() {
+    let server = setup_mock_server().await;
+
+    let result = provider(&server).create_zone("example.com").await;
+    assert!(matches!(
+        result,
+        Err(CreateZoneError::Custom(ScalewayError::MissingProjectId))
+    ));
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "dns_zones": [mock_zone("example.com", "")],
+            "total_count": 1
+        })))
+        .mount(&server)
+        .await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/domain/v2beta1/dns-zones/example.com"))
+        .and(query_param(
+            "project_id",
+            "11111111-1111-1111-1111-111111111111",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({})))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/domain/v2beta1/dns-zones/example.com/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [
+                mock_record("r1", "", "A", "192.0.2.1", 0),
+                mock_record("r2", "", "MX", "mail.example.com.", 10),
+                mock_record("r3", "_sip._tcp", "SRV", "5 5060 sip.example.com.", 20),
+                mock_record("r4", "txt", "TXT", "\"hello world\"", 0)
+            ],
+            "total_count": 4
+        })))
+        .mount(&server)
+        .await;
+
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 4);
+    assert_eq!(records[0].host, "@");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 20,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+    assert_eq!(records[3].data, RecordData::TXT("hello world".to_string()));
+}
+
+#[tokio::test]
+async fn test_create_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domain/v2beta1/dns-zones/example.com/records"))
+        .and(body_json(json!({
+            "changes": [{
+                "add": {
+                    "records": [{
+                        "name": "www",
+                        "type": "A",
+                        "data": "192.0.2.1",
+                        "ttl": 300,
+                        "priority": 0
+                    }]
+                }
+            }],
+            "return_all_records": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [mock_record("new-id", "www", "A", "192.0.2.1", 0)]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)),
+            300,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "new-id");
+    assert_eq!(record.host, "www");
+}
+
+#[tokio::test]
+async fn test_create_record_invalid() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domain/v2beta1/dns-zones/example.com/records"))
+        .respond_with(ResponseTemplate::new(400).set_body_json(json!({
+            "type": "invalid_arguments",
+            "message": "invalid argument(s)"
+        })))
+        .mount(&server)
+        .await;
+
+    let result = zone
+        .create_record("www", &RecordData::CNAME("target.com".to_string()), 300)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domain/v2beta1/dns-zones/example.com/records"))
+        .and(body_json(json!({
+            "changes": [{
+                "set": {
+                    "id": "r1",
+                    "records": [{
+                        "name": "",
+                        "type": "MX",
+                        "data": "mail.example.com",
+                        "ttl": 600,
+                        "priority": 5
+                    }]
+                }
+            }],
+            "return_all_records": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [mock_record("r1-new", "", "MX", "mail.example.com.", 5)]
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .update_record(
+            "r1",
+            "@",
+            &RecordData::MX {
+                priority: 5,
+                mail_server: "mail.example.com".to_string(),
+            },
+            600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "r1-new");
+    assert_eq!(record.host, "@");
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/domain/v2beta1/dns-zones/example.com/records"))
+        .and(body_json(json!({
+            "changes": [{ "delete": { "id": "r1" } }],
+            "return_all_records": false
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "records": [] })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.delete_record("r1").await.unwrap();
+}
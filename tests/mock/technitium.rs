@@ -0,0 +1,656 @@
+//! Mock-based tests for the Technitium DNS Server provider.
+//!
+//! These tests use `wiremock` to simulate the Technitium HTTP API without
+//! requiring a running DNS server.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Zone creation with type-specific options (secondary, stub, forwarder)
+//! - Zone transfer and notify options (`/api/zones/options/get` and `/set`)
+//! - In-place record updates (`/api/zones/records/update`) with old and new values
+//! - Record renames through `newDomain`
+//! - Rejection of record type changes
+//!
+//! # API Structure
+//!
+//! - All endpoints: `GET /api/...?token={token}&...`
+//! - Responses: `{"status": "ok", "response": {...}}`
+//! - Errors: `{"status": "error", "errorMessage": ...}`
+
+use crate::common::setup_mock_server;
+use crate::common::technitium::*;
+
+use manydns::technitium::api::{
+    ApiError, Client, CreateZoneOptions, ForwarderProtocol, NotifyPolicy, RecordParams,
+    ZoneTransferOptions, ZoneTransferPolicy, ZoneTransferProtocol,
+};
+use manydns::technitium::{TechnitiumProvider, TechnitiumZone};
+use manydns::{CreateRecordError, CreateZone, Provider, RecordData, Zone};
+use serde_json::{json, Value};
+use std::net::Ipv4Addr;
+use wiremock::matchers::{method, path, query_param, query_param_is_missing};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+const TOKEN: &str = "test-token";
+
+async fn zone(server: &MockServer) -> TechnitiumZone {
+    Mock::given(method("GET"))
+        .and(path("/api/zones/options/get"))
+        .and(query_param("zone", "example.com"))
+        .and(query_param("token", TOKEN))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_ok(json!({
+            "name": "example.com",
+            "type": "Primary",
+            "internal": false,
+            "disabled": false
+        }))))
+        .mount(server)
+        .await;
+
+    provider(server).get_zone("example.com").await.unwrap()
+}
+
+async fn mount_records(server: &MockServer, domain: &str, records: Vec<Value>) {
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/get"))
+        .and(query_param("domain", domain))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_ok(json!({
+            "zone": mock_zone_info("example.com"),
+            "records": records
+        }))))
+        .mount(server)
+        .await;
+}
+
+/// Returns the ID the provider assigns to a record, as listed in the zone.
+async fn record_id(server: &MockServer, zone: &TechnitiumZone, record: &Value) -> String {
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/get"))
+        .and(query_param("listZone", "true"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_ok(json!({
+            "zone": mock_zone_info("example.com"),
+            "records": [record]
+        }))))
+        .mount(server)
+        .await;
+
+    zone.list_records().await.unwrap().remove(0).id
+}
+
+fn updated(record: Value) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(mock_ok(json!({
+        "zone": mock_zone_info("example.com"),
+        "updatedRecord": record
+    })))
+}
+
+fn provider(server: &MockServer) -> TechnitiumProvider {
+    TechnitiumProvider::new(&server.uri(), TOKEN).unwrap()
+}
+
+fn created(domain: &str) -> ResponseTemplate {
+    ResponseTemplate::new(200).set_body_json(mock_ok(json!({ "domain": domain })))
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_create_primary_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/create"))
+        .and(query_param("zone", "example.com"))
+        .and(query_param("type", "Primary"))
+        .respond_with(created("example.com"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+    assert_eq!(zone.zone_type(), "Primary");
+}
+
+#[tokio::test]
+async fn test_create_secondary_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/create"))
+        .and(query_param("zone", "example.com"))
+        .and(query_param("type", "Secondary"))
+        .and(query_param(
+            "primaryNameServerAddresses",
+            "192.0.2.1,2001:db8::1",
+        ))
+        .and(query_param("zoneTransferProtocol", "Tls"))
+        .and(query_param("tsigKeyName", "transfer-key"))
+        .respond_with(created("example.com"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .create_zone_with_options(
+            "example.com",
+            &CreateZoneOptions::Secondary {
+                primary_name_server_addresses: vec![
+                    "192.0.2.1".to_string(),
+                    "2001:db8::1".to_string(),
+                ],
+                zone_transfer_protocol: ZoneTransferProtocol::Tls,
+                tsig_key_name: Some("transfer-key".to_string()),
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(zone.zone_type(), "Secondary");
+}
+
+#[tokio::test]
+async fn test_create_stub_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/create"))
+        .and(query_param("type", "Stub"))
+        .and(query_param_is_missing("primaryNameServerAddresses"))
+        .respond_with(created("example.com"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server)
+        .create_zone_with_options(
+            "example.com",
+            &CreateZoneOptions::Stub {
+                primary_name_server_addresses: vec![],
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(zone.zone_type(), "Stub");
+}
+
+#[tokio::test]
+async fn test_create_forwarder_zones() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/create"))
+        .and(query_param("zone", "corp.example"))
+        .and(query_param("type", "Forwarder"))
+        .and(query_param("initializeForwarder", "true"))
+        .and(query_param("protocol", "Https"))
+        .and(query_param(
+            "forwarder",
+            "https://dns.example.net/dns-query",
+        ))
+        .and(query_param("dnssecValidation", "true"))
+        .respond_with(created("corp.example"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/create"))
+        .and(query_param("zone", "lab.example"))
+        .and(query_param("type", "Forwarder"))
+        .and(query_param("initializeForwarder", "false"))
+        .and(query_param_is_missing("forwarder"))
+        .respond_with(created("lab.example"))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+
+    let zone = provider
+        .create_zone_with_options(
+            "corp.example",
+            &CreateZoneOptions::Forwarder {
+                forwarder: "https://dns.example.net/dns-query".to_string(),
+                protocol: ForwarderProtocol::Https,
+                dnssec_validation: true,
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(zone.zone_type(), "Forwarder");
+
+    let zone = provider
+        .create_zone_with_options("lab.example", &CreateZoneOptions::ConditionalForwarder)
+        .await
+        .unwrap();
+    assert_eq!(zone.zone_type(), "Forwarder");
+}
+
+#[tokio::test]
+async fn test_get_transfer_options() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/options/get"))
+        .and(query_param("zone", "example.com"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_ok(json!({
+            "name": "example.com",
+            "type": "Primary",
+            "internal": false,
+            "disabled": false,
+            "zoneTransfer": "AllowZoneNameServersAndUseSpecifiedNetworkACL",
+            "zoneTransferNetworkACL": ["192.0.2.0/24", "!192.0.2.1"],
+            "zoneTransferTsigKeyNames": ["transfer-key"],
+            "notify": "BothZoneAndSpecifiedNameServers",
+            "notifyNameServers": ["192.0.2.53"]
+        }))))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let options = zone.transfer_options().await.unwrap();
+
+    assert_eq!(
+        options,
+        ZoneTransferOptions {
+            zone_transfer: Some(ZoneTransferPolicy::AllowZoneNameServersAndUseSpecifiedNetworkAcl),
+            zone_transfer_network_acl: Some(vec![
+                "192.0.2.0/24".to_string(),
+                "!192.0.2.1".to_string()
+            ]),
+            zone_transfer_tsig_key_names: Some(vec!["transfer-key".to_string()]),
+            notify: Some(NotifyPolicy::BothZoneAndSpecifiedNameServers),
+            notify_name_servers: Some(vec!["192.0.2.53".to_string()]),
+            ..Default::default()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_set_transfer_options() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/options/set"))
+        .and(query_param("zone", "example.com"))
+        .and(query_param("zoneTransfer", "UseSpecifiedNetworkACL"))
+        .and(query_param(
+            "zoneTransferNetworkACL",
+            "192.0.2.0/24,198.51.100.7",
+        ))
+        .and(query_param("notify", "SpecifiedNameServers"))
+        .and(query_param("notifyNameServers", "false"))
+        .and(query_param_is_missing("zoneTransferTsigKeyNames"))
+        .and(query_param_is_missing("primaryNameServerAddresses"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "ok" })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.set_transfer_options(&ZoneTransferOptions {
+        zone_transfer: Some(ZoneTransferPolicy::UseSpecifiedNetworkAcl),
+        zone_transfer_network_acl: Some(vec![
+            "192.0.2.0/24".to_string(),
+            "198.51.100.7".to_string(),
+        ]),
+        notify: Some(NotifyPolicy::SpecifiedNameServers),
+        // An empty list clears the existing name servers.
+        notify_name_servers: Some(vec![]),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn test_set_secondary_primary_options() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/options/set"))
+        .and(query_param("primaryNameServerAddresses", "192.0.2.1"))
+        .and(query_param("primaryZoneTransferProtocol", "Quic"))
+        .and(query_param(
+            "primaryZoneTransferTsigKeyName",
+            "transfer-key",
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_error(
+            "Zone options can only be set for an existing zone: no such zone.",
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let result = zone
+        .set_transfer_options(&ZoneTransferOptions {
+            primary_name_server_addresses: Some(vec!["192.0.2.1".to_string()]),
+            primary_zone_transfer_protocol: Some(ZoneTransferProtocol::Quic),
+            primary_zone_transfer_tsig_key_name: Some("transfer-key".to_string()),
+            ..Default::default()
+        })
+        .await;
+    assert!(matches!(result, Err(ApiError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_update_record_ttl_and_value_in_place() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    let old = mock_record(
+        "www.example.com",
+        "A",
+        300,
+        json!({ "ipAddress": "192.0.2.1" }),
+    );
+    mount_records(&server, "www.example.com", vec![old.clone()]).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/update"))
+        .and(query_param("zone", "example.com"))
+        .and(query_param("domain", "www.example.com"))
+        .and(query_param("type", "A"))
+        .and(query_param("ttl", "60"))
+        .and(query_param("ipAddress", "192.0.2.1"))
+        .and(query_param("newIpAddress", "192.0.2.2"))
+        .and(query_param_is_missing("newDomain"))
+        .respond_with(updated(mock_record(
+            "www.example.com",
+            "A",
+            60,
+            json!({ "ipAddress": "192.0.2.2" }),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // The record must never be deleted and re-added.
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/delete"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({ "status": "ok" })))
+        .expect(0)
+        .mount(&server)
+        .await;
+
+    let record_id = record_id(&server, &zone, &old).await;
+    let record = zone
+        .update_record(
+            &record_id,
+            "www",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            60,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.host, "www.example.com");
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+    assert_eq!(record.ttl, 60);
+    assert_ne!(record.id, record_id);
+}
+
+#[tokio::test]
+async fn test_update_record_renames_mx() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    let old = mock_record(
+        "example.com",
+        "MX",
+        3600,
+        json!({ "preference": 10, "exchange": "mail.example.com" }),
+    );
+    let record_id = record_id(&server, &zone, &old).await;
+    mount_records(&server, "example.com", vec![old]).await;
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/update"))
+        .and(query_param("domain", "example.com"))
+        .and(query_param("newDomain", "sub.example.com"))
+        .and(query_param("type", "MX"))
+        .and(query_param("preference", "10"))
+        .and(query_param("newPreference", "20"))
+        .and(query_param("exchange", "mail.example.com"))
+        .and(query_param("newExchange", "mx.example.com"))
+        .respond_with(updated(mock_record(
+            "sub.example.com",
+            "MX",
+            3600,
+            json!({ "preference": 20, "exchange": "mx.example.com" }),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .update_record(
+            &record_id,
+            "sub",
+            &RecordData::MX {
+                priority: 20,
+                mail_server: "mx.example.com".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.host, "sub.example.com");
+}
+
+#[tokio::test]
+async fn test_update_record_rejects_type_change() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    let old = mock_record(
+        "www.example.com",
+        "A",
+        300,
+        json!({ "ipAddress": "192.0.2.1" }),
+    );
+    let record_id = record_id(&server, &zone, &old).await;
+    mount_records(&server, "www.example.com", vec![old]).await;
+
+    let result = zone
+        .update_record(
+            &record_id,
+            "www",
+            &RecordData::CNAME("target.example.com".to_string()),
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+}
+
+#[tokio::test]
+async fn test_update_record_not_found() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    mount_records(&server, "www.example.com", vec![]).await;
+
+    let result = zone
+        .update_record(
+            "www.example.com:A:0",
+            "www",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            300,
+        )
+        .await;
+    assert!(matches!(
+        result,
+        Err(CreateRecordError::Custom(ApiError::NotFound))
+    ));
+}
+
+// =============================================================================
+// API Client Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_client_update_record_params() {
+    let server = setup_mock_server().await;
+    let client = Client::new(&server.uri(), TOKEN).unwrap();
+
+    // TXT values are URL-encoded and passed as `text`/`newText`.
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/update"))
+        .and(query_param("type", "TXT"))
+        .and(query_param("text", "v=spf1 -all"))
+        .and(query_param("newText", "v=spf1 mx -all"))
+        .respond_with(updated(mock_record(
+            "example.com",
+            "TXT",
+            300,
+            json!({ "text": "v=spf1 mx -all" }),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // CNAME records only carry the new target.
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/update"))
+        .and(query_param("type", "CNAME"))
+        .and(query_param("cname", "new.example.net"))
+        .and(query_param_is_missing("newCname"))
+        .respond_with(updated(mock_record(
+            "www.example.com",
+            "CNAME",
+            300,
+            json!({ "cname": "new.example.net" }),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Unknown types use `rdata`/`newRData`.
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/update"))
+        .and(query_param("type", "SSHFP"))
+        .and(query_param("rdata", "1 1 abcd"))
+        .and(query_param("newRData", "1 1 ef01"))
+        .respond_with(updated(mock_record(
+            "host.example.com",
+            "SSHFP",
+            300,
+            json!({ "value": "1 1 ef01" }),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let response = client
+        .update_record(
+            "example.com",
+            "example.com",
+            None,
+            "TXT",
+            300,
+            &RecordParams::TXT {
+                text: "v=spf1 -all".to_string(),
+            },
+            &RecordParams::TXT {
+                text: "v=spf1 mx -all".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.updated_record.record_type, "TXT");
+
+    client
+        .update_record(
+            "example.com",
+            "www.example.com",
+            None,
+            "CNAME",
+            300,
+            &RecordParams::CNAME {
+                cname: "old.example.net".to_string(),
+            },
+            &RecordParams::CNAME {
+                cname: "new.example.net".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+
+    client
+        .update_record(
+            "example.com",
+            "host.example.com",
+            None,
+            "SSHFP",
+            300,
+            &RecordParams::Other {
+                value: "1 1 abcd".to_string(),
+            },
+            &RecordParams::Other {
+                value: "1 1 ef01".to_string(),
+            },
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_client_update_record_errors() {
+    let server = setup_mock_server().await;
+    let client = Client::new(&server.uri(), TOKEN).unwrap();
+
+    Mock::given(method("GET"))
+        .and(path("/api/zones/records/update"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_error("Cannot update record: record does not exist.")),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    // Mismatched record types are rejected before any request is sent.
+    let result = client
+        .update_record(
+            "example.com",
+            "www.example.com",
+            None,
+            "A",
+            300,
+            &RecordParams::A {
+                ip_address: "192.0.2.1".to_string(),
+            },
+            &RecordParams::AAAA {
+                ip_address: "2001:db8::1".to_string(),
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(ApiError::InvalidRecord)));
+
+    let result = client
+        .update_record(
+            "example.com",
+            "www.example.com",
+            None,
+            "A",
+            300,
+            &RecordParams::A {
+                ip_address: "192.0.2.1".to_string(),
+            },
+            &RecordParams::A {
+                ip_address: "192.0.2.2".to_string(),
+            },
+        )
+        .await;
+    assert!(matches!(result, Err(ApiError::NotFound)));
+}
@@ -0,0 +1,388 @@
+//! Mock-based tests for the Volcengine TrafficRoute DNS provider.
+//!
+//! These tests use `wiremock` to simulate the TrafficRoute DNS OpenAPI
+//! without requiring a Volcengine account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - `HMAC-SHA256` request signing (the `Authorization` and `X-Date` headers)
+//! - Zone operations (list with pagination, get by ZID or name, create, delete)
+//! - Record conversion (MX/SRV priorities in the value)
+//! - Record creation, update and deletion
+//! - Error handling (error codes in `ResponseMetadata.Error`)
+//!
+//! # API Structure
+//!
+//! - All actions: `POST /?Action={action}&Version=2018-08-01` with a JSON body
+//! - Responses: `{"ResponseMetadata": {...}, "Result": {...}}`
+//! - Errors: `ResponseMetadata.Error` with `Code` and `Message`
+
+use crate::common::setup_mock_server;
+use crate::common::volcengine::*;
+
+use manydns::volcengine::{Credentials, VolcengineProvider, VolcengineZone};
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteRecordError, DeleteZone,
+    Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header_exists, header_regex, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> VolcengineProvider {
+    VolcengineProvider::with_base_url(
+        Credentials::new("AKLTexampleaccesskey", "c2VjcmV0LWFjY2Vzcy1rZXk="),
+        &server.uri(),
+    )
+    .expect("Failed to create provider")
+}
+
+fn action(name: &str) -> wiremock::MockBuilder {
+    Mock::given(method("POST"))
+        .and(path("/"))
+        .and(query_param("Action", name))
+        .and(query_param("Version", "2018-08-01"))
+}
+
+async fn zone(server: &MockServer) -> VolcengineZone {
+    action("QueryZone")
+        .and(body_json(json!({ "ZID": 12345 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "QueryZone",
+            json!({
+                "ZoneName": "example.com",
+                "AllocateDNSServerList": ["ns1.volcdns.com", "ns2.volcdns.com"]
+            }),
+        )))
+        .mount(server)
+        .await;
+
+    provider(server).get_zone("12345").await.unwrap()
+}
+
+// =============================================================================
+// Zone Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_signed_and_paginated() {
+    let server = setup_mock_server().await;
+
+    let first_page: Vec<_> = (0..100)
+        .map(|i| mock_zone(i, &format!("example{}.com", i)))
+        .collect();
+
+    action("ListZones")
+        .and(header_regex(
+            "Authorization",
+            r"^HMAC-SHA256 Credential=AKLTexampleaccesskey/\d{8}/cn-north-1/DNS/request, SignedHeaders=content-type;host;x-content-sha256;x-date, Signature=[0-9a-f]{64}$",
+        ))
+        .and(header_regex("X-Date", r"^\d{8}T\d{6}Z$"))
+        .and(header_exists("X-Content-Sha256"))
+        .and(body_json(json!({ "PageNumber": 1, "PageSize": 100 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "ListZones",
+            json!({ "Zones": first_page, "Total": 101 }),
+        )))
+        .mount(&server)
+        .await;
+
+    action("ListZones")
+        .and(body_json(json!({ "PageNumber": 2, "PageSize": 100 })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "ListZones",
+            json!({ "Zones": [mock_zone(12345, "example.com")], "Total": 101 }),
+        )))
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+
+    assert_eq!(zones.len(), 101);
+    assert_eq!(zones[100].id(), "12345");
+    assert_eq!(zones[100].domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone_by_id() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    assert_eq!(zone.id(), "12345");
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.name_servers().len(), 2);
+}
+
+#[tokio::test]
+async fn test_get_zone_by_name() {
+    let server = setup_mock_server().await;
+
+    action("ListZones")
+        .and(body_json(
+            json!({ "PageNumber": 1, "PageSize": 100, "Key": "example.com" }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "ListZones",
+            json!({
+                "Zones": [mock_zone(1, "sub.example.com"), mock_zone(12345, "example.com")],
+                "Total": 2
+            }),
+        )))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.id(), "12345");
+}
+
+#[tokio::test]
+async fn test_get_zone_errors() {
+    let server = setup_mock_server().await;
+
+    action("QueryZone")
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "QueryZone",
+            "ZoneNotFound",
+            "The zone does not exist.",
+        )))
+        .mount(&server)
+        .await;
+
+    action("ListZones")
+        .respond_with(ResponseTemplate::new(401).set_body_json(mock_error(
+            "ListZones",
+            "InvalidAccessKey",
+            "The access key is invalid.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("999").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_and_delete_zone() {
+    let server = setup_mock_server().await;
+
+    action("CreateZone")
+        .and(body_json(json!({ "ZoneName": "example.com" })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(mock_response("CreateZone", mock_zone(12345, "example.com"))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    action("DeleteZone")
+        .and(body_json(json!({ "ZID": 12345 })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_response("DeleteZone", json!(null))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let provider = provider(&server);
+    let zone = provider.create_zone("example.com").await.unwrap();
+    assert_eq!(zone.id(), "12345");
+
+    provider.delete_zone(zone.id()).await.unwrap();
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    action("ListRecords")
+        .and(body_json(
+            json!({ "ZID": 12345, "PageNumber": 1, "PageSize": 100 }),
+        ))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "ListRecords",
+            json!({
+                "Records": [
+                    mock_record("r1", "@", "A", "192.0.2.1"),
+                    mock_record("r2", "@", "MX", "10 mail.example.com."),
+                    mock_record("r3", "_sip._tcp", "SRV", "20 5 5060 sip.example.com")
+                ],
+                "TotalCount": 3
+            }),
+        )))
+        .mount(&server)
+        .await;
+
+    let records = zone.list_records().await.unwrap();
+
+    assert_eq!(records.len(), 3);
+    assert_eq!(records[0].host, "@");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 20,
+            weight: 5,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_create_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    action("CreateRecord")
+        .and(body_json(json!({
+            "ZID": 12345,
+            "Host": "www",
+            "Type": "A",
+            "Value": "192.0.2.1",
+            "TTL": 600,
+            "Line": "default"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "CreateRecord",
+            mock_record("new-id", "www", "A", "192.0.2.1"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .create_record(
+            "www.example.com",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 1)),
+            600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "new-id");
+    assert_eq!(record.host, "www");
+}
+
+#[tokio::test]
+async fn test_create_record_invalid() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    action("CreateRecord")
+        .respond_with(ResponseTemplate::new(400).set_body_json(mock_error(
+            "CreateRecord",
+            "InvalidParameter.Value",
+            "The record value is invalid.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = zone
+        .create_record("www", &RecordData::CNAME("target.com".to_string()), 600)
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::InvalidRecord)));
+
+    let result = zone
+        .create_record(
+            "www",
+            &RecordData::Other {
+                typ: "SSHFP".to_string(),
+                value: "1 1 abc".to_string(),
+            },
+            600,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    action("UpdateRecord")
+        .and(body_json(json!({
+            "RecordID": "r2",
+            "Host": "@",
+            "Type": "MX",
+            "Value": "5 mail.example.com",
+            "TTL": 3600,
+            "Line": "default"
+        })))
+        .respond_with(ResponseTemplate::new(200).set_body_json(mock_response(
+            "UpdateRecord",
+            mock_record("r2", "@", "MX", "5 mail.example.com"),
+        )))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let record = zone
+        .update_record(
+            "r2",
+            "@",
+            &RecordData::MX {
+                priority: 5,
+                mail_server: "mail.example.com".to_string(),
+            },
+            3600,
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(record.id, "r2");
+    assert_eq!(record.ttl, 3600);
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    action("DeleteRecord")
+        .and(body_json(json!({ "RecordID": "r1" })))
+        .respond_with(
+            ResponseTemplate::new(200).set_body_json(mock_response("DeleteRecord", json!(null))),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    zone.delete_record("r1").await.unwrap();
+}
+
+#[tokio::test]
+async fn test_delete_record_not_found() {
+    let server = setup_mock_server().await;
+    let zone = zone(&server).await;
+
+    action("DeleteRecord")
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error(
+            "DeleteRecord",
+            "RecordNotFound",
+            "The record does not exist.",
+        )))
+        .mount(&server)
+        .await;
+
+    let result = zone.delete_record("missing").await;
+    assert!(matches!(result, Err(DeleteRecordError::NotFound)));
+}
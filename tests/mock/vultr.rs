@@ -0,0 +1,359 @@
+//! Mock-based tests for the Vultr provider.
+//!
+//! These tests use `wiremock` to simulate the Vultr API v2 without
+//! requiring a Vultr account.
+//!
+//! # Coverage
+//!
+//! This module provides testing for:
+//! - Bearer token authentication header
+//! - Domain operations (list with cursor pagination, get, create, delete)
+//! - Record conversion (apex name, MX/SRV priority, SRV data, quoted TXT)
+//! - Record creation, update and deletion
+//! - Error handling (401, 404)
+//!
+//! # API Structure
+//!
+//! - Domains: GET/POST `/v2/domains`, GET/DELETE `/v2/domains/{domain}`
+//! - Records: GET/POST `/v2/domains/{domain}/records`, GET/PATCH/DELETE `/v2/domains/{domain}/records/{id}`
+//! - Pagination: `?per_page=500&cursor=` with `meta.links.next`
+//! - Auth: `Authorization: Bearer {api_key}` header
+
+use crate::common::setup_mock_server;
+use crate::common::vultr::*;
+
+use manydns::vultr::VultrProvider;
+use manydns::{
+    CreateRecord, CreateRecordError, CreateZone, DeleteRecord, DeleteZone, DeleteZoneError,
+    Provider, RecordData, RetrieveZoneError, Zone,
+};
+use serde_json::json;
+use std::net::Ipv4Addr;
+use wiremock::matchers::{body_json, header, method, path, query_param};
+use wiremock::{Mock, MockServer, ResponseTemplate};
+
+fn provider(server: &MockServer) -> VultrProvider {
+    VultrProvider::with_base_url("test-key", &server.uri()).expect("Failed to create provider")
+}
+
+async fn mount_domain(server: &MockServer) {
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com"))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "domain": mock_domain("example.com") })),
+        )
+        .mount(server)
+        .await;
+}
+
+// =============================================================================
+// Domain Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_zones_pagination() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .and(query_param("cursor", "bmV4dF9fZXhhbXBsZS5vcmc="))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.org")],
+            "meta": mock_meta(2, "")
+        })))
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .and(query_param("per_page", "500"))
+        .and(header("Authorization", "Bearer test-key"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "domains": [mock_domain("example.com")],
+            "meta": mock_meta(2, "bmV4dF9fZXhhbXBsZS5vcmc=")
+        })))
+        .up_to_n_times(1)
+        .mount(&server)
+        .await;
+
+    let zones = provider(&server).list_zones().await.unwrap();
+    let domains: Vec<&str> = zones.iter().map(|z| z.domain()).collect();
+    assert_eq!(domains, vec!["example.com", "example.org"]);
+    assert_eq!(zones[0].id(), "example.com");
+}
+
+#[tokio::test]
+async fn test_get_zone() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("example.com.").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+    assert_eq!(zone.dns_sec(), Some("disabled"));
+}
+
+#[tokio::test]
+async fn test_get_zone_not_found() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Domain not found", 404)))
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).get_zone("missing.com").await;
+    assert!(matches!(result, Err(RetrieveZoneError::NotFound)));
+}
+
+#[tokio::test]
+async fn test_invalid_key_is_unauthorized() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains"))
+        .respond_with(
+            ResponseTemplate::new(401).set_body_json(mock_error("Invalid API token.", 401)),
+        )
+        .mount(&server)
+        .await;
+
+    let result = provider(&server).list_zones().await;
+    assert!(matches!(result, Err(RetrieveZoneError::Unauthorized)));
+}
+
+#[tokio::test]
+async fn test_create_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains"))
+        .and(body_json(json!({ "domain": "example.com" })))
+        .respond_with(
+            ResponseTemplate::new(200)
+                .set_body_json(json!({ "domain": mock_domain("example.com") })),
+        )
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).create_zone("example.com").await.unwrap();
+    assert_eq!(zone.domain(), "example.com");
+}
+
+#[tokio::test]
+async fn test_delete_zone() {
+    let server = setup_mock_server().await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/domains/example.com"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("DELETE"))
+        .and(path("/v2/domains/missing.com"))
+        .respond_with(ResponseTemplate::new(404).set_body_json(mock_error("Domain not found", 404)))
+        .mount(&server)
+        .await;
+
+    provider(&server).delete_zone("example.com").await.unwrap();
+    let result = provider(&server).delete_zone("missing.com").await;
+    assert!(matches!(result, Err(DeleteZoneError::NotFound)));
+}
+
+// =============================================================================
+// Record Tests
+// =============================================================================
+
+#[tokio::test]
+async fn test_list_records() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com/records"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "records": [
+                mock_record("a-id", "A", "www", "192.0.2.1", -1),
+                mock_record("mx-id", "MX", "", "mail.example.com", 10),
+                mock_record("srv-id", "SRV", "_sip._tcp", "20 5060 sip.example.com", 10),
+                mock_record("txt-id", "TXT", "", "\"v=spf1 -all\"", -1),
+                mock_record("caa-id", "CAA", "", "0 issue \"letsencrypt.org\"", -1)
+            ],
+            "meta": mock_meta(5, "")
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let records = zone.list_records().await.unwrap();
+    assert_eq!(records.len(), 5);
+
+    assert_eq!(records[0].id, "a-id");
+    assert_eq!(records[0].host, "www");
+    assert_eq!(records[0].data, RecordData::A(Ipv4Addr::new(192, 0, 2, 1)));
+
+    assert_eq!(records[1].host, "@");
+    assert_eq!(
+        records[1].data,
+        RecordData::MX {
+            priority: 10,
+            mail_server: "mail.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[2].host, "_sip._tcp");
+    assert_eq!(
+        records[2].data,
+        RecordData::SRV {
+            priority: 10,
+            weight: 20,
+            port: 5060,
+            target: "sip.example.com".to_string()
+        }
+    );
+
+    assert_eq!(records[3].data, RecordData::TXT("v=spf1 -all".to_string()));
+    assert_eq!(records[4].data.get_type(), "CAA");
+}
+
+#[tokio::test]
+async fn test_create_srv_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains/example.com/records"))
+        .and(body_json(json!({
+            "type": "SRV",
+            "name": "_sip._tcp",
+            "data": "20 5060 sip.example.com",
+            "ttl": 300,
+            "priority": 10
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "record": mock_record("srv-id", "SRV", "_sip._tcp", "20 5060 sip.example.com", 10)
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record(
+            "_sip._tcp.example.com",
+            &RecordData::SRV {
+                priority: 10,
+                weight: 20,
+                port: 5060,
+                target: "sip.example.com".to_string(),
+            },
+            300,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.id, "srv-id");
+}
+
+#[tokio::test]
+async fn test_create_txt_record_is_quoted() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("POST"))
+        .and(path("/v2/domains/example.com/records"))
+        .and(body_json(json!({
+            "type": "TXT",
+            "name": "",
+            "data": "\"v=spf1 -all\"",
+            "ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(201).set_body_json(json!({
+            "record": mock_record("txt-id", "TXT", "", "\"v=spf1 -all\"", -1)
+        })))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .create_record("@", &RecordData::TXT("v=spf1 -all".to_string()), 300)
+        .await
+        .unwrap();
+    assert_eq!(record.host, "@");
+    assert_eq!(record.data, RecordData::TXT("v=spf1 -all".to_string()));
+}
+
+#[tokio::test]
+async fn test_create_unsupported_record_type() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let result = zone
+        .create_record(
+            "@",
+            &RecordData::Other {
+                typ: "PTR".to_string(),
+                value: "host.example.com".to_string(),
+            },
+            300,
+        )
+        .await;
+    assert!(matches!(result, Err(CreateRecordError::UnsupportedType)));
+}
+
+#[tokio::test]
+async fn test_update_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("PATCH"))
+        .and(path("/v2/domains/example.com/records/a-id"))
+        .and(body_json(json!({
+            "type": "A",
+            "name": "www",
+            "data": "192.0.2.2",
+            "ttl": 300
+        })))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+    Mock::given(method("GET"))
+        .and(path("/v2/domains/example.com/records/a-id"))
+        .respond_with(ResponseTemplate::new(200).set_body_json(json!({
+            "record": mock_record("a-id", "A", "www", "192.0.2.2", -1)
+        })))
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    let record = zone
+        .update_record(
+            "a-id",
+            "www",
+            &RecordData::A(Ipv4Addr::new(192, 0, 2, 2)),
+            300,
+        )
+        .await
+        .unwrap();
+    assert_eq!(record.data, RecordData::A(Ipv4Addr::new(192, 0, 2, 2)));
+}
+
+#[tokio::test]
+async fn test_delete_record() {
+    let server = setup_mock_server().await;
+    mount_domain(&server).await;
+
+    Mock::given(method("DELETE"))
+        .and(path("/v2/domains/example.com/records/a-id"))
+        .respond_with(ResponseTemplate::new(204))
+        .expect(1)
+        .mount(&server)
+        .await;
+
+    let zone = provider(&server).get_zone("example.com").await.unwrap();
+    zone.delete_record("a-id").await.unwrap();
+}
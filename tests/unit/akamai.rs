@@ -0,0 +1,86 @@
+//! Unit tests for Akamai Edge DNS module helpers.
+//!
+//! Tests for the EdgeGrid (`EG1-HMAC-SHA256`) request signature (checked
+//! against signatures computed independently for the same input).
+
+use manydns::akamai::api::{sign, Credentials, SigningRequest};
+
+const HOST: &str = "akab-baseurl-xxxxxxxxxxx-xxxxxxxxxxxxx.luna.akamaiapis.net";
+const TIMESTAMP: &str = "20240101T08:00:00+0000";
+const NONCE: &str = "nonce-xx-xxxx-xxxx-xxxx-xxxxxxxxxxxx";
+const BODY: &[u8] = br#"{"name":"www.example.com","type":"A","ttl":300,"rdata":["192.0.2.1"]}"#;
+const PREFIX: &str = "EG1-HMAC-SHA256 client_token=akab-client-token-xxx-xxxxxxxxxxxxxxxx;\
+                      access_token=akab-access-token-xxx-xxxxxxxxxxxxxxxx;\
+                      timestamp=20240101T08:00:00+0000;\
+                      nonce=nonce-xx-xxxx-xxxx-xxxx-xxxxxxxxxxxx;";
+
+fn test_credentials() -> Credentials {
+    Credentials::new(
+        HOST,
+        "akab-client-token-xxx-xxxxxxxxxxxxxxxx",
+        "SOMESECRETxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx=",
+        "akab-access-token-xxx-xxxxxxxxxxxxxxxx",
+    )
+}
+
+fn signature(method: &str, path_and_query: &str, body: &[u8]) -> String {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method,
+            host: HOST,
+            path_and_query,
+            body,
+            timestamp: TIMESTAMP,
+            nonce: NONCE,
+        },
+    );
+    authorization
+        .strip_prefix(PREFIX)
+        .expect("unexpected authorization prefix")
+        .to_string()
+}
+
+#[test]
+fn test_sign_get_with_query() {
+    assert_eq!(
+        signature(
+            "GET",
+            "/config-dns/v2/zones?page=1&pageSize=1000&showAll=true",
+            b""
+        ),
+        "signature=/yZ2eQl/r/ncOzdt68Q8mJHPqwxJNIfJCELqrbSvNgM="
+    );
+}
+
+#[test]
+fn test_sign_post_hashes_body() {
+    assert_eq!(
+        signature(
+            "POST",
+            "/config-dns/v2/zones/example.com/names/www.example.com/types/A",
+            BODY
+        ),
+        "signature=iwppNMzbpZJlqngG4QL/yulJcejMoqox6CbTaafGdcs="
+    );
+}
+
+#[test]
+fn test_sign_put_ignores_body() {
+    assert_eq!(
+        signature(
+            "PUT",
+            "/config-dns/v2/zones/example.com/names/www.example.com/types/A",
+            BODY
+        ),
+        "signature=ydfhUFC8HwFMFYjLTOIfsjWYOWP1alTw3gFlbxbUdjE="
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secrets() {
+    let debug = format!("{:?}", test_credentials());
+    assert!(debug.contains("akab-client-token-xxx-xxxxxxxxxxxxxxxx"));
+    assert!(!debug.contains("SOMESECRET"));
+    assert!(!debug.contains("akab-access-token"));
+}
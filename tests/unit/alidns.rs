@@ -0,0 +1,41 @@
+//! Unit tests for Alidns module helpers.
+//!
+//! Tests for the HMAC-SHA1 RPC signature, checked against the example from
+//! Alibaba Cloud's signature documentation.
+
+use manydns::alidns::api::rpc_signature;
+
+// =============================================================================
+// Signature Tests
+// =============================================================================
+
+#[test]
+fn test_rpc_signature_documented_example() {
+    let params = [
+        ("AccessKeyId", "testid"),
+        ("Action", "DescribeDomainRecords"),
+        ("DomainName", "example.com"),
+        ("Format", "XML"),
+        ("SignatureMethod", "HMAC-SHA1"),
+        ("SignatureNonce", "f59ed6a9-83fc-473b-9cc6-99c95df3856e"),
+        ("SignatureVersion", "1.0"),
+        ("Timestamp", "2016-03-24T16:41:54Z"),
+        ("Version", "2015-01-09"),
+    ];
+
+    assert_eq!(
+        rpc_signature("testsecret", "GET", &params),
+        "uRpHwaSEt3J+6KQD//svCh/x+pI="
+    );
+}
+
+#[test]
+fn test_rpc_signature_is_order_independent() {
+    let sorted = [("Action", "DescribeDomains"), ("PageNumber", "1")];
+    let reversed = [("PageNumber", "1"), ("Action", "DescribeDomains")];
+
+    assert_eq!(
+        rpc_signature("secret", "GET", &sorted),
+        rpc_signature("secret", "GET", &reversed)
+    );
+}
@@ -0,0 +1,61 @@
+//! Unit tests for Baidu AI Cloud module helpers.
+//!
+//! Tests for the `bce-auth-v1` request signature (checked against signatures
+//! computed independently for the same input).
+
+use manydns::baiducloud::api::{sign, Credentials, SigningRequest};
+
+const TIMESTAMP: &str = "2024-01-01T08:00:00Z";
+
+fn test_credentials() -> Credentials {
+    Credentials::new("exampleaccesskeyid", "examplesecretaccesskey")
+}
+
+#[test]
+fn test_sign_get_with_query() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "GET",
+            path: "/v1/dns/zone",
+            query: &[("name", "example.com"), ("maxKeys", "1000")],
+            headers: &[("x-bce-date", TIMESTAMP), ("Host", "dns.baidubce.com")],
+            timestamp: TIMESTAMP,
+            expiration: 1800,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "bce-auth-v1/exampleaccesskeyid/2024-01-01T08:00:00Z/1800/host;x-bce-date/\
+         ed379540fc3d040f8aab4857c13bfac37988c06c9b0d45ad82bd7998af3f6095"
+    );
+}
+
+#[test]
+fn test_sign_put_record() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "PUT",
+            path: "/v1/dns/zone/example.com/record/1234",
+            query: &[],
+            headers: &[("host", "dns.baidubce.com"), ("x-bce-date", TIMESTAMP)],
+            timestamp: TIMESTAMP,
+            expiration: 1800,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "bce-auth-v1/exampleaccesskeyid/2024-01-01T08:00:00Z/1800/host;x-bce-date/\
+         2abe1f272aa0971e2e48bc56fac3aaa644bdc44a7d92eb56e26075c2459f3556"
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secret() {
+    let debug = format!("{:?}", test_credentials());
+    assert!(debug.contains("exampleaccesskeyid"));
+    assert!(!debug.contains("examplesecretaccesskey"));
+}
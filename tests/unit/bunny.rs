@@ -0,0 +1,51 @@
+//! Unit tests for bunny.net module helpers.
+//!
+//! Tests for the mapping between Bunny's numeric record types and
+//! [`RecordType`].
+
+use manydns::bunny::BunnyRecordType;
+use manydns::types::RecordType;
+
+#[test]
+fn test_record_type_numbers() {
+    for code in 0..=12u8 {
+        let typ = BunnyRecordType::from_u8(code).expect("known record type");
+        assert_eq!(typ.as_u8(), code);
+        assert_eq!(BunnyRecordType::parse(typ.as_str()), Some(typ));
+    }
+    assert_eq!(BunnyRecordType::from_u8(13), None);
+}
+
+#[test]
+fn test_record_type_to_standard() {
+    assert_eq!(BunnyRecordType::A.to_record_type(), Some(RecordType::A));
+    assert_eq!(BunnyRecordType::MX.to_record_type(), Some(RecordType::MX));
+    assert_eq!(BunnyRecordType::CAA.to_record_type(), Some(RecordType::CAA));
+    assert_eq!(BunnyRecordType::NS.to_record_type(), Some(RecordType::NS));
+    assert_eq!(BunnyRecordType::Redirect.to_record_type(), None);
+    assert_eq!(BunnyRecordType::PullZone.to_record_type(), None);
+}
+
+#[test]
+fn test_record_type_from_standard() {
+    assert_eq!(
+        BunnyRecordType::from_record_type(RecordType::AAAA),
+        Some(BunnyRecordType::AAAA)
+    );
+    assert_eq!(
+        BunnyRecordType::from_record_type(RecordType::SRV),
+        Some(BunnyRecordType::SRV)
+    );
+    assert_eq!(BunnyRecordType::from_record_type(RecordType::SOA), None);
+    assert_eq!(BunnyRecordType::from_record_type(RecordType::DS), None);
+}
+
+#[test]
+fn test_record_type_parse() {
+    assert_eq!(BunnyRecordType::parse("txt"), Some(BunnyRecordType::TXT));
+    assert_eq!(
+        BunnyRecordType::parse("PULLZONE"),
+        Some(BunnyRecordType::PullZone)
+    );
+    assert_eq!(BunnyRecordType::parse("HINFO"), None);
+}
@@ -0,0 +1,96 @@
+//! Unit tests for Exoscale module helpers.
+//!
+//! Tests for endpoint selection and the `EXO2-HMAC-SHA256` request signature
+//! (checked against signatures computed independently for the same input).
+
+use manydns::exoscale::api::{endpoint_for_zone, sign, Credentials, SigningRequest};
+
+const EXPIRES: u64 = 1700000600;
+
+fn test_credentials() -> Credentials {
+    Credentials::new("EXO0123456789abcdef01234567", "dHVzaWx2ZXJ0ZXN0c2VjcmV0")
+}
+
+// =============================================================================
+// Endpoint Tests
+// =============================================================================
+
+#[test]
+fn test_endpoint_for_zone() {
+    assert_eq!(
+        endpoint_for_zone("de-fra-1"),
+        "https://api-de-fra-1.exoscale.com/v2"
+    );
+}
+
+// =============================================================================
+// Signature Tests
+// =============================================================================
+
+#[test]
+fn test_sign_get() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "GET",
+            path: "/v2/dns-domain",
+            body: "",
+            query: &[],
+            expires: EXPIRES,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "EXO2-HMAC-SHA256 credential=EXO0123456789abcdef01234567,expires=1700000600,\
+         signature=Llr4aMH7bxXPXMXWqSxH9aUA/Ai1LqAO8//v+2VFfzc="
+    );
+}
+
+#[test]
+fn test_sign_post_with_body() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "POST",
+            path: "/v2/dns-domain/3b1e6c4e-0000-4000-8000-000000000001/record",
+            body: r#"{"name":"www","type":"A","content":"192.0.2.1","ttl":300}"#,
+            query: &[],
+            expires: EXPIRES,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "EXO2-HMAC-SHA256 credential=EXO0123456789abcdef01234567,expires=1700000600,\
+         signature=Zbtv8BtraA407sKxoRR8PVIGWzNtcbhDhuVvi6N/XFM="
+    );
+}
+
+#[test]
+fn test_sign_with_query_args() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "GET",
+            path: "/v2/dns-domain",
+            body: "",
+            query: &[("limit", "10"), ("zone", "ch-gva-2")],
+            expires: EXPIRES,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "EXO2-HMAC-SHA256 credential=EXO0123456789abcdef01234567,\
+         signed-query-args=limit;zone,expires=1700000600,\
+         signature=ZzbMb04Qd8rJZ5qukzH5kYKqP6o51SmX3fK9R57Mx50="
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secret() {
+    let debug = format!("{:?}", test_credentials());
+    assert!(debug.contains("EXO0123456789abcdef01234567"));
+    assert!(!debug.contains("dHVzaWx2ZXJ0ZXN0c2VjcmV0"));
+}
@@ -0,0 +1,89 @@
+//! Unit tests for Huawei Cloud module helpers.
+//!
+//! Tests for endpoint selection and the `SDK-HMAC-SHA256` request signature
+//! (checked against signatures computed independently for the same input).
+
+use manydns::huaweicloud::api::{endpoint_for_region, sign, Credentials, SigningRequest};
+
+const SDK_DATE: &str = "20191115T033655Z";
+
+fn test_credentials() -> Credentials {
+    Credentials::new(
+        "QTWAOYTTINDUT2QVKYUC",
+        "MFyfvK41ba2giqM7Uio6PznpdUKGpownRZlmVmHc",
+    )
+}
+
+// =============================================================================
+// Endpoint Tests
+// =============================================================================
+
+#[test]
+fn test_endpoint_for_region() {
+    assert_eq!(
+        endpoint_for_region("ap-southeast-1"),
+        "https://dns.ap-southeast-1.myhuaweicloud.com"
+    );
+}
+
+// =============================================================================
+// Signature Tests
+// =============================================================================
+
+#[test]
+fn test_sign_get_with_query() {
+    let headers = [("x-sdk-date", SDK_DATE)];
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "GET",
+            host: "dns.ap-southeast-1.myhuaweicloud.com",
+            path: "/v2/zones",
+            query: "limit=500&offset=0&type=public",
+            headers: &headers,
+            payload: b"",
+            sdk_date: SDK_DATE,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "SDK-HMAC-SHA256 Access=QTWAOYTTINDUT2QVKYUC, SignedHeaders=host;x-sdk-date, \
+         Signature=39a2d10e466c603383e21b8ebc2c79de6d575ee761a66c2cf7b76786edd794c3"
+    );
+}
+
+#[test]
+fn test_sign_post_with_body_and_project() {
+    let headers = [
+        ("x-sdk-date", SDK_DATE),
+        ("content-type", "application/json"),
+        ("x-project-id", "0123456789abcdef0123456789abcdef"),
+    ];
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "POST",
+            host: "dns.ap-southeast-1.myhuaweicloud.com",
+            path: "/v2/zones/2c9eb155587194ec01587224c9f90149/recordsets",
+            query: "",
+            headers: &headers,
+            payload: br#"{"name":"www.example.com.","type":"A","ttl":300,"records":["192.0.2.1"]}"#,
+            sdk_date: SDK_DATE,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "SDK-HMAC-SHA256 Access=QTWAOYTTINDUT2QVKYUC, \
+         SignedHeaders=content-type;host;x-project-id;x-sdk-date, \
+         Signature=e9977bf9751bd92bbcc6d495579ba50e13a2c9fdc80ea50a88ba6ee5f00eb661"
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secret() {
+    let debug = format!("{:?}", test_credentials());
+    assert!(debug.contains("QTWAOYTTINDUT2QVKYUC"));
+    assert!(!debug.contains("MFyfvK41ba2giqM7Uio6PznpdUKGpownRZlmVmHc"));
+}
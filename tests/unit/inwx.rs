@@ -0,0 +1,45 @@
+//! Unit tests for INWX module helpers.
+//!
+//! Tests for the TOTP codes used to unlock two-factor sessions, checked
+//! against the SHA-1 test vectors of RFC 6238 (truncated to 6 digits).
+
+use manydns::inwx::api::{totp, ClientConfig};
+use manydns::inwx::InwxError;
+
+/// Base32 of the RFC 6238 SHA-1 test secret `12345678901234567890`.
+const RFC_SECRET: &str = "GEZDGNBVGY3TQOJQGEZDGNBVGY3TQOJQ";
+
+#[test]
+fn test_totp_rfc6238_vectors() {
+    assert_eq!(totp(RFC_SECRET, 59).unwrap(), "287082");
+    assert_eq!(totp(RFC_SECRET, 1111111109).unwrap(), "081804");
+    assert_eq!(totp(RFC_SECRET, 1111111111).unwrap(), "050471");
+    assert_eq!(totp(RFC_SECRET, 1234567890).unwrap(), "005924");
+    assert_eq!(totp(RFC_SECRET, 2000000000).unwrap(), "279037");
+}
+
+#[test]
+fn test_totp_secret_formatting() {
+    // Secrets are often shown lowercase and grouped.
+    assert_eq!(
+        totp("gezd gnbv gy3t qojq gezd gnbv gy3t qojq", 59).unwrap(),
+        "287082"
+    );
+}
+
+#[test]
+fn test_totp_invalid_secret() {
+    assert!(matches!(
+        totp("not base32!", 59),
+        Err(InwxError::InvalidTotpSecret)
+    ));
+}
+
+#[test]
+fn test_config_debug_redacts_secrets() {
+    let config = ClientConfig::ote("user", "hunter2").with_totp_secret(RFC_SECRET);
+    let debug = format!("{:?}", config);
+    assert!(!debug.contains("hunter2"));
+    assert!(!debug.contains(RFC_SECRET));
+    assert!(config.api_url().contains("ote"));
+}
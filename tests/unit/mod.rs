@@ -10,3 +10,36 @@ mod namecheap;
 
 #[cfg(feature = "cloudflare")]
 mod http_config;
+
+#[cfg(feature = "route53")]
+mod route53;
+
+#[cfg(feature = "gcloud-dns")]
+mod gcloud;
+
+#[cfg(feature = "ovh")]
+mod ovh;
+
+#[cfg(feature = "alidns")]
+mod alidns;
+
+#[cfg(feature = "huaweicloud")]
+mod huaweicloud;
+
+#[cfg(feature = "bunny")]
+mod bunny;
+
+#[cfg(feature = "inwx")]
+mod inwx;
+
+#[cfg(feature = "exoscale")]
+mod exoscale;
+
+#[cfg(feature = "volcengine")]
+mod volcengine;
+
+#[cfg(feature = "baiducloud")]
+mod baiducloud;
+
+#[cfg(feature = "akamai")]
+mod akamai;
@@ -0,0 +1,67 @@
+//! Unit tests for OVHcloud module helpers.
+//!
+//! Tests for endpoint selection and the `$1$` request signature (checked
+//! against a SHA-1 digest computed independently for the same input).
+
+use manydns::ovh::api::{Credentials, Endpoint};
+
+// =============================================================================
+// Endpoint Tests
+// =============================================================================
+
+#[test]
+fn test_endpoint_names_and_urls() {
+    assert_eq!(Endpoint::default(), Endpoint::OvhEu);
+    assert_eq!(Endpoint::OvhEu.base_url(), "https://eu.api.ovh.com/1.0");
+    assert_eq!(Endpoint::OvhCa.base_url(), "https://ca.api.ovh.com/1.0");
+    assert_eq!(
+        Endpoint::OvhUs.base_url(),
+        "https://api.us.ovhcloud.com/1.0"
+    );
+
+    for endpoint in [Endpoint::OvhEu, Endpoint::OvhCa, Endpoint::OvhUs] {
+        assert_eq!(Endpoint::from_name(endpoint.name()), Some(endpoint));
+        assert_eq!(endpoint.to_string(), endpoint.name());
+    }
+    assert_eq!(Endpoint::from_name("kimsufi-eu"), None);
+}
+
+// =============================================================================
+// Signature Tests
+// =============================================================================
+
+#[test]
+fn test_signature() {
+    let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    let signature = credentials.sign(
+        "GET",
+        "https://eu.api.ovh.com/1.0/domain/zone",
+        "",
+        1_700_000_000,
+    );
+
+    assert_eq!(signature, "$1$25d043061df650fa0347cfc1d95aeba1bd5f070a");
+}
+
+#[test]
+fn test_signature_covers_body() {
+    let credentials = Credentials::new("app-key", "app-secret", "consumer-key");
+    let url = "https://eu.api.ovh.com/1.0/domain/zone/example.com/record";
+
+    assert_ne!(
+        credentials.sign("POST", url, r#"{"target":"192.0.2.1"}"#, 1_700_000_000),
+        credentials.sign("POST", url, r#"{"target":"192.0.2.2"}"#, 1_700_000_000)
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secrets() {
+    let debug = format!(
+        "{:?}",
+        Credentials::new("app-key", "app-secret", "consumer-key")
+    );
+
+    assert!(debug.contains("app-key"));
+    assert!(!debug.contains("app-secret"));
+    assert!(!debug.contains("consumer-key"));
+}
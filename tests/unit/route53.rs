@@ -0,0 +1,91 @@
+//! Unit tests for Route 53 module helpers.
+//!
+//! Tests for SigV4 signing (against the AWS test suite vectors), URI encoding
+//! and record name decoding.
+
+use manydns::route53::api::{decode_name, sign_v4, Credentials, SigningRequest};
+
+const AMZ_DATE: &str = "20150830T123600Z";
+
+fn test_credentials() -> Credentials {
+    Credentials::new("AKIDEXAMPLE", "wJalrXUtnFEMI/K7MDENG+bPxRfiCYEXAMPLEKEY")
+}
+
+fn test_request<'a>(query: &'a str, headers: &'a [(&'a str, &'a str)]) -> SigningRequest<'a> {
+    SigningRequest {
+        method: "GET",
+        host: "example.amazonaws.com",
+        path: "/",
+        query,
+        headers,
+        payload: b"",
+        region: "us-east-1",
+        service: "service",
+        amz_date: AMZ_DATE,
+    }
+}
+
+// =============================================================================
+// Signature Tests
+// =============================================================================
+
+#[test]
+fn test_sign_v4_get_vanilla() {
+    let headers = [("x-amz-date", AMZ_DATE)];
+    let authorization = sign_v4(&test_credentials(), &test_request("", &headers));
+
+    assert_eq!(
+        authorization,
+        "AWS4-HMAC-SHA256 Credential=AKIDEXAMPLE/20150830/us-east-1/service/aws4_request, \
+         SignedHeaders=host;x-amz-date, \
+         Signature=5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31"
+    );
+}
+
+#[test]
+fn test_sign_v4_get_vanilla_query_order() {
+    let headers = [("x-amz-date", AMZ_DATE)];
+    let authorization = sign_v4(
+        &test_credentials(),
+        &test_request("Param1=value1&Param2=value2", &headers),
+    );
+
+    assert!(authorization
+        .ends_with("Signature=b97d918cfa904a5beff61c982a1b6f458b799221646efd99d3219ec94cdf2500"));
+}
+
+#[test]
+fn test_sign_v4_session_token_is_signed() {
+    let headers = [
+        ("x-amz-date", AMZ_DATE),
+        ("x-amz-security-token", "session-token"),
+    ];
+    let credentials = test_credentials().with_session_token("session-token");
+    let authorization = sign_v4(&credentials, &test_request("", &headers));
+
+    assert!(authorization.contains("SignedHeaders=host;x-amz-date;x-amz-security-token,"));
+    assert!(
+        !authorization.contains("5fa00fa31553b73ebf1942676e86291e8372ff2a2260956d9b8aae1d763fbf31")
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secrets() {
+    let credentials = test_credentials().with_session_token("session-token");
+    let debug = format!("{:?}", credentials);
+
+    assert!(debug.contains("AKIDEXAMPLE"));
+    assert!(!debug.contains("wJalrXUtnFEMI"));
+    assert!(!debug.contains("session-token"));
+}
+
+// =============================================================================
+// Encoding Tests
+// =============================================================================
+
+#[test]
+fn test_decode_name() {
+    assert_eq!(decode_name("\\052.example.com."), "*.example.com.");
+    assert_eq!(decode_name("www.example.com."), "www.example.com.");
+    assert_eq!(decode_name("a\\0"), "a\\0");
+}
@@ -0,0 +1,65 @@
+//! Unit tests for Volcengine module helpers.
+//!
+//! Tests for the `HMAC-SHA256` request signature (checked against signatures
+//! computed independently for the same input).
+
+use manydns::volcengine::api::{sign, Credentials, SigningRequest};
+
+const X_DATE: &str = "20240101T080000Z";
+
+fn test_credentials() -> Credentials {
+    Credentials::new("AKLTexampleaccesskey", "c2VjcmV0LWFjY2Vzcy1rZXk=")
+}
+
+#[test]
+fn test_sign_list_zones() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "POST",
+            host: "open.volcengineapi.com",
+            path: "/",
+            query: "Action=ListZones&Version=2018-08-01",
+            payload: br#"{"PageNumber":1,"PageSize":100}"#,
+            region: "cn-north-1",
+            x_date: X_DATE,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "HMAC-SHA256 Credential=AKLTexampleaccesskey/20240101/cn-north-1/DNS/request, \
+         SignedHeaders=content-type;host;x-content-sha256;x-date, \
+         Signature=8f1d3d39095651357f2b351bfd2d9bf2d209dda11f2818a9650e88a1aac5458f"
+    );
+}
+
+#[test]
+fn test_sign_create_record() {
+    let authorization = sign(
+        &test_credentials(),
+        &SigningRequest {
+            method: "POST",
+            host: "open.volcengineapi.com",
+            path: "/",
+            query: "Action=CreateRecord&Version=2018-08-01",
+            payload: br#"{"Host":"www","Line":"default","TTL":600,"Type":"A","Value":"192.0.2.1","ZID":12345}"#,
+            region: "cn-north-1",
+            x_date: X_DATE,
+        },
+    );
+
+    assert_eq!(
+        authorization,
+        "HMAC-SHA256 Credential=AKLTexampleaccesskey/20240101/cn-north-1/DNS/request, \
+         SignedHeaders=content-type;host;x-content-sha256;x-date, \
+         Signature=3804d6be7f692f2b7a443d15ecc91acc979ea20ebce3dd01f317edbf14169c9f"
+    );
+}
+
+#[test]
+fn test_credentials_debug_redacts_secret() {
+    let debug = format!("{:?}", test_credentials());
+    assert!(debug.contains("AKLTexampleaccesskey"));
+    assert!(!debug.contains("c2VjcmV0LWFjY2Vzcy1rZXk="));
+}